@@ -189,6 +189,53 @@ pub fn system(args: TokenStream, input: TokenStream) -> TokenStream {
             }
         }
 
+        impl<#(#dimensions),*> core::ops::Neg for #struct_name<#(#dimensions),*>
+        where
+            #(#dimensions: typenum::Integer + core::ops::Neg,)*
+            #(<#dimensions as core::ops::Neg>::Output: typenum::Integer,)*
+        {
+            type Output = #struct_name<
+                #(<#dimensions as core::ops::Neg>::Output),*
+            >;
+
+            fn neg(self) -> Self::Output {
+                #struct_name(core::marker::PhantomData)
+            }
+        }
+
+        // Raise a dimension to a type-level integer power (multiply all exponents)
+        impl<#(#dimensions,)* Exp> typenum::Pow<Exp> for #struct_name<#(#dimensions),*>
+        where
+            Exp: typenum::Integer,
+            #(#dimensions: typenum::Integer + core::ops::Mul<Exp>,)*
+            #(<#dimensions as core::ops::Mul<Exp>>::Output: typenum::Integer,)*
+        {
+            type Output = #struct_name<
+                #(<#dimensions as core::ops::Mul<Exp>>::Output),*
+            >;
+
+            fn powi(self, _exp: Exp) -> Self::Output {
+                #struct_name(core::marker::PhantomData)
+            }
+        }
+
+        // Take a type-level integer root of a dimension (divide all exponents),
+        // only where every exponent divides evenly
+        impl<#(#dimensions,)* Exp> typenum::PartialDiv<Exp> for #struct_name<#(#dimensions),*>
+        where
+            Exp: typenum::Integer,
+            #(#dimensions: typenum::Integer + typenum::PartialDiv<Exp>,)*
+            #(<#dimensions as typenum::PartialDiv<Exp>>::Output: typenum::Integer,)*
+        {
+            type Output = #struct_name<
+                #(<#dimensions as typenum::PartialDiv<Exp>>::Output),*
+            >;
+
+            fn partial_div(self, _exp: Exp) -> Self::Output {
+                #struct_name(core::marker::PhantomData)
+            }
+        }
+
         // Simple inherent methods for common operations
         impl<#(#dimensions),*> #struct_name<#(#dimensions),*>
         where
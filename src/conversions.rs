@@ -14,11 +14,13 @@
 ///
 /// ## Key Components
 ///
-/// - **ConvertibleUnit Trait**: Extends Unit trait with conversion capabilities
+/// - **ConvertibleUnit Trait**: Names the base unit a unit converts through
+/// - **ConvertUnit Trait**: Converts between any two units sharing a base unit
+/// - **ConvertIntUnit Trait**: Exact integer conversions from `IntFactor` fractions
 /// - **FromUnit Trait**: Convert from any unit to its base unit
 /// - **IntoUnit Trait**: Convert from base unit to any unit
 /// - **Automatic Generation**: Conversion methods generated by macros
-/// - **Shared Internal Macros**: `__impl_conversion!` and `__impl_matrix_pair!` reduce duplication
+/// - **Shared Internal Macro**: `__impl_conversion!` reduces duplication
 ///
 /// ## Macro Hierarchy
 ///
@@ -28,8 +30,9 @@
 /// - `convert!` - Main macro generating conversions for all numeric types
 /// - `convert_linear!` - Simplified macro for linear conversions (y = ax + b)
 /// - `convert_int!` - Integer conversions using factor syntax
-/// - `convert_int_linear!` - Simplified macro for integer linear conversions
-/// - `convert_matrix!` - Generate all transitive conversions from a base unit
+/// - `convert_int_linear!` - Exact integer conversions (multiply, then divide; no f64)
+/// - `convert_reciprocal!` - Macro for inverse-proportional conversions (y = k / x)
+/// - `convert_log!` - Macro for logarithmic levels with a reference value
 ///
 /// ### Type-Group Macros (Middle layer)
 /// - `convert_float!` - Generates f32 and f64 conversions
@@ -38,12 +41,12 @@
 ///
 /// ### Individual Type Macros (Leaf macros)
 /// - `convert_f32!`, `convert_f64!` - Floating-point implementations
+/// - `convert_f16!`, `convert_bf16!` - Half-precision implementations (`half` feature)
 /// - `convert_i8!` through `convert_i128!` - Signed integer implementations
 /// - `convert_u8!` through `convert_u128!` - Unsigned integer implementations
 ///
 /// ### Internal Shared Macros (Implementation)
 /// - `__impl_conversion!` - Shared conversion implementation for all types
-/// - `__impl_matrix_pair!` - Shared matrix pair generation
 ///
 /// ## Conversion Type Hierarchy
 ///
@@ -60,10 +63,14 @@
 /// convert_linear! (linear conversions - f32, f64 only)
 ///     └── convert_float!
 ///
-/// convert_matrix! (transitive conversions)
-///     └── convert_matrix_float!
-///             ├── convert_matrix_generate_all_pairs_f32! → __impl_matrix_pair!
-///             └── convert_matrix_generate_all_pairs_f64! → __impl_matrix_pair!
+/// convert_reciprocal! (inverse-proportional conversions - f32, f64 only)
+///     └── convert_float!
+///
+/// convert_log! (logarithmic levels - f32, f64 only)
+///     └── convert_float!
+///
+/// convert_int_linear! (exact integer factors - all integer types)
+///     └── __impl_int_factor_conversion! → scale_int
 /// ```
 ///
 /// ## Usage Patterns
@@ -105,21 +112,13 @@
 /// }
 /// ```
 ///
-/// ### Matrix Conversions
+/// ### Unit-to-Unit Conversions
 /// ```rust,ignore
-/// use num_units::{convert, convert_matrix};
+/// use num_units::conversions::ConvertUnit;
+/// use num_units::si::length::{Foot, Inch};
 ///
-/// // Define base conversions first
-/// convert! {
-///     Radian: |unitless| unitless * 2.0 * PI;
-///     Unitless: |radian| radian / (2.0 * PI);
-/// }
-///
-/// // Generate all transitive conversions (f32, f64 only)
-/// convert_matrix! {
-///     Unitless => Revolution, Radian, Degree
-/// }
-/// // This generates: Revolution ↔ Radian, Revolution ↔ Degree, Radian ↔ Degree
+/// // Only Foot ↔ Meter and Inch ↔ Meter are defined; the route goes through Meter
+/// let inches = <Foot as ConvertUnit<Inch>>::convert(1.0); // 12.0
 /// ```
 ///
 /// ## Implementation Details
@@ -150,40 +149,182 @@
 ///    - `convert!` - Standard conversions for all numeric types
 ///    - `convert_linear!` - Simple scaling/offset conversions
 ///    - `convert_int!` - Integer conversions with factor syntax
-///    - `convert_int_linear!` - Simple integer scaling
+///    - `convert_int_linear!` - Exact scaling by a fraction, instead of `convert_linear!`
 ///
 /// 2. **Define a base unit for each dimension**:
 ///    - All other units convert to/from this base
-///    - Use `convert_matrix!` to generate transitive conversions
+///    - Conversions between two other units are routed through the base
+///      by `ConvertUnit`, so they need no definitions of their own
 ///
 /// 3. **Consider type coverage**:
 ///    - `convert!` generates conversions for all numeric types by default
 ///    - Integer conversions always go through f64 for consistency
-///    - Use `convert_matrix!` for transitive conversions (f32, f64 only)
 ///
 /// 4. **Organize conversions hierarchically**:
-///    - Define base ↔ derived conversions first
-///    - Use matrix macro to generate derived ↔ derived conversions
+///    - Define base ↔ derived conversions only
 ///    - Keep complex conversion logic in closures for maintainability
 
-/// Trait for units that can be converted to/from base units
+/// Multiply an integer by `num / den`, rounding half away from zero
+///
+/// The value is widened to i128 and divided before multiplying, so only the
+/// result has to fit. This is the integer-native path behind
+/// `convert_int_linear!` and `ConvertIntUnit`.
+///
+/// # Panics
+/// If the value or the result does not fit i128, or the result does not fit `T`.
+pub fn scale_int<T: num_traits::PrimInt>(value: T, num: u128, den: u128) -> T {
+    let scaled = value.to_i128().and_then(|value| {
+        let num = i128::try_from(num).ok()?;
+        let den = i128::try_from(den).ok()?;
+        let whole = (value / den).checked_mul(num)?;
+        let part = (value % den).checked_mul(num)?;
+        let mut fraction = part / den;
+        if (part % den).unsigned_abs() * 2 >= den.unsigned_abs() {
+            fraction += part.signum();
+        }
+        whole.checked_add(fraction)
+    });
+    scaled
+        .and_then(<T as num_traits::NumCast>::from)
+        .expect("integer unit conversion overflowed the value type")
+}
+
+/// The f64 nearest to `num / den`, for factors given as exact fractions
+///
+/// Computes enough quotient bits by long division to round correctly, so the
+/// result matches the float literal of the same value: `ratio_to_f64(1,
+/// 10u128.pow(24))` is exactly `1e-24`, which `1.0 / 1e24` is not.
+///
+/// # Panics
+/// If either part is zero, or `den` is 2¹²⁷ or more.
+pub const fn ratio_to_f64(num: u128, den: u128) -> f64 {
+    assert!(
+        num != 0 && den != 0 && den < 1 << 127,
+        "unit factor must be a positive fraction"
+    );
+    let (mut quotient, mut remainder) = (num / den, num % den);
+    let mut exponent: i32 = 0;
+    // 56 significant bits leave three below the f64 mantissa for rounding
+    while quotient < 1 << 55 {
+        remainder <<= 1;
+        quotient <<= 1;
+        if remainder >= den {
+            remainder -= den;
+            quotient |= 1;
+        }
+        exponent -= 1;
+    }
+    if remainder != 0 {
+        // Sticky bit: breaks the tie the truncated quotient would fake
+        quotient |= 1;
+    }
+    // Scaling by a power of two is exact for the factors of real units
+    let scale = f64::from_bits(((1023 + exponent) as u64) << 52);
+    quotient as f64 * scale
+}
+
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// `(n1 / d1) / (n2 / d2)` as a reduced fraction, `None` on overflow
+fn divide_ratios(n1: u128, d1: u128, n2: u128, d2: u128) -> Option<(u128, u128)> {
+    let (gn, gd) = (gcd(n1, n2), gcd(d1, d2));
+    let num = (n1 / gn).checked_mul(d2 / gd)?;
+    let den = (d1 / gd).checked_mul(n2 / gn)?;
+    Some((num, den))
+}
+
+/// Trait naming the base unit a unit converts through
 ///
-/// This trait extends the Unit trait with conversion capabilities
-/// using the FromUnit/IntoUnit system.
+/// Implemented by `convert_linear!`, `convert_int_linear!`, `convert_reciprocal!`
+/// and `convert_log!` for each derived unit, and by `quantity!` for the base
+/// unit itself (as its own base). Units declared with `convert!` implement it
+/// by hand.
 pub trait ConvertibleUnit: crate::unit::Unit {
     /// The base unit for this unit's dimension
     type BaseUnit: crate::unit::Unit;
 
-    /// Convert a value in this unit to its base unit
-    fn to_base_unit<V>(value: V) -> V
-    where
-        V: num_traits::Num + Copy + From<f64> + num_traits::NumCast;
+    /// The exact `(NUM, DEN)` of the unit's `IntFactor`, if it has one
+    ///
+    /// Lets generic code such as `Quantity::try_to` take the integer-native
+    /// path where it exists without requiring `IntFactor` of every unit.
+    const INT_FACTOR: Option<(u128, u128)> = None;
+}
+
+/// Conversion between two units that share a base unit
+///
+/// A blanket implementation routes every pair through the base, `Self → base
+/// → To`, so only the conversions to and from the base have to be defined.
+/// Units of different dimensions have different base units and do not match.
+pub trait ConvertUnit<To: crate::unit::Unit, V: num_traits::Num = f64>: crate::unit::Unit {
+    /// Convert a value in this unit to unit `To`
+    fn convert(value: V) -> V;
+}
+
+impl<From, To, V> ConvertUnit<To, V> for From
+where
+    From: ConvertibleUnit,
+    To: ConvertibleUnit<BaseUnit = From::BaseUnit>,
+    V: num_traits::Num,
+    From::BaseUnit: crate::unit::FromUnit<From, V> + crate::unit::FromUnit<To, V>,
+{
+    fn convert(value: V) -> V {
+        let base_value = <From::BaseUnit as crate::unit::FromUnit<From, V>>::to_base(value);
+        <From::BaseUnit as crate::unit::FromUnit<To, V>>::from_base(base_value)
+    }
+}
+
+/// Size of one `From` in units of `To`, for units with constant scale factors
+///
+/// Evaluable in `const` items, e.g. `conversion_factor::<Mile, Foot>()` is
+/// 5280.0. It divides the `UnitFactor` constants of the shared base unit.
+pub const fn conversion_factor<From, To>() -> f64
+where
+    From: ConvertibleUnit,
+    To: ConvertibleUnit<BaseUnit = From::BaseUnit>,
+    From::BaseUnit: crate::unit::UnitFactor<From> + crate::unit::UnitFactor<To>,
+{
+    <From::BaseUnit as crate::unit::UnitFactor<From>>::FACTOR
+        / <From::BaseUnit as crate::unit::UnitFactor<To>>::FACTOR
+}
 
-    /// Convert a value from this unit's base unit to this unit
-    fn from_base_unit<V>(base_value: V) -> V
-    where
-        V: num_traits::Num + Copy + From<f64> + num_traits::NumCast;
+/// Exact conversion between two units with integer factors to a shared base
+///
+/// Where `ConvertUnit` would round to the base unit on the way (1500 μm is 0 m),
+/// this combines both `IntFactor`s into one fraction first: micrometers to
+/// millimeters divides by 1000, rounding half away from zero, without f64.
+pub trait ConvertIntUnit<To: crate::unit::Unit, V: num_traits::PrimInt>: crate::unit::Unit {
+    /// Convert an integer value in this unit to unit `To`
+    ///
+    /// # Panics
+    /// If the result, or the combined factor, overflows.
+    fn convert_int(value: V) -> V;
 }
+
+impl<From, To, V> ConvertIntUnit<To, V> for From
+where
+    From: ConvertibleUnit,
+    To: ConvertibleUnit<BaseUnit = From::BaseUnit>,
+    V: num_traits::PrimInt,
+    From::BaseUnit: crate::unit::IntFactor<From> + crate::unit::IntFactor<To>,
+{
+    fn convert_int(value: V) -> V {
+        use crate::unit::IntFactor;
+        let (num, den) = divide_ratios(
+            <From::BaseUnit as IntFactor<From>>::NUM,
+            <From::BaseUnit as IntFactor<From>>::DEN,
+            <From::BaseUnit as IntFactor<To>>::NUM,
+            <From::BaseUnit as IntFactor<To>>::DEN,
+        )
+        .expect("integer unit conversion factor overflowed");
+        scale_int(value, num, den)
+    }
+}
+
 /// Macro for establishing bidirectional conversion relationships between units
 ///
 /// This macro creates conversion relationships between two units for all numeric types.
@@ -294,6 +435,16 @@ macro_rules! convert_linear {
             $derived: |val| (val - ($b)) / ($a);
             $base: |val| val * ($a) + ($b);
         }
+
+        impl $crate::conversions::ConvertibleUnit for $derived {
+            type BaseUnit = $base;
+        }
+
+        $crate::__convert_fixed!($derived => $base: $a, $b);
+        $crate::__convert_decimal!($derived => $base: $a, $b);
+        $crate::__convert_rational!($derived => $base: $a, $b);
+        $crate::__convert_bigint!($derived => $base: $a, $b);
+        $crate::__convert_complex!($derived => $base: $a, $b);
     };
 
     // Pattern with only a (y = ax)
@@ -302,10 +453,32 @@ macro_rules! convert_linear {
     // So: DerivedUnit = BaseUnit / scale
     //     BaseUnit = DerivedUnit * scale
     ($derived:ident => $base:ident: $a:expr;) => {
+        $crate::convert_linear!(@scale $derived => $base: $a);
+
+        impl $crate::conversions::ConvertibleUnit for $derived {
+            type BaseUnit = $base;
+        }
+    };
+
+    // The scale conversions without `ConvertibleUnit`, shared with
+    // `convert_int_linear!`, which implements it with the exact factor
+    (@scale $derived:ident => $base:ident: $a:expr) => {
+        // The factor goes through the `UnitFactor` constant, so it is evaluated
+        // once at compile time however long the expression
         $crate::convert_float! {
-            $derived: |val| val / ($a);
-            $base: |val| val * ($a);
+            $derived: |val| val / <$base as $crate::unit::UnitFactor<$derived>>::FACTOR;
+            $base: |val| val * <$base as $crate::unit::UnitFactor<$derived>>::FACTOR;
         }
+
+        impl $crate::unit::UnitFactor<$derived> for $base {
+            const FACTOR: f64 = $a;
+        }
+
+        $crate::__convert_fixed!($derived => $base: $a, 0.0);
+        $crate::__convert_decimal!($derived => $base: $a, 0.0);
+        $crate::__convert_rational!($derived => $base: $a, 0.0);
+        $crate::__convert_bigint!($derived => $base: $a, 0.0);
+        $crate::__convert_complex!($derived => $base: $a, 0.0);
     };
 
     // Multiple conversions
@@ -318,42 +491,268 @@ macro_rules! convert_linear {
     };
 }
 
-/// Macro for generating integer linear conversion relationships
+/// Macro for generating exact integer conversion relationships
 ///
-/// This is the integer version of `convert_linear!` which generates
-/// `convert_int!` code for exact integer conversions. Use this when
-/// both the scaling factor and offset (if any) are integers.
+/// This is the exact counterpart of `convert_linear!`. The factor is an exact
+/// fraction of integer literals, `DerivedUnit => BaseUnit: num / den;` meaning
+/// 1 DerivedUnit = num/den BaseUnits (`/ den` may be left out). It generates
+/// `IntFactor` and the `FromUnit` conversions of every primitive integer type,
+/// which multiply, then divide in i128 and round to the nearest integer, so
+/// they never go through f64. Use it instead of a `convert_linear!` entry: the
+/// float and other conversions use the fraction rounded to the nearest f64
+/// (see `ratio_to_f64`), or an f64 factor given after a comma where it has to
+/// match another rounding, e.g. `Microgram => Kilogram: 1 / 1_000_000_000,
+/// MICRO / KILO;`.
 ///
 /// # Examples
 /// ```rust,ignore
 /// use num_units::convert_int_linear;
 ///
-/// // Simple integer scaling: 1 km = 1000 m (kilometer is derived, meter is base)
+/// // 1 km = 1000 m and 1 μm = 1/1000000 m
 /// convert_int_linear! {
 ///     Kilometer => Meter: 1000;
+///     Micrometer => Meter: 1 / 1_000_000;
 /// }
-/// // Generates:
-/// // convert_int! {
-/// //     Kilometer: 1;
-/// //     Meter: 1000;
-/// // }
 /// ```
 #[macro_export]
 macro_rules! convert_int_linear {
-    // Pattern with only scaling factor (integers use numerator/denominator pattern)
-    // DerivedUnit => BaseUnit: scale means DerivedUnit * 1 = BaseUnit * scale
-    ($derived:ident => $base:ident: $scale:expr;) => {
-        $crate::convert_int! {
-            $derived: 1;
-            $base: $scale;
+    ($derived:ident => $base:ident: $num:literal $(, $float:expr)?;) => {
+        $crate::convert_int_linear! {
+            $derived => $base: $num / 1 $(, $float)?;
+        }
+    };
+
+    ($derived:ident => $base:ident: $num:literal / $den:literal;) => {
+        $crate::convert_int_linear! {
+            $derived => $base: $num / $den, $crate::conversions::ratio_to_f64($num, $den);
+        }
+    };
+
+    ($derived:ident => $base:ident: $num:literal / $den:literal, $float:expr;) => {
+        $crate::convert_linear!(@scale $derived => $base: $float);
+
+        impl $crate::conversions::ConvertibleUnit for $derived {
+            type BaseUnit = $base;
+            const INT_FACTOR: Option<(u128, u128)> = Some(($num, $den));
+        }
+
+        impl $crate::unit::IntFactor<$derived> for $base {
+            const NUM: u128 = $num;
+            const DEN: u128 = $den;
         }
+
+        $crate::__impl_int_factor_conversion!(
+            $derived => $base: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+        );
     };
 
     // Multiple conversions
-    ($($derived:ident => $base:ident: $scale:expr;)+) => {
+    ($($derived:ident => $base:ident: $num:literal $(/ $den:literal)? $(, $float:expr)?;)+) => {
         $(
             $crate::convert_int_linear! {
-                $derived => $base: $scale;
+                $derived => $base: $num $(/ $den)? $(, $float)?;
+            }
+        )+
+    };
+}
+
+/// Internal helper generating the `FromUnit` impls of `convert_int_linear!`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_int_factor_conversion {
+    ($derived:ident => $base:ident: $($type:ty),+) => {
+        $(
+            impl $crate::unit::FromUnit<$derived, $type> for $base {
+                fn to_base(value: $type) -> $type {
+                    $crate::conversions::scale_int(
+                        value,
+                        <$base as $crate::unit::IntFactor<$derived>>::NUM,
+                        <$base as $crate::unit::IntFactor<$derived>>::DEN,
+                    )
+                }
+
+                fn from_base(base_value: $type) -> $type {
+                    $crate::conversions::scale_int(
+                        base_value,
+                        <$base as $crate::unit::IntFactor<$derived>>::DEN,
+                        <$base as $crate::unit::IntFactor<$derived>>::NUM,
+                    )
+                }
+            }
+        )+
+    };
+}
+
+/// Macro for generating reciprocal conversion relationships (y = k / x)
+///
+/// Some units measure the inverse of the quantity they belong to, e.g. fuel consumption in
+/// liters per 100 km versus fuel economy in kilometers per liter. A value in such a unit is
+/// inversely proportional to the base unit value, so no scale factor can map one onto the
+/// other. This macro generates conversions of the form `base = k / derived` in both
+/// directions (the relation is its own inverse).
+///
+/// A zero value maps to infinity and vice versa, following IEEE 754 float division.
+///
+/// # Syntax
+/// ```rust,ignore
+/// use num_units::convert_reciprocal;
+///
+/// // DerivedUnit => BaseUnit: k means x DerivedUnits = k / x BaseUnits
+/// convert_reciprocal! {
+///     DerivedUnit => BaseUnit: k;
+/// }
+/// ```
+///
+/// # Generated Code
+/// For `DerivedUnit => BaseUnit: k;`, this generates:
+/// ```rust,ignore
+/// convert_float! {
+///     DerivedUnit: |val| (k) / val; // DerivedUnit = k / BaseUnit
+///     BaseUnit: |val| (k) / val;    // BaseUnit = k / DerivedUnit
+/// }
+/// ```
+///
+/// # Examples
+/// ```rust,ignore
+/// use num_units::convert_reciprocal;
+///
+/// // 5 L/100 km = 1e8 / 5 m/m³ (= 20 km/L)
+/// convert_reciprocal! {
+///     LiterPer100Kilometer => MeterPerCubicMeter: 1.0E8;
+/// }
+/// ```
+#[macro_export]
+macro_rules! convert_reciprocal {
+    ($derived:ident => $base:ident: $k:expr;) => {
+        $crate::convert_float! {
+            $derived: |val| ($k) / val;
+            $base: |val| ($k) / val;
+        }
+
+        impl $crate::conversions::ConvertibleUnit for $derived {
+            type BaseUnit = $base;
+        }
+    };
+
+    // Multiple conversions
+    ($($derived:ident => $base:ident: $k:expr;)+) => {
+        $(
+            $crate::convert_reciprocal! {
+                $derived => $base: $k;
+            }
+        )+
+    };
+}
+
+/// Macro for generating absolute (point) conversions with an offset (y = ax + b)
+///
+/// Scales such as Celsius and Fahrenheit measure temperatures from a different
+/// zero than kelvin. Their `FromUnit` conversions (via `convert_linear!`) handle
+/// temperature differences; this macro adds the `AffineFromUnit` path for
+/// absolute values, used by `Quantity::from_absolute` and `Quantity::to_absolute`.
+/// The conversions are generic over float value types.
+///
+/// # Syntax
+/// ```rust,ignore
+/// use num_units::convert_affine;
+///
+/// // DerivedUnit => BaseUnit: scale, offset means BaseUnit = DerivedUnit * scale + offset
+/// convert_affine! {
+///     DerivedUnit => BaseUnit: scale, offset;
+/// }
+/// ```
+///
+/// # Examples
+/// ```rust,ignore
+/// use num_units::convert_affine;
+///
+/// // K = °C + 273.15 and K = (°F + 459.67) * 5/9
+/// convert_affine! {
+///     DegreeCelsius => Kelvin: 1.0, 273.15;
+///     DegreeFahrenheit => Kelvin: 5.0 / 9.0, 459.67 * 5.0 / 9.0;
+/// }
+/// ```
+#[macro_export]
+macro_rules! convert_affine {
+    ($($derived:ident => $base:ident: $a:expr, $b:expr;)+) => {
+        $(
+            impl<V> $crate::unit::AffineFromUnit<$derived, V> for $base
+            where
+                V: $crate::num_traits::float::FloatCore,
+            {
+                fn to_base_point(value: V) -> V {
+                    let scale: V = $crate::num_traits::NumCast::from($a).unwrap();
+                    let offset: V = $crate::num_traits::NumCast::from($b).unwrap();
+                    value * scale + offset
+                }
+
+                fn from_base_point(base_value: V) -> V {
+                    let scale: V = $crate::num_traits::NumCast::from($a).unwrap();
+                    let offset: V = $crate::num_traits::NumCast::from($b).unwrap();
+                    (base_value - offset) / scale
+                }
+            }
+        )+
+    };
+}
+
+/// Macro for generating conversions for logarithmic level units
+///
+/// Levels such as the decibel, the neper or pH count powers of a log base
+/// relative to a fixed reference value of the base unit. The reference and the
+/// scale are part of the unit: `30 dBm` is always `1 W`. Only positive base
+/// values have a finite level; zero maps to negative infinity and negative
+/// values to NaN. The conversions are float-only and evaluated in f64.
+///
+/// # Syntax
+/// ```rust,ignore
+/// use num_units::convert_log;
+///
+/// // DerivedUnit => BaseUnit: reference, log_base, divisor means
+/// // BaseUnit = reference * log_base^(DerivedUnit / divisor)
+/// convert_log! {
+///     DerivedUnit => BaseUnit: reference, log_base, divisor;
+/// }
+/// ```
+///
+/// # Generated Code
+/// For `DerivedUnit => BaseUnit: r, b, d;`, this generates:
+/// ```rust,ignore
+/// convert_float! {
+///     DerivedUnit: |val| (d) * ln(val / (r)) / ln(b); // DerivedUnit = d * log_b(BaseUnit / r)
+///     BaseUnit: |val| (r) * powf(b, val / (d));       // BaseUnit = r * b^(DerivedUnit / d)
+/// }
+/// ```
+///
+/// # Examples
+/// ```rust,ignore
+/// use num_units::convert_log;
+///
+/// // P = 1 mW * 10^(dBm / 10) and [H⁺] = 1 mol/L * 0.1^pH
+/// convert_log! {
+///     DecibelMilliwatt => Watt: 1.0E-3, 10.0, 10.0;
+///     PotentialOfHydrogen => MolePerCubicMeter: 1.0E3, 0.1, 1.0;
+/// }
+/// ```
+#[macro_export]
+macro_rules! convert_log {
+    ($derived:ident => $base:ident: $reference:expr, $log_base:expr, $divisor:expr;) => {
+        $crate::convert_float! {
+            $derived: |val| ($divisor) * <f64 as $crate::num_traits::Float>::ln(val / ($reference))
+                / <f64 as $crate::num_traits::Float>::ln($log_base);
+            $base: |val| ($reference) * <f64 as $crate::num_traits::Float>::powf($log_base, val / ($divisor));
+        }
+
+        impl $crate::conversions::ConvertibleUnit for $derived {
+            type BaseUnit = $base;
+        }
+    };
+
+    // Multiple conversions
+    ($($derived:ident => $base:ident: $reference:expr, $log_base:expr, $divisor:expr;)+) => {
+        $(
+            $crate::convert_log! {
+                $derived => $base: $reference, $log_base, $divisor;
             }
         )+
     };
@@ -424,6 +823,35 @@ macro_rules! __impl_conversion {
         }
     };
 
+    // Pattern for half-precision floats (convert through f64, round once on the way back)
+    (half: $type:ty, $unit1:ident, $param1:ident, $expr1:expr, $unit2:ident, $param2:ident, $expr2:expr) => {
+        // Forward conversion: $unit2 -> $unit1
+        impl $crate::unit::FromUnit<$unit2, $type> for $unit1 {
+            fn to_base(value: $type) -> $type {
+                let $param2 = <$type>::to_f64(value);
+                <$type>::from_f64($expr1)
+            }
+
+            fn from_base(base_value: $type) -> $type {
+                let $param1 = <$type>::to_f64(base_value);
+                <$type>::from_f64($expr2)
+            }
+        }
+
+        // Reverse conversion: $unit1 -> $unit2
+        impl $crate::unit::FromUnit<$unit1, $type> for $unit2 {
+            fn to_base(value: $type) -> $type {
+                let $param1 = <$type>::to_f64(value);
+                <$type>::from_f64($expr2)
+            }
+
+            fn from_base(base_value: $type) -> $type {
+                let $param2 = <$type>::to_f64(base_value);
+                <$type>::from_f64($expr1)
+            }
+        }
+    };
+
     // Pattern for f64 (native precision)
     (f64: $type:ty, $unit1:ident, $param1:ident, $expr1:expr, $unit2:ident, $param2:ident, $expr2:expr) => {
         // Forward conversion: $unit2 -> $unit1
@@ -605,568 +1033,422 @@ macro_rules! convert_f64 {
     () => {};
 }
 
-/// Macro for all signed integer type conversions
+/// Macro for half-precision type conversions (f16)
 #[macro_export]
-macro_rules! convert_signed {
-    ($($input:tt)*) => {
-        $crate::convert_i8! { $($input)* }
-        $crate::convert_i16! { $($input)* }
-        $crate::convert_i32! { $($input)* }
-        $crate::convert_i64! { $($input)* }
-        $crate::convert_i128! { $($input)* }
+macro_rules! convert_f16 {
+    ($unit1:ident: |$param1:ident| $expr1:expr; $unit2:ident: |$param2:ident| $expr2:expr; $($rest:tt)*) => {
+        $crate::__impl_conversion!(half: $crate::half::f16, $unit1, $param1, $expr1, $unit2, $param2, $expr2);
+        convert_f16! { $($rest)* }
     };
+    () => {};
 }
 
-/// Macro for all unsigned integer type conversions
+/// Macro for bfloat16 type conversions (bf16)
 #[macro_export]
-macro_rules! convert_unsigned {
-    ($($input:tt)*) => {
-        $crate::convert_u8! { $($input)* }
-        $crate::convert_u16! { $($input)* }
-        $crate::convert_u32! { $($input)* }
-        $crate::convert_u64! { $($input)* }
-        $crate::convert_u128! { $($input)* }
+macro_rules! convert_bf16 {
+    ($unit1:ident: |$param1:ident| $expr1:expr; $unit2:ident: |$param2:ident| $expr2:expr; $($rest:tt)*) => {
+        $crate::__impl_conversion!(half: $crate::half::bf16, $unit1, $param1, $expr1, $unit2, $param2, $expr2);
+        convert_bf16! { $($rest)* }
     };
+    () => {};
 }
 
-/// Macro for all floating-point type conversions
+/// Half-precision conversions for `convert_float!`, only emitted with the
+/// `half` feature
+#[cfg(feature = "half")]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! convert_float {
+macro_rules! __convert_half {
     ($($input:tt)*) => {
-        $crate::convert_f32! { $($input)* }
-        $crate::convert_f64! { $($input)* }
+        $crate::convert_f16! { $($input)* }
+        $crate::convert_bf16! { $($input)* }
     };
 }
 
-/// Macro for establishing bidirectional conversion relationships between units (all integer types)
-///
-/// This macro creates conversion relationships for all integer types (signed and unsigned).
-/// It converts the integer factor syntax into f64-based closures and delegates to
-/// convert_signed! and convert_unsigned!.
-///
-/// # Syntax
-/// ```rust,ignore
-/// use num_units::convert_int;
-///
-/// // Define conversions using integer factors
-/// convert_int! {
-///     TargetUnit: 1;    // 1 base = 1 target
-///     SourceUnit: 10;   // 1 base = 10 source
-/// }
-/// ```
-///
-/// # Examples
-/// ```rust,ignore
-/// use num_units::convert_int;
-///
-/// // Define conversion with exact integer factors
-/// convert_int! {
-///     Millimeter: 1000;  // 1 meter = 1000 millimeters
-///     Meter: 1;          // 1 meter = 1 meter (base unit)
-/// }
-/// ```
-///
-/// This delegates to convert_signed! and convert_unsigned! which generate
-/// implementations for all integer types using the shared __impl_conversion! macro.
+#[cfg(not(feature = "half"))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! convert_int {
-    // Process pairs of units with integer conversion factors
-    ($unit1:ident: $factor1:expr; $unit2:ident: $factor2:expr; $($rest:tt)*) => {
-        // Convert integer factors to f64-based closures and delegate to signed/unsigned macros
-        $crate::convert_signed! {
-            $unit1: |val| val * ($factor2 as f64) / ($factor1 as f64);
-            $unit2: |val| val * ($factor1 as f64) / ($factor2 as f64);
-        }
-
-        $crate::convert_unsigned! {
-            $unit1: |val| val * ($factor2 as f64) / ($factor1 as f64);
-            $unit2: |val| val * ($factor1 as f64) / ($factor2 as f64);
-        }
-
-        // Process remaining conversions recursively
-        convert_int! { $($rest)* }
-    };
-
-    // Base case: no more conversions to process
-    () => {};
+macro_rules! __convert_half {
+    ($($input:tt)*) => {};
 }
 
-// 🎩✨ CONVERSION MATRIX MAGIC ✨🎩
-// Define conversions from one base unit to all others, then generate all combinations!
-
-/// Internal helper for matrix pair generation
-/// This reduces duplication across all the type-specific matrix pair generators
+/// Fixed-point conversions for `convert_linear!`, only emitted with the `fixed`
+/// feature. The factors are `FixedFactor` constants; the arithmetic lives in
+/// `quantity::fixed`.
+#[cfg(feature = "fixed")]
 #[doc(hidden)]
 #[macro_export]
-macro_rules! __impl_matrix_pair {
-    ($from:ty, $base:ty, $to:ty, $type:ty) => {
-        // Generate $from -> $to via $base (transitive conversion)
-        impl $crate::unit::FromUnit<$from, $type> for $to {
-            fn to_base(value: $type) -> $type {
-                // Chain: $from -> $base -> $to
-                let base_value = <$base as $crate::unit::FromUnit<$from, $type>>::to_base(value);
-                <$to as $crate::unit::FromUnit<$base, $type>>::to_base(base_value)
-            }
-
-            fn from_base(base_value: $type) -> $type {
-                // Chain: $to -> $base -> $from
-                let base_intermediate =
-                    <$to as $crate::unit::FromUnit<$base, $type>>::from_base(base_value);
-                <$base as $crate::unit::FromUnit<$from, $type>>::from_base(base_intermediate)
-            }
+macro_rules! __convert_fixed {
+    ($derived:ident => $base:ident: $a:expr, $b:expr) => {
+        impl $crate::quantity::fixed::FixedFactor<$derived> for $base {
+            const FACTOR: $crate::quantity::fixed::Factor =
+                $crate::quantity::fixed::Factor::new($a, $b);
         }
 
-        // Generate $to -> $from via $base (reverse transitive conversion)
-        impl $crate::unit::FromUnit<$to, $type> for $from {
-            fn to_base(value: $type) -> $type {
-                // Chain: $to -> $base -> $from
-                let base_value = <$base as $crate::unit::FromUnit<$to, $type>>::to_base(value);
-                <$from as $crate::unit::FromUnit<$base, $type>>::to_base(base_value)
+        $crate::__convert_fixed!(
+            @each $derived, $base;
+            FixedI8, FixedI16, FixedI32, FixedI64, FixedI128,
+            FixedU8, FixedU16, FixedU32, FixedU64, FixedU128
+        );
+    };
+    (@each $derived:ident, $base:ident; $($fixed:ident),+) => {
+        $(
+            impl<Frac> $crate::unit::FromUnit<$derived, $crate::fixed::$fixed<Frac>> for $base
+            where
+                $crate::fixed::$fixed<Frac>: $crate::num_traits::Num + $crate::fixed::traits::Fixed,
+            {
+                fn to_base(value: $crate::fixed::$fixed<Frac>) -> $crate::fixed::$fixed<Frac> {
+                    $crate::quantity::fixed::to_base(
+                        value,
+                        &<$base as $crate::quantity::fixed::FixedFactor<$derived>>::FACTOR,
+                    )
+                }
+
+                fn from_base(base_value: $crate::fixed::$fixed<Frac>) -> $crate::fixed::$fixed<Frac> {
+                    $crate::quantity::fixed::from_base(
+                        base_value,
+                        &<$base as $crate::quantity::fixed::FixedFactor<$derived>>::FACTOR,
+                    )
+                }
             }
 
-            fn from_base(base_value: $type) -> $type {
-                // Chain: $from -> $base -> $to
-                let base_intermediate =
-                    <$from as $crate::unit::FromUnit<$base, $type>>::from_base(base_value);
-                <$base as $crate::unit::FromUnit<$to, $type>>::from_base(base_intermediate)
+            impl<Frac> $crate::unit::FromUnit<$base, $crate::fixed::$fixed<Frac>> for $derived
+            where
+                $crate::fixed::$fixed<Frac>: $crate::num_traits::Num + $crate::fixed::traits::Fixed,
+            {
+                fn to_base(value: $crate::fixed::$fixed<Frac>) -> $crate::fixed::$fixed<Frac> {
+                    $crate::quantity::fixed::from_base(
+                        value,
+                        &<$base as $crate::quantity::fixed::FixedFactor<$derived>>::FACTOR,
+                    )
+                }
+
+                fn from_base(base_value: $crate::fixed::$fixed<Frac>) -> $crate::fixed::$fixed<Frac> {
+                    $crate::quantity::fixed::to_base(
+                        base_value,
+                        &<$base as $crate::quantity::fixed::FixedFactor<$derived>>::FACTOR,
+                    )
+                }
             }
-        }
-    };
-}
-
-/// Generate a single bidirectional transitive conversion pair for i8
-#[macro_export]
-macro_rules! convert_matrix_generate_pair_i8 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, i8);
-    };
-}
-
-/// Generate a single bidirectional transitive conversion pair for i16
-#[macro_export]
-macro_rules! convert_matrix_generate_pair_i16 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, i16);
-    };
-}
-
-/// Generate a single bidirectional transitive conversion pair for i32
-#[macro_export]
-macro_rules! convert_matrix_generate_pair_i32 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, i32);
-    };
-}
-
-/// Generate a single bidirectional transitive conversion pair for i64
-#[macro_export]
-macro_rules! convert_matrix_generate_pair_i64 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, i64);
-    };
-}
-
-/// Generate a single bidirectional transitive conversion pair for i128
-#[macro_export]
-macro_rules! convert_matrix_generate_pair_i128 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, i128);
-    };
-}
-
-/// Generate a single bidirectional transitive conversion pair for u8
-#[macro_export]
-macro_rules! convert_matrix_generate_pair_u8 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, u8);
-    };
-}
-
-/// Generate a single bidirectional transitive conversion pair for u16
-#[macro_export]
-macro_rules! convert_matrix_generate_pair_u16 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, u16);
-    };
-}
-
-/// Generate a single bidirectional transitive conversion pair for u32
-#[macro_export]
-macro_rules! convert_matrix_generate_pair_u32 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, u32);
-    };
-}
-
-/// Generate a single bidirectional transitive conversion pair for u64
-#[macro_export]
-macro_rules! convert_matrix_generate_pair_u64 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, u64);
-    };
-}
-
-/// Generate a single bidirectional transitive conversion pair for u128
-#[macro_export]
-macro_rules! convert_matrix_generate_pair_u128 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, u128);
-    };
-}
-
-/// Generate a single bidirectional transitive conversion pair for f32
-#[macro_export]
-macro_rules! convert_matrix_generate_pair_f32 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, f32);
+        )+
     };
 }
 
-/// Generate a single bidirectional transitive conversion pair for f64
+#[cfg(not(feature = "fixed"))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! convert_matrix_generate_pair_f64 {
-    ($from:ty, $base:ty, $to:ty) => {
-        $crate::__impl_matrix_pair!($from, $base, $to, f64);
-    };
+macro_rules! __convert_fixed {
+    ($($input:tt)*) => {};
 }
 
-/// Helper macro to generate all pairwise transitive conversions for i8
+/// `rust_decimal::Decimal` conversions for `convert_linear!`, only emitted with
+/// the `rust_decimal` feature. The factors are `DecimalFactor` constants; the
+/// arithmetic lives in `quantity::rust_decimal`.
+#[cfg(feature = "rust_decimal")]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_i8 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_i8!($first, $base, $rest);
-        )*
+macro_rules! __convert_decimal {
+    ($derived:ident => $base:ident: $a:expr, $b:expr) => {
+        impl $crate::quantity::rust_decimal::DecimalFactor<$derived> for $base {
+            const FACTOR: $crate::quantity::rust_decimal::Factor =
+                $crate::quantity::rust_decimal::Factor::new($a, $b);
+        }
 
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_i8!($base; $($rest),*);
-    };
+        impl $crate::unit::FromUnit<$derived, $crate::rust_decimal::Decimal> for $base {
+            fn to_base(value: $crate::rust_decimal::Decimal) -> $crate::rust_decimal::Decimal {
+                $crate::quantity::rust_decimal::to_base(
+                    value,
+                    &<$base as $crate::quantity::rust_decimal::DecimalFactor<$derived>>::FACTOR,
+                )
+            }
 
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
-}
+            fn from_base(
+                base_value: $crate::rust_decimal::Decimal,
+            ) -> $crate::rust_decimal::Decimal {
+                $crate::quantity::rust_decimal::from_base(
+                    base_value,
+                    &<$base as $crate::quantity::rust_decimal::DecimalFactor<$derived>>::FACTOR,
+                )
+            }
+        }
 
-/// Helper macro to generate all pairwise transitive conversions for i16
-#[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_i16 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_i16!($first, $base, $rest);
-        )*
+        impl $crate::unit::FromUnit<$base, $crate::rust_decimal::Decimal> for $derived {
+            fn to_base(value: $crate::rust_decimal::Decimal) -> $crate::rust_decimal::Decimal {
+                $crate::quantity::rust_decimal::from_base(
+                    value,
+                    &<$base as $crate::quantity::rust_decimal::DecimalFactor<$derived>>::FACTOR,
+                )
+            }
 
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_i16!($base; $($rest),*);
+            fn from_base(
+                base_value: $crate::rust_decimal::Decimal,
+            ) -> $crate::rust_decimal::Decimal {
+                $crate::quantity::rust_decimal::to_base(
+                    base_value,
+                    &<$base as $crate::quantity::rust_decimal::DecimalFactor<$derived>>::FACTOR,
+                )
+            }
+        }
     };
-
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
 }
 
-/// Helper macro to generate all pairwise transitive conversions for i32
+#[cfg(not(feature = "rust_decimal"))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_i32 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_i32!($first, $base, $rest);
-        )*
-
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_i32!($base; $($rest),*);
-    };
-
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
+macro_rules! __convert_decimal {
+    ($($input:tt)*) => {};
 }
 
-/// Helper macro to generate all pairwise transitive conversions for i64
+/// `num_rational::Ratio` conversions for `convert_linear!`, only emitted with the
+/// `num-rational` feature. The factors are `RationalFactor` constants; the
+/// arithmetic lives in `quantity::num_rational`.
+#[cfg(feature = "num-rational")]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_i64 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_i64!($first, $base, $rest);
-        )*
+macro_rules! __convert_rational {
+    ($derived:ident => $base:ident: $a:expr, $b:expr) => {
+        impl $crate::quantity::num_rational::RationalFactor<$derived> for $base {
+            const FACTOR: $crate::quantity::num_rational::Factor =
+                $crate::quantity::num_rational::Factor::new(
+                    $a,
+                    $b,
+                    <$derived as $crate::conversions::ConvertibleUnit>::INT_FACTOR,
+                );
+        }
 
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_i64!($base; $($rest),*);
-    };
+        impl<T> $crate::unit::FromUnit<$derived, $crate::num_rational::Ratio<T>> for $base
+        where
+            T: $crate::quantity::num_rational::RationalInteger,
+        {
+            fn to_base(value: $crate::num_rational::Ratio<T>) -> $crate::num_rational::Ratio<T> {
+                $crate::quantity::num_rational::to_base(
+                    value,
+                    &<$base as $crate::quantity::num_rational::RationalFactor<$derived>>::FACTOR,
+                )
+            }
 
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
-}
+            fn from_base(
+                base_value: $crate::num_rational::Ratio<T>,
+            ) -> $crate::num_rational::Ratio<T> {
+                $crate::quantity::num_rational::from_base(
+                    base_value,
+                    &<$base as $crate::quantity::num_rational::RationalFactor<$derived>>::FACTOR,
+                )
+            }
+        }
 
-/// Helper macro to generate all pairwise transitive conversions for i128
-#[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_i128 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_i128!($first, $base, $rest);
-        )*
+        impl<T> $crate::unit::FromUnit<$base, $crate::num_rational::Ratio<T>> for $derived
+        where
+            T: $crate::quantity::num_rational::RationalInteger,
+        {
+            fn to_base(value: $crate::num_rational::Ratio<T>) -> $crate::num_rational::Ratio<T> {
+                $crate::quantity::num_rational::from_base(
+                    value,
+                    &<$base as $crate::quantity::num_rational::RationalFactor<$derived>>::FACTOR,
+                )
+            }
 
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_i128!($base; $($rest),*);
+            fn from_base(
+                base_value: $crate::num_rational::Ratio<T>,
+            ) -> $crate::num_rational::Ratio<T> {
+                $crate::quantity::num_rational::to_base(
+                    base_value,
+                    &<$base as $crate::quantity::num_rational::RationalFactor<$derived>>::FACTOR,
+                )
+            }
+        }
     };
-
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
 }
 
-/// Helper macro to generate all pairwise transitive conversions for u8
+#[cfg(not(feature = "num-rational"))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_u8 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_u8!($first, $base, $rest);
-        )*
-
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_u8!($base; $($rest),*);
-    };
-
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
+macro_rules! __convert_rational {
+    ($($input:tt)*) => {};
 }
 
-/// Helper macro to generate all pairwise transitive conversions for u16
+/// `BigInt`/`BigUint` conversions for `convert_linear!`, only emitted with the
+/// `num-bigint` feature. Like the primitive integers, results are rounded. The
+/// factors are the `RationalFactor` constants of `__convert_rational!`.
+#[cfg(feature = "num-bigint")]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_u16 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_u16!($first, $base, $rest);
-        )*
-
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_u16!($base; $($rest),*);
+macro_rules! __convert_bigint {
+    ($derived:ident => $base:ident: $a:expr, $b:expr) => {
+        $crate::__convert_bigint!(@impl $crate::num_bigint::BigInt, $derived => $base: $a, $b);
+        $crate::__convert_bigint!(@impl $crate::num_bigint::BigUint, $derived => $base: $a, $b);
     };
+    (@impl $type:ty, $derived:ident => $base:ident: $a:expr, $b:expr) => {
+        impl $crate::unit::FromUnit<$derived, $type> for $base {
+            fn to_base(value: $type) -> $type {
+                $crate::quantity::num_bigint::to_base(
+                    value,
+                    &<$base as $crate::quantity::num_rational::RationalFactor<$derived>>::FACTOR,
+                )
+            }
 
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
-}
+            fn from_base(base_value: $type) -> $type {
+                $crate::quantity::num_bigint::from_base(
+                    base_value,
+                    &<$base as $crate::quantity::num_rational::RationalFactor<$derived>>::FACTOR,
+                )
+            }
+        }
 
-/// Helper macro to generate all pairwise transitive conversions for u32
-#[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_u32 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_u32!($first, $base, $rest);
-        )*
+        impl $crate::unit::FromUnit<$base, $type> for $derived {
+            fn to_base(value: $type) -> $type {
+                $crate::quantity::num_bigint::from_base(
+                    value,
+                    &<$base as $crate::quantity::num_rational::RationalFactor<$derived>>::FACTOR,
+                )
+            }
 
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_u32!($base; $($rest),*);
+            fn from_base(base_value: $type) -> $type {
+                $crate::quantity::num_bigint::to_base(
+                    base_value,
+                    &<$base as $crate::quantity::num_rational::RationalFactor<$derived>>::FACTOR,
+                )
+            }
+        }
     };
-
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
 }
 
-/// Helper macro to generate all pairwise transitive conversions for u64
+#[cfg(not(feature = "num-bigint"))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_u64 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_u64!($first, $base, $rest);
-        )*
-
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_u64!($base; $($rest),*);
-    };
-
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
+macro_rules! __convert_bigint {
+    ($($input:tt)*) => {};
 }
 
-/// Helper macro to generate all pairwise transitive conversions for u128
+/// `num_complex::Complex` conversions for `convert_linear!`, only emitted with
+/// the `num-complex` feature. The arithmetic lives in `quantity::complex`.
+#[cfg(feature = "num-complex")]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_u128 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_u128!($first, $base, $rest);
-        )*
-
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_u128!($base; $($rest),*);
-    };
+macro_rules! __convert_complex {
+    ($derived:ident => $base:ident: $a:expr, $b:expr) => {
+        impl<T> $crate::unit::FromUnit<$derived, $crate::num_complex::Complex<T>> for $base
+        where
+            T: $crate::num_traits::float::FloatCore,
+        {
+            fn to_base(value: $crate::num_complex::Complex<T>) -> $crate::num_complex::Complex<T> {
+                $crate::quantity::complex::to_base(value, $a, $b)
+            }
 
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
-}
+            fn from_base(
+                base_value: $crate::num_complex::Complex<T>,
+            ) -> $crate::num_complex::Complex<T> {
+                $crate::quantity::complex::from_base(base_value, $a, $b)
+            }
+        }
 
-/// Helper macro to generate all pairwise transitive conversions for f32
-#[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_f32 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_f32!($first, $base, $rest);
-        )*
+        impl<T> $crate::unit::FromUnit<$base, $crate::num_complex::Complex<T>> for $derived
+        where
+            T: $crate::num_traits::float::FloatCore,
+        {
+            fn to_base(value: $crate::num_complex::Complex<T>) -> $crate::num_complex::Complex<T> {
+                $crate::quantity::complex::from_base(value, $a, $b)
+            }
 
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_f32!($base; $($rest),*);
+            fn from_base(
+                base_value: $crate::num_complex::Complex<T>,
+            ) -> $crate::num_complex::Complex<T> {
+                $crate::quantity::complex::to_base(base_value, $a, $b)
+            }
+        }
     };
-
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
 }
 
-/// Helper macro to generate all pairwise transitive conversions for f64
+#[cfg(not(feature = "num-complex"))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! convert_matrix_generate_all_pairs_f64 {
-    ($base:ty; $first:ty $(, $rest:ty)*) => {
-        // Generate conversions from $first to all remaining targets
-        $(
-            $crate::convert_matrix_generate_pair_f64!($first, $base, $rest);
-        )*
-
-        // Recursively process remaining targets (but don't include $first again)
-        $crate::convert_matrix_generate_all_pairs_f64!($base; $($rest),*);
-    };
-
-    // Base case: only one or no targets left
-    ($base:ty; $single:ty) => {};
-    ($base:ty;) => {};
+macro_rules! __convert_complex {
+    ($($input:tt)*) => {};
 }
 
-/// Conversion matrix macro for signed integers - generates all possible unit conversions from a base unit
+/// Macro for all signed integer type conversions
 #[macro_export]
-macro_rules! convert_matrix_signed {
-    ($base:ty => $($target:ty),* $(,)?) => {
-        // Generate all transitive conversions: Target1 ↔ Target2 via Base
-        $crate::convert_matrix_generate_all_pairs_i8!($base; $($target),*);
-        $crate::convert_matrix_generate_all_pairs_i16!($base; $($target),*);
-        $crate::convert_matrix_generate_all_pairs_i32!($base; $($target),*);
-        $crate::convert_matrix_generate_all_pairs_i64!($base; $($target),*);
-        $crate::convert_matrix_generate_all_pairs_i128!($base; $($target),*);
+macro_rules! convert_signed {
+    ($($input:tt)*) => {
+        $crate::convert_i8! { $($input)* }
+        $crate::convert_i16! { $($input)* }
+        $crate::convert_i32! { $($input)* }
+        $crate::convert_i64! { $($input)* }
+        $crate::convert_i128! { $($input)* }
     };
 }
 
-/// Conversion matrix macro for unsigned integers - generates all possible unit conversions from a base unit
+/// Macro for all unsigned integer type conversions
 #[macro_export]
-macro_rules! convert_matrix_unsigned {
-    ($base:ty => $($target:ty),* $(,)?) => {
-        // Generate all transitive conversions: Target1 ↔ Target2 via Base
-        $crate::convert_matrix_generate_all_pairs_u8!($base; $($target),*);
-        $crate::convert_matrix_generate_all_pairs_u16!($base; $($target),*);
-        $crate::convert_matrix_generate_all_pairs_u32!($base; $($target),*);
-        $crate::convert_matrix_generate_all_pairs_u64!($base; $($target),*);
-        $crate::convert_matrix_generate_all_pairs_u128!($base; $($target),*);
+macro_rules! convert_unsigned {
+    ($($input:tt)*) => {
+        $crate::convert_u8! { $($input)* }
+        $crate::convert_u16! { $($input)* }
+        $crate::convert_u32! { $($input)* }
+        $crate::convert_u64! { $($input)* }
+        $crate::convert_u128! { $($input)* }
     };
 }
 
-/// Conversion matrix macro for floating-point types - generates all possible unit conversions from a base unit
+/// Macro for all floating-point type conversions
 #[macro_export]
-macro_rules! convert_matrix_float {
-    ($base:ty => $($target:ty),* $(,)?) => {
-        // Generate all transitive conversions: Target1 ↔ Target2 via Base
-        $crate::convert_matrix_generate_all_pairs_f32!($base; $($target),*);
-        $crate::convert_matrix_generate_all_pairs_f64!($base; $($target),*);
+macro_rules! convert_float {
+    ($($input:tt)*) => {
+        $crate::convert_f32! { $($input)* }
+        $crate::convert_f64! { $($input)* }
+        $crate::__convert_half! { $($input)* }
     };
 }
 
-/// Conversion matrix macro - generates all possible unit conversions from a base unit
-///
-/// This macro takes a base unit and a list of target units, then automatically
-/// generates all possible transitive conversions between every pair via the base unit.
-/// You must define the direct base ↔ target conversions using `convert!` first.
+/// Macro for establishing bidirectional conversion relationships between units (all integer types)
 ///
-/// This is the top-level macro that calls the floating-point matrix generation.
+/// This macro creates conversion relationships for all integer types (signed and unsigned).
+/// It converts the integer factor syntax into f64-based closures and delegates to
+/// convert_signed! and convert_unsigned!.
 ///
 /// # Syntax
 /// ```rust,ignore
-/// use num_units::{units, convert, convert_matrix, convert_float};
-///
-/// // First define units
-/// units! {
-///     BaseUnit: "bu", "base unit";
-///     TargetUnit1: "t1", "target unit 1";
-///     TargetUnit2: "t2", "target unit 2";
-///     TargetUnit3: "t3", "target unit 3";
-/// }
+/// use num_units::convert_int;
 ///
-/// // Then use the matrix
-/// convert_matrix! {
-///     BaseUnit => TargetUnit1, TargetUnit2, TargetUnit3
+/// // Define conversions using integer factors
+/// convert_int! {
+///     TargetUnit: 1;    // 1 base = 1 target
+///     SourceUnit: 10;   // 1 base = 10 source
 /// }
 /// ```
 ///
-/// # Requirements
-/// Before using the matrix, you must define direct conversions using `convert!`:
+/// # Examples
 /// ```rust,ignore
-/// use num_units::{units, convert, convert_float};
-///
-/// // Define units
-/// units! {
-///     BaseUnit: "bu", "base unit";
-///     TargetUnit1: "t1", "target unit 1";
-/// }
+/// use num_units::convert_int;
 ///
-/// // Define conversions
-/// convert! {
-///     TargetUnit1: |base| base * 2.0;
-///     BaseUnit: |target1| target1 / 2.0;
+/// // Define conversion with exact integer factors
+/// convert_int! {
+///     Millimeter: 1000;  // 1 meter = 1000 millimeters
+///     Meter: 1;          // 1 meter = 1 meter (base unit)
 /// }
 /// ```
 ///
-/// # Generated Conversions
-/// The matrix automatically generates transitive conversions:
-/// - TargetUnit1 ↔ TargetUnit2 (via BaseUnit)
-/// - TargetUnit1 ↔ TargetUnit3 (via BaseUnit)
-/// - TargetUnit2 ↔ TargetUnit3 (via BaseUnit)
-///
-/// This generates conversions for f32 and f64 types by default.
-/// Use `convert_matrix_signed!`, `convert_matrix_unsigned!`, etc. for specific type sets.
-///
-/// # Example
-/// ```rust,ignore
-/// use num_units::{units, convert, convert_matrix, convert_float};
-/// use std::f64::consts::PI;
-///
-/// // First define units
-/// units! {
-///     Unitless: "", "unitless";
-///     Revolution: "rev", "revolution";
-///     Radian: "rad", "radian";
-///     Degree: "deg", "degree";
-/// }
-///
-/// // First define direct conversions
-/// convert! {
-///     Revolution: |unitless| unitless;
-///     Unitless: |revolution| revolution;
-/// }
-/// convert! {
-///     Radian: |unitless| unitless * 2.0 * PI;
-///     Unitless: |radian| radian / (2.0 * PI);
-/// }
-///
-/// // Then generate all transitive conversions
-/// convert_matrix! {
-///     Unitless => Revolution, Radian, Degree
-/// }
-/// ```
-/// This generates Revolution ↔ Radian, Revolution ↔ Degree, Radian ↔ Degree automatically!
+/// This delegates to convert_signed! and convert_unsigned! which generate
+/// implementations for all integer types using the shared __impl_conversion! macro.
 #[macro_export]
-macro_rules! convert_matrix {
-    ($base:ty => $($target:ty),* $(,)?) => {
-        // $crate::convert_matrix_float! { $base => $($target),* }
+macro_rules! convert_int {
+    // Process pairs of units with integer conversion factors
+    ($unit1:ident: $factor1:expr; $unit2:ident: $factor2:expr; $($rest:tt)*) => {
+        // Convert integer factors to f64-based closures and delegate to signed/unsigned macros
+        $crate::convert_signed! {
+            $unit1: |val| val * ($factor2 as f64) / ($factor1 as f64);
+            $unit2: |val| val * ($factor1 as f64) / ($factor2 as f64);
+        }
+
+        $crate::convert_unsigned! {
+            $unit1: |val| val * ($factor2 as f64) / ($factor1 as f64);
+            $unit2: |val| val * ($factor1 as f64) / ($factor2 as f64);
+        }
+
+        // Process remaining conversions recursively
+        convert_int! { $($rest)* }
     };
+
+    // Base case: no more conversions to process
+    () => {};
 }
@@ -1,7 +1,9 @@
-#![recursion_limit = "512"]
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "schemars")]
+extern crate alloc;
+
 #[macro_use]
 pub mod prefix;
 #[macro_use]
@@ -17,9 +19,38 @@ pub mod conversions;
 
 pub mod si;
 
+#[cfg(feature = "serde")]
+pub mod serde;
+
 #[cfg(test)]
 #[macro_use]
 pub mod uom_comparison;
 
 // Re-export num_traits for convenience
 pub use num_traits;
+
+// Crates named by the exported macros, reached through `$crate::` so that
+// crates invoking them need no dependency of their own
+#[doc(hidden)]
+pub use paste;
+#[doc(hidden)]
+pub use typenum;
+
+#[cfg(feature = "fixed")]
+#[doc(hidden)]
+pub use fixed;
+#[cfg(feature = "half")]
+#[doc(hidden)]
+pub use half;
+#[cfg(feature = "num-bigint")]
+#[doc(hidden)]
+pub use num_bigint;
+#[cfg(feature = "num-complex")]
+#[doc(hidden)]
+pub use num_complex;
+#[cfg(feature = "num-rational")]
+#[doc(hidden)]
+pub use num_rational;
+#[cfg(feature = "rust_decimal")]
+#[doc(hidden)]
+pub use rust_decimal;
@@ -1,11 +1,10 @@
 use super::Quantity;
-use core::ops::Add;
-use num_traits::Num;
+use core::ops::{Add, AddAssign};
 
 // Addition: same dimension and scale
 impl<V, D, S> Add for Quantity<V, D, S>
 where
-    V: Num + Add<Output = V>,
+    V: Add<Output = V>,
 {
     type Output = Self;
 
@@ -14,6 +13,36 @@ where
     }
 }
 
+// By reference (`&a + &b`), for storage types that are not `Copy`
+impl<'a, 'b, V, D, S> Add<&'b Quantity<V, D, S>> for &'a Quantity<V, D, S>
+where
+    &'a V: Add<&'b V, Output = V>,
+{
+    type Output = Quantity<V, D, S>;
+
+    fn add(self, rhs: &'b Quantity<V, D, S>) -> Self::Output {
+        Quantity::from_base(&self.value + &rhs.value)
+    }
+}
+
+impl<V, D, S> AddAssign for Quantity<V, D, S>
+where
+    V: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl<'b, V, D, S> AddAssign<&'b Quantity<V, D, S>> for Quantity<V, D, S>
+where
+    V: AddAssign<&'b V>,
+{
+    fn add_assign(&mut self, rhs: &'b Quantity<V, D, S>) {
+        self.value += &rhs.value;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::si::length::Length;
@@ -26,4 +55,35 @@ mod tests {
         let sum = length1 + length2;
         assert_eq!(*sum.base(), 8.0);
     }
+
+    #[test]
+    fn test_reference_addition() {
+        use crate::quantity::Quantity;
+        use core::ops::Add;
+
+        // Generic code can add borrowed quantities without `Copy`
+        fn total<V, D, S>(items: &[Quantity<V, D, S>], zero: Quantity<V, D, S>) -> Quantity<V, D, S>
+        where
+            for<'a> &'a V: Add<&'a V, Output = V>,
+        {
+            items.iter().fold(zero, |sum, item| &sum + item)
+        }
+
+        let lengths = [1.0, 2.0, 4.5].map(Length::from_base);
+        assert_eq!(*(&lengths[0] + &lengths[1]).base(), 3.0);
+        assert_eq!(
+            *total::<f64, _, _>(&lengths, Length::from_base(0.0)).base(),
+            7.5
+        );
+    }
+
+    #[test]
+    fn test_add_assign() {
+        let mut total = Length::from_base(0.0);
+        for step in [1.5, 2.0, 0.5].map(Length::from_base) {
+            total += step;
+        }
+        total += &Length::from_base(1.0);
+        assert_eq!(*total.base(), 5.0);
+    }
 }
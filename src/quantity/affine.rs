@@ -0,0 +1,39 @@
+use super::{BaseUnitOf, Quantity};
+use crate::unit::{AffineFromUnit, Unit};
+
+impl<V, D, S> Quantity<V, D, S>
+where
+    S: BaseUnitOf<D>,
+{
+    /// Create a quantity from an absolute value on an offset scale, e.g. a
+    /// temperature reading in °C
+    ///
+    /// Unlike [`from`](Self::from), which converts differences, this applies
+    /// the scale's offset (see `convert_affine!`).
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::temperature::{DegreeCelsius, DegreeFahrenheit, Temperature};
+    ///
+    /// let room = Temperature::from_absolute::<DegreeCelsius>(20.0);
+    /// assert_eq!(*room.base(), 293.15);
+    /// assert_eq!(room.to_absolute::<DegreeFahrenheit>(), 68.0);
+    /// ```
+    pub fn from_absolute<U>(value: V) -> Self
+    where
+        U: Unit,
+        S::BaseUnit: AffineFromUnit<U, V>,
+    {
+        Self::from_base(<S::BaseUnit as AffineFromUnit<U, V>>::to_base_point(value))
+    }
+
+    /// Get the absolute value of this quantity on an offset scale
+    pub fn to_absolute<U>(&self) -> V
+    where
+        U: Unit,
+        V: Clone,
+        S::BaseUnit: AffineFromUnit<U, V>,
+    {
+        <S::BaseUnit as AffineFromUnit<U, V>>::from_base_point(self.value.clone())
+    }
+}
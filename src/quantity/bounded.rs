@@ -0,0 +1,41 @@
+use super::Quantity;
+use num_traits::{Bounded, Num};
+
+// num-traits support for Bounded; LowerBounded/UpperBounded follow from the
+// blanket impls in num-traits
+impl<V, D, S> Bounded for Quantity<V, D, S>
+where
+    V: Num + Bounded,
+{
+    fn min_value() -> Self {
+        Quantity::from_base(V::min_value())
+    }
+
+    fn max_value() -> Self {
+        Quantity::from_base(V::max_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use crate::si::time::Time;
+    use num_traits::Bounded;
+
+    fn saturate<T: Bounded + PartialOrd + Copy>(value: T, limit: T) -> T {
+        if value > limit { T::max_value() } else { value }
+    }
+
+    #[test]
+    fn test_bounded_integers() {
+        assert_eq!(*<Time<u16> as Bounded>::max_value().base(), u16::MAX);
+        assert_eq!(*<Time<i32> as Bounded>::min_value().base(), i32::MIN);
+    }
+
+    #[test]
+    fn test_bounded_in_generic_code() {
+        let limit = Length::from_base(100.0);
+        assert_eq!(*saturate(Length::from_base(5.0), limit).base(), 5.0);
+        assert_eq!(*saturate(Length::from_base(500.0), limit).base(), f64::MAX);
+    }
+}
@@ -0,0 +1,28 @@
+use super::Quantity;
+use num_traits::{CheckedAdd, Num};
+
+// Checked addition: same dimension and scale, `None` on overflow
+impl<V, D, S> CheckedAdd for Quantity<V, D, S>
+where
+    V: Num + CheckedAdd,
+{
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        self.value.checked_add(&rhs.value).map(Self::from_base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use num_traits::CheckedAdd;
+
+    #[test]
+    fn test_checked_add() {
+        let a = Length::from_base(250_u8);
+        assert_eq!(
+            a.checked_add(&Length::from_base(5)),
+            Some(Length::from_base(255))
+        );
+        assert_eq!(a.checked_add(&Length::from_base(6)), None);
+    }
+}
@@ -0,0 +1,52 @@
+use super::Quantity;
+use core::ops::Sub;
+use num_traits::{CheckedDiv, Num};
+
+// As with multiplication, `num_traits::CheckedDiv` needs `Div<Self, Output = Self>`,
+// so division is offered as inherent methods. Both return `None` for a zero
+// divisor and for `MIN / -1` on signed integers.
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: Num + CheckedDiv,
+{
+    /// Divide by a scalar, returning `None` on division by zero or overflow
+    pub fn checked_div_scalar(&self, scalar: V) -> Option<Self> {
+        self.value.checked_div(&scalar).map(Self::from_base)
+    }
+
+    /// Divide by another quantity, returning `None` on division by zero or overflow
+    ///
+    /// The dimensions subtract as with the `/` operator.
+    pub fn checked_div_quantity<D2>(
+        &self,
+        rhs: &Quantity<V, D2, S>,
+    ) -> Option<Quantity<V, <D as Sub<D2>>::Output, S>>
+    where
+        D: Sub<D2>,
+    {
+        self.value.checked_div(&rhs.value).map(Quantity::from_base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use crate::si::time::Time;
+    use crate::si::velocity::Velocity;
+
+    #[test]
+    fn test_checked_div_scalar() {
+        let length = Length::from_base(9_i32);
+        assert_eq!(length.checked_div_scalar(3), Some(Length::from_base(3)));
+        assert_eq!(length.checked_div_scalar(0), None);
+        assert_eq!(Length::from_base(i32::MIN).checked_div_scalar(-1), None);
+    }
+
+    #[test]
+    fn test_checked_div_quantity() {
+        let distance = Length::from_base(120_u32);
+        let speed: Option<Velocity<u32>> = distance.checked_div_quantity(&Time::from_base(4));
+        assert_eq!(speed, Some(Velocity::from_base(30)));
+        assert_eq!(distance.checked_div_quantity(&Time::from_base(0)), None);
+    }
+}
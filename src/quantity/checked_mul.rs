@@ -0,0 +1,50 @@
+use super::Quantity;
+use core::ops::Add;
+use num_traits::{CheckedMul, Num};
+
+// `num_traits::CheckedMul` requires `Mul<Self, Output = Self>`, which does not
+// hold for quantities (Length * Length is an Area), so checked multiplication is
+// provided as inherent methods instead.
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: Num + CheckedMul,
+{
+    /// Multiply by a scalar, returning `None` on overflow
+    pub fn checked_mul_scalar(&self, scalar: V) -> Option<Self> {
+        self.value.checked_mul(&scalar).map(Self::from_base)
+    }
+
+    /// Multiply by another quantity, returning `None` on overflow
+    ///
+    /// The dimensions add up as with the `*` operator.
+    pub fn checked_mul_quantity<D2>(
+        &self,
+        rhs: &Quantity<V, D2, S>,
+    ) -> Option<Quantity<V, <D as Add<D2>>::Output, S>>
+    where
+        D: Add<D2>,
+    {
+        self.value.checked_mul(&rhs.value).map(Quantity::from_base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::area::Area;
+    use crate::si::length::Length;
+
+    #[test]
+    fn test_checked_mul_scalar() {
+        let length = Length::from_base(100_i8);
+        assert_eq!(length.checked_mul_scalar(1), Some(length));
+        assert_eq!(length.checked_mul_scalar(2), None);
+    }
+
+    #[test]
+    fn test_checked_mul_quantity() {
+        let side = Length::from_base(200_u16);
+        let area: Option<Area<u16>> = side.checked_mul_quantity(&Length::from_base(300));
+        assert_eq!(area, Some(Area::from_base(60_000)));
+        assert_eq!(side.checked_mul_quantity(&Length::from_base(400)), None);
+    }
+}
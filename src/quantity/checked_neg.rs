@@ -0,0 +1,28 @@
+use super::Quantity;
+use num_traits::{CheckedNeg, Num};
+
+// Checked negation: `None` for unsigned non-zero values and `MIN` of signed types
+impl<V, D, S> CheckedNeg for Quantity<V, D, S>
+where
+    V: Num + CheckedNeg,
+{
+    fn checked_neg(&self) -> Option<Self> {
+        self.value.checked_neg().map(Self::from_base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use num_traits::CheckedNeg;
+
+    #[test]
+    fn test_checked_neg() {
+        assert_eq!(
+            Length::from_base(7_i16).checked_neg(),
+            Some(Length::from_base(-7))
+        );
+        assert_eq!(Length::from_base(i16::MIN).checked_neg(), None);
+        assert_eq!(Length::from_base(3_u16).checked_neg(), None);
+    }
+}
@@ -0,0 +1,28 @@
+use super::Quantity;
+use num_traits::{CheckedSub, Num};
+
+// Checked subtraction: same dimension and scale, `None` on overflow
+impl<V, D, S> CheckedSub for Quantity<V, D, S>
+where
+    V: Num + CheckedSub,
+{
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.value.checked_sub(&rhs.value).map(Self::from_base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::time::Time;
+    use num_traits::CheckedSub;
+
+    #[test]
+    fn test_checked_sub() {
+        let elapsed = Time::from_base(10_u32);
+        assert_eq!(
+            elapsed.checked_sub(&Time::from_base(4)),
+            Some(Time::from_base(6))
+        );
+        assert_eq!(elapsed.checked_sub(&Time::from_base(11)), None);
+    }
+}
@@ -0,0 +1,74 @@
+use super::Quantity;
+
+// Clamping compares base values like `PartialOrd`; NaN inputs pass through
+// unchanged, following `num_traits::clamp`.
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: PartialOrd,
+{
+    /// Restrict this quantity to the range `[min, max]`
+    ///
+    /// Works for float-backed quantities as well as `Ord` ones. Panics in debug
+    /// builds if `min > max`.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::velocity::{KilometerPerHour, Velocity};
+    ///
+    /// let limit = Velocity::from::<KilometerPerHour>(50.0);
+    /// let command = Velocity::from::<KilometerPerHour>(64.0).clamp(-limit, limit);
+    /// assert_eq!(command, limit);
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self {
+            value: num_traits::clamp(self.value, min.value, max.value),
+            ..self
+        }
+    }
+
+    /// Restrict this quantity to be at least `min`
+    pub fn clamp_min(self, min: Self) -> Self {
+        Self {
+            value: num_traits::clamp_min(self.value, min.value),
+            ..self
+        }
+    }
+
+    /// Restrict this quantity to be at most `max`
+    pub fn clamp_max(self, max: Self) -> Self {
+        Self {
+            value: num_traits::clamp_max(self.value, max.value),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::power::Power;
+    use crate::si::temperature::Temperature;
+
+    #[test]
+    fn test_clamp() {
+        let min = Power::from_base(0.0);
+        let max = Power::from_base(1500.0);
+        assert_eq!(Power::from_base(-20.0).clamp(min, max), min);
+        assert_eq!(Power::from_base(2000.0).clamp(min, max), max);
+        assert_eq!(
+            Power::from_base(750.0).clamp(min, max),
+            Power::from_base(750.0)
+        );
+        assert!(Power::from_base(f64::NAN).clamp(min, max).is_nan());
+    }
+
+    #[test]
+    fn test_clamp_one_sided() {
+        let floor = Temperature::from_base(273_i32);
+        assert_eq!(Temperature::from_base(250).clamp_min(floor), floor);
+        assert_eq!(Temperature::from_base(300).clamp_max(floor), floor);
+        assert_eq!(
+            Temperature::from_base(300).clamp_min(floor),
+            Temperature::from_base(300)
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use super::Quantity;
+use ::num_complex::Complex;
+use num_traits::float::FloatCore;
+use num_traits::{Float, Num};
+
+// Complex-valued quantities such as phasor currents or impedances. Unit
+// conversions for `Complex<T>` are generated by `convert_linear!` (through
+// `__convert_complex!`); the real factor scales both parts, and an offset only
+// shifts the real part.
+
+/// Convert a value in a derived unit to the base unit: `value * factor + offset`
+#[doc(hidden)]
+pub fn to_base<T: FloatCore>(value: Complex<T>, scale: f64, offset: f64) -> Complex<T> {
+    value * factor::<T>(scale) + factor::<T>(offset)
+}
+
+/// Convert a base-unit value to the derived unit: `(value - offset) / factor`
+#[doc(hidden)]
+pub fn from_base<T: FloatCore>(value: Complex<T>, scale: f64, offset: f64) -> Complex<T> {
+    (value - factor::<T>(offset)) / factor::<T>(scale)
+}
+
+fn factor<T: FloatCore>(value: f64) -> T {
+    T::from(value).unwrap_or_else(T::nan)
+}
+
+impl<T, D, S> Quantity<Complex<T>, D, S>
+where
+    T: Clone + Num,
+{
+    /// Build a complex quantity from its real and imaginary parts
+    pub fn from_parts(re: Quantity<T, D, S>, im: Quantity<T, D, S>) -> Self {
+        Self::from_base(Complex::new(re.value, im.value))
+    }
+
+    /// The real part, e.g. the resistance of an impedance
+    pub fn re(&self) -> Quantity<T, D, S> {
+        Quantity::from_base(self.value.re.clone())
+    }
+
+    /// The imaginary part, e.g. the reactance of an impedance
+    pub fn im(&self) -> Quantity<T, D, S> {
+        Quantity::from_base(self.value.im.clone())
+    }
+
+    /// The complex conjugate
+    pub fn conj(&self) -> Self
+    where
+        T: core::ops::Neg<Output = T>,
+    {
+        Self::from_base(self.value.conj())
+    }
+}
+
+impl<T, D, S> Quantity<Complex<T>, D, S>
+where
+    T: Float,
+{
+    /// Build a phasor from its magnitude and phase angle in radians
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::current::Current;
+    ///
+    /// let current = Current::from_polar(Current::from_base(2.0), 0.5);
+    /// assert_eq!(*current.norm().base(), 2.0);
+    /// ```
+    pub fn from_polar(magnitude: Quantity<T, D, S>, phase: T) -> Self {
+        let (sin, cos) = phase.sin_cos();
+        Self::from_base(Complex::new(magnitude.value * cos, magnitude.value * sin))
+    }
+
+    /// The magnitude (amplitude of a phasor)
+    pub fn norm(&self) -> Quantity<T, D, S> {
+        Quantity::from_base(self.value.re.hypot(self.value.im))
+    }
+
+    /// The phase angle in radians, in `(-π, π]`
+    pub fn arg(&self) -> T {
+        self.value.im.atan2(self.value.re)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::current::{Current, Milliampere};
+    use crate::si::power::{Kilowatt, Power};
+    use ::num_complex::{Complex, Complex32, Complex64};
+    use core::f64::consts::FRAC_PI_2;
+
+    fn assert_close(actual: Complex64, expected: Complex64) {
+        assert!(
+            (actual - expected).norm_sqr() < 1e-18,
+            "{actual} != {expected}"
+        );
+    }
+
+    #[test]
+    fn test_unit_conversions() {
+        let current = Current::from::<Milliampere>(Complex64::new(300.0, -400.0));
+        assert_close(*current.base(), Complex::new(0.3, -0.4));
+        assert_close(current.to::<Milliampere>(), Complex::new(300.0, -400.0));
+        let power = Power::from::<Kilowatt>(Complex32::new(1.5, 2.0));
+        assert_eq!(*power.base(), Complex32::new(1500.0, 2000.0));
+    }
+
+    #[test]
+    fn test_parts_and_polar() {
+        let current = Current::from_parts(Current::from_base(3.0), Current::from_base(4.0));
+        assert_eq!(*current.re().base(), 3.0);
+        assert_eq!(*current.im().base(), 4.0);
+        assert_eq!(*current.norm().base(), 5.0);
+        assert_eq!(*current.conj().im().base(), -4.0);
+
+        let quadrature = Current::from_polar(Current::from_base(2.0), FRAC_PI_2);
+        assert_close(*quadrature.base(), Complex::new(0.0, 2.0));
+        assert!((quadrature.arg() - FRAC_PI_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ac_impedance() {
+        // Apparent power S = V * conj(I), so V = S / conj(I) and Z = V / I
+        let current = Current::from_base(Complex64::new(2.0, -2.0));
+        let apparent = Power::from_base(Complex64::new(100.0, 50.0));
+        let voltage = apparent / current.conj();
+        let impedance = voltage / current;
+        assert_close(*voltage.base(), Complex::new(37.5, -12.5));
+        assert_close(*impedance.base(), Complex::new(12.5, 6.25));
+        // Resistive and reactive parts of the load
+        assert!((*impedance.re().base() - 12.5).abs() < 1e-12);
+        assert!((*impedance.im().base() - 6.25).abs() < 1e-12);
+    }
+}
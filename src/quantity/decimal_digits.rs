@@ -0,0 +1,359 @@
+// Conversion factors are declared as `f64` constants. Value types that can hold
+// decimal fractions exactly (`Decimal`, `Ratio`) do not take their binary value
+// but the shortest decimal that round-trips to the same `f64`, so factors
+// written as decimal literals (0.3048, 1e-3, 1e24) stay exact, while ratios
+// such as 5.0 / 9.0 carry f64 precision.
+//
+// The digits are those `{:e}` prints, found with the Dragon4 shortest mode of
+// `core`'s float formatting. It is a `const fn` here so the factors of every
+// unit are computed once, at compile time.
+
+/// A finite f64 as the shortest decimal that round-trips to it:
+/// `digits * 10^exponent`, negated if `negative`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct DecimalDigits {
+    pub negative: bool,
+    pub digits: u64,
+    pub exponent: i16,
+}
+
+impl DecimalDigits {
+    /// The shortest round-trip decimal of `value`, `None` if it is not finite
+    pub const fn of(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        let bits = value.to_bits();
+        let negative = bits >> 63 != 0;
+        let biased = ((bits >> 52) & 0x7ff) as i16;
+        let fraction = bits & ((1 << 52) - 1);
+        if biased == 0 && fraction == 0 {
+            return Some(Self {
+                negative,
+                digits: 0,
+                exponent: 0,
+            });
+        }
+        // value = mant * 2^exp, with the half-way points to the neighbouring
+        // f64 at (mant - minus) and (mant + plus) in the same units
+        let (mant, exp) = if biased == 0 {
+            (fraction << 1, -1075)
+        } else {
+            (fraction | 1 << 52, biased - 1075)
+        };
+        let inclusive = mant & 1 == 0;
+        let (mant, minus, plus, exp) = if biased == 0 {
+            (mant, 1, 1, exp)
+        } else if mant == 1 << 52 {
+            // The next f64 down is half as far away as the next one up
+            (mant << 2, 1, 2, exp - 2)
+        } else {
+            (mant << 1, 1, 1, exp - 1)
+        };
+        let (digits, exponent) = shortest(mant, minus, plus, exp, inclusive);
+        Some(Self {
+            negative,
+            digits,
+            exponent,
+        })
+    }
+}
+
+/// Dragon4 in shortest mode: returns `(digits, exponent)` with trailing zeros
+/// stripped
+const fn shortest(mant: u64, minus: u64, plus: u64, exp: i16, inclusive: bool) -> (u64, i16) {
+    // 10^(k-1) <= mant * 2^exp < 10^(k+1)
+    let nbits = 64 - (mant + plus - 1).leading_zeros() as i64;
+    let mut k = (((nbits + exp as i64) * 1_292_913_986) >> 32) as i16;
+
+    let mut mant = Big::from_u64(mant);
+    let mut minus = Big::from_u64(minus);
+    let mut plus = Big::from_u64(plus);
+    let mut scale = Big::from_u64(1);
+    if exp < 0 {
+        scale = scale.mul_pow2(-exp as u32);
+    } else {
+        mant = mant.mul_pow2(exp as u32);
+        minus = minus.mul_pow2(exp as u32);
+        plus = plus.mul_pow2(exp as u32);
+    }
+    if k >= 0 {
+        scale = scale.mul_pow10(k as u32);
+    } else {
+        mant = mant.mul_pow10(-k as u32);
+        minus = minus.mul_pow10(-k as u32);
+        plus = plus.mul_pow10(-k as u32);
+    }
+    // Fix up the estimate so that mant / scale < 1 and the first digit is the
+    // integer part of mant * 10 / scale
+    if below(&scale, &mant.add(&plus), inclusive) {
+        k += 1;
+    } else {
+        mant = mant.mul_small(10);
+        minus = minus.mul_small(10);
+        plus = plus.mul_small(10);
+    }
+
+    let mut digits: u64 = 0;
+    let mut count: i16 = 0;
+    let (down, up) = loop {
+        let mut digit = 0;
+        while mant.cmp(&scale) >= 0 {
+            mant = mant.sub(&scale);
+            digit += 1;
+        }
+        digits = digits * 10 + digit;
+        count += 1;
+        // Stop as soon as the digits so far, rounded down or up, fall within
+        // the round-trip interval
+        let down = below(&mant, &minus, inclusive);
+        let up = below(&scale, &mant.add(&plus), inclusive);
+        if down || up {
+            break (down, up);
+        }
+        mant = mant.mul_small(10);
+        minus = minus.mul_small(10);
+        plus = plus.mul_small(10);
+    };
+    if up && (!down || mant.mul_pow2(1).cmp(&scale) >= 0) {
+        digits += 1;
+    }
+
+    let mut exponent = k - count;
+    while digits.is_multiple_of(10) {
+        digits /= 10;
+        exponent += 1;
+    }
+    (digits, exponent)
+}
+
+/// `a < b`, or `a <= b` if the interval bound is `inclusive`
+const fn below(a: &Big, b: &Big, inclusive: bool) -> bool {
+    let order = a.cmp(b);
+    order < 0 || (inclusive && order == 0)
+}
+
+/// Number of 32-bit limbs, enough for any f64 scaled by powers of two and ten
+/// (the same bound `core` uses)
+const LIMBS: usize = 40;
+
+/// Little-endian unsigned big integer for `const` evaluation
+#[derive(Clone, Copy)]
+struct Big {
+    limbs: [u32; LIMBS],
+    len: usize,
+}
+
+impl Big {
+    const fn from_u64(value: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = value as u32;
+        limbs[1] = (value >> 32) as u32;
+        let len = if limbs[1] != 0 {
+            2
+        } else if limbs[0] != 0 {
+            1
+        } else {
+            0
+        };
+        Self { limbs, len }
+    }
+
+    const fn mul_small(mut self, factor: u32) -> Self {
+        let mut carry: u64 = 0;
+        let mut i = 0;
+        while i < self.len {
+            let product = self.limbs[i] as u64 * factor as u64 + carry;
+            self.limbs[i] = product as u32;
+            carry = product >> 32;
+            i += 1;
+        }
+        if carry != 0 {
+            self.limbs[self.len] = carry as u32;
+            self.len += 1;
+        }
+        self
+    }
+
+    const fn mul_pow2(mut self, bits: u32) -> Self {
+        if self.len == 0 {
+            return self;
+        }
+        let words = (bits / 32) as usize;
+        let bits = bits % 32;
+        // Shift whole limbs, then the remaining bits, from the top down
+        let mut i = self.len;
+        while i > 0 {
+            i -= 1;
+            self.limbs[i + words] = self.limbs[i];
+        }
+        let mut i = 0;
+        while i < words {
+            self.limbs[i] = 0;
+            i += 1;
+        }
+        self.len += words;
+        if bits > 0 {
+            let top = self.limbs[self.len - 1] >> (32 - bits);
+            let mut i = self.len - 1;
+            while i > words {
+                self.limbs[i] = self.limbs[i] << bits | self.limbs[i - 1] >> (32 - bits);
+                i -= 1;
+            }
+            self.limbs[words] <<= bits;
+            if top != 0 {
+                self.limbs[self.len] = top;
+                self.len += 1;
+            }
+        }
+        self
+    }
+
+    const fn mul_pow10(mut self, mut exponent: u32) -> Self {
+        while exponent >= 9 {
+            self = self.mul_small(1_000_000_000);
+            exponent -= 9;
+        }
+        if exponent > 0 {
+            self = self.mul_small(10_u32.pow(exponent));
+        }
+        self
+    }
+
+    const fn add(&self, other: &Big) -> Big {
+        let mut sum = *self;
+        let len = if self.len > other.len {
+            self.len
+        } else {
+            other.len
+        };
+        let mut carry = 0;
+        let mut i = 0;
+        while i < len {
+            let total = self.limbs[i] as u64 + other.limbs[i] as u64 + carry;
+            sum.limbs[i] = total as u32;
+            carry = total >> 32;
+            i += 1;
+        }
+        sum.len = len;
+        if carry != 0 {
+            sum.limbs[len] = carry as u32;
+            sum.len += 1;
+        }
+        sum
+    }
+
+    /// `self - other`, which must not be negative
+    const fn sub(&self, other: &Big) -> Big {
+        let mut difference = *self;
+        let mut borrow = 0;
+        let mut i = 0;
+        while i < self.len {
+            let total = self.limbs[i] as i64 - other.limbs[i] as i64 - borrow;
+            difference.limbs[i] = total as u32;
+            borrow = (total < 0) as i64;
+            i += 1;
+        }
+        while difference.len > 0 && difference.limbs[difference.len - 1] == 0 {
+            difference.len -= 1;
+        }
+        difference
+    }
+
+    /// -1, 0 or 1 as `self` is less than, equal to or greater than `other`
+    const fn cmp(&self, other: &Big) -> i8 {
+        if self.len != other.len {
+            return if self.len < other.len { -1 } else { 1 };
+        }
+        let mut i = self.len;
+        while i > 0 {
+            i -= 1;
+            if self.limbs[i] != other.limbs[i] {
+                return if self.limbs[i] < other.limbs[i] {
+                    -1
+                } else {
+                    1
+                };
+            }
+        }
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DecimalDigits;
+
+    /// The digits and exponent `{:e}` prints for `value`
+    fn formatted(value: f64) -> DecimalDigits {
+        let text = format!("{value:e}");
+        let (mantissa, exponent) = text.split_once('e').unwrap();
+        let negative = mantissa.starts_with('-');
+        let mantissa = mantissa.trim_start_matches('-');
+        let decimals = mantissa.split_once('.').map_or(0, |(_, tail)| tail.len());
+        let mut digits: u64 = mantissa.replace('.', "").parse().unwrap();
+        let mut exponent = exponent.parse::<i16>().unwrap() - decimals as i16;
+        while digits != 0 && digits % 10 == 0 {
+            digits /= 10;
+            exponent += 1;
+        }
+        DecimalDigits {
+            negative,
+            digits,
+            exponent: if digits == 0 { 0 } else { exponent },
+        }
+    }
+
+    #[test]
+    fn test_factor_digits() {
+        let digits = |value| DecimalDigits::of(value).map(|d| (d.negative, d.digits, d.exponent));
+        assert_eq!(digits(0.3048), Some((false, 3048, -4)));
+        assert_eq!(digits(1e24), Some((false, 1, 24)));
+        assert_eq!(digits(-2.5), Some((true, 25, -1)));
+        assert_eq!(digits(5.0 / 9.0), Some((false, 5_555_555_555_555_556, -16)));
+        assert_eq!(digits(0.0), Some((false, 0, 0)));
+        assert_eq!(digits(f64::NAN), None);
+        assert_eq!(digits(f64::INFINITY), None);
+    }
+
+    #[test]
+    fn test_matches_float_formatting() {
+        let specials = [
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            -f64::MIN_POSITIVE,
+            5e-324,
+            2.2250738585072009e-308,
+            1e-72,
+            1.0000000000000003e-72,
+            1e72,
+            9_007_199_254_740_993.0,
+            0.1,
+            0.3,
+            1.0 / 3.0,
+            123_456.789,
+        ];
+        for value in specials {
+            assert_eq!(
+                DecimalDigits::of(value),
+                Some(formatted(value)),
+                "{value:e}"
+            );
+        }
+        // A fixed linear congruential sequence of bit patterns
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..20_000 {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let value = f64::from_bits(state);
+            if value.is_finite() {
+                assert_eq!(
+                    DecimalDigits::of(value),
+                    Some(formatted(value)),
+                    "{value:e}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use super::{BaseUnitOf, Quantity};
+use crate::unit::{FromUnit, Unit};
+use core::fmt;
+use core::marker::PhantomData;
+use num_traits::ToPrimitive;
+use num_traits::float::FloatCore;
+
+/// Formatter adapter returned by [`Quantity::display`]
+///
+/// Holds the value already converted to the unit `U` and prints it followed by
+/// `U::ABBREVIATION`. Precision and sign apply to the value; width, fill and
+/// alignment pad the whole text.
+#[derive(Clone, Copy, Debug)]
+pub struct QuantityDisplay<V, U> {
+    value: V,
+    _unit: PhantomData<U>,
+}
+
+impl<V, U> fmt::Display for QuantityDisplay<V, U>
+where
+    V: fmt::Display,
+    U: Unit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_with_symbol(f, &self.value, U::ABBREVIATION)
+    }
+}
+
+/// Formatter adapter returned by [`Quantity::display_long`]
+///
+/// Prints the value followed by the unit's singular name for one and its
+/// plural name otherwise. Precision and sign apply to the value; width, fill
+/// and alignment pad the whole text.
+#[derive(Clone, Copy, Debug)]
+pub struct LongDisplay<V, U> {
+    value: V,
+    _unit: PhantomData<U>,
+}
+
+impl<V, U> fmt::Display for LongDisplay<V, U>
+where
+    V: fmt::Display + ToPrimitive,
+    U: Unit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // English uses the singular for exactly one (and minus one) only:
+        // "1 meter", but "0 meters" and "1.5 meters"
+        let one = self.value.to_f64().map(FloatCore::abs) == Some(1.0);
+        write_quantity(f, &self.value, &if one { U::SINGULAR } else { U::PLURAL })
+    }
+}
+
+/// ASCII rendering of a unit abbreviation, e.g. `um` for `µm`, `m/s^2` for
+/// `m/s²` and `Ohm*m` for `Ω·m`
+///
+/// Every quantity formatter switches to it with the alternate flag, so
+/// `format!("{:#}", q)` is safe for serial consoles and legacy systems.
+/// Runs of superscripts become an exponent after `^` and runs of subscripts an
+/// index after `_`; symbols without a usual spelling fall back to `?`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AsciiSymbol<'a>(pub &'a str);
+
+impl fmt::Display for AsciiSymbol<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut in_superscript = false;
+        let mut in_subscript = false;
+        for c in self.0.chars() {
+            if c.is_ascii() {
+                in_superscript = false;
+                in_subscript = false;
+                fmt::Write::write_char(f, c)?;
+                continue;
+            }
+            if let Some(digit) = superscript(c) {
+                if !in_superscript {
+                    f.write_str("^")?;
+                }
+                (in_superscript, in_subscript) = (true, false);
+                fmt::Write::write_char(f, digit)?;
+                continue;
+            }
+            if let Some(digit) = subscript(c) {
+                if !in_subscript {
+                    f.write_str("_")?;
+                }
+                (in_superscript, in_subscript) = (false, true);
+                fmt::Write::write_char(f, digit)?;
+                continue;
+            }
+            (in_superscript, in_subscript) = (false, false);
+            f.write_str(match c {
+                'µ' | 'μ' => "u",
+                'Ω' => "Ohm",
+                '·' | '⋅' => "*",
+                '°' => "deg",
+                'Å' => "Angstrom",
+                'é' => "e",
+                'ε' => "eps",
+                'ħ' => "hbar",
+                '☉' => "sun",
+                '⊕' => "earth",
+                '‰' => "permille",
+                '′' => "'",
+                '″' => "\"",
+                '\u{f8ff}' => "apple",
+                _ => "?",
+            })?;
+        }
+        Ok(())
+    }
+}
+
+fn superscript(c: char) -> Option<char> {
+    match c {
+        '⁰' => Some('0'),
+        '¹' => Some('1'),
+        '²' => Some('2'),
+        '³' => Some('3'),
+        '⁴'..='⁹' => char::from_u32(c as u32 - '⁴' as u32 + '4' as u32),
+        '⁻' => Some('-'),
+        _ => None,
+    }
+}
+
+fn subscript(c: char) -> Option<char> {
+    match c {
+        '₀'..='₉' => char::from_u32(c as u32 - '₀' as u32 + '0' as u32),
+        'ₕ' => Some('h'),
+        _ => None,
+    }
+}
+
+/// Write a value and a unit abbreviation through [`write_quantity`], the
+/// abbreviation in ASCII if the formatter has the alternate flag
+pub(crate) fn write_with_symbol(
+    f: &mut fmt::Formatter<'_>,
+    value: &dyn fmt::Display,
+    abbreviation: &str,
+) -> fmt::Result {
+    if f.alternate() {
+        write_quantity(f, value, &AsciiSymbol(abbreviation))
+    } else {
+        write_quantity(f, value, &abbreviation)
+    }
+}
+
+/// Write a value and its unit separated by a space
+///
+/// Precision and the `+` flag apply to the value; width, fill and alignment
+/// pad the whole text through [`pad_whole`], so quantities line up in tables
+/// whatever their unit. With the `0` flag the value is zero-padded to the
+/// width the unit leaves, e.g. `{:+08.1}` gives `+003.0 m`.
+pub(crate) fn write_quantity(
+    f: &mut fmt::Formatter<'_>,
+    value: &dyn fmt::Display,
+    unit: &dyn fmt::Display,
+) -> fmt::Result {
+    let (precision, plus) = (f.precision(), f.sign_plus());
+    let zeros = match f.width() {
+        Some(width) if f.sign_aware_zero_pad() => {
+            width.saturating_sub(1 + measure(|w| write!(w, "{unit}"))?)
+        }
+        _ => 0,
+    };
+    pad_whole(f, |w| {
+        match (precision, plus) {
+            (Some(precision), true) => write!(w, "{value:+0zeros$.precision$}")?,
+            (Some(precision), false) => write!(w, "{value:0zeros$.precision$}")?,
+            (None, true) => write!(w, "{value:+0zeros$}")?,
+            (None, false) => write!(w, "{value:0zeros$}")?,
+        }
+        write!(w, " {unit}")
+    })
+}
+
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: num_traits::Num + Clone,
+{
+    /// Display this quantity in a specific unit
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::length::{Kilometer, Length};
+    ///
+    /// let distance = Length::from_base(3200.0);
+    /// assert_eq!(format!("{:.1}", distance.display::<Kilometer>()), "3.2 km");
+    /// ```
+    pub fn display<U>(&self) -> QuantityDisplay<V, U>
+    where
+        U: Unit,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: FromUnit<U, V>,
+    {
+        QuantityDisplay {
+            value: self.to::<U>(),
+            _unit: PhantomData,
+        }
+    }
+
+    /// Display this quantity in a specific unit with the unit's full name
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::length::{Length, Meter};
+    ///
+    /// assert_eq!(format!("{}", Length::from_base(1.0).display_long::<Meter>()), "1 meter");
+    /// assert_eq!(format!("{}", Length::from_base(2.5).display_long::<Meter>()), "2.5 meters");
+    /// ```
+    pub fn display_long<U>(&self) -> LongDisplay<V, U>
+    where
+        U: Unit,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: FromUnit<U, V>,
+    {
+        LongDisplay {
+            value: self.to::<U>(),
+            _unit: PhantomData,
+        }
+    }
+}
+
+/// Write `render`'s output padded to the formatter's width as one unit
+///
+/// For formatters that print several parts, a value and its unit or the
+/// fields of a time, where forwarding the flags to a single value would not
+/// line up in a table. Honors fill and alignment, right-aligning by default
+/// like numbers do. `render` is called twice when a width is set, first to
+/// measure its output without allocating.
+pub(crate) fn pad_whole<F>(f: &mut fmt::Formatter<'_>, render: F) -> fmt::Result
+where
+    F: Fn(&mut dyn fmt::Write) -> fmt::Result,
+{
+    let Some(width) = f.width() else {
+        return render(f);
+    };
+    let padding = width.saturating_sub(measure(&render)?);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    render(f)?;
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
+
+/// The number of characters `render` writes
+fn measure<F>(render: F) -> Result<usize, fmt::Error>
+where
+    F: Fn(&mut dyn fmt::Write) -> fmt::Result,
+{
+    struct Counter(usize);
+
+    impl fmt::Write for Counter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.chars().count();
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    render(&mut counter)?;
+    Ok(counter.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Kilometer, Length, Millimeter};
+    use crate::si::velocity::{KilometerPerHour, Velocity};
+
+    #[test]
+    fn test_display_in_unit() {
+        let distance = Length::from_base(3200.0);
+        assert_eq!(format!("{}", distance.display::<Kilometer>()), "3.2 km");
+        assert_eq!(
+            format!("{}", distance.display::<Millimeter>()),
+            "3200000 mm"
+        );
+    }
+
+    #[test]
+    fn test_display_forwards_flags() {
+        let speed = Velocity::from::<KilometerPerHour>(90.0);
+        assert_eq!(
+            format!("{:.2}", speed.display::<KilometerPerHour>()),
+            "90.00 km/h"
+        );
+        assert_eq!(
+            format!("{:>12.1}", speed.display::<KilometerPerHour>()),
+            "   90.0 km/h"
+        );
+        assert_eq!(
+            format!("{:+}", Length::from_base(1.5).display::<Kilometer>()),
+            "+0.0015 km"
+        );
+    }
+
+    #[test]
+    fn test_flags_line_up_in_tables() {
+        let rows = [Length::from_base(3.0), Length::from_base(-12.25)];
+        let table: Vec<String> = rows.iter().map(|l| format!("{l:>8.2}|")).collect();
+        assert_eq!(table, ["  3.00 m|", "-12.25 m|"]);
+        assert_eq!(format!("{:<6}|", rows[0]), "3 m   |");
+        assert_eq!(format!("{:^7}|", rows[0]), "  3 m  |");
+        assert_eq!(format!("{:*>5}|", rows[0]), "**3 m|");
+        assert_eq!(format!("{:+08.1}", rows[0]), "+003.0 m");
+        // Units of different lengths still end in the same column
+        let mixed = [
+            format!("{:>12.1}", Length::from_base(2500.0).display::<Kilometer>()),
+            format!(
+                "{:>12.1}",
+                Length::from_base(2500.0).display::<Millimeter>()
+            ),
+        ];
+        assert_eq!(mixed, ["      2.5 km", "2500000.0 mm"]);
+    }
+
+    #[test]
+    fn test_long_and_si_display_pad_whole() {
+        use crate::si::length::Meter;
+
+        let long = Length::from_base(2.0).display_long::<Meter>();
+        assert_eq!(format!("{long:>10}|"), "  2 meters|");
+        assert_eq!(format!("{long:<10.1}|"), "2.0 meters|");
+        let si = Length::from_base(0.0042).format_si();
+        assert_eq!(format!("{si:>8.1}|"), "  4.2 mm|");
+        assert_eq!(format!("{si:+08.1}|"), "+04.2 mm|");
+    }
+
+    #[test]
+    fn test_display_long_pluralizes() {
+        use crate::si::length::{Foot, Meter};
+
+        let long = |meters: f64| format!("{}", Length::from_base(meters).display_long::<Meter>());
+        assert_eq!(long(1.0), "1 meter");
+        assert_eq!(long(-1.0), "-1 meter");
+        assert_eq!(long(2.5), "2.5 meters");
+        assert_eq!(long(0.0), "0 meters");
+        assert_eq!(
+            format!("{:.1}", Length::from_base(3.048).display_long::<Foot>()),
+            "10.0 feet"
+        );
+        assert_eq!(
+            format!("{}", Length::from_base(1_u32).display_long::<Meter>()),
+            "1 meter"
+        );
+    }
+
+    #[test]
+    fn test_display_long_compound_names() {
+        use crate::si::temperature::{DegreeCelsius, Temperature};
+
+        let speed = Velocity::from::<KilometerPerHour>(90.0);
+        assert_eq!(
+            format!("{}", speed.display_long::<KilometerPerHour>()),
+            "90 kilometers per hour"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Temperature::from::<DegreeCelsius>(1.0).display_long::<DegreeCelsius>()
+            ),
+            "1 degree Celsius"
+        );
+    }
+
+    #[test]
+    fn test_ascii_symbols() {
+        use super::AsciiSymbol;
+
+        let ascii = |abbreviation| format!("{}", AsciiSymbol(abbreviation));
+        assert_eq!(ascii("µm"), "um");
+        assert_eq!(ascii("m/s²"), "m/s^2");
+        assert_eq!(ascii("Å⁻¹"), "Angstrom^-1");
+        assert_eq!(ascii("10¹⁵ Btu (IT)"), "10^15 Btu (IT)");
+        assert_eq!(ascii("a₀ · Eₕ/ħ"), "a_0 * E_h/hbar");
+        assert_eq!(ascii("Btu₅₉"), "Btu_59");
+        assert_eq!(ascii("°C"), "degC");
+        assert_eq!(ascii("kΩ·m"), "kOhm*m");
+        assert_eq!(ascii("km"), "km");
+    }
+
+    #[test]
+    fn test_alternate_flag_selects_ascii() {
+        use crate::si::acceleration::{Acceleration, MicrometerPerSecondSquared};
+
+        let a = Acceleration::from_base(9.81);
+        assert_eq!(format!("{a}"), "9.81 m/s²");
+        assert_eq!(format!("{a:#}"), "9.81 m/s^2");
+        assert_eq!(format!("{a:#.1}"), "9.8 m/s^2");
+        assert_eq!(
+            format!("{:#.0}", a.display::<MicrometerPerSecondSquared>()),
+            "9810000 um/s^2"
+        );
+        assert_eq!(
+            format!("{:#.1}", Length::from_base(4.2e-6).format_si()),
+            "4.2 um"
+        );
+    }
+}
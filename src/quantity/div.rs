@@ -1,13 +1,11 @@
 use super::Quantity;
-use core::ops::{Div, Sub};
+use core::ops::{Div, DivAssign, Sub};
 use num_traits::Num;
 
 // Division: Divides values and subtracts dimensions (D1 - D2), preserve scale
 impl<V1, V2, D1, D2, S> Div<Quantity<V2, D2, S>> for Quantity<V1, D1, S>
 where
-    V1: Num + Div<V2>,
-    V2: Num,
-    <V1 as Div<V2>>::Output: Num,
+    V1: Div<V2>,
     D1: Sub<D2>,
 {
     type Output = Quantity<<V1 as Div<V2>>::Output, <D1 as Sub<D2>>::Output, S>;
@@ -20,7 +18,7 @@ where
 // Scalar division (quantity / scalar)
 impl<V, D, S> Div<V> for Quantity<V, D, S>
 where
-    V: Num + Div<Output = V> + Copy,
+    V: Num + Div<Output = V>,
 {
     type Output = Quantity<V, D, S>;
 
@@ -28,3 +26,63 @@ where
         Quantity::from_base(self.value / scalar)
     }
 }
+
+// By reference (`&a / &b`), subtracting dimensions like the owned impl
+impl<'a, 'b, V1, V2, D1, D2, S> Div<&'b Quantity<V2, D2, S>> for &'a Quantity<V1, D1, S>
+where
+    &'a V1: Div<&'b V2>,
+    D1: Sub<D2>,
+{
+    type Output = Quantity<<&'a V1 as Div<&'b V2>>::Output, <D1 as Sub<D2>>::Output, S>;
+
+    fn div(self, rhs: &'b Quantity<V2, D2, S>) -> Self::Output {
+        Quantity::from_base(&self.value / &rhs.value)
+    }
+}
+
+// Scalar division by reference (`&quantity / scalar`)
+impl<'a, V, D, S> Div<V> for &'a Quantity<V, D, S>
+where
+    V: Num,
+    &'a V: Div<V, Output = V>,
+{
+    type Output = Quantity<V, D, S>;
+
+    fn div(self, scalar: V) -> Self::Output {
+        Quantity::from_base(&self.value / scalar)
+    }
+}
+
+// Scalar division in place (quantity /= scalar)
+impl<V, D, S> DivAssign<V> for Quantity<V, D, S>
+where
+    V: Num + DivAssign,
+{
+    fn div_assign(&mut self, scalar: V) {
+        self.value /= scalar;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use crate::si::time::Time;
+    use crate::si::velocity::Velocity;
+
+    #[test]
+    fn test_reference_division() {
+        let distance = Length::from_base(100.0);
+        let time = Time::from_base(8.0);
+
+        let speed: Velocity<f64> = &distance / &time;
+        assert_eq!(*speed.base(), 12.5);
+        assert_eq!(*(&distance / 4.0).base(), 25.0);
+    }
+
+    #[test]
+    fn test_scalar_div_assign() {
+        let mut length = Length::from_base(9_i32);
+        length /= 2;
+        assert_eq!(*length.base(), 4);
+    }
+}
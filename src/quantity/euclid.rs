@@ -0,0 +1,79 @@
+use super::Quantity;
+use core::ops::{Div, Rem, Sub};
+use num_traits::{Euclid, Num};
+
+// Euclidean division keeps the remainder non-negative, so wrapping a negative
+// time of day or angle lands inside `[0, rhs)` instead of `(-rhs, 0]` as `%` does.
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: Num + Euclid,
+{
+    /// Euclidean quotient of two quantities of the same dimension
+    ///
+    /// The result is dimensionless: it counts how many whole `rhs` fit in `self`.
+    pub fn div_euclid(&self, rhs: &Self) -> Quantity<V, <D as Sub>::Output, S>
+    where
+        D: Sub,
+    {
+        Quantity::from_base(self.value.div_euclid(&rhs.value))
+    }
+
+    /// Least non-negative remainder of `self` modulo `rhs`
+    pub fn rem_euclid(&self, rhs: &Self) -> Self {
+        Self::from_base(self.value.rem_euclid(&rhs.value))
+    }
+}
+
+// `Euclid` requires `Div<Output = Self>` and `Rem<Output = Self>`, which only
+// holds for dimensionless quantities (`Scalar`), where `D - D = D`.
+impl<V, D, S> Euclid for Quantity<V, D, S>
+where
+    V: Num + Euclid,
+    Self: Div<Self, Output = Self> + Rem<Self, Output = Self>,
+{
+    fn div_euclid(&self, rhs: &Self) -> Self {
+        Self::from_base(self.value.div_euclid(&rhs.value))
+    }
+
+    fn rem_euclid(&self, rhs: &Self) -> Self {
+        Self::from_base(self.value.rem_euclid(&rhs.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::angle::{Degree, Revolution};
+    use crate::si::scalar::Scalar;
+    use crate::si::time::{Hour, Time};
+    use num_traits::Euclid;
+
+    #[test]
+    fn test_rem_euclid_time_of_day() {
+        let day = Time::from_base(86_400_i64);
+        let before_midnight = Time::from_base(-7_200_i64);
+        assert_eq!(before_midnight.rem_euclid(&day), Time::from_base(79_200));
+        assert_eq!(before_midnight % day, Time::from_base(-7_200));
+        assert_eq!(*Time::from_base(-180_000_i64).div_euclid(&day).base(), -3);
+
+        let evening = Time::from::<Hour>(-2.0).rem_euclid(&Time::from::<Hour>(24.0));
+        assert_eq!(evening.to::<Hour>(), 22.0);
+    }
+
+    #[test]
+    fn test_rem_euclid_angle() {
+        let turn = Scalar::from::<Revolution>(1.0_f64);
+        let heading = Scalar::from::<Degree>(-90.0).rem_euclid(&turn);
+        assert!((heading.to::<Degree>() - 270.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_euclid_trait_for_scalar() {
+        fn wrap<T: Euclid>(value: T, modulus: T) -> T {
+            value.rem_euclid(&modulus)
+        }
+        assert_eq!(
+            wrap(Scalar::from_base(-7_i32), Scalar::from_base(3)),
+            Scalar::from_base(2)
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use super::Quantity;
+use ::fixed::traits::Fixed;
+use ::fixed::types::extra::{U0, U64, U128};
+use ::fixed::{FixedU128, traits::ToFixed};
+
+// Unit conversions for fixed-point values are generated by `convert_linear!`
+// (through `__convert_fixed!`), which stores each unit's `Factor` as an
+// associated constant. The `f64` factors are turned into exact wide
+// fixed-point numbers at compile time; at runtime the value is scaled with
+// fixed-point `mul`/`div` only, without any float arithmetic.
+
+/// A non-negative `f64` constant as an exact unsigned 128-bit fixed-point
+/// number, with as many fractional bits as its size allows
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Magnitude {
+    /// Below one: the bits of a `FixedU128<U128>`
+    Fraction(u128),
+    /// Below 2^64: the bits of a `FixedU128<U64>`
+    Mixed(u128),
+    /// Below 2^128: the bits of a `FixedU128<U0>`
+    Whole(u128),
+    /// 2^128 or more, too large for every fixed-point type
+    Beyond,
+}
+
+impl Magnitude {
+    /// `value` must be finite and non-negative. Values from 2^-64 up are exact;
+    /// smaller ones are truncated.
+    const fn of(value: f64) -> Self {
+        let bits = value.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as u128;
+        // value = mantissa * 2^exponent
+        let (mantissa, exponent) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased - 1075)
+        };
+        if value >= 340_282_366_920_938_463_463_374_607_431_768_211_456.0 {
+            return Self::Beyond;
+        }
+        let whole = value >= 18_446_744_073_709_551_616.0;
+        let mixed = value >= 1.0;
+        let frac_bits = if whole {
+            0
+        } else if mixed {
+            64
+        } else {
+            128
+        };
+        let shift = exponent + frac_bits;
+        let fixed_bits = if shift >= 0 {
+            mantissa << shift
+        } else if shift > -64 {
+            mantissa >> -shift
+        } else {
+            0
+        };
+        if whole {
+            Self::Whole(fixed_bits)
+        } else if mixed {
+            Self::Mixed(fixed_bits)
+        } else {
+            Self::Fraction(fixed_bits)
+        }
+    }
+
+    /// The nearest `F`, or `None` if it is too large for `F`
+    fn to_fixed<F: Fixed>(self) -> Option<F> {
+        match self {
+            Self::Fraction(bits) => nearest(FixedU128::<U128>::from_bits(bits), 128),
+            Self::Mixed(bits) => nearest(FixedU128::<U64>::from_bits(bits), 64),
+            Self::Whole(bits) => F::checked_from_num(FixedU128::<U0>::from_bits(bits)),
+            Self::Beyond => None,
+        }
+    }
+}
+
+/// Round `value` with `frac_bits` fractional bits to the nearest `F`;
+/// converting between fixed-point types alone would truncate
+fn nearest<F: Fixed, Src: Fixed<Bits = u128> + ToFixed>(value: Src, frac_bits: u32) -> Option<F> {
+    let half = match frac_bits.checked_sub(F::FRAC_NBITS + 1) {
+        Some(shift) => 1 << shift,
+        None => 0,
+    };
+    F::checked_from_num(Src::from_bits(value.to_bits().saturating_add(half)))
+}
+
+/// Multiplication or division by a factor, whichever keeps it exact
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Scale {
+    Mul(Magnitude),
+    Div(Magnitude),
+}
+
+impl Scale {
+    /// Factors below one are applied as a division by their inverse, which
+    /// keeps them exact for prefixes such as milli (1/1000 is not
+    /// representable in binary)
+    const fn of(factor: f64) -> Self {
+        if factor >= 1.0 {
+            Self::Mul(Magnitude::of(factor))
+        } else {
+            Self::Div(Magnitude::of(1.0 / factor))
+        }
+    }
+
+    /// Scale `value`, saturating at the bounds of `F`
+    fn apply<F: Fixed>(self, value: F) -> F {
+        match self {
+            Self::Mul(factor) => match factor.to_fixed::<F>() {
+                Some(factor) => value.saturating_mul(factor),
+                None if value == F::ZERO => F::ZERO,
+                None if value > F::ZERO => F::MAX,
+                None => F::MIN,
+            },
+            // An inverse too large for `F` means the result underflows to zero
+            Self::Div(inverse) => match inverse.to_fixed::<F>() {
+                Some(inverse) => value.saturating_div(inverse),
+                None => F::ZERO,
+            },
+        }
+    }
+}
+
+/// The scale and offset of a `convert_linear!` entry as fixed-point constants
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Factor {
+    to_base: Scale,
+    from_base: Scale,
+    negative_offset: bool,
+    offset: Magnitude,
+}
+
+impl Factor {
+    pub const fn new(scale: f64, offset: f64) -> Self {
+        Self {
+            to_base: Scale::of(scale),
+            from_base: Scale::of(1.0 / scale),
+            negative_offset: offset < 0.0,
+            offset: Magnitude::of(offset.abs()),
+        }
+    }
+
+    /// Add the offset to `value`, or subtract it with `negate`, saturating
+    fn shift<F: Fixed>(&self, value: F, negate: bool) -> F {
+        if self.offset == Magnitude::Fraction(0) {
+            return value;
+        }
+        let up = self.negative_offset == negate;
+        match self.offset.to_fixed::<F>() {
+            Some(offset) if up => value.saturating_add(offset),
+            Some(offset) => value.saturating_sub(offset),
+            None if up => F::MAX,
+            None => F::MIN,
+        }
+    }
+}
+
+/// The `Factor` of converting unit `From` to this base unit, implemented by
+/// `convert_linear!`
+#[doc(hidden)]
+pub trait FixedFactor<From> {
+    const FACTOR: Factor;
+}
+
+/// Convert a value in a derived unit to the base unit: `value * factor + offset`
+#[doc(hidden)]
+#[inline]
+pub fn to_base<F: Fixed>(value: F, factor: &Factor) -> F {
+    factor.shift(factor.to_base.apply(value), false)
+}
+
+/// Convert a base-unit value to the derived unit: `(value - offset) / factor`
+#[doc(hidden)]
+#[inline]
+pub fn from_base<F: Fixed>(value: F, factor: &Factor) -> F {
+    factor.from_base.apply(factor.shift(value, true))
+}
+
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: Fixed,
+{
+    /// Build a fixed-point quantity from any number convertible with
+    /// `Fixed::from_num`, e.g. an integer sensor reading or a float literal
+    pub fn from_num<Src: ::fixed::traits::ToFixed>(value: Src) -> Self {
+        Quantity {
+            value: V::from_num(value),
+            _dimension: core::marker::PhantomData,
+            _scale: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Magnitude;
+    use crate::si::length::{Exameter, Kilometer, Length, Millimeter};
+    use crate::si::mass::{Gram, Mass};
+    use crate::si::temperature::{DegreeFahrenheit, Temperature};
+    use ::fixed::types::{I16F16, I128F0, U8F8};
+
+    // Non-binary factors such as 1/1000 or 5/9 are off by at most a few ulps
+    fn assert_close(actual: I16F16, expected: f64) {
+        let error = (actual - I16F16::from_num(expected)).abs();
+        assert!(error <= 2 * I16F16::DELTA, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn test_fixed_unit_conversions() {
+        let distance = Length::from::<Kilometer>(I16F16::from_num(1.5));
+        assert_eq!(*distance.base(), I16F16::from_num(1500));
+        assert_eq!(distance.to::<Kilometer>(), I16F16::from_num(1.5));
+
+        let gap = Length::from::<Millimeter>(I16F16::from_num(250));
+        assert_eq!(*gap.base(), I16F16::from_num(0.25));
+
+        let sample = Mass::from::<Gram>(I16F16::from_num(500));
+        assert_eq!(*sample.base(), I16F16::from_num(0.5));
+
+        let rise = Temperature::from::<DegreeFahrenheit>(I16F16::from_num(9));
+        assert_close(*rise.base(), 5.0);
+    }
+
+    #[test]
+    fn test_fixed_saturates() {
+        let far = Length::from::<Kilometer>(U8F8::from_num(2));
+        assert_eq!(*far.base(), U8F8::MAX);
+        let tiny = Length::<I16F16>::from_num(3).to::<Kilometer>();
+        assert_close(tiny, 0.003);
+        let arithmetic = Length::<I16F16>::from_num(1.25) + Length::from_num(2);
+        assert_eq!(*arithmetic.base(), I16F16::from_num(3.25));
+    }
+
+    #[test]
+    fn test_factor_constants_are_exact() {
+        assert_eq!(Magnitude::of(0.5), Magnitude::Fraction(1 << 127));
+        assert_eq!(Magnitude::of(1000.0), Magnitude::Mixed(1000 << 64));
+        assert_eq!(Magnitude::of(1e24), Magnitude::Whole(1e24 as u128));
+        assert_eq!(Magnitude::of(1e40), Magnitude::Beyond);
+        let far = Length::from::<Exameter>(I128F0::from_num(3));
+        assert_eq!(
+            *far.base(),
+            I128F0::from_num(3_000_000_000_000_000_000_i128)
+        );
+        assert_eq!(far.to::<Exameter>(), I128F0::from_num(3));
+    }
+}
@@ -178,6 +178,13 @@ where
         Self::from_base(self.value.cbrt())
     }
 
+    /// Returns `self` with the magnitude of `self` and the sign of `sign`.
+    ///
+    /// `sign` may have any dimension, only its sign bit is used.
+    pub fn copysign<D2>(self, sign: Quantity<V, D2, S>) -> Self {
+        Self::from_base(self.value.copysign(sign.value))
+    }
+
     /// Calculate the euclidean distance between `self` and `other`.
     pub fn hypot(self, other: Self) -> Self {
         Self::from_base(self.value.hypot(other.value))
@@ -346,6 +353,17 @@ mod tests {
         assert_eq!(*zero.signum().base(), 1.0);
     }
 
+    #[test]
+    fn test_copysign() {
+        use crate::si::velocity::Velocity;
+
+        let distance = Length::from_base(3.0);
+        let heading = Velocity::from_base(-0.5);
+        assert_eq!(*distance.copysign(heading).base(), -3.0);
+        assert_eq!(*Length::from_base(-2.0).copysign(distance).base(), 2.0);
+        assert_eq!(*distance.copysign(Length::from_base(-0.0)).base(), -3.0);
+    }
+
     #[test]
     fn test_abs_function() {
         let positive = Length::from_base(42.5);
@@ -195,9 +195,9 @@ mod tests {
     #[test]
     fn test_float_const_trait() {
         // Test that the FloatConst trait is implemented
-        let pi = Length::PI();
-        let e = Length::E();
-        let tau = Length::TAU();
+        let pi = Length::<f32>::PI();
+        let e = Length::<f32>::E();
+        let tau = Length::<f32>::TAU();
 
         assert!((pi.base() - std::f32::consts::PI).abs() < 1e-10);
         assert!((e.base() - std::f32::consts::E).abs() < 1e-10);
@@ -206,12 +206,12 @@ mod tests {
 
     #[test]
     fn test_pi_constants() {
-        let pi = Length::PI();
-        let half_pi = Length::FRAC_PI_2();
-        let quarter_pi = Length::FRAC_PI_4();
-        let pi_3 = Length::FRAC_PI_3();
-        let pi_6 = Length::FRAC_PI_6();
-        let pi_8 = Length::FRAC_PI_8();
+        let pi = Length::<f32>::PI();
+        let half_pi = Length::<f32>::FRAC_PI_2();
+        let quarter_pi = Length::<f32>::FRAC_PI_4();
+        let pi_3 = Length::<f32>::FRAC_PI_3();
+        let pi_6 = Length::<f32>::FRAC_PI_6();
+        let pi_8 = Length::<f32>::FRAC_PI_8();
 
         assert!((pi.base() - std::f32::consts::PI).abs() < 1e-10);
         assert!((half_pi.base() - std::f32::consts::FRAC_PI_2).abs() < 1e-10);
@@ -223,9 +223,9 @@ mod tests {
 
     #[test]
     fn test_inverse_pi_constants() {
-        let inv_pi = Length::FRAC_1_PI();
-        let two_pi = Length::FRAC_2_PI();
-        let two_sqrt_pi = Length::FRAC_2_SQRT_PI();
+        let inv_pi = Length::<f32>::FRAC_1_PI();
+        let two_pi = Length::<f32>::FRAC_2_PI();
+        let two_sqrt_pi = Length::<f32>::FRAC_2_SQRT_PI();
 
         assert!((inv_pi.base() - std::f32::consts::FRAC_1_PI).abs() < 1e-10);
         assert!((two_pi.base() - std::f32::consts::FRAC_2_PI).abs() < 1e-10);
@@ -234,9 +234,9 @@ mod tests {
 
     #[test]
     fn test_e_constants() {
-        let e = Length::E();
-        let log2_e = Length::LOG2_E();
-        let log10_e = Length::LOG10_E();
+        let e = Length::<f32>::E();
+        let log2_e = Length::<f32>::LOG2_E();
+        let log10_e = Length::<f32>::LOG10_E();
 
         assert!((e.base() - std::f32::consts::E).abs() < 1e-10);
         assert!((log2_e.base() - std::f32::consts::LOG2_E).abs() < 1e-10);
@@ -245,8 +245,8 @@ mod tests {
 
     #[test]
     fn test_logarithm_constants() {
-        let ln_2 = Length::LN_2();
-        let ln_10 = Length::LN_10();
+        let ln_2 = Length::<f32>::LN_2();
+        let ln_10 = Length::<f32>::LN_10();
 
         assert!((ln_2.base() - std::f32::consts::LN_2).abs() < 1e-10);
         assert!((ln_10.base() - std::f32::consts::LN_10).abs() < 1e-10);
@@ -254,8 +254,8 @@ mod tests {
 
     #[test]
     fn test_sqrt_constants() {
-        let sqrt_2 = Length::SQRT_2();
-        let inv_sqrt_2 = Length::FRAC_1_SQRT_2();
+        let sqrt_2 = Length::<f32>::SQRT_2();
+        let inv_sqrt_2 = Length::<f32>::FRAC_1_SQRT_2();
 
         assert!((sqrt_2.base() - std::f32::consts::SQRT_2).abs() < 1e-10);
         assert!((inv_sqrt_2.base() - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-10);
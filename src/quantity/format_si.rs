@@ -0,0 +1,176 @@
+use super::{BaseUnitOf, Quantity};
+use core::fmt;
+use num_traits::ToPrimitive;
+use num_traits::float::FloatCore;
+
+/// Base units whose quantity has engineering-prefixed units (k, M, m, μ, ...)
+///
+/// Implemented through [`si_prefixed!`](crate::si_prefixed) next to the unit
+/// definitions; used by [`Quantity::format_si`].
+pub trait SiPrefixed: crate::unit::Unit {
+    /// Pick the prefixed unit best suited for `base_value`, returned as the
+    /// size of that unit in base units and its abbreviation
+    fn select_prefix(base_value: f64) -> (f64, &'static str);
+}
+
+/// Register the engineering-prefixed units of a base unit for `format_si`
+///
+/// List the prefixed units of the base unit's own family: the prefixed meters
+/// for `Meter`, the prefixed square meters for `SquareMeter` (steps of 1000²)
+/// and so on. The base unit itself is always a candidate.
+///
+/// # Examples
+/// ```rust,ignore
+/// crate::si_prefixed! {
+///     Meter => Kilometer, Millimeter, Micrometer
+/// }
+/// ```
+#[macro_export]
+macro_rules! si_prefixed {
+    ($base:ident => $($unit:ident),+ $(,)?) => {
+        impl $crate::quantity::format_si::SiPrefixed for $base {
+            fn select_prefix(base_value: f64) -> (f64, &'static str) {
+                $crate::quantity::format_si::select_prefix(
+                    base_value,
+                    &[
+                        (1.0, <$base as $crate::unit::Unit>::ABBREVIATION),
+                        $((
+                            <$base as $crate::unit::FromUnit<$unit, f64>>::to_base(1.0),
+                            <$unit as $crate::unit::Unit>::ABBREVIATION,
+                        )),+
+                    ],
+                )
+            }
+        }
+    };
+    ($base:ident) => {
+        impl $crate::quantity::format_si::SiPrefixed for $base {
+            fn select_prefix(_base_value: f64) -> (f64, &'static str) {
+                (1.0, <$base as $crate::unit::Unit>::ABBREVIATION)
+            }
+        }
+    };
+}
+
+/// Choose the largest unit that keeps the magnitude at or above one, falling
+/// back to the smallest unit for tiny values. Zero and non-finite values stay
+/// in the first candidate, which is the base unit.
+#[doc(hidden)]
+pub fn select_prefix(base_value: f64, candidates: &[(f64, &'static str)]) -> (f64, &'static str) {
+    let magnitude = base_value.abs();
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return candidates[0];
+    }
+    let mut best: Option<(f64, &'static str)> = None;
+    let mut smallest = candidates[0];
+    for &candidate in candidates {
+        if candidate.0 < smallest.0 {
+            smallest = candidate;
+        }
+        if candidate.0 <= magnitude && best.is_none_or(|b| candidate.0 > b.0) {
+            best = Some(candidate);
+        }
+    }
+    best.unwrap_or(smallest)
+}
+
+/// Formatter adapter returned by [`Quantity::format_si`]
+///
+/// Precision and sign apply to the scaled value; width, fill and alignment pad
+/// the whole text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SiDisplay {
+    value: f64,
+    abbreviation: &'static str,
+}
+
+impl fmt::Display for SiDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::display::write_with_symbol(f, &self.value, self.abbreviation)
+    }
+}
+
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: ToPrimitive,
+{
+    /// Display this quantity with the best-fitting SI prefix
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::current::Current;
+    ///
+    /// let current = Current::from_base(0.00042);
+    /// assert_eq!(format!("{}", current.format_si()), "420 μA");
+    /// ```
+    pub fn format_si(&self) -> SiDisplay
+    where
+        S: BaseUnitOf<D>,
+        S::BaseUnit: SiPrefixed,
+    {
+        let base_value = self.value.to_f64().unwrap_or(f64::NAN);
+        let (size, abbreviation) = S::BaseUnit::select_prefix(base_value);
+        // Prefix sizes are powers of ten; dividing by a small size (or multiplying
+        // by its inexact reciprocal) adds noise like 419.99999999999994, so small
+        // sizes are inverted and rounded back to the exact power first.
+        let value = if size < 1.0 {
+            base_value * FloatCore::round(1.0 / size)
+        } else {
+            base_value / size
+        };
+        SiDisplay {
+            value,
+            abbreviation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::current::Current;
+    use crate::si::length::Length;
+    use crate::si::mass::Mass;
+    use crate::si::power::Power;
+    use crate::si::time::Time;
+
+    #[test]
+    fn test_format_si_picks_prefix() {
+        assert_eq!(
+            format!("{}", Current::from_base(0.00042).format_si()),
+            "420 μA"
+        );
+        assert_eq!(
+            format!("{}", Length::from_base(4200.0).format_si()),
+            "4.2 km"
+        );
+        assert_eq!(
+            format!("{}", Power::from_base(-2.5e9).format_si()),
+            "-2.5 GW"
+        );
+        assert_eq!(format!("{}", Time::from_base(1.0).format_si()), "1 s");
+    }
+
+    #[test]
+    fn test_format_si_mass_uses_grams() {
+        assert_eq!(format!("{}", Mass::from_base(0.25).format_si()), "250 g");
+        assert_eq!(format!("{}", Mass::from_base(3.0).format_si()), "3 kg");
+        assert_eq!(format!("{}", Mass::from_base(2.0e-9).format_si()), "2 μg");
+    }
+
+    #[test]
+    fn test_format_si_edge_cases() {
+        assert_eq!(format!("{}", Length::from_base(0.0).format_si()), "0 m");
+        assert_eq!(
+            format!("{:.6}", Length::from_base(1e-30).format_si()),
+            "0.000001 ym"
+        );
+        assert_eq!(
+            format!("{}", Length::from_base(f64::INFINITY).format_si()),
+            "inf m"
+        );
+        assert_eq!(
+            format!("{:.1}", Length::from_base(1500_i32).format_si()),
+            "1.5 km"
+        );
+    }
+}
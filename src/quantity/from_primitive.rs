@@ -0,0 +1,52 @@
+use super::Quantity;
+use num_traits::{FromPrimitive, Num};
+
+// Primitives are interpreted as base-unit values, e.g. `Length::from_u32(5)` is
+// 5 m. Every method is forwarded so floats are not routed through `from_i64`.
+macro_rules! forward_from_primitive {
+    ($($method:ident($ty:ty);)+) => {
+        $(
+            fn $method(n: $ty) -> Option<Self> {
+                V::$method(n).map(Quantity::from_base)
+            }
+        )+
+    };
+}
+
+impl<V, D, S> FromPrimitive for Quantity<V, D, S>
+where
+    V: Num + FromPrimitive,
+{
+    forward_from_primitive! {
+        from_isize(isize);
+        from_i8(i8);
+        from_i16(i16);
+        from_i32(i32);
+        from_i64(i64);
+        from_i128(i128);
+        from_usize(usize);
+        from_u8(u8);
+        from_u16(u16);
+        from_u32(u32);
+        from_u64(u64);
+        from_u128(u128);
+        from_f32(f32);
+        from_f64(f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use crate::si::time::Time;
+    use num_traits::FromPrimitive;
+
+    #[test]
+    fn test_from_primitive_as_base_value() {
+        assert_eq!(*Length::<f64>::from_u32(5).unwrap().base(), 5.0);
+        assert_eq!(*Length::<f64>::from_f32(0.5).unwrap().base(), 0.5);
+        assert_eq!(*Time::<u8>::from_i32(200).unwrap().base(), 200);
+        assert!(Time::<u8>::from_i32(-1).is_none());
+        assert!(Time::<i16>::from_f64(1e9).is_none());
+    }
+}
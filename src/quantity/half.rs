@@ -0,0 +1,59 @@
+use super::Quantity;
+use ::half::{bf16, f16};
+use core::marker::PhantomData;
+
+// Widening a half-precision quantity is lossless, so it is offered through
+// `From`. Narrowing goes through `f16::from_f32` and friends on the value and
+// is left to the caller, as it rounds.
+macro_rules! impl_widen {
+    ($($half:ty => $($wide:ty),+;)+) => {
+        $($(
+            impl<D, S> From<Quantity<$half, D, S>> for Quantity<$wide, D, S> {
+                fn from(quantity: Quantity<$half, D, S>) -> Self {
+                    Quantity {
+                        value: quantity.value.into(),
+                        _dimension: PhantomData,
+                        _scale: PhantomData,
+                    }
+                }
+            }
+        )+)+
+    };
+}
+
+impl_widen! {
+    f16 => f32, f64;
+    bf16 => f32, f64;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Kilometer, Length, Millimeter};
+    use crate::si::temperature::{DegreeFahrenheit, Temperature};
+    use ::half::{bf16, f16};
+
+    #[test]
+    fn test_half_unit_conversions() {
+        let distance = Length::from::<Kilometer>(f16::from_f32(1.5));
+        assert_eq!(*distance.base(), f16::from_f32(1500.0));
+        assert_eq!(distance.to::<Kilometer>(), f16::from_f32(1.5));
+
+        let gap = Length::from::<Millimeter>(bf16::from_f32(250.0));
+        assert_eq!(*gap.base(), bf16::from_f32(0.25));
+
+        let rise = Temperature::from::<DegreeFahrenheit>(f16::from_f32(9.0));
+        assert_eq!(*rise.base(), f16::from_f32(5.0));
+    }
+
+    #[test]
+    fn test_half_arithmetic_and_widening() {
+        let a = Length::from_base(f16::from_f32(0.5));
+        let b = Length::from_base(f16::from_f32(0.25));
+        assert_eq!(*(a + b).base(), f16::from_f32(0.75));
+
+        let wide: Length<f32> = (a + b).into();
+        assert_eq!(*wide.base(), 0.75);
+        let wide: Length<f64> = Length::from_base(bf16::from_f32(-2.0)).into();
+        assert_eq!(*wide.base(), -2.0);
+    }
+}
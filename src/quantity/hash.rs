@@ -0,0 +1,34 @@
+use super::Quantity;
+use core::hash::{Hash, Hasher};
+
+// Only the value is hashed; the dimension and scale are zero-sized markers that
+// are equal for every quantity of the same type.
+impl<V, D, S> Hash for Quantity<V, D, S>
+where
+    V: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::time::Time;
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn test_hash_map_keys() {
+        let mut labels = HashMap::new();
+        labels.insert(Time::from_base(1500_i64), "debounce");
+        labels.insert(Time::from_base(250_i64), "poll");
+        assert_eq!(labels[&Time::from_base(250_i64)], "poll");
+    }
+
+    #[test]
+    fn test_dedup_set() {
+        let samples = [3_u32, 5, 3, 8, 5].map(Time::from_base);
+        let unique: HashSet<_> = samples.into_iter().collect();
+        assert_eq!(unique.len(), 3);
+    }
+}
@@ -0,0 +1,47 @@
+use super::Quantity;
+use core::ops::Neg;
+use num_traits::Inv;
+
+// Inverting a quantity inverts its dimension: 1 / (2 s) = 0.5 s⁻¹. This differs
+// from `Float::recip`, which keeps the dimension of `self`.
+impl<V, D, S> Inv for Quantity<V, D, S>
+where
+    V: Inv,
+    D: Neg,
+{
+    type Output = Quantity<<V as Inv>::Output, <D as Neg>::Output, S>;
+
+    fn inv(self) -> Self::Output {
+        Quantity {
+            value: self.value.inv(),
+            _dimension: core::marker::PhantomData,
+            _scale: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Kilometer, Length};
+    use crate::si::radioactivity::Radioactivity;
+    use crate::si::time::Time;
+    use crate::si::wavenumber::{ReciprocalKilometer, Wavenumber};
+    use num_traits::Inv;
+
+    #[test]
+    fn test_inv_negates_dimension() {
+        // Becquerel shares the s⁻¹ dimension of a frequency
+        let rate: Radioactivity<f64> = Time::from_base(0.25).inv();
+        assert_eq!(*rate.base(), 4.0);
+
+        let wavenumber: Wavenumber<f64> = Length::from::<Kilometer>(2.0).inv();
+        assert_eq!(wavenumber.to::<ReciprocalKilometer>(), 0.5);
+    }
+
+    #[test]
+    fn test_inv_round_trip() {
+        let period = Time::from_base(0.125_f32);
+        let back: Time<f32> = period.inv().inv();
+        assert_eq!(back, period);
+    }
+}
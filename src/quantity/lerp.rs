@@ -0,0 +1,69 @@
+use super::Quantity;
+use num_traits::float::FloatCore;
+
+// Interpolation between two quantities of the same dimension. The parameter `t`
+// is a plain number, so the result stays in the quantity's dimension.
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: FloatCore,
+{
+    /// Linear interpolation from `self` (at `t = 0`) to `other` (at `t = 1`)
+    ///
+    /// `t` is not clamped, so values outside `[0, 1]` extrapolate. The endpoints
+    /// are returned exactly.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::temperature::Temperature;
+    ///
+    /// let start = Temperature::from_base(290.0);
+    /// let end = Temperature::from_base(310.0);
+    /// assert_eq!(*start.lerp(end, 0.25).base(), 295.0);
+    /// ```
+    pub fn lerp(self, other: Self, t: V) -> Self {
+        Self {
+            value: self.value * (V::one() - t) + other.value * t,
+            ..self
+        }
+    }
+
+    /// The point halfway between `self` and `other`, without overflowing for
+    /// values near `V::max_value()`
+    pub fn midpoint(self, other: Self) -> Self {
+        let two = V::one() + V::one();
+        let (a, b) = (self.value, other.value);
+        let half_max = V::max_value() / two;
+        let value = if a.abs() <= half_max && b.abs() <= half_max {
+            (a + b) / two
+        } else {
+            a / two + b / two
+        };
+        Self { value, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use crate::si::temperature::Temperature;
+
+    #[test]
+    fn test_lerp() {
+        let start = Temperature::from_base(290.0);
+        let end = Temperature::from_base(310.0);
+        assert_eq!(start.lerp(end, 0.0), start);
+        assert_eq!(start.lerp(end, 1.0), end);
+        assert_eq!(*start.lerp(end, 0.25).base(), 295.0);
+        assert_eq!(*start.lerp(end, 1.5).base(), 320.0);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let a = Length::from_base(-1.0_f32);
+        let b = Length::from_base(3.0_f32);
+        assert_eq!(*a.midpoint(b).base(), 1.0);
+
+        let huge = Length::from_base(f64::MAX);
+        assert_eq!(huge.midpoint(huge), huge);
+    }
+}
@@ -0,0 +1,56 @@
+use super::Quantity;
+
+impl<V, D, S> Quantity<V, D, S> {
+    /// Transform the base value, keeping dimension and scale
+    ///
+    /// The closure receives and returns the value in base units, so it can
+    /// apply a calibration curve or filter without unwrapping the quantity.
+    /// It may also change the value type.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::length::Length;
+    ///
+    /// let raw = Length::from_base(1.02);
+    /// let calibrated = raw.map(|meters| meters * 0.98 - 0.001);
+    /// ```
+    pub fn map<W, F>(self, f: F) -> Quantity<W, D, S>
+    where
+        F: FnOnce(V) -> W,
+    {
+        Quantity::from_base(f(self.value))
+    }
+
+    /// Fallibly transform the base value, keeping dimension and scale
+    ///
+    /// Like [`map`](Self::map), but the closure can reject the value; its
+    /// error is returned unchanged.
+    pub fn try_map<W, E, F>(self, f: F) -> Result<Quantity<W, D, S>, E>
+    where
+        F: FnOnce(V) -> Result<W, E>,
+    {
+        f(self.value).map(Quantity::from_base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use crate::si::temperature::Temperature;
+
+    #[test]
+    fn test_map_applies_calibration() {
+        let raw = Temperature::from_base(300.0);
+        let calibrated = raw.map(|kelvin| 1.01 * kelvin - 2.0);
+        assert_eq!(*calibrated.base(), 301.0);
+        let single: Length<f32> = Length::from_base(2.5_f64).map(|m| m as f32);
+        assert_eq!(*single.base(), 2.5_f32);
+    }
+
+    #[test]
+    fn test_try_map_propagates_errors() {
+        let ok: Result<Length<u8>, _> = Length::from_base(200_i32).try_map(u8::try_from);
+        assert_eq!(ok.map(|length| *length.base()), Ok(200));
+        assert!(Length::from_base(-1_i32).try_map(u8::try_from).is_err());
+    }
+}
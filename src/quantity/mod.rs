@@ -1,5 +1,3 @@
-use num_traits::Num;
-
 /// Create a dimension type alias
 ///
 /// This macro generates a public type alias for any dimension type. It creates
@@ -31,14 +29,14 @@ use num_traits::Num;
 /// // Type safety prevents dimensional errors
 /// // let invalid = distance + time_val; // ❌ Compile error!
 ///
-/// // Display quantities with their dimensions
-/// println!("Distance: {}", distance);  // Shows value with unit
-/// println!("Velocity: {}", velocity);  // Shows value with derived unit
+/// // Display quantities with their base unit
+/// println!("Distance: {}", distance);  // "Distance: 100 m"
+/// println!("Velocity: {}", velocity);  // "Velocity: 10 m/s"
 /// ```
 #[macro_export]
 macro_rules! quantity {
     ($name:ident, $dimension:ty, $scale_name:ty, $base_unit:ty) => {
-        ::paste::paste! {
+        $crate::paste::paste! {
             mod [<$name:snake>] {
                 use super::*;
 
@@ -52,38 +50,91 @@ macro_rules! quantity {
             impl $crate::quantity::BaseUnitOf<$dimension> for Scale {
                 type BaseUnit = $base_unit;
             }
+
+            // The base unit routes unit-to-unit conversions through itself
+            impl $crate::conversions::ConvertibleUnit for $base_unit {
+                type BaseUnit = $base_unit;
+                const INT_FACTOR: Option<(u128, u128)> = Some((1, 1));
+            }
+
+            impl $crate::unit::IntFactor<$base_unit> for $base_unit {
+                const NUM: u128 = 1;
+                const DEN: u128 = 1;
+            }
         }
     };
 }
 
 pub mod add;
+pub mod affine;
+pub mod bounded;
 // pub mod as_primitive;
-// pub mod checked_add;
-// pub mod checked_div;
-// pub mod checked_mul;
-// pub mod checked_neg;
+pub mod checked_add;
+pub mod checked_div;
+pub mod checked_mul;
+pub mod checked_neg;
 // pub mod checked_rem;
-// pub mod checked_sub;
+pub mod checked_sub;
+pub mod clamp;
+#[cfg(feature = "num-complex")]
+pub mod complex;
 pub mod const_one;
 pub mod const_zero;
+#[cfg(any(feature = "rust_decimal", feature = "num-rational"))]
+mod decimal_digits;
+pub mod display;
 pub mod div;
+pub mod euclid;
+#[cfg(feature = "fixed")]
+pub mod fixed;
 pub mod float;
 pub mod float_const;
-// pub mod from_primitive;
+pub mod format_si;
+pub mod from_primitive;
+#[cfg(feature = "half")]
+pub mod half;
+pub mod hash;
+pub mod inv;
+pub mod lerp;
+pub mod map;
 pub mod mul;
 pub mod mul_add;
 pub mod mul_add_assign;
-// pub mod neg;
-// pub mod num_cast;
+pub mod neg;
+#[cfg(feature = "num-bigint")]
+pub mod num_bigint;
+pub mod num_cast;
+#[cfg(feature = "num-rational")]
+pub mod num_rational;
 pub mod one;
+pub mod ord;
+pub mod positive;
 pub mod pow;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+#[cfg(feature = "rand")]
+pub mod rand;
+pub mod registry;
 pub mod rem;
-// pub mod saturating_add;
-// pub mod saturating_mul;
-// pub mod saturating_sub;
+pub mod round_to;
+#[cfg(feature = "rust_decimal")]
+pub mod rust_decimal;
+pub mod saturating_add;
+pub mod saturating_mul;
+pub mod saturating_sub;
+#[cfg(feature = "schemars")]
+pub mod schemars;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod signed;
 pub mod sub;
-// pub mod to_primitive;
+pub mod sum;
+pub mod to_primitive;
+pub mod try_to;
+#[cfg(feature = "wide")]
+pub mod wide;
 // pub mod wrapping_add;
 // pub mod wrapping_mul;
 // pub mod wrapping_neg;
@@ -132,12 +183,9 @@ where
 
 impl<V, D, S> Copy for Quantity<V, D, S> where V: Copy {}
 
-impl<V, D, S> Quantity<V, D, S>
-where
-    V: Num,
-{
+impl<V, D, S> Quantity<V, D, S> {
     /// Get the value of this quantity scaled to its base units
-    pub fn base(&self) -> &V {
+    pub const fn base(&self) -> &V {
         &self.value
     }
 
@@ -159,7 +207,7 @@ where
 // Generic implementations for any numeric type that supports unit conversions
 impl<V, D, S> Quantity<V, D, S>
 where
-    V: num_traits::Num + Copy,
+    V: num_traits::Num + Clone,
 {
     /// Create a quantity from a value in a specific unit
     ///
@@ -187,7 +235,7 @@ where
     }
 
     /// Create a quantity from a value in the base unit (no conversion)
-    pub fn from_base_unit(value: V) -> Self
+    pub const fn from_base_unit(value: V) -> Self
     where
         S: BaseUnitOf<D>,
         S::BaseUnit: crate::unit::Unit,
@@ -215,7 +263,7 @@ where
         S::BaseUnit: crate::unit::Unit + crate::unit::FromUnit<U, V>,
     {
         // Convert FROM BaseUnit TO U using from_base
-        <S::BaseUnit as crate::unit::FromUnit<U, V>>::from_base(self.value)
+        <S::BaseUnit as crate::unit::FromUnit<U, V>>::from_base(self.value.clone())
     }
 
     /// Get the value of this quantity in the base unit (no conversion)
@@ -224,7 +272,29 @@ where
         S: BaseUnitOf<D>,
         S::BaseUnit: crate::unit::Unit,
     {
-        self.value
+        self.value.clone()
+    }
+
+    /// Alias of [`from`](Self::from) matching uom's `Quantity::new::<Unit>`
+    ///
+    /// Together with [`get`](Self::get), eases porting code from uom.
+    pub fn new<U>(value: V) -> Self
+    where
+        U: crate::unit::Unit,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: crate::unit::Unit + crate::unit::FromUnit<U, V>,
+    {
+        Self::from::<U>(value)
+    }
+
+    /// Alias of [`to`](Self::to) matching uom's `Quantity::get::<Unit>`
+    pub fn get<U>(&self) -> V
+    where
+        U: crate::unit::Unit,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: crate::unit::Unit + crate::unit::FromUnit<U, V>,
+    {
+        self.to::<U>()
     }
 }
 
@@ -237,16 +307,53 @@ pub trait BaseUnitOf<D> {
 // Unit-aware methods for f64 quantities
 impl<D, S> Quantity<f64, D, S> {
     // Unit-specific methods are now generated by the unit! macro
+
+    /// Create a quantity from a value in a specific unit, usable in `const` items
+    ///
+    /// Only available for units with a constant scale factor to the base unit
+    /// (see `UnitFactor`); the result is identical to `from::<U>`.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::velocity::{KilometerPerHour, Velocity};
+    ///
+    /// const MAX_SPEED: Velocity<f64> = Velocity::const_from::<KilometerPerHour>(120.0);
+    /// ```
+    pub const fn const_from<U>(value: f64) -> Self
+    where
+        U: crate::unit::Unit,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: crate::unit::UnitFactor<U>,
+    {
+        Self::from_base(value * <S::BaseUnit as crate::unit::UnitFactor<U>>::FACTOR)
+    }
+
+    /// Get the value in a specific unit in a `const` context, see `const_from`
+    pub const fn const_to<U>(&self) -> f64
+    where
+        U: crate::unit::Unit,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: crate::unit::UnitFactor<U>,
+    {
+        self.value / <S::BaseUnit as crate::unit::UnitFactor<U>>::FACTOR
+    }
 }
 
-// Display implementation
+// Display implementation: the base value followed by the base unit abbreviation,
+// e.g. "12.5 m". Precision and sign apply to the value; width, fill and
+// alignment pad the whole text. The alternate flag `{:#}` spells the
+// abbreviation in ASCII.
 impl<V, D, S> core::fmt::Display for Quantity<V, D, S>
 where
     V: core::fmt::Display,
-    D: core::fmt::Display + Default,
+    S: BaseUnitOf<D>,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{} [{}]", self.value, D::default())
+        display::write_with_symbol(
+            f,
+            &self.value,
+            <S::BaseUnit as crate::unit::Unit>::ABBREVIATION,
+        )
     }
 }
 
@@ -272,10 +379,25 @@ mod tests {
 
     #[test]
     fn test_display() {
-        let length = crate::si::length::Length::from_base(3.14159);
-        // Test that the display trait is implemented
-        // In no_std, we can't easily test format! but we can verify the trait exists
-        let _: &dyn core::fmt::Display = &length;
+        let length = crate::si::length::Length::from_base(12.5);
+        assert_eq!(format!("{length}"), "12.5 m");
+        assert_eq!(format!("{length:.2}"), "12.50 m");
+
+        let velocity: crate::si::velocity::Velocity<f64> =
+            length / crate::si::time::Time::from_base(2.5);
+        assert_eq!(format!("{velocity}"), "5 m/s");
+
+        let force = crate::si::force::Force::from_base(3_i32);
+        assert_eq!(format!("{force}"), "3 N");
+    }
+
+    #[test]
+    fn test_uom_style_aliases() {
+        use crate::si::length::{Kilometer, Length, Meter};
+
+        let distance = Length::new::<Kilometer>(2.5);
+        assert_eq!(distance, Length::from::<Kilometer>(2.5));
+        assert_eq!(distance.get::<Meter>(), 2500.0);
     }
 
     #[test]
@@ -403,4 +525,47 @@ mod tests {
             <Kilometer as FromUnit<Meter>>::from_base(2.5)
         );
     }
+
+    #[test]
+    fn test_const_from() {
+        use crate::si::length::{Kilometer, Length, Millimeter};
+        use crate::si::mass::{Gram, Mass};
+        use crate::si::velocity::{KilometerPerHour, Velocity};
+
+        const MAX_SPEED: Velocity<f64> = Velocity::const_from::<KilometerPerHour>(120.0);
+        const STEP: Length<f64> = Length::const_from::<Millimeter>(2.5);
+        const MAX_SPEED_KMH: f64 = MAX_SPEED.const_to::<KilometerPerHour>();
+
+        assert_eq!(MAX_SPEED, Velocity::from::<KilometerPerHour>(120.0));
+        assert_eq!(STEP, Length::from::<Millimeter>(2.5));
+        assert_eq!(MAX_SPEED_KMH, MAX_SPEED.to::<KilometerPerHour>());
+        assert_eq!(Mass::const_from::<Gram>(250.0), Mass::from_base(0.25));
+        assert_eq!(
+            Length::const_from::<Kilometer>(1.5).const_to::<Kilometer>(),
+            1.5
+        );
+    }
+
+    #[test]
+    fn test_const_conversion_chain() {
+        use crate::conversions::conversion_factor;
+        use crate::si::acceleration::{Acceleration, FootPerHourSquared};
+        use crate::si::length::{Foot, Length, Mile};
+
+        const FEET_PER_MILE: f64 = conversion_factor::<Mile, Foot>();
+        const RUN: Length<f64> = Length::const_from::<Mile>(2.0);
+        const RUN_METERS: f64 = *RUN.base();
+        const RUN_FEET: f64 = RUN.const_to::<Foot>();
+
+        assert!((FEET_PER_MILE - 5280.0).abs() < 1e-9);
+        assert_eq!(RUN_METERS, 3218.688);
+        assert!((RUN_FEET - 10_560.0).abs() < 1e-9);
+        // The runtime path uses the same constant, so both agree exactly
+        let drift = Acceleration::from::<FootPerHourSquared>(7.0);
+        assert_eq!(drift, Acceleration::const_from::<FootPerHourSquared>(7.0));
+        assert_eq!(
+            drift.to::<FootPerHourSquared>(),
+            drift.const_to::<FootPerHourSquared>()
+        );
+    }
 }
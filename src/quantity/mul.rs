@@ -1,13 +1,11 @@
 use super::Quantity;
-use core::ops::{Add, Mul};
+use core::ops::{Add, Mul, MulAssign};
 use num_traits::Num;
 
 // Multiplication: Multiplies values and adds dimensions (D1 + D2), preserve scale
 impl<V1, V2, D1, D2, S> Mul<Quantity<V2, D2, S>> for Quantity<V1, D1, S>
 where
-    V1: Num + Mul<V2>,
-    V2: Num,
-    <V1 as Mul<V2>>::Output: Num,
+    V1: Mul<V2>,
     D1: Add<D2>,
 {
     type Output = Quantity<<V1 as Mul<V2>>::Output, <D1 as Add<D2>>::Output, S>;
@@ -20,7 +18,7 @@ where
 // Scalar multiplication (quantity * scalar)
 impl<V, D, S> Mul<V> for Quantity<V, D, S>
 where
-    V: Num + Mul<Output = V> + Copy,
+    V: Num + Mul<Output = V>,
 {
     type Output = Quantity<V, D, S>;
 
@@ -29,6 +27,63 @@ where
     }
 }
 
+// By reference (`&a * &b`), adding dimensions like the owned impl
+impl<'a, 'b, V1, V2, D1, D2, S> Mul<&'b Quantity<V2, D2, S>> for &'a Quantity<V1, D1, S>
+where
+    &'a V1: Mul<&'b V2>,
+    D1: Add<D2>,
+{
+    type Output = Quantity<<&'a V1 as Mul<&'b V2>>::Output, <D1 as Add<D2>>::Output, S>;
+
+    fn mul(self, rhs: &'b Quantity<V2, D2, S>) -> Self::Output {
+        Quantity::from_base(&self.value * &rhs.value)
+    }
+}
+
+// Scalar multiplication by reference (`&quantity * scalar`)
+impl<'a, V, D, S> Mul<V> for &'a Quantity<V, D, S>
+where
+    V: Num,
+    &'a V: Mul<V, Output = V>,
+{
+    type Output = Quantity<V, D, S>;
+
+    fn mul(self, scalar: V) -> Self::Output {
+        Quantity::from_base(&self.value * scalar)
+    }
+}
+
+// Scalar multiplication from the left (scalar * quantity). The scalar impl
+// above is generic, but a foreign type can only be the left operand for
+// concrete types, so the primitives are listed.
+macro_rules! impl_left_scalar_mul {
+    ($($scalar:ty),+) => {
+        $(
+            impl<D, S> Mul<Quantity<$scalar, D, S>> for $scalar {
+                type Output = Quantity<$scalar, D, S>;
+
+                fn mul(self, quantity: Quantity<$scalar, D, S>) -> Self::Output {
+                    Quantity::from_base(self * quantity.value)
+                }
+            }
+        )+
+    };
+}
+
+impl_left_scalar_mul!(
+    f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+// Scalar multiplication in place (quantity *= scalar)
+impl<V, D, S> MulAssign<V> for Quantity<V, D, S>
+where
+    V: Num + MulAssign,
+{
+    fn mul_assign(&mut self, scalar: V) {
+        self.value *= scalar;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::si::area::Area;
@@ -42,6 +97,16 @@ mod tests {
         assert_eq!(*doubled.base(), 10.0);
     }
 
+    #[test]
+    fn test_reference_multiplication() {
+        let width = Length::from_base(3.0);
+        let height = Length::from_base(4.0);
+
+        let area: Area<f64> = &width * &height;
+        assert_eq!(*area.base(), 12.0);
+        assert_eq!(*(&width * 2.0).base(), 6.0);
+    }
+
     #[test]
     fn test_dimensional_multiplication() {
         // Test that multiplication properly adds dimensions
@@ -76,4 +141,20 @@ mod tests {
         // Expected: 3.048 * 2.0 = 6.096 square meters
         assert!((mixed_area.into_base() - 6.096).abs() < 0.001);
     }
+
+    #[test]
+    fn test_scalar_mul_assign() {
+        let mut length = Length::from_base(1.5);
+        length *= 4.0;
+        assert_eq!(*length.base(), 6.0);
+    }
+
+    #[test]
+    fn test_left_scalar_multiplication() {
+        let length = Length::from_base(5.0);
+        assert_eq!(2.0 * length, length * 2.0);
+        assert_eq!(*(3_u32 * Length::from_base(4_u32)).base(), 12);
+        let area: Area<f32> = 0.5_f32 * (Length::from_base(2.0_f32) * Length::from_base(3.0));
+        assert_eq!(*area.base(), 3.0);
+    }
 }
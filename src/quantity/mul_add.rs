@@ -42,7 +42,7 @@ where
 // Dimensional MulAdd operations
 impl<V, D1, S> Quantity<V, D1, S>
 where
-    V: Num + MulAdd + Copy,
+    V: Num + MulAdd,
 {
     /// Dimensional fused multiply-add: self * multiplier + addend
     /// Where multiplier has dimension D2, and addend has dimension D1 + D2
@@ -8,7 +8,7 @@ use num_traits::{MulAddAssign, Num};
 // depending on the implementation in num-traits for the specific numeric type.
 impl<V, D, S> Quantity<V, D, S>
 where
-    V: Num + MulAddAssign + Copy,
+    V: Num + MulAddAssign,
 {
     /// Fused multiply-add assignment with same-type quantities: self = self * a + b
     /// All quantities must have the same dimension
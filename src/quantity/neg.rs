@@ -0,0 +1,47 @@
+use super::Quantity;
+use core::ops::Neg;
+
+// Negation keeps the dimension: -(5 m) = -5 m
+impl<V, D, S> Neg for Quantity<V, D, S>
+where
+    V: Neg<Output = V>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Quantity {
+            value: -self.value,
+            _dimension: core::marker::PhantomData,
+            _scale: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, V, D, S> Neg for &'a Quantity<V, D, S>
+where
+    &'a V: Neg<Output = V>,
+{
+    type Output = Quantity<V, D, S>;
+
+    fn neg(self) -> Self::Output {
+        Quantity::from_base(-&self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+
+    #[test]
+    fn test_neg() {
+        assert_eq!(*(-Length::from_base(2.5)).base(), -2.5);
+        assert_eq!(*(-Length::from_base(-7_i32)).base(), 7);
+    }
+
+    #[test]
+    fn test_neg_reference() {
+        let length = Length::from_base(2.5);
+        assert_eq!(*(-&length).base(), -2.5);
+        assert_eq!(*length.base(), 2.5);
+    }
+}
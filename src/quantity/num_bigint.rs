@@ -0,0 +1,93 @@
+use super::num_rational::{self as rational, Factor, RationalInteger};
+use ::num_rational::Ratio;
+
+// Unit conversions for `BigInt`/`BigUint` values are generated by
+// `convert_linear!` (through `__convert_bigint!`). The value is scaled as an
+// exact fraction and rounded once at the end, half away from zero, matching the
+// primitive integer conversions without their detour through `f64`.
+
+/// Convert a value in a derived unit to the base unit: `value * factor + offset`
+#[doc(hidden)]
+pub fn to_base<T: RationalInteger>(value: T, factor: &Factor) -> T {
+    rational::to_base(Ratio::from_integer(value), factor)
+        .round()
+        .to_integer()
+}
+
+/// Convert a base-unit value to the derived unit: `(value - offset) / factor`
+///
+/// # Panics
+/// For `BigUint` values, if the offset is larger than the value.
+#[doc(hidden)]
+pub fn from_base<T: RationalInteger>(value: T, factor: &Factor) -> T {
+    rational::from_base(Ratio::from_integer(value), factor)
+        .round()
+        .to_integer()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Kilometer, Length, Meter, Mile, Millimeter};
+    use crate::si::temperature::{DegreeFahrenheit, Temperature};
+    use crate::si::volume::{CubicYoctometer, CubicYottameter, Volume};
+    use ::num_bigint::{BigInt, BigUint};
+
+    #[test]
+    fn test_accumulate_beyond_u128() {
+        // 1000 readings of 10^36 mm each overflow u128 (about 3.4 * 10^38)
+        let reading = Length::from::<Millimeter>(BigUint::from(10_u8).pow(36));
+        let total: Length<BigUint> = core::iter::repeat_n(reading, 1000).sum();
+        assert_eq!(total.to::<Kilometer>(), BigUint::from(10_u8).pow(33));
+        assert_eq!(*total.base(), BigUint::from(10_u8).pow(36));
+    }
+
+    #[test]
+    fn test_conversions_round() {
+        let length = Length::from::<Mile>(BigInt::from(3));
+        assert_eq!(*length.base(), BigInt::from(4828));
+        assert_eq!(length.to::<Mile>(), BigInt::from(3));
+        assert_eq!(
+            Length::from_base(BigInt::from(-2500)).to::<Kilometer>(),
+            BigInt::from(-3)
+        );
+    }
+
+    #[test]
+    fn test_inexact_factor() {
+        // 5/9 is stored as the decimal 0.5555555555555556
+        let interval = Temperature::from::<DegreeFahrenheit>(BigInt::from(9));
+        assert_eq!(*interval.base(), BigInt::from(5));
+        assert_eq!(interval.to::<DegreeFahrenheit>(), BigInt::from(9));
+    }
+
+    #[test]
+    fn test_arithmetic_without_copy() {
+        let a = Length::from_base(BigInt::from(7));
+        let b = Length::from_base(BigInt::from(5));
+        assert_eq!(*(&a - &b).base(), BigInt::from(2));
+        assert_eq!(*(&a * BigInt::from(3)).base(), BigInt::from(21));
+        assert_eq!(*(-&a).base(), BigInt::from(-7));
+        assert_eq!(*(&a * &b).base(), BigInt::from(35));
+        assert_eq!(*(a + b).base(), BigInt::from(12));
+    }
+
+    #[test]
+    fn test_base_unit_without_copy() {
+        let length = Length::from::<Meter>(BigInt::from(3));
+        assert_eq!(length.to::<Meter>(), BigInt::from(3));
+        assert_eq!(format!("{}", length.display::<Meter>()), "3 m");
+    }
+
+    #[test]
+    fn test_cubic_prefix_factors() {
+        // The factors near 10^±72 have 73 digits; their f64 rounding (YOCTO³
+        // is 1.0000000000000003e-72) carries over
+        let per_cubic_yoctometer = Volume::from_base(BigInt::from(1)).to::<CubicYoctometer>();
+        assert_eq!(per_cubic_yoctometer.to_string().len(), 73);
+        let back = Volume::from::<CubicYoctometer>(per_cubic_yoctometer);
+        assert_eq!(*back.base(), BigInt::from(1));
+        let large = Volume::from::<CubicYottameter>(BigInt::from(2));
+        assert_eq!(large.base().to_string().len(), 73);
+        assert_eq!(large.to::<CubicYottameter>(), BigInt::from(2));
+    }
+}
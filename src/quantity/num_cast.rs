@@ -0,0 +1,65 @@
+use super::Quantity;
+use num_traits::{NumCast, ToPrimitive};
+
+// `NumCast::from` builds a quantity from any primitive as its base value. Call
+// it as `<Length as NumCast>::from(n)`: plain `Length::from` resolves to the
+// inherent unit conversion.
+impl<V, D, S> NumCast for Quantity<V, D, S>
+where
+    V: NumCast,
+{
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        V::from(n).map(Self::from_base)
+    }
+}
+
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: ToPrimitive,
+{
+    /// Convert the storage type, keeping dimension and scale
+    ///
+    /// Returns `None` if the base value does not fit `T`, e.g. a negative
+    /// value cast to an unsigned type or NaN cast to an integer.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::length::Length;
+    ///
+    /// let precise: Length<f64> = Length::from_base(2.5_f32).cast().unwrap();
+    /// let counts = Length::from_base(7_i64).cast::<f64>();
+    /// ```
+    pub fn cast<T: NumCast>(self) -> Option<Quantity<T, D, S>> {
+        T::from(self.value).map(Quantity::from_base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Kilometer, Length};
+    use crate::si::time::Time;
+    use num_traits::NumCast;
+
+    #[test]
+    fn test_cast_between_storage_types() {
+        let single = Length::from::<Kilometer>(1.25_f32);
+        let double: Length<f64> = single.cast().unwrap();
+        assert_eq!(double.to::<Kilometer>(), 1.25);
+        assert_eq!(*Time::from_base(7_i64).cast::<f64>().unwrap().base(), 7.0);
+        assert_eq!(*Time::from_base(2.9_f64).cast::<u8>().unwrap().base(), 2);
+    }
+
+    #[test]
+    fn test_cast_out_of_range() {
+        assert_eq!(Time::from_base(-1_i32).cast::<u32>(), None);
+        assert_eq!(Length::from_base(f64::NAN).cast::<i64>(), None);
+        assert_eq!(Length::from_base(300_u16).cast::<i8>(), None);
+    }
+
+    #[test]
+    fn test_num_cast_trait() {
+        let length: Length<f32> = <Length<f32> as NumCast>::from(3_u8).unwrap();
+        assert_eq!(*length.base(), 3.0);
+        assert_eq!(<Time<u8> as NumCast>::from(-2_i32), None);
+    }
+}
@@ -0,0 +1,159 @@
+use super::decimal_digits::DecimalDigits;
+use ::num_rational::Ratio;
+use num_integer::Integer;
+use num_traits::{CheckedAdd, CheckedMul, FromPrimitive, Zero};
+
+// Unit conversions for `Ratio` values are generated by `convert_linear!`
+// (through `__convert_rational!`), which stores each unit's `Factor` as an
+// associated constant, and call the helpers below. Decimal factors become
+// exact fractions (0.0254 = 127/5000), so a conversion followed by its inverse
+// returns the original value.
+
+/// Integer types usable as the numerator and denominator of a converted `Ratio`
+#[doc(hidden)]
+pub trait RationalInteger: Clone + Integer + CheckedMul + CheckedAdd + FromPrimitive {}
+
+impl<T> RationalInteger for T where T: Clone + Integer + CheckedMul + CheckedAdd + FromPrimitive {}
+
+/// An exact conversion constant: a shortest round-trip decimal, or the
+/// `IntFactor` fraction of units declared with `convert_int_linear!`
+#[derive(Clone, Copy, Debug)]
+enum Exact {
+    Decimal(DecimalDigits),
+    Fraction(u128, u128),
+}
+
+/// The scale and offset of a `convert_linear!` entry as exact constants,
+/// independent of the integer type of the `Ratio`
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct Factor {
+    scale: Option<Exact>,
+    offset: Option<Exact>,
+}
+
+impl Factor {
+    /// The factor of `scale` and `offset`, taking the scale from `int_factor`
+    /// where the unit has one
+    pub const fn new(scale: f64, offset: f64, int_factor: Option<(u128, u128)>) -> Self {
+        Self {
+            scale: match int_factor {
+                Some((num, den)) => Some(Exact::Fraction(num, den)),
+                None => decimal(scale),
+            },
+            offset: decimal(offset),
+        }
+    }
+
+    fn has_offset(&self) -> bool {
+        !matches!(
+            self.offset,
+            Some(Exact::Decimal(DecimalDigits { digits: 0, .. }))
+        )
+    }
+}
+
+const fn decimal(value: f64) -> Option<Exact> {
+    match DecimalDigits::of(value) {
+        Some(decimal) => Some(Exact::Decimal(decimal)),
+        None => None,
+    }
+}
+
+/// The `Factor` of converting unit `From` to this base unit, implemented by
+/// `convert_linear!`
+#[doc(hidden)]
+pub trait RationalFactor<From> {
+    const FACTOR: Factor;
+}
+
+/// `digits * 10^exponent` as a reduced fraction, or `None` if it overflows `T`
+fn decimal_ratio<T: RationalInteger>(decimal: DecimalDigits) -> Option<Ratio<T>> {
+    let digits = T::from_u64(decimal.digits)?;
+    let power = num_traits::checked_pow(T::from_u8(10)?, decimal.exponent.unsigned_abs().into())?;
+    let ratio = if decimal.exponent < 0 {
+        Ratio::new(digits, power)
+    } else {
+        Ratio::from_integer(digits.checked_mul(&power)?)
+    };
+    Some(if decimal.negative {
+        Ratio::zero() - ratio
+    } else {
+        ratio
+    })
+}
+
+/// The fraction of a conversion constant
+///
+/// # Panics
+/// If the fraction does not fit `T`, e.g. the yotta prefix (10^24) in
+/// `Ratio<i64>`; the converted value would overflow in that case anyway.
+fn exact<T: RationalInteger>(factor: Option<Exact>) -> Ratio<T> {
+    factor
+        .and_then(|factor| match factor {
+            Exact::Decimal(decimal) => decimal_ratio(decimal),
+            Exact::Fraction(num, den) => Some(Ratio::new(T::from_u128(num)?, T::from_u128(den)?)),
+        })
+        .expect("conversion factor does not fit the rational value type")
+}
+
+/// Convert a value in a derived unit to the base unit: `value * factor + offset`
+#[doc(hidden)]
+pub fn to_base<T: RationalInteger>(value: Ratio<T>, factor: &Factor) -> Ratio<T> {
+    let scaled = value * exact(factor.scale);
+    if factor.has_offset() {
+        scaled + exact(factor.offset)
+    } else {
+        scaled
+    }
+}
+
+/// Convert a base-unit value to the derived unit: `(value - offset) / factor`
+#[doc(hidden)]
+pub fn from_base<T: RationalInteger>(value: Ratio<T>, factor: &Factor) -> Ratio<T> {
+    let shifted = if factor.has_offset() {
+        value - exact(factor.offset)
+    } else {
+        value
+    };
+    shifted / exact(factor.scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecimalDigits, Exact, RationalInteger, exact};
+    use crate::si::length::{Inch, Length, Meter, Mile};
+    use ::num_rational::{BigRational, Ratio, Rational64};
+
+    fn ratio<T: RationalInteger>(numer: i64, denom: i64) -> Ratio<T> {
+        Ratio::new(T::from_i64(numer).unwrap(), T::from_i64(denom).unwrap())
+    }
+
+    #[test]
+    fn test_factor_is_exact_fraction() {
+        let factor = |value| exact::<i64>(DecimalDigits::of(value).map(Exact::Decimal));
+        assert_eq!(factor(0.0254), Rational64::new(127, 5000));
+        assert_eq!(factor(-2.5), Rational64::new(-5, 2));
+        assert_eq!(factor(1e6), Rational64::from_integer(1_000_000));
+        let third = exact::<i64>(Some(Exact::Fraction(1, 3)));
+        assert_eq!(third, Rational64::new(1, 3));
+    }
+
+    #[test]
+    fn test_inch_round_trip() {
+        let length = Length::from::<Inch>(Rational64::new(1, 3));
+        assert_eq!(*length.base(), Rational64::new(127, 15000));
+        assert_eq!(length.to::<Inch>(), Rational64::new(1, 3));
+        let meters = Length::<Rational64>::from::<Meter>(length.to::<Meter>());
+        assert_eq!(meters.to::<Inch>(), Rational64::new(1, 3));
+    }
+
+    #[test]
+    fn test_big_rational() {
+        let length = Length::<BigRational>::from::<Mile>(ratio(7, 2));
+        assert_eq!(*length.base(), ratio(704_088, 125));
+        assert_eq!(length.to::<Mile>(), ratio(7, 2));
+        let meters = Length::from::<Meter>(length.to::<Meter>());
+        assert_eq!(meters.get::<Meter>(), ratio(704_088, 125));
+    }
+}
@@ -0,0 +1,82 @@
+use super::Quantity;
+use core::cmp::Ordering;
+use num_traits::float::TotalOrder;
+
+// Ordering compares base values, which is meaningful because both sides share
+// the same dimension and scale. `Self: PartialEq`/`Eq` carries over the bounds of
+// the derived equality impls.
+impl<V, D, S> PartialOrd for Quantity<V, D, S>
+where
+    V: PartialOrd,
+    Self: PartialEq,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<V, D, S> Ord for Quantity<V, D, S>
+where
+    V: Ord,
+    Self: Eq,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: TotalOrder,
+{
+    /// Total ordering of float-backed quantities (IEEE 754 `totalOrder`)
+    ///
+    /// Unlike `partial_cmp` this also orders NaN and signed zeros, so it can be
+    /// used with `sort_by` on slices that may contain them.
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.value.total_cmp(&other.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use crate::si::time::Time;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_partial_ord() {
+        let short = Length::from_base(1.5);
+        let long = Length::from_base(20.0);
+        assert!(short < long);
+        assert!(long >= short);
+        assert_eq!(Length::from_base(f64::NAN).partial_cmp(&short), None);
+    }
+
+    #[test]
+    fn test_ord_integer_keys() {
+        let mut schedule = BTreeMap::new();
+        schedule.insert(Time::from_base(30_u32), "second");
+        schedule.insert(Time::from_base(10_u32), "first");
+        let order: Vec<_> = schedule.values().copied().collect();
+        assert_eq!(order, ["first", "second"]);
+        assert_eq!(
+            Time::from_base(3_i64).max(Time::from_base(7_i64)),
+            Time::from_base(7_i64)
+        );
+    }
+
+    #[test]
+    fn test_total_cmp_sorts_floats() {
+        let mut lengths = [
+            Length::from_base(2.0),
+            Length::from_base(f64::NAN),
+            Length::from_base(-1.0),
+            Length::from_base(0.5),
+        ];
+        lengths.sort_by(|a, b| a.total_cmp(b));
+        assert_eq!(*lengths[0].base(), -1.0);
+        assert_eq!(*lengths[2].base(), 2.0);
+        assert!(lengths[3].base().is_nan());
+    }
+}
@@ -0,0 +1,189 @@
+use super::Quantity;
+use core::ops::{Add, Mul};
+use num_traits::{Float, Zero};
+use typenum::{P2, PartialDiv};
+
+/// A quantity known to be greater than zero
+///
+/// Built with the checked [`Positive::new`]; NaN is rejected. Sums stay
+/// positive, while products may underflow to zero and are [`NonNegative`].
+///
+/// # Examples
+/// ```rust,ignore
+/// use num_units::quantity::positive::Positive;
+/// use num_units::si::mass::Mass;
+///
+/// let payload = Positive::new(Mass::from_base(12.5)).unwrap();
+/// let tare = Positive::new(Mass::from_base(0.5)).unwrap();
+/// assert_eq!(*(payload + tare).get().base(), 13.0);
+/// assert!(Positive::new(Mass::from_base(-1.0)).is_none());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Positive<Q>(Q);
+
+/// A quantity known to be zero or greater
+///
+/// Built with the checked [`NonNegative::new`]; `-0.0` counts as zero and
+/// NaN is rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonNegative<Q>(Q);
+
+impl<V, D, S> Positive<Quantity<V, D, S>>
+where
+    V: Zero + PartialOrd,
+{
+    /// Wrap `quantity` if it is greater than zero
+    pub fn new(quantity: Quantity<V, D, S>) -> Option<Self> {
+        (quantity.value > V::zero()).then_some(Self(quantity))
+    }
+}
+
+impl<V, D, S> NonNegative<Quantity<V, D, S>>
+where
+    V: Zero + PartialOrd,
+{
+    /// Wrap `quantity` if it is zero or greater
+    pub fn new(quantity: Quantity<V, D, S>) -> Option<Self> {
+        (quantity.value >= V::zero()).then_some(Self(quantity))
+    }
+}
+
+macro_rules! impl_wrapper {
+    ($wrapper:ident) => {
+        impl<Q> $wrapper<Q> {
+            /// The wrapped quantity
+            pub fn get(&self) -> &Q {
+                &self.0
+            }
+
+            /// Unwrap into the plain quantity
+            pub fn into_inner(self) -> Q {
+                self.0
+            }
+        }
+
+        impl<V, D, S> From<$wrapper<Quantity<V, D, S>>> for Quantity<V, D, S> {
+            fn from(wrapped: $wrapper<Quantity<V, D, S>>) -> Self {
+                wrapped.0
+            }
+        }
+
+        impl<V, D, S> $wrapper<Quantity<V, D, S>>
+        where
+            V: Float,
+        {
+            /// Square root, which keeps the sign and halves the dimension:
+            /// the root of an area is a length
+            pub fn sqrt(self) -> $wrapper<Quantity<V, <D as PartialDiv<P2>>::Output, S>>
+            where
+                D: PartialDiv<P2>,
+            {
+                $wrapper(Quantity::from_base(self.0.value.sqrt()))
+            }
+        }
+    };
+}
+
+impl_wrapper!(Positive);
+impl_wrapper!(NonNegative);
+
+impl<Q> From<Positive<Q>> for NonNegative<Q> {
+    fn from(positive: Positive<Q>) -> Self {
+        NonNegative(positive.0)
+    }
+}
+
+impl<V, D, S> Positive<Quantity<V, D, S>>
+where
+    V: Float,
+    // Only dimensionless quantities multiply into themselves (see `Product`)
+    Quantity<V, D, S>: Mul<Quantity<V, D, S>, Output = Quantity<V, D, S>>,
+{
+    /// Natural logarithm of a positive dimensionless value (e.g. of a ratio),
+    /// defined for every positive value
+    pub fn ln(self) -> Quantity<V, D, S> {
+        self.0.ln()
+    }
+}
+
+// Adding a non-negative quantity never lowers the value, so the sum keeps the
+// stronger guarantee of the two (integer overflow aside, as with plain
+// quantities).
+macro_rules! impl_add {
+    ($($lhs:ident + $rhs:ident => $output:ident),+ $(,)?) => {
+        $(
+            impl<Q: Add<Output = Q>> Add<$rhs<Q>> for $lhs<Q> {
+                type Output = $output<Q>;
+
+                fn add(self, other: $rhs<Q>) -> Self::Output {
+                    $output(self.0 + other.0)
+                }
+            }
+        )+
+    };
+}
+
+impl_add!(
+    Positive + Positive => Positive,
+    Positive + NonNegative => Positive,
+    NonNegative + Positive => Positive,
+    NonNegative + NonNegative => NonNegative,
+);
+
+// A product of positive floats can underflow to zero, so it is only known to
+// be non-negative. Non-negative factors are left out: `0 * ∞` is NaN.
+impl<Q1, Q2> Mul<Positive<Q2>> for Positive<Q1>
+where
+    Q1: Mul<Q2>,
+{
+    type Output = NonNegative<Q1::Output>;
+
+    fn mul(self, other: Positive<Q2>) -> Self::Output {
+        NonNegative(self.0 * other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NonNegative, Positive};
+    use crate::si::area::Area;
+    use crate::si::length::Length;
+    use crate::si::mass::Mass;
+    use crate::si::scalar::Scalar;
+
+    #[test]
+    fn test_checked_constructors() {
+        assert!(Positive::new(Mass::from_base(0.5)).is_some());
+        assert!(Positive::new(Mass::from_base(0.0)).is_none());
+        assert!(Positive::new(Mass::from_base(f64::NAN)).is_none());
+        assert!(NonNegative::new(Mass::from_base(0.0)).is_some());
+        assert!(NonNegative::new(Mass::from_base(-1_i32)).is_none());
+        assert!(NonNegative::new(Mass::from_base(f64::NAN)).is_none());
+    }
+
+    #[test]
+    fn test_arithmetic_keeps_domain() {
+        let a = Positive::new(Length::from_base(2.0)).unwrap();
+        let b = NonNegative::new(Length::from_base(0.0)).unwrap();
+        let sum: Positive<Length<f64>> = a + b;
+        assert_eq!(*sum.get(), Length::from_base(2.0));
+        let total: NonNegative<Length<f64>> = b + b;
+        assert_eq!(total.into_inner(), Length::from_base(0.0));
+
+        let area: NonNegative<Area<f64>> = a * a;
+        assert_eq!(*area.get().base(), 4.0);
+        let side: NonNegative<Length<f64>> = area.sqrt();
+        assert_eq!(side.into_inner(), Length::from_base(2.0));
+        let root: Positive<Length<f64>> = Positive::new(Area::from_base(9.0)).unwrap().sqrt();
+        assert_eq!(*root.get(), Length::from_base(3.0));
+    }
+
+    #[test]
+    fn test_float_functions() {
+        let ratio = Positive::new(Scalar::from_base(core::f64::consts::E)).unwrap();
+        assert_eq!(*ratio.ln().base(), 1.0);
+        let widened: NonNegative<Scalar<f64>> = ratio.into();
+        let plain: Scalar<f64> = widened.into();
+        assert_eq!(*plain.base(), core::f64::consts::E);
+    }
+}
@@ -1,6 +1,7 @@
 use super::Quantity;
 use core::ops::Mul;
 use num_traits::{Num, Pow};
+use typenum::{Integer, P2, P3};
 
 // Pow implementation
 // Raises a quantity to the power of another quantity, potentially with different value types
@@ -45,10 +46,67 @@ where
     }
 }
 
+// Type-level power
+//
+// The scalar `Pow` impls above keep the dimension unchanged. Raising to a
+// typenum exponent also multiplies the dimension exponents, so the result is a
+// different quantity (Length^3 = Volume). `num_traits::Pow<P3>` cannot be used
+// for this because it would overlap with the generic scalar impl.
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: Num + Clone,
+{
+    /// Raise to the typenum exponent `E`, scaling the dimension accordingly
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::length::Length;
+    /// use num_units::si::volume::Volume;
+    /// use typenum::P3;
+    ///
+    /// let volume: Volume<f64> = Length::from_base(2.0).typed_pow::<P3>();
+    /// assert_eq!(*volume.base(), 8.0);
+    /// ```
+    pub fn typed_pow<E>(self) -> Quantity<V, <D as typenum::Pow<E>>::Output, S>
+    where
+        E: Integer,
+        D: typenum::Pow<E>,
+    {
+        let magnitude = num_traits::pow(self.value, E::I32.unsigned_abs() as usize);
+        let value = if E::I32 < 0 {
+            V::one() / magnitude
+        } else {
+            magnitude
+        };
+        Quantity::from_base(value)
+    }
+
+    /// Square this quantity, e.g. `Length` to `Area`
+    pub fn squared(self) -> Quantity<V, <D as typenum::Pow<P2>>::Output, S>
+    where
+        D: typenum::Pow<P2>,
+    {
+        self.typed_pow::<P2>()
+    }
+
+    /// Cube this quantity, e.g. `Length` to `Volume`
+    pub fn cubed(self) -> Quantity<V, <D as typenum::Pow<P3>>::Output, S>
+    where
+        D: typenum::Pow<P3>,
+    {
+        self.typed_pow::<P3>()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::si::area::Area;
     use crate::si::length::Length;
+    use crate::si::radioactivity::Radioactivity;
+    use crate::si::time::Time;
+    use crate::si::volume::Volume;
     use num_traits::Pow; // Import the Pow trait to use the .pow() method
+    use typenum::{N1, N2, P3, Z0};
 
     #[test]
     fn test_scalar_power_with_integers() {
@@ -64,6 +122,28 @@ mod tests {
         assert_eq!(*cubed.base(), 27);
     }
 
+    #[test]
+    fn test_typed_pow_scales_dimension() {
+        let side = Length::from_base(3);
+        let area: Area<i32> = side.squared();
+        let volume: Volume<i32> = side.cubed();
+        assert_eq!(*area.base(), 9);
+        assert_eq!(*volume.base(), 27);
+
+        let same: Volume<f64> = Length::from_base(0.5).typed_pow::<P3>();
+        assert_eq!(*same.base(), 0.125);
+    }
+
+    #[test]
+    fn test_typed_pow_negative_and_zero() {
+        let rate: Radioactivity<f64> = Time::from_base(4.0).typed_pow::<N1>();
+        assert_eq!(*rate.base(), 0.25);
+        let per_area = Length::from_base(2.0).typed_pow::<N2>();
+        assert_eq!(*per_area.base(), 0.25);
+        let dimensionless = Length::from_base(7.0).typed_pow::<Z0>();
+        assert_eq!(*dimensionless.base(), 1.0);
+    }
+
     // Floating-point power tests - only available with std or libm features
     #[cfg(any(feature = "std", feature = "libm"))]
     #[test]
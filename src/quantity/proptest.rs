@@ -0,0 +1,47 @@
+use super::Quantity;
+use ::proptest::arbitrary::Arbitrary;
+use ::proptest::strategy::{Map, Strategy};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+// Generated quantities wrap a generated base value, so shrinking follows the
+// value type's own shrinking (towards zero for numbers).
+impl<V, D, S> Arbitrary for Quantity<V, D, S>
+where
+    V: Arbitrary,
+    Self: Debug,
+{
+    type Parameters = V::Parameters;
+    type Strategy = Map<V::Strategy, fn(V) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        V::arbitrary_with(args).prop_map(|value| Quantity {
+            value,
+            _dimension: PhantomData,
+            _scale: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use crate::si::time::Time;
+    use ::proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_addition_commutes(a: Length<i32>, b: Length<i32>) {
+            let a = Length::from_base(a.base() / 2);
+            let b = Length::from_base(b.base() / 2);
+            prop_assert_eq!(a + b, b + a);
+        }
+
+        #[test]
+        fn test_strategy_from_value_range(
+            t in (0.0..60.0_f64).prop_map(Time::from_base)
+        ) {
+            prop_assert!(t >= Time::from_base(0.0) && t < Time::from_base(60.0));
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use super::Quantity;
+use ::quickcheck::{Arbitrary, Gen};
+use core::marker::PhantomData;
+
+// Generation and shrinking both delegate to the base value.
+impl<V, D, S> Arbitrary for Quantity<V, D, S>
+where
+    V: Arbitrary,
+    D: 'static,
+    S: 'static,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Quantity {
+            value: V::arbitrary(g),
+            _dimension: PhantomData,
+            _scale: PhantomData,
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.value.shrink().map(|value| Quantity {
+            value,
+            _dimension: PhantomData,
+            _scale: PhantomData,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use ::quickcheck::{Arbitrary, Gen, quickcheck};
+
+    quickcheck! {
+        fn test_sub_self_is_zero(length: Length<i64>) -> bool {
+            length - length == Length::from_base(0)
+        }
+    }
+
+    #[test]
+    fn test_shrink_follows_value() {
+        let shrunk: Vec<_> = Length::from_base(8_u32).shrink().collect();
+        assert!(!shrunk.is_empty());
+        assert!(shrunk.iter().all(|length| *length.base() < 8));
+        let _ = Length::<f64>::arbitrary(&mut Gen::new(10));
+    }
+}
@@ -0,0 +1,110 @@
+use super::Quantity;
+use ::rand::Rng;
+use ::rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use ::rand::distributions::{Distribution, Standard};
+use core::marker::PhantomData;
+
+// Random quantities are sampled on the base value; the dimension and scale only
+// ride along in the type.
+impl<V, D, S> Distribution<Quantity<V, D, S>> for Standard
+where
+    Standard: Distribution<V>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quantity<V, D, S> {
+        Quantity {
+            value: rng.r#gen(),
+            _dimension: PhantomData,
+            _scale: PhantomData,
+        }
+    }
+}
+
+/// Uniform sampler for quantities, used by `Rng::gen_range` and
+/// `rand::distributions::Uniform`
+///
+/// Wraps the sampler of the value type `V`.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformQuantity<V: SampleUniform, D, S> {
+    sampler: V::Sampler,
+    _quantity: PhantomData<(D, S)>,
+}
+
+impl<V, D, S> SampleUniform for Quantity<V, D, S>
+where
+    V: SampleUniform,
+{
+    type Sampler = UniformQuantity<V, D, S>;
+}
+
+impl<V, D, S> UniformSampler for UniformQuantity<V, D, S>
+where
+    V: SampleUniform,
+{
+    type X = Quantity<V, D, S>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformQuantity {
+            sampler: V::Sampler::new(&low.borrow().value, &high.borrow().value),
+            _quantity: PhantomData,
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformQuantity {
+            sampler: V::Sampler::new_inclusive(&low.borrow().value, &high.borrow().value),
+            _quantity: PhantomData,
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Quantity {
+            value: self.sampler.sample(rng),
+            _dimension: PhantomData,
+            _scale: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Kilometer, Length, Meter};
+    use crate::si::time::Time;
+    use ::rand::distributions::Uniform;
+    use ::rand::rngs::SmallRng;
+    use ::rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_gen_range() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let low = Length::from::<Meter>(0.0);
+        let high = Length::from::<Meter>(10.0);
+        for _ in 0..100 {
+            let length = rng.gen_range(low..high);
+            assert!(length >= low && length < high);
+        }
+        let tick: Time<u8> = rng.gen_range(Time::from_base(1)..=Time::from_base(3));
+        assert!((1..=3).contains(tick.base()));
+    }
+
+    #[test]
+    fn test_standard_and_uniform() {
+        let mut rng = SmallRng::seed_from_u64(11);
+        let unit: Length<f64> = rng.r#gen();
+        assert!((0.0..1.0).contains(unit.base()));
+
+        let spread = Uniform::new(
+            Length::from::<Kilometer>(1.0),
+            Length::from::<Kilometer>(2.0),
+        );
+        let sample = rng.sample(spread);
+        assert!((1000.0..2000.0).contains(sample.base()));
+    }
+}
@@ -0,0 +1,245 @@
+use super::{BaseUnitOf, Quantity};
+use crate::unit::{AffineFromUnit, FromUnit, Unit};
+use core::fmt;
+
+/// A unit looked up at runtime, with its f64 conversions to the base unit
+///
+/// Entries are built by [`unit_registry!`](crate::unit_registry) and handed out
+/// by [`Quantity::unit_by_symbol`] and [`Quantity::unit_by_name`].
+#[derive(Clone, Copy)]
+pub struct RuntimeUnit {
+    /// The abbreviation, e.g. "km"
+    pub symbol: &'static str,
+    /// The singular name, e.g. "kilometer"
+    pub singular: &'static str,
+    /// The plural name, e.g. "kilometers"
+    pub plural: &'static str,
+    to_base: fn(f64) -> f64,
+    from_base: fn(f64) -> f64,
+}
+
+impl RuntimeUnit {
+    /// The entry of unit `U`, converting through base unit `B`
+    pub const fn of<B, U>() -> Self
+    where
+        U: Unit,
+        B: FromUnit<U, f64>,
+    {
+        Self {
+            symbol: U::ABBREVIATION,
+            singular: U::SINGULAR,
+            plural: U::PLURAL,
+            to_base: <B as FromUnit<U, f64>>::to_base,
+            from_base: <B as FromUnit<U, f64>>::from_base,
+        }
+    }
+
+    /// The entry of unit `U` on an offset scale, converting absolute values
+    /// (e.g. a reading of 20 °C) through base unit `B`
+    pub const fn absolute<B, U>() -> Self
+    where
+        U: Unit,
+        B: AffineFromUnit<U, f64>,
+    {
+        Self {
+            symbol: U::ABBREVIATION,
+            singular: U::SINGULAR,
+            plural: U::PLURAL,
+            to_base: <B as AffineFromUnit<U, f64>>::to_base_point,
+            from_base: <B as AffineFromUnit<U, f64>>::from_base_point,
+        }
+    }
+
+    /// Convert a value in this unit to the base unit
+    pub fn to_base(&self, value: f64) -> f64 {
+        (self.to_base)(value)
+    }
+
+    /// Convert a value in the base unit to this unit
+    pub fn from_base(&self, base_value: f64) -> f64 {
+        (self.from_base)(base_value)
+    }
+
+    /// Convert a value in this unit to unit `other` of the same registry
+    pub fn convert(&self, value: f64, other: &RuntimeUnit) -> f64 {
+        other.from_base(self.to_base(value))
+    }
+}
+
+impl fmt::Debug for RuntimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RuntimeUnit")
+            .field("symbol", &self.symbol)
+            .field("singular", &self.singular)
+            .field("plural", &self.plural)
+            .finish_non_exhaustive()
+    }
+}
+
+// Function pointers are not compared; the names identify a unit
+impl PartialEq for RuntimeUnit {
+    fn eq(&self, other: &Self) -> bool {
+        (self.symbol, self.singular, self.plural) == (other.symbol, other.singular, other.plural)
+    }
+}
+
+/// Base units whose quantity can look up its units by string at runtime
+///
+/// Implemented through [`unit_registry!`](crate::unit_registry) next to the
+/// unit definitions.
+pub trait UnitRegistry: Unit {
+    /// Every registered unit, the base unit first
+    const UNITS: &'static [RuntimeUnit];
+}
+
+/// Register the units of a base unit for runtime lookup by symbol or name
+///
+/// The base unit is always registered first. Where two units share a symbol
+/// (e.g. ‰ for per mille and promille), the one listed first wins.
+///
+/// Units on an offset scale (°C, °F) go after `absolute:` and convert their
+/// values as absolute readings through `AffineFromUnit` (see `convert_affine!`).
+///
+/// # Examples
+/// ```rust,ignore
+/// crate::unit_registry! {
+///     Meter => Kilometer, Millimeter, Foot, Inch
+/// }
+///
+/// crate::unit_registry! {
+///     Kelvin => Millikelvin;
+///     absolute: DegreeCelsius, DegreeFahrenheit
+/// }
+/// ```
+#[macro_export]
+macro_rules! unit_registry {
+    ($base:ident => $($unit:ty),* $(,)? $(; absolute: $($absolute:ty),* $(,)?)?) => {
+        impl $crate::quantity::registry::UnitRegistry for $base {
+            const UNITS: &'static [$crate::quantity::registry::RuntimeUnit] = &[
+                $crate::quantity::registry::RuntimeUnit::of::<$base, $base>(),
+                $($crate::quantity::registry::RuntimeUnit::of::<$base, $unit>(),)*
+                $($($crate::quantity::registry::RuntimeUnit::absolute::<$base, $absolute>(),)*)?
+            ];
+        }
+    };
+}
+
+// Runtime lookups convert in f64, like the config files and command lines
+// they serve; typed code keeps using `from::<U>` and `to::<U>`.
+impl<D, S> Quantity<f64, D, S>
+where
+    S: BaseUnitOf<D>,
+    S::BaseUnit: UnitRegistry,
+{
+    /// All units of this quantity that can be looked up at runtime
+    pub fn units() -> &'static [RuntimeUnit] {
+        S::BaseUnit::UNITS
+    }
+
+    /// Look up a unit of this quantity by its exact symbol, e.g. "km"
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::length::Length;
+    ///
+    /// let unit = Length::unit_by_symbol("km").unwrap();
+    /// assert_eq!(unit.singular, "kilometer");
+    /// assert!(Length::unit_by_symbol("kg").is_none());
+    /// ```
+    pub fn unit_by_symbol(symbol: &str) -> Option<&'static RuntimeUnit> {
+        Self::units().iter().find(|unit| unit.symbol == symbol)
+    }
+
+    /// Look up a unit of this quantity by its singular or plural name,
+    /// ignoring ASCII case, e.g. "Feet"
+    pub fn unit_by_name(name: &str) -> Option<&'static RuntimeUnit> {
+        Self::units().iter().find(|unit| {
+            unit.singular.eq_ignore_ascii_case(name) || unit.plural.eq_ignore_ascii_case(name)
+        })
+    }
+
+    /// Create a quantity from a value in the unit with the given symbol or name
+    ///
+    /// Symbols are tried first; `None` if no unit of this quantity matches.
+    pub fn from_unit_str(value: f64, unit: &str) -> Option<Self> {
+        Self::lookup(unit).map(|unit| Self::from_base(unit.to_base(value)))
+    }
+
+    /// Get the value in the unit with the given symbol or name, see
+    /// [`from_unit_str`](Self::from_unit_str)
+    pub fn to_unit_str(&self, unit: &str) -> Option<f64> {
+        Self::lookup(unit).map(|unit| unit.from_base(self.value))
+    }
+
+    fn lookup(unit: &str) -> Option<&'static RuntimeUnit> {
+        Self::unit_by_symbol(unit).or_else(|| Self::unit_by_name(unit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Foot, Length, Mile};
+    use crate::si::scalar::Scalar;
+    use crate::si::temperature::Temperature;
+    use crate::unit::Unit;
+
+    #[test]
+    fn test_lookup_by_symbol_and_name() {
+        let km = Length::unit_by_symbol("km").unwrap();
+        assert_eq!(km.plural, "kilometers");
+        assert_eq!(km.to_base(2.5), 2500.0);
+        assert_eq!(
+            Length::unit_by_name("Feet").unwrap().symbol,
+            Foot::ABBREVIATION
+        );
+        assert_eq!(
+            Length::unit_by_name("mile").unwrap().symbol,
+            Mile::ABBREVIATION
+        );
+        assert_eq!(Length::units()[0].symbol, "m");
+        assert!(Length::unit_by_symbol("kg").is_none());
+        assert!(Length::unit_by_name("gallon").is_none());
+    }
+
+    #[test]
+    fn test_convert_user_units() {
+        let run = Length::from_unit_str(5.0, "km").unwrap();
+        assert!((run.to_unit_str("mi").unwrap() - 3.106_855_961_186_67).abs() < 1e-12);
+        assert_eq!(run, Length::from::<crate::si::length::Kilometer>(5.0));
+        assert_eq!(run.to_unit_str("furlongs").map(f64::round), Some(25.0));
+        assert_eq!(Length::from_unit_str(1.0, "parsecs per hour"), None);
+
+        let ft = Length::unit_by_symbol("ft").unwrap();
+        let inch = Length::unit_by_symbol("in").unwrap();
+        assert!((ft.convert(2.0, inch) - 24.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_dimensionless_and_absolute_units() {
+        // Scalar collects the angle, ratio and information units
+        let half = Scalar::from_unit_str(50.0, "%").unwrap();
+        assert!((half.to_unit_str("°").unwrap() - 180.0).abs() < 1e-9);
+        // Temperature scales read absolute values, like a thermostat setting
+        let room = Temperature::from_unit_str(20.0, "°C").unwrap();
+        assert!((room.to_unit_str("K").unwrap() - 293.15).abs() < 1e-9);
+        assert!((room.to_unit_str("degrees Fahrenheit").unwrap() - 68.0).abs() < 1e-9);
+        let celsius = Temperature::unit_by_symbol("°C").unwrap();
+        let fahrenheit = Temperature::unit_by_symbol("°F").unwrap();
+        assert!((celsius.convert(100.0, fahrenheit) - 212.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_shared_symbols_are_the_same_unit() {
+        // "B" is the byte; the bel is not in the shared dimensionless registry
+        let byte = Scalar::unit_by_symbol("B").unwrap();
+        assert_eq!(byte.singular, "byte");
+        assert!(Scalar::unit_by_name("bel").is_none());
+        assert!(Scalar::unit_by_symbol("dB").is_none());
+
+        // Where symbols repeat (‰), every unit behind them converts alike
+        for unit in Scalar::units() {
+            let first = Scalar::unit_by_symbol(unit.symbol).unwrap();
+            assert_eq!(unit.to_base(3.0), first.to_base(3.0), "{}", unit.singular);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::Quantity;
-use core::ops::Rem;
+use core::ops::{Rem, RemAssign};
 use num_traits::Num;
 
 // Remainder (modulo) operation for quantities
@@ -20,7 +20,7 @@ where
 // Remainder with scalar values
 impl<V, D, S> Rem<V> for Quantity<V, D, S>
 where
-    V: Num + Rem<Output = V> + Copy,
+    V: Num + Rem<Output = V>,
 {
     type Output = Self;
 
@@ -29,6 +29,25 @@ where
     }
 }
 
+// Remainder in place, with quantities of the same dimension or scalars
+impl<V, D, S> RemAssign for Quantity<V, D, S>
+where
+    V: Num + RemAssign,
+{
+    fn rem_assign(&mut self, rhs: Self) {
+        self.value %= rhs.value;
+    }
+}
+
+impl<V, D, S> RemAssign<V> for Quantity<V, D, S>
+where
+    V: Num + RemAssign,
+{
+    fn rem_assign(&mut self, rhs: V) {
+        self.value %= rhs;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::si::length::Length;
@@ -156,4 +175,13 @@ mod tests {
         let result2 = c % d;
         assert!(result2.base().is_nan()); // ∞ % 3.0 = NaN
     }
+
+    #[test]
+    fn test_rem_assign() {
+        let mut length = Length::from_base(370.0);
+        length %= 360.0;
+        assert_eq!(*length.base(), 10.0);
+        length %= Length::from_base(4.0);
+        assert_eq!(*length.base(), 2.0);
+    }
 }
@@ -0,0 +1,128 @@
+use super::{BaseUnitOf, Quantity};
+use crate::unit::{FromUnit, Unit};
+use num_traits::float::FloatCore;
+
+// Snapping converts into `U`, rounds there and converts back, so the result is
+// a whole number of `U` up to the rounding of the conversion factor itself.
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: FloatCore,
+    S: BaseUnitOf<D>,
+{
+    fn snap_to<U>(self, snap: fn(V) -> V) -> Self
+    where
+        U: Unit,
+        S::BaseUnit: FromUnit<U, V>,
+    {
+        let in_unit = <S::BaseUnit as FromUnit<U, V>>::from_base(self.value);
+        // Converting an on-grid value picks up a few ULPs of noise (0.7 m is
+        // 699.9999999999999 mm), which floor and ceil would turn into a whole
+        // step. Values that close to a whole number are already on the grid.
+        let nearest = in_unit.round();
+        let noise = V::epsilon() * (V::one() + V::one() + V::one() + V::one());
+        if (in_unit - nearest).abs() <= nearest.abs() * noise {
+            return self;
+        }
+        Self::from_base(<S::BaseUnit as FromUnit<U, V>>::to_base(snap(in_unit)))
+    }
+
+    /// Round to the nearest whole multiple of unit `U`, half-way cases away
+    /// from zero
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::length::{Length, Millimeter};
+    ///
+    /// let feed = Length::from::<Millimeter>(12.46).round_to::<Millimeter>();
+    /// assert_eq!(feed.to::<Millimeter>(), 12.0);
+    /// ```
+    pub fn round_to<U>(self) -> Self
+    where
+        U: Unit,
+        S::BaseUnit: FromUnit<U, V>,
+    {
+        self.snap_to::<U>(FloatCore::round)
+    }
+
+    /// Round down to a whole multiple of unit `U`
+    pub fn floor_to<U>(self) -> Self
+    where
+        U: Unit,
+        S::BaseUnit: FromUnit<U, V>,
+    {
+        self.snap_to::<U>(FloatCore::floor)
+    }
+
+    /// Round up to a whole multiple of unit `U`
+    pub fn ceil_to<U>(self) -> Self
+    where
+        U: Unit,
+        S::BaseUnit: FromUnit<U, V>,
+    {
+        self.snap_to::<U>(FloatCore::ceil)
+    }
+
+    /// Round toward zero to a whole multiple of unit `U`
+    pub fn trunc_to<U>(self) -> Self
+    where
+        U: Unit,
+        S::BaseUnit: FromUnit<U, V>,
+    {
+        self.snap_to::<U>(FloatCore::trunc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Foot, Length, Millimeter};
+    use crate::si::time::{Minute, Time};
+
+    #[test]
+    fn test_round_to_unit() {
+        let length = Length::from_base(0.01246);
+        assert_eq!(length.round_to::<Millimeter>().to::<Millimeter>(), 12.0);
+        assert_eq!(length.floor_to::<Millimeter>().to::<Millimeter>(), 12.0);
+        assert_eq!(length.ceil_to::<Millimeter>().to::<Millimeter>(), 13.0);
+        assert_eq!(
+            Length::from_base(-0.0125)
+                .round_to::<Millimeter>()
+                .to::<Millimeter>(),
+            -13.0
+        );
+        assert_eq!(
+            Length::from_base(-0.0125)
+                .trunc_to::<Millimeter>()
+                .to::<Millimeter>(),
+            -12.0
+        );
+    }
+
+    #[test]
+    fn test_snap_preserves_type_and_exact_values() {
+        let elapsed: Time<f32> = Time::from_base(150.0_f32);
+        assert_eq!(elapsed.floor_to::<Minute>(), Time::from_base(120.0_f32));
+        assert_eq!(elapsed.ceil_to::<Minute>(), Time::from_base(180.0_f32));
+        // 0.9144 m is exactly three feet, so nothing moves
+        let yard: Length<f64> = Length::from_base(0.9144);
+        assert!((yard.round_to::<Foot>().to::<Foot>() - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_on_grid_values_stay_put() {
+        // 0.7 m converts to 699.9999999999999 mm
+        let length = Length::from_base(0.7);
+        assert_eq!(length.floor_to::<Millimeter>(), length);
+        assert_eq!(length.ceil_to::<Millimeter>(), length);
+        // 1005 mm converts back to 1005.0000000000001 mm
+        let length = Length::from::<Millimeter>(1005.0);
+        assert_eq!(length.ceil_to::<Millimeter>(), length);
+        assert_eq!(length.floor_to::<Millimeter>(), length);
+        assert_eq!(
+            Length::from_base(0.7005_f64)
+                .floor_to::<Millimeter>()
+                .to::<Millimeter>()
+                .round(),
+            700.0
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use super::decimal_digits::DecimalDigits;
+use ::rust_decimal::Decimal;
+
+// Unit conversions for `Decimal` values are generated by `convert_linear!`
+// (through `__convert_decimal!`), which stores each unit's `Factor` as an
+// associated constant, and call the helpers below.
+
+/// The decimal value of an `f64` conversion constant, `None` if `Decimal`
+/// cannot hold it exactly: beyond its 28 digits, as for the cubic yottameter
+/// (10^72 m³), or with more than 28 decimal places, as for the cubic
+/// yoctometer or the dalton
+#[doc(hidden)]
+pub const fn factor(value: f64) -> Option<Decimal> {
+    let Some(decimal) = DecimalDigits::of(value) else {
+        return None;
+    };
+    let mut mantissa = decimal.digits as u128;
+    let mut exponent = decimal.exponent;
+    while exponent > 0 {
+        mantissa = match mantissa.checked_mul(10) {
+            Some(mantissa) => mantissa,
+            None => return None,
+        };
+        exponent -= 1;
+    }
+    // A 96-bit mantissa and at most 28 decimal places
+    if mantissa >> 96 != 0 || exponent < -28 {
+        return None;
+    }
+    Some(Decimal::from_parts(
+        mantissa as u32,
+        (mantissa >> 32) as u32,
+        (mantissa >> 64) as u32,
+        decimal.negative,
+        -exponent as u32,
+    ))
+}
+
+/// The scale and offset of a `convert_linear!` entry as decimals
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct Factor {
+    scale: Option<Decimal>,
+    offset: Option<Decimal>,
+}
+
+impl Factor {
+    pub const fn new(scale: f64, offset: f64) -> Self {
+        Self {
+            scale: factor(scale),
+            offset: factor(offset),
+        }
+    }
+}
+
+/// The `Factor` of converting unit `From` to this base unit, implemented by
+/// `convert_linear!`
+#[doc(hidden)]
+pub trait DecimalFactor<From> {
+    const FACTOR: Factor;
+}
+
+/// # Panics
+/// If the factor does not fit `Decimal` exactly. Rounding it instead would
+/// silently change every converted value.
+fn exact(factor: Option<Decimal>) -> Decimal {
+    factor.expect("conversion factor does not fit Decimal exactly")
+}
+
+/// Convert a value in a derived unit to the base unit: `value * factor + offset`
+#[doc(hidden)]
+pub fn to_base(value: Decimal, factor: &Factor) -> Decimal {
+    let scaled = value * exact(factor.scale);
+    if factor.offset == Some(Decimal::ZERO) {
+        scaled
+    } else {
+        scaled + exact(factor.offset)
+    }
+}
+
+/// Convert a base-unit value to the derived unit: `(value - offset) / factor`
+#[doc(hidden)]
+pub fn from_base(value: Decimal, factor: &Factor) -> Decimal {
+    let shifted = if factor.offset == Some(Decimal::ZERO) {
+        value
+    } else {
+        value - exact(factor.offset)
+    };
+    shifted / exact(factor.scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::factor;
+    use crate::si::energy::{Energy, KilowattHour};
+    use crate::si::length::{Foot, Kilometer, Length};
+    use crate::si::volume::{CubicYoctometer, CubicYottameter, Volume};
+    use ::rust_decimal::Decimal;
+    use core::str::FromStr;
+
+    fn dec(text: &str) -> Decimal {
+        Decimal::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_factor_is_decimal_exact() {
+        assert_eq!(factor(0.3048), Some(dec("0.3048")));
+        assert_eq!(factor(1e-3), Some(dec("0.001")));
+        assert_eq!(factor(1e24), Some(dec("1000000000000000000000000")));
+        assert_eq!(factor(-273.15), Some(dec("-273.15")));
+        assert_eq!(factor(1e72), None);
+        assert_eq!(factor(1e-29), None);
+    }
+
+    #[test]
+    fn test_decimal_unit_conversions() {
+        let metered = Energy::from::<KilowattHour>(dec("12.345"));
+        assert_eq!(*metered.base(), dec("44442000"));
+        assert_eq!(metered.to::<KilowattHour>(), dec("12.345"));
+
+        let run = Length::from::<Foot>(dec("10"));
+        assert_eq!(*run.base(), dec("3.048"));
+        assert_eq!(
+            (run + Length::from::<Kilometer>(dec("0.1"))).to::<Kilometer>(),
+            dec("0.103048")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "conversion factor does not fit Decimal exactly")]
+    fn test_factor_above_decimal_range_panics() {
+        let _ = Volume::from::<CubicYottameter>(Decimal::ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "conversion factor does not fit Decimal exactly")]
+    fn test_factor_below_decimal_precision_panics() {
+        let _ = Volume::from_base(Decimal::ONE).to::<CubicYoctometer>();
+    }
+}
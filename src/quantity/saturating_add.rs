@@ -0,0 +1,31 @@
+use super::Quantity;
+use num_traits::{Num, SaturatingAdd};
+
+// Saturating addition: same dimension and scale, clamped to the bounds of `V`
+impl<V, D, S> SaturatingAdd for Quantity<V, D, S>
+where
+    V: Num + SaturatingAdd,
+{
+    fn saturating_add(&self, rhs: &Self) -> Self {
+        Self::from_base(self.value.saturating_add(&rhs.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::time::Time;
+    use num_traits::SaturatingAdd;
+
+    #[test]
+    fn test_saturating_add() {
+        let uptime = Time::from_base(u16::MAX - 10);
+        assert_eq!(
+            uptime.saturating_add(&Time::from_base(4)),
+            Time::from_base(u16::MAX - 6)
+        );
+        assert_eq!(
+            uptime.saturating_add(&Time::from_base(60)),
+            Time::from_base(u16::MAX)
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use super::Quantity;
+use num_traits::{Num, SaturatingMul};
+
+// `num_traits::SaturatingMul` requires `Mul<Self, Output = Self>`, which
+// quantities do not satisfy, so only scalar multiplication is provided.
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: Num + SaturatingMul,
+{
+    /// Multiply by a scalar, clamping to the bounds of `V` instead of overflowing
+    pub fn saturating_mul_scalar(&self, scalar: V) -> Self {
+        Self::from_base(self.value.saturating_mul(&scalar))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::mass::Mass;
+
+    #[test]
+    fn test_saturating_mul_scalar() {
+        let load = Mass::from_base(100_u8);
+        assert_eq!(load.saturating_mul_scalar(2), Mass::from_base(200));
+        assert_eq!(load.saturating_mul_scalar(3), Mass::from_base(u8::MAX));
+        assert_eq!(
+            Mass::from_base(-100_i8).saturating_mul_scalar(2),
+            Mass::from_base(i8::MIN)
+        );
+    }
+}
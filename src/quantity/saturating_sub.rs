@@ -0,0 +1,35 @@
+use super::Quantity;
+use num_traits::{Num, SaturatingSub};
+
+// Saturating subtraction: same dimension and scale, clamped to the bounds of `V`
+impl<V, D, S> SaturatingSub for Quantity<V, D, S>
+where
+    V: Num + SaturatingSub,
+{
+    fn saturating_sub(&self, rhs: &Self) -> Self {
+        Self::from_base(self.value.saturating_sub(&rhs.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use num_traits::SaturatingSub;
+
+    #[test]
+    fn test_saturating_sub() {
+        let remaining = Length::from_base(5_u32);
+        assert_eq!(
+            remaining.saturating_sub(&Length::from_base(3)),
+            Length::from_base(2)
+        );
+        assert_eq!(
+            remaining.saturating_sub(&Length::from_base(8)),
+            Length::from_base(0)
+        );
+        assert_eq!(
+            Length::from_base(i8::MIN + 1).saturating_sub(&Length::from_base(5)),
+            Length::from_base(i8::MIN)
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use super::{BaseUnitOf, Quantity};
+use crate::unit::Unit;
+use ::schemars::{JsonSchema, Schema, SchemaGenerator};
+use alloc::borrow::Cow;
+use alloc::format;
+
+// The schema mirrors the serde encoding: the bare value type, annotated with the
+// base unit the number is expressed in. It is inlined so that every field gets
+// its own unit description instead of sharing one `Quantity` definition.
+impl<V, D, S> JsonSchema for Quantity<V, D, S>
+where
+    V: JsonSchema,
+    S: BaseUnitOf<D>,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!(
+            "{}_in_{}",
+            V::schema_name(),
+            <S::BaseUnit as Unit>::SINGULAR.replace(' ', "_")
+        )
+        .into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        core::any::type_name::<Self>().into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = V::json_schema(generator);
+        schema.insert(
+            "description".into(),
+            format!(
+                "Value in {} ({})",
+                <S::BaseUnit as Unit>::SINGULAR,
+                <S::BaseUnit as Unit>::ABBREVIATION
+            )
+            .into(),
+        );
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use crate::si::velocity::Velocity;
+    use schemars::{JsonSchema, schema_for};
+
+    #[allow(dead_code)]
+    #[derive(JsonSchema)]
+    struct Leg {
+        distance: Length<f64>,
+        speed: Velocity<f32>,
+    }
+
+    #[test]
+    fn test_schema_describes_base_unit() {
+        let schema = schema_for!(Length<f64>);
+        assert_eq!(schema.get("type").unwrap(), "number");
+        assert_eq!(schema.get("description").unwrap(), "Value in meter (m)");
+    }
+
+    #[test]
+    fn test_schema_inside_struct() {
+        let schema = schema_for!(Leg).to_value();
+        let properties = &schema["properties"];
+        assert_eq!(properties["distance"]["description"], "Value in meter (m)");
+        assert_eq!(properties["speed"]["format"], "float");
+        assert_eq!(
+            properties["speed"]["description"],
+            "Value in meter per second (m/s)"
+        );
+        assert!(schema.get("$defs").is_none());
+    }
+}
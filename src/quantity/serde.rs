@@ -0,0 +1,70 @@
+use super::Quantity;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Quantities serialize as their bare base value, so a `Length<f64>` field holding
+// 2.5 km is written as `2500.0` (meters). The dimension and scale are carried by
+// the type and never appear in the serialized form.
+impl<V, D, S> Serialize for Quantity<V, D, S>
+where
+    V: Serialize,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de, V, D, S> Deserialize<'de> for Quantity<V, D, S>
+where
+    V: Deserialize<'de>,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        V::deserialize(deserializer).map(|value| Quantity {
+            value,
+            _dimension: core::marker::PhantomData,
+            _scale: core::marker::PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Kilometer, Length};
+    use crate::si::time::{Second, Time};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Leg {
+        distance: Length<f64>,
+        duration: Time<f64>,
+    }
+
+    #[test]
+    fn test_serialize_as_base_value() {
+        let distance = Length::from::<Kilometer>(2.5);
+        assert_eq!(serde_json::to_string(&distance).unwrap(), "2500.0");
+    }
+
+    #[test]
+    fn test_round_trip_inside_struct() {
+        let leg = Leg {
+            distance: Length::from::<Kilometer>(1.2),
+            duration: Time::from::<Second>(300.0),
+        };
+        let json = serde_json::to_string(&leg).unwrap();
+        assert_eq!(json, r#"{"distance":1200.0,"duration":300.0}"#);
+        assert_eq!(serde_json::from_str::<Leg>(&json).unwrap(), leg);
+    }
+
+    #[test]
+    fn test_integer_values() {
+        let length: Length<i32> = serde_json::from_str("42").unwrap();
+        assert_eq!(length.into_base(), 42);
+        assert!(serde_json::from_str::<Length<i32>>("\"42 m\"").is_err());
+    }
+}
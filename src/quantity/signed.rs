@@ -1,7 +1,14 @@
 use super::Quantity;
+use core::ops::Sub;
 use num_traits::Signed;
 
 // Signed implementations
+//
+// These are inherent methods rather than an `impl num_traits::Signed`: the trait
+// requires `Num`, and a `Num` impl for `Quantity` would let a quantity be the
+// value type of another quantity, making the scalar `Mul<V>`/`Div<V>` impls
+// overlap with the quantity-by-quantity ones (E0119). Generic code can bound on
+// `V: Signed` and go through `Quantity::from_base` instead.
 impl<V, D, S> Quantity<V, D, S>
 where
     V: Signed,
@@ -14,7 +21,14 @@ where
         Quantity::from_base(self.value.abs_sub(&other.value))
     }
 
-    pub fn signum(&self) -> Self {
+    /// The sign as a dimensionless quantity (`Scalar` for SI): `1`, `-1` or `0`
+    ///
+    /// A sign carries no physical dimension, so `q.abs() * q.signum()` has the
+    /// dimension of `q` again rather than its square.
+    pub fn signum(&self) -> Quantity<V, <D as Sub<D>>::Output, S>
+    where
+        D: Sub<D>,
+    {
         Quantity::from_base(self.value.signum())
     }
 
@@ -88,4 +102,17 @@ mod tests {
         assert_eq!(int_positive.signum().into_base(), 1);
         assert_eq!(int_negative.signum().into_base(), -1);
     }
+
+    #[test]
+    fn test_signum_is_dimensionless() {
+        use crate::si::scalar::Scalar;
+
+        let displacement = Length::from_base(-4.0);
+        let sign: Scalar<f64> = displacement.signum();
+        assert_eq!(*sign.base(), -1.0);
+        // Multiplying by the sign keeps the dimension of the magnitude
+        let restored: Length<f64> = displacement.abs() * sign;
+        assert_eq!(restored, displacement);
+        assert_eq!(*Length::from_base(0_i32).signum().base(), 0);
+    }
 }
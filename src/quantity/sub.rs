@@ -1,11 +1,10 @@
 use super::Quantity;
-use core::ops::Sub;
-use num_traits::Num;
+use core::ops::{Sub, SubAssign};
 
 // Subtraction: same dimension and scale
 impl<V, D, S> Sub for Quantity<V, D, S>
 where
-    V: Num + Sub<Output = V>,
+    V: Sub<Output = V>,
 {
     type Output = Self;
 
@@ -14,6 +13,36 @@ where
     }
 }
 
+// By reference (`&a - &b`)
+impl<'a, 'b, V, D, S> Sub<&'b Quantity<V, D, S>> for &'a Quantity<V, D, S>
+where
+    &'a V: Sub<&'b V, Output = V>,
+{
+    type Output = Quantity<V, D, S>;
+
+    fn sub(self, rhs: &'b Quantity<V, D, S>) -> Self::Output {
+        Quantity::from_base(&self.value - &rhs.value)
+    }
+}
+
+impl<V, D, S> SubAssign for Quantity<V, D, S>
+where
+    V: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+impl<'b, V, D, S> SubAssign<&'b Quantity<V, D, S>> for Quantity<V, D, S>
+where
+    V: SubAssign<&'b V>,
+{
+    fn sub_assign(&mut self, rhs: &'b Quantity<V, D, S>) {
+        self.value -= &rhs.value;
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -25,4 +54,21 @@ mod tests {
         let diff = length1 - length2;
         assert_eq!(*diff.base(), 2.0);
     }
+
+    #[test]
+    fn test_reference_subtraction() {
+        let length1 = crate::si::length::Length::from_base(5.0);
+        let length2 = crate::si::length::Length::from_base(3.0);
+
+        assert_eq!(*(&length1 - &length2).base(), 2.0);
+        assert_eq!(*(length1 - length2).base(), 2.0);
+    }
+
+    #[test]
+    fn test_sub_assign() {
+        let mut remaining = crate::si::length::Length::from_base(10_i32);
+        remaining -= crate::si::length::Length::from_base(3);
+        remaining -= &crate::si::length::Length::from_base(4);
+        assert_eq!(*remaining.base(), 3);
+    }
 }
@@ -0,0 +1,89 @@
+use super::Quantity;
+use core::iter::{Product, Sum};
+use core::ops::Mul;
+use num_traits::Num;
+
+// Summing keeps the dimension, so any iterator of same-dimension quantities can
+// be summed into that quantity
+impl<V, D, S> Sum for Quantity<V, D, S>
+where
+    V: Num + Sum,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::from_base(iter.map(|quantity| quantity.value).sum())
+    }
+}
+
+impl<'a, V, D, S> Sum<&'a Quantity<V, D, S>> for Quantity<V, D, S>
+where
+    V: Num + Sum<&'a V> + 'a,
+    D: 'a,
+    S: 'a,
+{
+    fn sum<I: Iterator<Item = &'a Quantity<V, D, S>>>(iter: I) -> Self {
+        Self::from_base(iter.map(|quantity| &quantity.value).sum())
+    }
+}
+
+// A product of n quantities has n times the dimension, which is only `Self`
+// for dimensionless quantities (`Scalar`)
+impl<V, D, S> Product for Quantity<V, D, S>
+where
+    V: Num + Product,
+    Self: Mul<Self, Output = Self>,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::from_base(iter.map(|quantity| quantity.value).product())
+    }
+}
+
+impl<'a, V, D, S> Product<&'a Quantity<V, D, S>> for Quantity<V, D, S>
+where
+    V: Num + Product<&'a V> + 'a,
+    D: 'a,
+    S: 'a,
+    Self: Mul<Self, Output = Self>,
+{
+    fn product<I: Iterator<Item = &'a Quantity<V, D, S>>>(iter: I) -> Self {
+        Self::from_base(iter.map(|quantity| &quantity.value).product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::Length;
+    use crate::si::scalar::Scalar;
+    use crate::si::time::Time;
+
+    #[test]
+    fn test_sum() {
+        let samples = [
+            Length::from_base(1.5),
+            Length::from_base(2.0),
+            Length::from_base(0.5),
+        ];
+        assert_eq!(
+            samples.iter().copied().sum::<Length<f64>>(),
+            Length::from_base(4.0)
+        );
+        assert_eq!(samples.iter().sum::<Length<f64>>(), Length::from_base(4.0));
+        assert_eq!(
+            core::iter::empty::<Time<u32>>().sum::<Time<u32>>(),
+            Time::from_base(0)
+        );
+    }
+
+    #[test]
+    fn test_product_of_scalars() {
+        let gains = [
+            Scalar::from_base(2),
+            Scalar::from_base(3),
+            Scalar::from_base(4),
+        ];
+        assert_eq!(
+            gains.iter().copied().product::<Scalar<i32>>(),
+            Scalar::from_base(24)
+        );
+        assert_eq!(gains.iter().product::<Scalar<i32>>(), Scalar::from_base(24));
+    }
+}
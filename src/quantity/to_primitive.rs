@@ -0,0 +1,51 @@
+use super::Quantity;
+use num_traits::ToPrimitive;
+
+// The base value is converted; every method is forwarded because the trait's
+// default methods route through `to_i64`/`to_u64`, which would drop fractions.
+macro_rules! forward_to_primitive {
+    ($($method:ident -> $ty:ty;)+) => {
+        $(
+            fn $method(&self) -> Option<$ty> {
+                self.value.$method()
+            }
+        )+
+    };
+}
+
+impl<V, D, S> ToPrimitive for Quantity<V, D, S>
+where
+    V: ToPrimitive,
+{
+    forward_to_primitive! {
+        to_isize -> isize;
+        to_i8 -> i8;
+        to_i16 -> i16;
+        to_i32 -> i32;
+        to_i64 -> i64;
+        to_i128 -> i128;
+        to_usize -> usize;
+        to_u8 -> u8;
+        to_u16 -> u16;
+        to_u32 -> u32;
+        to_u64 -> u64;
+        to_u128 -> u128;
+        to_f32 -> f32;
+        to_f64 -> f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Kilometer, Length};
+    use num_traits::ToPrimitive;
+
+    #[test]
+    fn test_to_primitive_uses_base_value() {
+        let distance = Length::from::<Kilometer>(1.5);
+        assert_eq!(distance.to_f64(), Some(1500.0));
+        assert_eq!(distance.to_u16(), Some(1500));
+        assert_eq!(distance.to_i8(), None);
+        assert_eq!(Length::from_base(2.75).to_f32(), Some(2.75));
+    }
+}
@@ -0,0 +1,225 @@
+use super::{BaseUnitOf, Quantity};
+use crate::conversions::ConvertibleUnit;
+use crate::unit::{FromUnit, Unit};
+use core::fmt;
+use num_traits::PrimInt;
+use num_traits::float::FloatCore;
+
+/// Error returned by [`Quantity::try_to`] and [`Quantity::try_to_within`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConversionError {
+    /// The converted value does not fit the integer type
+    OutOfRange,
+    /// Rounding to an integer would change the converted value, given here,
+    /// by more than the tolerance
+    Inexact(f64),
+    /// The unit has no exact integer factor and the value or the result is
+    /// too large to convert through f64 without losing digits
+    Imprecise,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange => write!(f, "converted value out of range for the value type"),
+            Self::Inexact(value) => write!(f, "converted value {value} is not a whole number"),
+            Self::Imprecise => write!(f, "value too large to convert exactly through f64"),
+        }
+    }
+}
+
+impl core::error::Error for ConversionError {}
+
+impl<V, D, S> Quantity<V, D, S>
+where
+    V: PrimInt,
+{
+    /// Get the value in unit `U`, failing instead of rounding or saturating
+    ///
+    /// Units with an exact integer factor (see `convert_int_linear!`) convert
+    /// with integer arithmetic only. Other units convert in f64, which must
+    /// hold the value exactly and land on a whole number; only the rounding
+    /// noise of the factor is accepted. See [`try_to_within`](Self::try_to_within)
+    /// to allow rounding.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::length::{Kilometer, Length};
+    ///
+    /// assert_eq!(Length::from_base(3000_i32).try_to::<Kilometer>(), Ok(3));
+    /// assert!(Length::from_base(2500_i32).try_to::<Kilometer>().is_err());
+    /// ```
+    pub fn try_to<U>(&self) -> Result<V, ConversionError>
+    where
+        U: ConvertibleUnit<BaseUnit = S::BaseUnit>,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: FromUnit<U, f64>,
+    {
+        self.try_to_within::<U>(0.0)
+    }
+
+    /// Get the value in unit `U`, rounded to the nearest integer if that
+    /// changes it by at most `tolerance` (in units of `U`)
+    pub fn try_to_within<U>(&self, tolerance: f64) -> Result<V, ConversionError>
+    where
+        U: ConvertibleUnit<BaseUnit = S::BaseUnit>,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: FromUnit<U, f64>,
+    {
+        match U::INT_FACTOR {
+            Some((num, den)) => self.try_scale_within(num, den, tolerance),
+            None => self.try_convert_f64_within::<U>(tolerance),
+        }
+    }
+
+    // `base * den / num` in i128, with the remainder deciding exactness
+    fn try_scale_within(&self, num: u128, den: u128, tolerance: f64) -> Result<V, ConversionError> {
+        let value = self.value.to_i128().ok_or(ConversionError::OutOfRange)?;
+        let num = i128::try_from(num).map_err(|_| ConversionError::OutOfRange)?;
+        let den = i128::try_from(den).map_err(|_| ConversionError::OutOfRange)?;
+        let whole = (value / num)
+            .checked_mul(den)
+            .ok_or(ConversionError::OutOfRange)?;
+        let part = (value % num)
+            .checked_mul(den)
+            .ok_or(ConversionError::OutOfRange)?;
+        let quotient = whole
+            .checked_add(part / num)
+            .ok_or(ConversionError::OutOfRange)?;
+        let remainder = part % num;
+        let mut rounded = quotient;
+        if remainder != 0 {
+            // Off by `remainder / num` from the truncated quotient, and by the
+            // rest of a whole unit from the next one, half-way away from zero
+            let mut off = remainder.unsigned_abs();
+            if off * 2 >= num.unsigned_abs() {
+                off = num.unsigned_abs() - off;
+                rounded = quotient
+                    .checked_add(remainder.signum())
+                    .ok_or(ConversionError::OutOfRange)?;
+            }
+            if off as f64 / num as f64 > tolerance {
+                let exact = quotient as f64 + remainder as f64 / num as f64;
+                return Err(ConversionError::Inexact(exact));
+            }
+        }
+        V::from(rounded).ok_or(ConversionError::OutOfRange)
+    }
+
+    fn try_convert_f64_within<U>(&self, tolerance: f64) -> Result<V, ConversionError>
+    where
+        U: Unit,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: FromUnit<U, f64>,
+    {
+        let base = self.value.to_f64().ok_or(ConversionError::OutOfRange)?;
+        if V::from(base) != Some(self.value) {
+            return Err(ConversionError::Imprecise);
+        }
+        let exact = <S::BaseUnit as FromUnit<U, f64>>::from_base(base);
+        let rounded = FloatCore::round(exact);
+        // Above 2^53 an f64 no longer tells neighbouring integers apart
+        if FloatCore::abs(rounded) > MAX_EXACT_F64 {
+            let fits = V::from(rounded).is_some();
+            return Err(if fits {
+                ConversionError::Imprecise
+            } else {
+                ConversionError::OutOfRange
+            });
+        }
+        let value = V::from(rounded).ok_or(ConversionError::OutOfRange)?;
+        // Factors like 0.3048 are inexact in f64, which must not count as truncation
+        let noise = FloatCore::abs(exact) * 4.0 * f64::EPSILON;
+        if FloatCore::abs(exact - rounded) > tolerance + noise {
+            return Err(ConversionError::Inexact(exact));
+        }
+        Ok(value)
+    }
+}
+
+/// 2^53, the largest magnitude below which f64 holds every integer
+const MAX_EXACT_F64: f64 = 9_007_199_254_740_992.0;
+
+#[cfg(test)]
+mod tests {
+    use super::ConversionError;
+    use crate::si::length::{Chain, Foot, Kilometer, Length, Meter, Microinch, Millimeter};
+    use crate::si::time::{Hour, Time};
+
+    #[test]
+    fn test_exact_conversions() {
+        assert_eq!(Length::from_base(3000_i32).try_to::<Kilometer>(), Ok(3));
+        assert_eq!(Time::from_base(7200_u64).try_to::<Hour>(), Ok(2));
+        // 0.3048 is not exact in f64, but 3048 m is exactly 10000 ft
+        assert_eq!(Length::from_base(3048_i64).try_to::<Foot>(), Ok(10_000));
+    }
+
+    #[test]
+    fn test_truncation_is_rejected() {
+        assert_eq!(
+            Length::from_base(2500_i32).try_to::<Kilometer>(),
+            Err(ConversionError::Inexact(2.5))
+        );
+        assert_eq!(
+            Length::from_base(2500_i32).try_to_within::<Kilometer>(0.5),
+            Ok(3)
+        );
+        assert_eq!(
+            Length::from_base(2400_i32).try_to_within::<Kilometer>(0.5),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn test_overflow_is_rejected() {
+        assert_eq!(
+            Length::from_base(100_i8).try_to::<Millimeter>(),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(
+            Length::from_base(-1_i64).try_to_within::<Kilometer>(1.0),
+            Ok(0)
+        );
+        assert_eq!(
+            Length::from_base(u64::MAX).try_to::<Millimeter>(),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_large_values_stay_exact() {
+        // 2^53 + 1 has no f64, but meters and millimeters have integer factors
+        let length = Length::from_base(9_007_199_254_740_993_i64);
+        assert_eq!(length.try_to::<Meter>(), Ok(9_007_199_254_740_993));
+        assert_eq!(length.try_to::<Millimeter>(), Ok(9_007_199_254_740_993_000));
+        assert_eq!(
+            Length::from_base(9_007_199_254_740_993_400_i64).try_to_within::<Kilometer>(0.5),
+            Ok(9_007_199_254_740_993)
+        );
+        assert!(matches!(
+            Length::from_base(9_007_199_254_740_993_400_i64).try_to::<Kilometer>(),
+            Err(ConversionError::Inexact(_))
+        ));
+        assert_eq!(
+            Length::from_base(i128::MAX).try_to::<Millimeter>(),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_f64_path_rejects_lost_digits() {
+        // Chains have no integer factor, so the value goes through f64
+        assert_eq!(
+            Length::from_base(9_007_199_254_740_993_i64).try_to::<Chain>(),
+            Err(ConversionError::Imprecise)
+        );
+        assert_eq!(
+            Length::from_base(9_007_199_254_740_992_i128).try_to::<Microinch>(),
+            Err(ConversionError::Imprecise)
+        );
+        assert_eq!(
+            Length::from_base(2_011_684_i64).try_to::<Chain>(),
+            Ok(100_000)
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use super::{BaseUnitOf, Quantity};
+use crate::unit::{Unit, UnitFactor};
+use ::wide::{f32x4, f64x4};
+
+// SIMD batches of identically dimensioned samples, one sample per lane.
+// Arithmetic between batches (`+`, `-`, `*`, `/` and negation) goes through the
+// regular operator impls and keeps the dimension checks. `wide` types do not
+// implement `num_traits::Num`, so unit conversions use the constant
+// `UnitFactor` of pure scale conversions instead of `FromUnit`.
+//
+// `core::simd` is not supported while it requires a nightly compiler.
+
+macro_rules! impl_wide {
+    ($batch:ident, $lane:ident, $lanes:literal) => {
+        impl<D, S> Quantity<$batch, D, S> {
+            /// A batch with every lane set to `quantity`
+            pub fn splat(quantity: Quantity<$lane, D, S>) -> Self {
+                Self::from_base($batch::splat(quantity.value))
+            }
+
+            /// Pack samples into a batch, one per lane
+            pub fn from_lanes(quantities: [Quantity<$lane, D, S>; $lanes]) -> Self {
+                Self::from_base($batch::from(quantities.map(|q| q.value)))
+            }
+
+            /// Unpack the batch into its samples
+            pub fn to_lanes(self) -> [Quantity<$lane, D, S>; $lanes] {
+                self.value.to_array().map(Quantity::from_base)
+            }
+
+            /// The sum of all lanes
+            pub fn reduce_add(self) -> Quantity<$lane, D, S> {
+                Quantity::from_base(self.value.reduce_add())
+            }
+
+            /// Create a batch from values in the unit `U`
+            pub fn from_unit<U>(values: $batch) -> Self
+            where
+                U: Unit,
+                S: BaseUnitOf<D>,
+                S::BaseUnit: UnitFactor<U>,
+            {
+                let factor = <S::BaseUnit as UnitFactor<U>>::FACTOR as $lane;
+                Self::from_base(values * $batch::splat(factor))
+            }
+
+            /// The lanes expressed in the unit `U`
+            pub fn to_unit<U>(self) -> $batch
+            where
+                U: Unit,
+                S: BaseUnitOf<D>,
+                S::BaseUnit: UnitFactor<U>,
+            {
+                let factor = <S::BaseUnit as UnitFactor<U>>::FACTOR as $lane;
+                self.value / $batch::splat(factor)
+            }
+        }
+    };
+}
+
+impl_wide!(f64x4, f64, 4);
+impl_wide!(f32x4, f32, 4);
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Kilometer, Length};
+    use crate::si::scalar::Scalar;
+    use crate::si::time::Time;
+    use crate::si::velocity::Velocity;
+    use ::wide::{f32x4, f64x4};
+
+    #[test]
+    fn test_lanes_round_trip() {
+        let samples = [1.0, 2.0, 3.0, 4.0].map(Length::from_base);
+        let batch = Length::<f64x4>::from_lanes(samples);
+        assert_eq!(batch.to_lanes(), samples);
+        assert_eq!(*batch.reduce_add().base(), 10.0);
+        assert_eq!(
+            Length::<f32x4>::splat(Length::from_base(1.5)).to_lanes(),
+            [Length::from_base(1.5_f32); 4]
+        );
+    }
+
+    #[test]
+    fn test_batch_arithmetic_keeps_dimensions() {
+        let distances = Length::<f64x4>::from_unit::<Kilometer>(f64x4::from([1.0, 2.0, 3.0, 4.5]));
+        let times = Time::from_base(f64x4::splat(500.0));
+        let speeds: Velocity<f64x4> = distances / times;
+        assert_eq!(speeds.base().to_array(), [2.0, 4.0, 6.0, 9.0]);
+
+        let doubled = (speeds + speeds) * Scalar::from_base(f64x4::splat(0.5));
+        assert_eq!(doubled, speeds);
+        assert_eq!(
+            (-distances).to_unit::<Kilometer>().to_array(),
+            [-1.0, -2.0, -3.0, -4.5]
+        );
+    }
+}
@@ -0,0 +1,240 @@
+/// # Serde Adapters - Unit-Tagged Serialization
+///
+/// By default a `Quantity` serializes as its bare base value (see
+/// `quantity::serde`). The adapters in this module are meant for
+/// `#[serde(with = "...")]` fields whose wire format should use an explicit
+/// unit instead:
+///
+/// - [`in_unit`]: the bare number, expressed in the unit `U`
+/// - [`tagged`]: a `{ "value": 5.0, "unit": "km" }` object; the unit is
+///   checked against `U`'s abbreviation on deserialize
+///
+/// ## Example Usage
+///
+/// ```rust,ignore
+/// use num_units::si::length::{Kilometer, Length};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Route {
+///     #[serde(with = "num_units::serde::in_unit::<Kilometer>")]
+///     distance: Length<f64>, // written as 5.0
+///     #[serde(with = "num_units::serde::tagged::<Kilometer>")]
+///     detour: Length<f64>, // written as {"value":0.5,"unit":"km"}
+/// }
+/// ```
+use crate::quantity::{BaseUnitOf, Quantity};
+use crate::unit::{FromUnit, Unit};
+use ::serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use ::serde::ser::{Serialize, SerializeStruct, Serializer};
+use core::fmt;
+use core::marker::PhantomData;
+
+/// Serialize a quantity as a bare number in the unit `U`
+///
+/// Use as `#[serde(with = "num_units::serde::in_unit::<Kilometer>")]`.
+#[allow(non_camel_case_types)]
+pub struct in_unit<U>(PhantomData<U>);
+
+impl<U: Unit> in_unit<U> {
+    pub fn serialize<V, D, S, Ser>(
+        quantity: &Quantity<V, D, S>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        V: num_traits::Num + Copy + Serialize,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: FromUnit<U, V>,
+        Ser: Serializer,
+    {
+        quantity.to::<U>().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, V, D, S, De>(deserializer: De) -> Result<Quantity<V, D, S>, De::Error>
+    where
+        V: num_traits::Num + Copy + Deserialize<'de>,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: FromUnit<U, V>,
+        De: Deserializer<'de>,
+    {
+        V::deserialize(deserializer).map(Quantity::from::<U>)
+    }
+}
+
+/// Serialize a quantity as `{ "value": ..., "unit": "<abbreviation>" }`
+///
+/// Use as `#[serde(with = "num_units::serde::tagged::<Kilometer>")]`.
+/// Deserializing fails if the `unit` field does not match `U::ABBREVIATION`.
+#[allow(non_camel_case_types)]
+pub struct tagged<U>(PhantomData<U>);
+
+const FIELDS: &[&str] = &["value", "unit"];
+
+impl<U: Unit> tagged<U> {
+    pub fn serialize<V, D, S, Ser>(
+        quantity: &Quantity<V, D, S>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        V: num_traits::Num + Copy + Serialize,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: FromUnit<U, V>,
+        Ser: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Quantity", 2)?;
+        state.serialize_field("value", &quantity.to::<U>())?;
+        state.serialize_field("unit", U::ABBREVIATION)?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, V, D, S, De>(deserializer: De) -> Result<Quantity<V, D, S>, De::Error>
+    where
+        V: num_traits::Num + Copy + Deserialize<'de>,
+        S: BaseUnitOf<D>,
+        S::BaseUnit: FromUnit<U, V>,
+        De: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_struct("Quantity", FIELDS, TaggedVisitor::<U, V>(PhantomData))
+            .map(Quantity::from::<U>)
+    }
+}
+
+// Accepts only the abbreviation of `U`; works for borrowed and transient
+// strings alike so no allocation is needed.
+struct Abbreviation<U>(PhantomData<U>);
+
+impl<'de, U: Unit> Deserialize<'de> for Abbreviation<U> {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(Abbreviation::<U>(PhantomData))
+    }
+}
+
+impl<U: Unit> Visitor<'_> for Abbreviation<U> {
+    type Value = Self;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the unit abbreviation `{}`", U::ABBREVIATION)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self, E> {
+        if value == U::ABBREVIATION {
+            Ok(self)
+        } else {
+            Err(E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+    }
+}
+
+enum Field {
+    Value,
+    Unit,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("`value` or `unit`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "value" => Ok(Field::Value),
+                    "unit" => Ok(Field::Unit),
+                    _ => Err(E::unknown_field(value, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct TaggedVisitor<U, V>(PhantomData<(U, V)>);
+
+impl<'de, U: Unit, V: Deserialize<'de>> Visitor<'de> for TaggedVisitor<U, V> {
+    type Value = V;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a quantity tagged with unit `{}`", U::ABBREVIATION)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<V, A::Error> {
+        let value = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        seq.next_element::<Abbreviation<U>>()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(value)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<V, A::Error> {
+        let mut value = None;
+        let mut unit = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Value if value.is_some() => return Err(de::Error::duplicate_field("value")),
+                Field::Unit if unit.is_some() => return Err(de::Error::duplicate_field("unit")),
+                Field::Value => value = Some(map.next_value()?),
+                Field::Unit => unit = Some(map.next_value::<Abbreviation<U>>()?),
+            }
+        }
+        unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+        value.ok_or_else(|| de::Error::missing_field("value"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::length::{Kilometer, Length, Mile};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Route {
+        #[serde(with = "crate::serde::in_unit::<Kilometer>")]
+        distance: Length<f64>,
+        #[serde(with = "crate::serde::tagged::<Mile>")]
+        detour: Length<f64>,
+    }
+
+    #[test]
+    fn test_in_unit_round_trip() {
+        let route = Route {
+            distance: Length::from::<Kilometer>(5.0),
+            detour: Length::from::<Mile>(0.5),
+        };
+        let json = serde_json::to_string(&route).unwrap();
+        assert_eq!(
+            json,
+            r#"{"distance":5.0,"detour":{"value":0.5,"unit":"mi"}}"#
+        );
+        assert_eq!(serde_json::from_str::<Route>(&json).unwrap(), route);
+    }
+
+    #[test]
+    fn test_tagged_rejects_wrong_unit() {
+        let json = r#"{"distance":5.0,"detour":{"value":0.5,"unit":"km"}}"#;
+        let err = serde_json::from_str::<Route>(json).unwrap_err().to_string();
+        assert!(err.contains("the unit abbreviation `mi`"), "{err}");
+    }
+
+    #[test]
+    fn test_tagged_requires_unit() {
+        let json = r#"{"distance":5.0,"detour":{"value":0.5}}"#;
+        assert!(serde_json::from_str::<Route>(json).is_err());
+        let json = r#"{"distance":5.0,"detour":{"unit":"mi","value":0.5}}"#;
+        let route = serde_json::from_str::<Route>(json).unwrap();
+        assert!((route.detour.into_base() - 804.672_f64).abs() < 1e-9);
+    }
+}
@@ -11,163 +11,165 @@ use typenum::*;
 
 // SI base unit
 units! {
-    MeterPerSecondSquared: "m/s²", "meter per second squared";
+    MeterPerSecondSquared: "m/s²", "meter per second squared", "meters per second squared";
 }
 
 units! {
     // SI prefix units
-    YottameterPerSecondSquared: "Ym/s²", "yottameter per second squared";
-    ZettameterPerSecondSquared: "Zm/s²", "zettameter per second squared";
-    ExameterPerSecondSquared: "Em/s²", "exameter per second squared";
-    PetameterPerSecondSquared: "Pm/s²", "petameter per second squared";
-    TerameterPerSecondSquared: "Tm/s²", "terameter per second squared";
-    GigameterPerSecondSquared: "Gm/s²", "gigameter per second squared";
-    MegameterPerSecondSquared: "Mm/s²", "megameter per second squared";
-    KilometerPerSecondSquared: "km/s²", "kilometer per second squared";
-    HectometerPerSecondSquared: "hm/s²", "hectometer per second squared";
-    DecameterPerSecondSquared: "dam/s²", "decameter per second squared";
-    DecimeterPerSecondSquared: "dm/s²", "decimeter per second squared";
-    CentimeterPerSecondSquared: "cm/s²", "centimeter per second squared";
-    MillimeterPerSecondSquared: "mm/s²", "millimeter per second squared";
-    MicrometerPerSecondSquared: "µm/s²", "micrometer per second squared";
-    NanometerPerSecondSquared: "nm/s²", "nanometer per second squared";
-    PicometerPerSecondSquared: "pm/s²", "picometer per second squared";
-    FemtometerPerSecondSquared: "fm/s²", "femtometer per second squared";
-    AttometerPerSecondSquared: "am/s²", "attometer per second squared";
-    ZeptometerPerSecondSquared: "zm/s²", "zeptometer per second squared";
-    YoctometerPerSecondSquared: "ym/s²", "yoctometer per second squared";
+    YottameterPerSecondSquared: "Ym/s²", "yottameter per second squared", "yottameters per second squared";
+    ZettameterPerSecondSquared: "Zm/s²", "zettameter per second squared", "zettameters per second squared";
+    ExameterPerSecondSquared: "Em/s²", "exameter per second squared", "exameters per second squared";
+    PetameterPerSecondSquared: "Pm/s²", "petameter per second squared", "petameters per second squared";
+    TerameterPerSecondSquared: "Tm/s²", "terameter per second squared", "terameters per second squared";
+    GigameterPerSecondSquared: "Gm/s²", "gigameter per second squared", "gigameters per second squared";
+    MegameterPerSecondSquared: "Mm/s²", "megameter per second squared", "megameters per second squared";
+    KilometerPerSecondSquared: "km/s²", "kilometer per second squared", "kilometers per second squared";
+    HectometerPerSecondSquared: "hm/s²", "hectometer per second squared", "hectometers per second squared";
+    DecameterPerSecondSquared: "dam/s²", "decameter per second squared", "decameters per second squared";
+    DecimeterPerSecondSquared: "dm/s²", "decimeter per second squared", "decimeters per second squared";
+    CentimeterPerSecondSquared: "cm/s²", "centimeter per second squared", "centimeters per second squared";
+    MillimeterPerSecondSquared: "mm/s²", "millimeter per second squared", "millimeters per second squared";
+    MicrometerPerSecondSquared: "µm/s²", "micrometer per second squared", "micrometers per second squared";
+    NanometerPerSecondSquared: "nm/s²", "nanometer per second squared", "nanometers per second squared";
+    PicometerPerSecondSquared: "pm/s²", "picometer per second squared", "picometers per second squared";
+    FemtometerPerSecondSquared: "fm/s²", "femtometer per second squared", "femtometers per second squared";
+    AttometerPerSecondSquared: "am/s²", "attometer per second squared", "attometers per second squared";
+    ZeptometerPerSecondSquared: "zm/s²", "zeptometer per second squared", "zeptometers per second squared";
+    YoctometerPerSecondSquared: "ym/s²", "yoctometer per second squared", "yoctometers per second squared";
 
     // Other units
-    FootPerSecondSquared: "ft/s²", "foot per second squared";
+    FootPerSecondSquared: "ft/s²", "foot per second squared", "feet per second squared";
     Galileo: "Gal", "galileo";
-    InchPerSecondSquared: "in/s²", "inch per second squared";
-    MillimeterPerMinuteSquared: "mm/min²", "millimeter per minute squared";
-    StandardGravity: "g₀", "standard acceleration of gravity";
+    InchPerSecondSquared: "in/s²", "inch per second squared", "inches per second squared";
+    MillimeterPerMinuteSquared: "mm/min²", "millimeter per minute squared", "millimeters per minute squared";
+    StandardGravity: "g₀", "standard acceleration of gravity", "standard accelerations of gravity";
+    KnotPerSecond: "kn/s", "knot per second", "knots per second";
+    MilePerHourPerSecond: "mph/s", "mile per hour per second", "miles per hour per second";
 
     // Time combinations with minutes and hours
     // Per minute per second
-    YottameterPerMinutePerSecond: "Ym/(min·s)", "yottameter per minute per second";
-    ZettameterPerMinutePerSecond: "Zm/(min·s)", "zettameter per minute per second";
-    ExameterPerMinutePerSecond: "Em/(min·s)", "exameter per minute per second";
-    PetameterPerMinutePerSecond: "Pm/(min·s)", "petameter per minute per second";
-    TerameterPerMinutePerSecond: "Tm/(min·s)", "terameter per minute per second";
-    GigameterPerMinutePerSecond: "Gm/(min·s)", "gigameter per minute per second";
-    MegameterPerMinutePerSecond: "Mm/(min·s)", "megameter per minute per second";
-    KilometerPerMinutePerSecond: "km/(min·s)", "kilometer per minute per second";
-    HectometerPerMinutePerSecond: "hm/(min·s)", "hectometer per minute per second";
-    DecameterPerMinutePerSecond: "dam/(min·s)", "decameter per minute per second";
-    MeterPerMinutePerSecond: "m/(min·s)", "meter per minute per second";
-    DecimeterPerMinutePerSecond: "dm/(min·s)", "decimeter per minute per second";
-    CentimeterPerMinutePerSecond: "cm/(min·s)", "centimeter per minute per second";
-    MillimeterPerMinutePerSecond: "mm/(min·s)", "millimeter per minute per second";
-    MicrometerPerMinutePerSecond: "µm/(min·s)", "micrometer per minute per second";
-    NanometerPerMinutePerSecond: "nm/(min·s)", "nanometer per minute per second";
-    PicometerPerMinutePerSecond: "pm/(min·s)", "picometer per minute per second";
-    FemtometerPerMinutePerSecond: "fm/(min·s)", "femtometer per minute per second";
-    AttometerPerMinutePerSecond: "am/(min·s)", "attometer per minute per second";
-    ZeptometerPerMinutePerSecond: "zm/(min·s)", "zeptometer per minute per second";
-    YoctometerPerMinutePerSecond: "ym/(min·s)", "yoctometer per minute per second";
-    FootPerMinutePerSecond: "ft/(min·s)", "foot per minute per second";
-    InchPerMinutePerSecond: "in/(min·s)", "inch per minute per second";
+    YottameterPerMinutePerSecond: "Ym/(min·s)", "yottameter per minute per second", "yottameters per minute per second";
+    ZettameterPerMinutePerSecond: "Zm/(min·s)", "zettameter per minute per second", "zettameters per minute per second";
+    ExameterPerMinutePerSecond: "Em/(min·s)", "exameter per minute per second", "exameters per minute per second";
+    PetameterPerMinutePerSecond: "Pm/(min·s)", "petameter per minute per second", "petameters per minute per second";
+    TerameterPerMinutePerSecond: "Tm/(min·s)", "terameter per minute per second", "terameters per minute per second";
+    GigameterPerMinutePerSecond: "Gm/(min·s)", "gigameter per minute per second", "gigameters per minute per second";
+    MegameterPerMinutePerSecond: "Mm/(min·s)", "megameter per minute per second", "megameters per minute per second";
+    KilometerPerMinutePerSecond: "km/(min·s)", "kilometer per minute per second", "kilometers per minute per second";
+    HectometerPerMinutePerSecond: "hm/(min·s)", "hectometer per minute per second", "hectometers per minute per second";
+    DecameterPerMinutePerSecond: "dam/(min·s)", "decameter per minute per second", "decameters per minute per second";
+    MeterPerMinutePerSecond: "m/(min·s)", "meter per minute per second", "meters per minute per second";
+    DecimeterPerMinutePerSecond: "dm/(min·s)", "decimeter per minute per second", "decimeters per minute per second";
+    CentimeterPerMinutePerSecond: "cm/(min·s)", "centimeter per minute per second", "centimeters per minute per second";
+    MillimeterPerMinutePerSecond: "mm/(min·s)", "millimeter per minute per second", "millimeters per minute per second";
+    MicrometerPerMinutePerSecond: "µm/(min·s)", "micrometer per minute per second", "micrometers per minute per second";
+    NanometerPerMinutePerSecond: "nm/(min·s)", "nanometer per minute per second", "nanometers per minute per second";
+    PicometerPerMinutePerSecond: "pm/(min·s)", "picometer per minute per second", "picometers per minute per second";
+    FemtometerPerMinutePerSecond: "fm/(min·s)", "femtometer per minute per second", "femtometers per minute per second";
+    AttometerPerMinutePerSecond: "am/(min·s)", "attometer per minute per second", "attometers per minute per second";
+    ZeptometerPerMinutePerSecond: "zm/(min·s)", "zeptometer per minute per second", "zeptometers per minute per second";
+    YoctometerPerMinutePerSecond: "ym/(min·s)", "yoctometer per minute per second", "yoctometers per minute per second";
+    FootPerMinutePerSecond: "ft/(min·s)", "foot per minute per second", "feet per minute per second";
+    InchPerMinutePerSecond: "in/(min·s)", "inch per minute per second", "inches per minute per second";
 
     // Per hour per second
-    YottameterPerHourPerSecond: "Ym/(h·s)", "yottameter per hour per second";
-    ZettameterPerHourPerSecond: "Zm/(h·s)", "zettameter per hour per second";
-    ExameterPerHourPerSecond: "Em/(h·s)", "exameter per hour per second";
-    PetameterPerHourPerSecond: "Pm/(h·s)", "petameter per hour per second";
-    TerameterPerHourPerSecond: "Tm/(h·s)", "terameter per hour per second";
-    GigameterPerHourPerSecond: "Gm/(h·s)", "gigameter per hour per second";
-    MegameterPerHourPerSecond: "Mm/(h·s)", "megameter per hour per second";
-    KilometerPerHourPerSecond: "km/(h·s)", "kilometer per hour per second";
-    HectometerPerHourPerSecond: "hm/(h·s)", "hectometer per hour per second";
-    DecameterPerHourPerSecond: "dam/(h·s)", "decameter per hour per second";
-    MeterPerHourPerSecond: "m/(h·s)", "meter per hour per second";
-    DecimeterPerHourPerSecond: "dm/(h·s)", "decimeter per hour per second";
-    CentimeterPerHourPerSecond: "cm/(h·s)", "centimeter per hour per second";
-    MillimeterPerHourPerSecond: "mm/(h·s)", "millimeter per hour per second";
-    MicrometerPerHourPerSecond: "µm/(h·s)", "micrometer per hour per second";
-    NanometerPerHourPerSecond: "nm/(h·s)", "nanometer per hour per second";
-    PicometerPerHourPerSecond: "pm/(h·s)", "picometer per hour per second";
-    FemtometerPerHourPerSecond: "fm/(h·s)", "femtometer per hour per second";
-    AttometerPerHourPerSecond: "am/(h·s)", "attometer per hour per second";
-    ZeptometerPerHourPerSecond: "zm/(h·s)", "zeptometer per hour per second";
-    YoctometerPerHourPerSecond: "ym/(h·s)", "yoctometer per hour per second";
-    FootPerHourPerSecond: "ft/(h·s)", "foot per hour per second";
-    InchPerHourPerSecond: "in/(h·s)", "inch per hour per second";
+    YottameterPerHourPerSecond: "Ym/(h·s)", "yottameter per hour per second", "yottameters per hour per second";
+    ZettameterPerHourPerSecond: "Zm/(h·s)", "zettameter per hour per second", "zettameters per hour per second";
+    ExameterPerHourPerSecond: "Em/(h·s)", "exameter per hour per second", "exameters per hour per second";
+    PetameterPerHourPerSecond: "Pm/(h·s)", "petameter per hour per second", "petameters per hour per second";
+    TerameterPerHourPerSecond: "Tm/(h·s)", "terameter per hour per second", "terameters per hour per second";
+    GigameterPerHourPerSecond: "Gm/(h·s)", "gigameter per hour per second", "gigameters per hour per second";
+    MegameterPerHourPerSecond: "Mm/(h·s)", "megameter per hour per second", "megameters per hour per second";
+    KilometerPerHourPerSecond: "km/(h·s)", "kilometer per hour per second", "kilometers per hour per second";
+    HectometerPerHourPerSecond: "hm/(h·s)", "hectometer per hour per second", "hectometers per hour per second";
+    DecameterPerHourPerSecond: "dam/(h·s)", "decameter per hour per second", "decameters per hour per second";
+    MeterPerHourPerSecond: "m/(h·s)", "meter per hour per second", "meters per hour per second";
+    DecimeterPerHourPerSecond: "dm/(h·s)", "decimeter per hour per second", "decimeters per hour per second";
+    CentimeterPerHourPerSecond: "cm/(h·s)", "centimeter per hour per second", "centimeters per hour per second";
+    MillimeterPerHourPerSecond: "mm/(h·s)", "millimeter per hour per second", "millimeters per hour per second";
+    MicrometerPerHourPerSecond: "µm/(h·s)", "micrometer per hour per second", "micrometers per hour per second";
+    NanometerPerHourPerSecond: "nm/(h·s)", "nanometer per hour per second", "nanometers per hour per second";
+    PicometerPerHourPerSecond: "pm/(h·s)", "picometer per hour per second", "picometers per hour per second";
+    FemtometerPerHourPerSecond: "fm/(h·s)", "femtometer per hour per second", "femtometers per hour per second";
+    AttometerPerHourPerSecond: "am/(h·s)", "attometer per hour per second", "attometers per hour per second";
+    ZeptometerPerHourPerSecond: "zm/(h·s)", "zeptometer per hour per second", "zeptometers per hour per second";
+    YoctometerPerHourPerSecond: "ym/(h·s)", "yoctometer per hour per second", "yoctometers per hour per second";
+    FootPerHourPerSecond: "ft/(h·s)", "foot per hour per second", "feet per hour per second";
+    InchPerHourPerSecond: "in/(h·s)", "inch per hour per second", "inches per hour per second";
 
     // Per minute squared
-    YottameterPerMinuteSquared: "Ym/min²", "yottameter per minute squared";
-    ZettameterPerMinuteSquared: "Zm/min²", "zettameter per minute squared";
-    ExameterPerMinuteSquared: "Em/min²", "exameter per minute squared";
-    PetameterPerMinuteSquared: "Pm/min²", "petameter per minute squared";
-    TerameterPerMinuteSquared: "Tm/min²", "terameter per minute squared";
-    GigameterPerMinuteSquared: "Gm/min²", "gigameter per minute squared";
-    MegameterPerMinuteSquared: "Mm/min²", "megameter per minute squared";
-    KilometerPerMinuteSquared: "km/min²", "kilometer per minute squared";
-    HectometerPerMinuteSquared: "hm/min²", "hectometer per minute squared";
-    DecameterPerMinuteSquared: "dam/min²", "decameter per minute squared";
-    MeterPerMinuteSquared: "m/min²", "meter per minute squared";
-    DecimeterPerMinuteSquared: "dm/min²", "decimeter per minute squared";
-    CentimeterPerMinuteSquared: "cm/min²", "centimeter per minute squared";
-    MicrometerPerMinuteSquared: "µm/min²", "micrometer per minute squared";
-    NanometerPerMinuteSquared: "nm/min²", "nanometer per minute squared";
-    PicometerPerMinuteSquared: "pm/min²", "picometer per minute squared";
-    FemtometerPerMinuteSquared: "fm/min²", "femtometer per minute squared";
-    AttometerPerMinuteSquared: "am/min²", "attometer per minute squared";
-    ZeptometerPerMinuteSquared: "zm/min²", "zeptometer per minute squared";
-    YoctometerPerMinuteSquared: "ym/min²", "yoctometer per minute squared";
-    FootPerMinuteSquared: "ft/min²", "foot per minute squared";
-    InchPerMinuteSquared: "in/min²", "inch per minute squared";
+    YottameterPerMinuteSquared: "Ym/min²", "yottameter per minute squared", "yottameters per minute squared";
+    ZettameterPerMinuteSquared: "Zm/min²", "zettameter per minute squared", "zettameters per minute squared";
+    ExameterPerMinuteSquared: "Em/min²", "exameter per minute squared", "exameters per minute squared";
+    PetameterPerMinuteSquared: "Pm/min²", "petameter per minute squared", "petameters per minute squared";
+    TerameterPerMinuteSquared: "Tm/min²", "terameter per minute squared", "terameters per minute squared";
+    GigameterPerMinuteSquared: "Gm/min²", "gigameter per minute squared", "gigameters per minute squared";
+    MegameterPerMinuteSquared: "Mm/min²", "megameter per minute squared", "megameters per minute squared";
+    KilometerPerMinuteSquared: "km/min²", "kilometer per minute squared", "kilometers per minute squared";
+    HectometerPerMinuteSquared: "hm/min²", "hectometer per minute squared", "hectometers per minute squared";
+    DecameterPerMinuteSquared: "dam/min²", "decameter per minute squared", "decameters per minute squared";
+    MeterPerMinuteSquared: "m/min²", "meter per minute squared", "meters per minute squared";
+    DecimeterPerMinuteSquared: "dm/min²", "decimeter per minute squared", "decimeters per minute squared";
+    CentimeterPerMinuteSquared: "cm/min²", "centimeter per minute squared", "centimeters per minute squared";
+    MicrometerPerMinuteSquared: "µm/min²", "micrometer per minute squared", "micrometers per minute squared";
+    NanometerPerMinuteSquared: "nm/min²", "nanometer per minute squared", "nanometers per minute squared";
+    PicometerPerMinuteSquared: "pm/min²", "picometer per minute squared", "picometers per minute squared";
+    FemtometerPerMinuteSquared: "fm/min²", "femtometer per minute squared", "femtometers per minute squared";
+    AttometerPerMinuteSquared: "am/min²", "attometer per minute squared", "attometers per minute squared";
+    ZeptometerPerMinuteSquared: "zm/min²", "zeptometer per minute squared", "zeptometers per minute squared";
+    YoctometerPerMinuteSquared: "ym/min²", "yoctometer per minute squared", "yoctometers per minute squared";
+    FootPerMinuteSquared: "ft/min²", "foot per minute squared", "feet per minute squared";
+    InchPerMinuteSquared: "in/min²", "inch per minute squared", "inches per minute squared";
 
     // Per hour per minute
-    YottameterPerHourPerMinute: "Ym/(h·min)", "yottameter per hour per minute";
-    ZettameterPerHourPerMinute: "Zm/(h·min)", "zettameter per hour per minute";
-    ExameterPerHourPerMinute: "Em/(h·min)", "exameter per hour per minute";
-    PetameterPerHourPerMinute: "Pm/(h·min)", "petameter per hour per minute";
-    TerameterPerHourPerMinute: "Tm/(h·min)", "terameter per hour per minute";
-    GigameterPerHourPerMinute: "Gm/(h·min)", "gigameter per hour per minute";
-    MegameterPerHourPerMinute: "Mm/(h·min)", "megameter per hour per minute";
-    KilometerPerHourPerMinute: "km/(h·min)", "kilometer per hour per minute";
-    HectometerPerHourPerMinute: "hm/(h·min)", "hectometer per hour per minute";
-    DecameterPerHourPerMinute: "dam/(h·min)", "decameter per hour per minute";
-    MeterPerHourPerMinute: "m/(h·min)", "meter per hour per minute";
-    DecimeterPerHourPerMinute: "dm/(h·min)", "decimeter per hour per minute";
-    CentimeterPerHourPerMinute: "cm/(h·min)", "centimeter per hour per minute";
-    MillimeterPerHourPerMinute: "mm/(h·min)", "millimeter per hour per minute";
-    MicrometerPerHourPerMinute: "µm/(h·min)", "micrometer per hour per minute";
-    NanometerPerHourPerMinute: "nm/(h·min)", "nanometer per hour per minute";
-    PicometerPerHourPerMinute: "pm/(h·min)", "picometer per hour per minute";
-    FemtometerPerHourPerMinute: "fm/(h·min)", "femtometer per hour per minute";
-    AttometerPerHourPerMinute: "am/(h·min)", "attometer per hour per minute";
-    ZeptometerPerHourPerMinute: "zm/(h·min)", "zeptometer per hour per minute";
-    YoctometerPerHourPerMinute: "ym/(h·min)", "yoctometer per hour per minute";
-    FootPerHourPerMinute: "ft/(h·min)", "foot per hour per minute";
-    InchPerHourPerMinute: "in/(h·min)", "inch per hour per minute";
+    YottameterPerHourPerMinute: "Ym/(h·min)", "yottameter per hour per minute", "yottameters per hour per minute";
+    ZettameterPerHourPerMinute: "Zm/(h·min)", "zettameter per hour per minute", "zettameters per hour per minute";
+    ExameterPerHourPerMinute: "Em/(h·min)", "exameter per hour per minute", "exameters per hour per minute";
+    PetameterPerHourPerMinute: "Pm/(h·min)", "petameter per hour per minute", "petameters per hour per minute";
+    TerameterPerHourPerMinute: "Tm/(h·min)", "terameter per hour per minute", "terameters per hour per minute";
+    GigameterPerHourPerMinute: "Gm/(h·min)", "gigameter per hour per minute", "gigameters per hour per minute";
+    MegameterPerHourPerMinute: "Mm/(h·min)", "megameter per hour per minute", "megameters per hour per minute";
+    KilometerPerHourPerMinute: "km/(h·min)", "kilometer per hour per minute", "kilometers per hour per minute";
+    HectometerPerHourPerMinute: "hm/(h·min)", "hectometer per hour per minute", "hectometers per hour per minute";
+    DecameterPerHourPerMinute: "dam/(h·min)", "decameter per hour per minute", "decameters per hour per minute";
+    MeterPerHourPerMinute: "m/(h·min)", "meter per hour per minute", "meters per hour per minute";
+    DecimeterPerHourPerMinute: "dm/(h·min)", "decimeter per hour per minute", "decimeters per hour per minute";
+    CentimeterPerHourPerMinute: "cm/(h·min)", "centimeter per hour per minute", "centimeters per hour per minute";
+    MillimeterPerHourPerMinute: "mm/(h·min)", "millimeter per hour per minute", "millimeters per hour per minute";
+    MicrometerPerHourPerMinute: "µm/(h·min)", "micrometer per hour per minute", "micrometers per hour per minute";
+    NanometerPerHourPerMinute: "nm/(h·min)", "nanometer per hour per minute", "nanometers per hour per minute";
+    PicometerPerHourPerMinute: "pm/(h·min)", "picometer per hour per minute", "picometers per hour per minute";
+    FemtometerPerHourPerMinute: "fm/(h·min)", "femtometer per hour per minute", "femtometers per hour per minute";
+    AttometerPerHourPerMinute: "am/(h·min)", "attometer per hour per minute", "attometers per hour per minute";
+    ZeptometerPerHourPerMinute: "zm/(h·min)", "zeptometer per hour per minute", "zeptometers per hour per minute";
+    YoctometerPerHourPerMinute: "ym/(h·min)", "yoctometer per hour per minute", "yoctometers per hour per minute";
+    FootPerHourPerMinute: "ft/(h·min)", "foot per hour per minute", "feet per hour per minute";
+    InchPerHourPerMinute: "in/(h·min)", "inch per hour per minute", "inches per hour per minute";
 
     // Per hour squared
-    YottameterPerHourSquared: "Ym/h²", "yottameter per hour squared";
-    ZettameterPerHourSquared: "Zm/h²", "zettameter per hour squared";
-    ExameterPerHourSquared: "Em/h²", "exameter per hour squared";
-    PetameterPerHourSquared: "Pm/h²", "petameter per hour squared";
-    TerameterPerHourSquared: "Tm/h²", "terameter per hour squared";
-    GigameterPerHourSquared: "Gm/h²", "gigameter per hour squared";
-    MegameterPerHourSquared: "Mm/h²", "megameter per hour squared";
-    KilometerPerHourSquared: "km/h²", "kilometer per hour squared";
-    HectometerPerHourSquared: "hm/h²", "hectometer per hour squared";
-    DecameterPerHourSquared: "dam/h²", "decameter per hour squared";
-    MeterPerHourSquared: "m/h²", "meter per hour squared";
-    DecimeterPerHourSquared: "dm/h²", "decimeter per hour squared";
-    CentimeterPerHourSquared: "cm/h²", "centimeter per hour squared";
-    MillimeterPerHourSquared: "mm/h²", "millimeter per hour squared";
-    MicrometerPerHourSquared: "µm/h²", "micrometer per hour squared";
-    NanometerPerHourSquared: "nm/h²", "nanometer per hour squared";
-    PicometerPerHourSquared: "pm/h²", "picometer per hour squared";
-    FemtometerPerHourSquared: "fm/h²", "femtometer per hour squared";
-    AttometerPerHourSquared: "am/h²", "attometer per hour squared";
-    ZeptometerPerHourSquared: "zm/h²", "zeptometer per hour squared";
-    YoctometerPerHourSquared: "ym/h²", "yoctometer per hour squared";
-    FootPerHourSquared: "ft/h²", "foot per hour squared";
-    InchPerHourSquared: "in/h²", "inch per hour squared";
+    YottameterPerHourSquared: "Ym/h²", "yottameter per hour squared", "yottameters per hour squared";
+    ZettameterPerHourSquared: "Zm/h²", "zettameter per hour squared", "zettameters per hour squared";
+    ExameterPerHourSquared: "Em/h²", "exameter per hour squared", "exameters per hour squared";
+    PetameterPerHourSquared: "Pm/h²", "petameter per hour squared", "petameters per hour squared";
+    TerameterPerHourSquared: "Tm/h²", "terameter per hour squared", "terameters per hour squared";
+    GigameterPerHourSquared: "Gm/h²", "gigameter per hour squared", "gigameters per hour squared";
+    MegameterPerHourSquared: "Mm/h²", "megameter per hour squared", "megameters per hour squared";
+    KilometerPerHourSquared: "km/h²", "kilometer per hour squared", "kilometers per hour squared";
+    HectometerPerHourSquared: "hm/h²", "hectometer per hour squared", "hectometers per hour squared";
+    DecameterPerHourSquared: "dam/h²", "decameter per hour squared", "decameters per hour squared";
+    MeterPerHourSquared: "m/h²", "meter per hour squared", "meters per hour squared";
+    DecimeterPerHourSquared: "dm/h²", "decimeter per hour squared", "decimeters per hour squared";
+    CentimeterPerHourSquared: "cm/h²", "centimeter per hour squared", "centimeters per hour squared";
+    MillimeterPerHourSquared: "mm/h²", "millimeter per hour squared", "millimeters per hour squared";
+    MicrometerPerHourSquared: "µm/h²", "micrometer per hour squared", "micrometers per hour squared";
+    NanometerPerHourSquared: "nm/h²", "nanometer per hour squared", "nanometers per hour squared";
+    PicometerPerHourSquared: "pm/h²", "picometer per hour squared", "picometers per hour squared";
+    FemtometerPerHourSquared: "fm/h²", "femtometer per hour squared", "femtometers per hour squared";
+    AttometerPerHourSquared: "am/h²", "attometer per hour squared", "attometers per hour squared";
+    ZeptometerPerHourSquared: "zm/h²", "zeptometer per hour squared", "zeptometers per hour squared";
+    YoctometerPerHourSquared: "ym/h²", "yoctometer per hour squared", "yoctometers per hour squared";
+    FootPerHourSquared: "ft/h²", "foot per hour squared", "feet per hour squared";
+    InchPerHourSquared: "in/h²", "inch per hour squared", "inches per hour squared";
 }
 
 // Import necessary prefixes
@@ -205,6 +207,8 @@ crate::convert_linear! {
     InchPerSecondSquared => MeterPerSecondSquared: 2.54E-2;
     MillimeterPerMinuteSquared => MeterPerSecondSquared: 2.7777777777777778E-7;
     StandardGravity => MeterPerSecondSquared: 9.80665;
+    KnotPerSecond => MeterPerSecondSquared: 1.852E3 / HOUR;
+    MilePerHourPerSecond => MeterPerSecondSquared: 1.609344E3 / HOUR;
 
     // Time combinations - Per minute per second (m/(min·s) = m/s² × 60)
     YottameterPerMinutePerSecond => MeterPerSecondSquared: YOTTA * MINUTE;
@@ -331,55 +335,87 @@ crate::convert_linear! {
     InchPerHourSquared => MeterPerSecondSquared: 2.54E-2 / 12960000.0;
 }
 
-// Split into multiple convert_matrix! calls to avoid recursion limit
-convert_matrix! {
-    MeterPerSecondSquared => YottameterPerSecondSquared, ZettameterPerSecondSquared, ExameterPerSecondSquared,
-        PetameterPerSecondSquared, TerameterPerSecondSquared, GigameterPerSecondSquared,
-        MegameterPerSecondSquared, KilometerPerSecondSquared, HectometerPerSecondSquared,
-        DecameterPerSecondSquared, DecimeterPerSecondSquared, CentimeterPerSecondSquared,
-        MillimeterPerSecondSquared, MicrometerPerSecondSquared, NanometerPerSecondSquared,
-        PicometerPerSecondSquared, FemtometerPerSecondSquared, AttometerPerSecondSquared,
-        ZeptometerPerSecondSquared, YoctometerPerSecondSquared, FootPerSecondSquared,
-        Galileo, InchPerSecondSquared, MillimeterPerMinuteSquared, StandardGravity, YottameterPerMinutePerSecond, ZettameterPerMinutePerSecond, ExameterPerMinutePerSecond,
-        PetameterPerMinutePerSecond, TerameterPerMinutePerSecond, GigameterPerMinutePerSecond,
-        MegameterPerMinutePerSecond, KilometerPerMinutePerSecond, HectometerPerMinutePerSecond,
-        DecameterPerMinutePerSecond, MeterPerMinutePerSecond, DecimeterPerMinutePerSecond,
-        CentimeterPerMinutePerSecond, MillimeterPerMinutePerSecond, MicrometerPerMinutePerSecond,
-        NanometerPerMinutePerSecond, PicometerPerMinutePerSecond, FemtometerPerMinutePerSecond,
-        AttometerPerMinutePerSecond, ZeptometerPerMinutePerSecond, YoctometerPerMinutePerSecond,
-        FootPerMinutePerSecond, InchPerMinutePerSecond,
-        YottameterPerHourPerSecond, ZettameterPerHourPerSecond, ExameterPerHourPerSecond,
-        PetameterPerHourPerSecond, TerameterPerHourPerSecond, GigameterPerHourPerSecond,
-        MegameterPerHourPerSecond, KilometerPerHourPerSecond, HectometerPerHourPerSecond,
-        DecameterPerHourPerSecond, MeterPerHourPerSecond, DecimeterPerHourPerSecond,
-        CentimeterPerHourPerSecond, MillimeterPerHourPerSecond, MicrometerPerHourPerSecond,
-        NanometerPerHourPerSecond, PicometerPerHourPerSecond, FemtometerPerHourPerSecond,
-        AttometerPerHourPerSecond, ZeptometerPerHourPerSecond, YoctometerPerHourPerSecond,
-        FootPerHourPerSecond, InchPerHourPerSecond,
-        YottameterPerMinuteSquared, ZettameterPerMinuteSquared, ExameterPerMinuteSquared,
-        PetameterPerMinuteSquared, TerameterPerMinuteSquared, GigameterPerMinuteSquared,
-        MegameterPerMinuteSquared, KilometerPerMinuteSquared, HectometerPerMinuteSquared,
-        DecameterPerMinuteSquared, MeterPerMinuteSquared, DecimeterPerMinuteSquared,
-        CentimeterPerMinuteSquared, MicrometerPerMinuteSquared, NanometerPerMinuteSquared,
-        PicometerPerMinuteSquared, FemtometerPerMinuteSquared, AttometerPerMinuteSquared,
-        ZeptometerPerMinuteSquared, YoctometerPerMinuteSquared, FootPerMinuteSquared,
-        InchPerMinuteSquared,
-        YottameterPerHourPerMinute, ZettameterPerHourPerMinute, ExameterPerHourPerMinute,
-        PetameterPerHourPerMinute, TerameterPerHourPerMinute, GigameterPerHourPerMinute,
-        MegameterPerHourPerMinute, KilometerPerHourPerMinute, HectometerPerHourPerMinute,
-        DecameterPerHourPerMinute, MeterPerHourPerMinute, DecimeterPerHourPerMinute,
-        CentimeterPerHourPerMinute, MillimeterPerHourPerMinute, MicrometerPerHourPerMinute,
-        NanometerPerHourPerMinute, PicometerPerHourPerMinute, FemtometerPerHourPerMinute,
-        AttometerPerHourPerMinute, ZeptometerPerHourPerMinute, YoctometerPerHourPerMinute,
-        FootPerHourPerMinute, InchPerHourPerMinute,
-        YottameterPerHourSquared, ZettameterPerHourSquared, ExameterPerHourSquared,
-        PetameterPerHourSquared, TerameterPerHourSquared, GigameterPerHourSquared,
-        MegameterPerHourSquared, KilometerPerHourSquared, HectometerPerHourSquared,
-        DecameterPerHourSquared, MeterPerHourSquared, DecimeterPerHourSquared,
-        CentimeterPerHourSquared, MillimeterPerHourSquared, MicrometerPerHourSquared,
-        NanometerPerHourSquared, PicometerPerHourSquared, FemtometerPerHourSquared,
-        AttometerPerHourSquared, ZeptometerPerHourSquared, YoctometerPerHourSquared,
-        FootPerHourSquared, InchPerHourSquared
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    MeterPerSecondSquared => YottameterPerSecondSquared, ZettameterPerSecondSquared,
+                             ExameterPerSecondSquared, PetameterPerSecondSquared,
+                             TerameterPerSecondSquared, GigameterPerSecondSquared,
+                             MegameterPerSecondSquared, KilometerPerSecondSquared,
+                             MillimeterPerSecondSquared, MicrometerPerSecondSquared,
+                             NanometerPerSecondSquared, PicometerPerSecondSquared,
+                             FemtometerPerSecondSquared, AttometerPerSecondSquared,
+                             ZeptometerPerSecondSquared, YoctometerPerSecondSquared
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    MeterPerSecondSquared => YottameterPerSecondSquared, ZettameterPerSecondSquared,
+                             ExameterPerSecondSquared, PetameterPerSecondSquared,
+                             TerameterPerSecondSquared, GigameterPerSecondSquared,
+                             MegameterPerSecondSquared, KilometerPerSecondSquared,
+                             HectometerPerSecondSquared, DecameterPerSecondSquared,
+                             DecimeterPerSecondSquared, CentimeterPerSecondSquared,
+                             MillimeterPerSecondSquared, MicrometerPerSecondSquared,
+                             NanometerPerSecondSquared, PicometerPerSecondSquared,
+                             FemtometerPerSecondSquared, AttometerPerSecondSquared,
+                             ZeptometerPerSecondSquared, YoctometerPerSecondSquared,
+                             FootPerSecondSquared, Galileo, InchPerSecondSquared,
+                             MillimeterPerMinuteSquared, StandardGravity, KnotPerSecond,
+                             MilePerHourPerSecond, YottameterPerMinutePerSecond,
+                             ZettameterPerMinutePerSecond, ExameterPerMinutePerSecond,
+                             PetameterPerMinutePerSecond, TerameterPerMinutePerSecond,
+                             GigameterPerMinutePerSecond, MegameterPerMinutePerSecond,
+                             KilometerPerMinutePerSecond, HectometerPerMinutePerSecond,
+                             DecameterPerMinutePerSecond, MeterPerMinutePerSecond,
+                             DecimeterPerMinutePerSecond, CentimeterPerMinutePerSecond,
+                             MillimeterPerMinutePerSecond, MicrometerPerMinutePerSecond,
+                             NanometerPerMinutePerSecond, PicometerPerMinutePerSecond,
+                             FemtometerPerMinutePerSecond, AttometerPerMinutePerSecond,
+                             ZeptometerPerMinutePerSecond, YoctometerPerMinutePerSecond,
+                             FootPerMinutePerSecond, InchPerMinutePerSecond,
+                             YottameterPerHourPerSecond, ZettameterPerHourPerSecond,
+                             ExameterPerHourPerSecond, PetameterPerHourPerSecond,
+                             TerameterPerHourPerSecond, GigameterPerHourPerSecond,
+                             MegameterPerHourPerSecond, KilometerPerHourPerSecond,
+                             HectometerPerHourPerSecond, DecameterPerHourPerSecond,
+                             MeterPerHourPerSecond, DecimeterPerHourPerSecond,
+                             CentimeterPerHourPerSecond, MillimeterPerHourPerSecond,
+                             MicrometerPerHourPerSecond, NanometerPerHourPerSecond,
+                             PicometerPerHourPerSecond, FemtometerPerHourPerSecond,
+                             AttometerPerHourPerSecond, ZeptometerPerHourPerSecond,
+                             YoctometerPerHourPerSecond, FootPerHourPerSecond, InchPerHourPerSecond,
+                             YottameterPerMinuteSquared, ZettameterPerMinuteSquared,
+                             ExameterPerMinuteSquared, PetameterPerMinuteSquared,
+                             TerameterPerMinuteSquared, GigameterPerMinuteSquared,
+                             MegameterPerMinuteSquared, KilometerPerMinuteSquared,
+                             HectometerPerMinuteSquared, DecameterPerMinuteSquared,
+                             MeterPerMinuteSquared, DecimeterPerMinuteSquared,
+                             CentimeterPerMinuteSquared, MicrometerPerMinuteSquared,
+                             NanometerPerMinuteSquared, PicometerPerMinuteSquared,
+                             FemtometerPerMinuteSquared, AttometerPerMinuteSquared,
+                             ZeptometerPerMinuteSquared, YoctometerPerMinuteSquared,
+                             FootPerMinuteSquared, InchPerMinuteSquared, YottameterPerHourPerMinute,
+                             ZettameterPerHourPerMinute, ExameterPerHourPerMinute,
+                             PetameterPerHourPerMinute, TerameterPerHourPerMinute,
+                             GigameterPerHourPerMinute, MegameterPerHourPerMinute,
+                             KilometerPerHourPerMinute, HectometerPerHourPerMinute,
+                             DecameterPerHourPerMinute, MeterPerHourPerMinute,
+                             DecimeterPerHourPerMinute, CentimeterPerHourPerMinute,
+                             MillimeterPerHourPerMinute, MicrometerPerHourPerMinute,
+                             NanometerPerHourPerMinute, PicometerPerHourPerMinute,
+                             FemtometerPerHourPerMinute, AttometerPerHourPerMinute,
+                             ZeptometerPerHourPerMinute, YoctometerPerHourPerMinute,
+                             FootPerHourPerMinute, InchPerHourPerMinute, YottameterPerHourSquared,
+                             ZettameterPerHourSquared, ExameterPerHourSquared,
+                             PetameterPerHourSquared, TerameterPerHourSquared,
+                             GigameterPerHourSquared, MegameterPerHourSquared,
+                             KilometerPerHourSquared, HectometerPerHourSquared,
+                             DecameterPerHourSquared, MeterPerHourSquared, DecimeterPerHourSquared,
+                             CentimeterPerHourSquared, MillimeterPerHourSquared,
+                             MicrometerPerHourSquared, NanometerPerHourSquared,
+                             PicometerPerHourSquared, FemtometerPerHourSquared,
+                             AttometerPerHourSquared, ZeptometerPerHourSquared,
+                             YoctometerPerHourSquared, FootPerHourSquared, InchPerHourSquared
 }
 
 // Acceleration quantity definition (Length/Time²)
@@ -435,4 +471,17 @@ mod tests {
     test_uom_acceleration!(InchPerSecondSquared, inch_per_second_squared);
     test_uom_acceleration!(MillimeterPerMinuteSquared, millimeter_per_minute_squared);
     test_uom_acceleration!(StandardGravity, standard_gravity);
+
+    #[test]
+    fn test_velocity_per_second_units() {
+        use crate::si::acceleration::*;
+
+        // 0-60 mph in 3 seconds
+        let launch = Acceleration::from::<MilePerHourPerSecond>(20.0);
+        assert!((launch.to::<MeterPerSecondSquared>() - 8.9408_f64).abs() < 1e-12);
+        assert!((launch.to::<StandardGravity>() - 0.911_708_f64).abs() < 1e-6);
+
+        let takeoff_roll = Acceleration::from::<KnotPerSecond>(3.6);
+        assert!((takeoff_roll.to::<MeterPerSecondSquared>() - 1.852_f64).abs() < 1e-12);
+    }
 }
@@ -28,6 +28,12 @@
 /// - **StandardCubicCentimeter (cm³(STP))**: Amount in 1 cm³ at STP
 /// - **StandardCubicFoot (scf)**: Amount in 1 ft³ at STP
 ///
+/// ## Customary Mass-Based Units
+///
+/// Amount whose mass in pounds (ounces) equals the molar mass in g/mol:
+/// - **PoundMole (lb-mol)**: 453.59237 moles (exact)
+/// - **OunceMole (oz-mol)**: 28.349523125 moles (exact)
+///
 use crate::prefix::*;
 use typenum::*;
 
@@ -66,7 +72,11 @@ units! {
     StandardCubicMeter: "m³(STP)", "standard cubic meter";
     StandardLiter: "L(STP)", "standard liter";
     StandardCubicCentimeter: "cm³(STP)", "standard cubic centimeter";
-    StandardCubicFoot: "scf", "standard cubic foot";
+    StandardCubicFoot: "scf", "standard cubic foot", "standard cubic feet";
+
+    // Customary mass-based units
+    PoundMole: "lb-mol", "pound-mole";
+    OunceMole: "oz-mol", "ounce-mole";
 }
 
 // Mole is the SI base unit for amount of substance
@@ -105,13 +115,24 @@ crate::convert_linear! {
     StandardLiter => Mole: 1E5 * MILLI / 8.314462618 / 273.15;
     StandardCubicCentimeter => Mole: 1E5 * MICRO / 8.314462618 / 273.15;
     StandardCubicFoot => Mole: 1E5 * 2.831685E-2 / 8.314462618 / 273.15;
+
+    // Customary mass-based units (avoirdupois pound and ounce expressed in grams)
+    PoundMole => Mole: 453.59237;
+    OunceMole => Mole: 28.349523125;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Mole => Yottamole, Zettamole, Examole, Petamole, Teramole, Gigamole, Megamole, Kilomole,
+            Millimole, Micromole, Nanomole, Picomole, Attomole, Zeptomole, Yoctomole
 }
 
-convert_matrix! {
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
     Mole => Yottamole, Zettamole, Examole, Petamole, Teramole, Gigamole, Megamole, Kilomole,
-        Hectomole, Decamole, Decimole, Centimole, Millimole, Micromole, Nanomole, Picomole,
-        Femtumole, Attomole, Zeptomole, Yoctomole, Particle, StandardCubicMeter, StandardLiter,
-        StandardCubicCentimeter, StandardCubicFoot
+            Hectomole, Decamole, Decimole, Centimole, Millimole, Micromole, Nanomole, Picomole,
+            Femtumole, Attomole, Zeptomole, Yoctomole, Particle, StandardCubicMeter, StandardLiter,
+            StandardCubicCentimeter, StandardCubicFoot, PoundMole, OunceMole
 }
 
 // Amount quantity definition
@@ -169,4 +190,13 @@ mod tests {
     test_uom_amount!(StandardLiter, standard_liter);
     test_uom_amount!(StandardCubicCentimeter, standard_centimeter);
     test_uom_amount!(StandardCubicFoot, standard_cubic_foot);
+
+    #[test]
+    fn test_customary_mass_based_units() {
+        use crate::si::amount::*;
+
+        let lb_mol = Amount::from::<PoundMole>(1.0);
+        assert!((lb_mol.to::<Kilomole>() - 0.453_592_37_f64).abs() < 1e-12);
+        assert!((lb_mol.to::<OunceMole>() - 16.0_f64).abs() < 1e-12);
+    }
 }
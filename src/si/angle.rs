@@ -11,6 +11,9 @@
 /// - Angle quantities are treated as dimensionless scalars
 /// - UOM compatibility tests cannot be performed for angle units
 /// - All conversions are done through the Unitless base unit
+/// - `Angle<V>` is an alias of `Scalar<V>` storing revolutions, not radians; the
+///   free functions `to_degrees`, `to_radians`, `normalize` and `normalize_signed`
+///   read it that way
 ///
 /// ## Base Unit
 ///
@@ -24,8 +27,14 @@
 /// - **Mil**: 1/6400 of a revolution
 /// - **Minute (′)**: 1/60 of a degree (1/21600 revolutions)
 /// - **Second (″)**: 1/60 of a minute (1/1296000 revolutions)
+/// - **Arcminute (arcmin)**, **Arcsecond (arcsec)**: Astronomy names for minute and second
+/// - **Gradian (grad)**: Surveying name for the gon
+/// - **Milliradian (mrad)**: 1/1000 radian, used for ballistic and optical sight adjustment
+///   (distinct from the NATO mil of 1/6400 revolution)
 ///
 use super::scalar::Unitless;
+use core::f64::consts::TAU;
+use num_traits::float::FloatCore;
 
 // Base angle unit
 units! {
@@ -40,6 +49,10 @@ units! {
     Mil: "mil", "mil";
     Minute: "′", "minute";
     Second: "″", "second";
+    Arcminute: "arcmin", "arcminute";
+    Arcsecond: "arcsec", "arcsecond";
+    Gradian: "grad", "gradian";
+    Milliradian: "mrad", "milliradian";
 }
 
 // Angle unit conversions with Revolution as base unit
@@ -52,8 +65,111 @@ crate::convert_linear! {
     Mil => Unitless: 1.562_5_E-4;                          // 1/6400
     Minute => Unitless: 4.629_629_629_629_63_E-5;          // 1/21600
     Second => Unitless: 7.716_049_382_716_049_E-7;         // 1/1296000
+    Arcminute => Unitless: 4.629_629_629_629_63_E-5;       // 1/21600
+    Arcsecond => Unitless: 7.716_049_382_716_049_E-7;      // 1/1296000
+    Gradian => Unitless: 2.5_E-3;                          // 1/400
+    Milliradian => Unitless: 1.591_549_430_918_953_4_E-4;  // 1/(2000π)
 }
 
-crate::convert_matrix! {
-    Unitless => Revolution, Radian, Degree, Gon, Mil, Minute, Second
+// ===== ANGLE QUANTITY =====
+
+/// Angles share the dimensionless `Scalar` quantity
+pub type Angle<V> = super::scalar::Scalar<V>;
+
+// The helpers below are free functions rather than methods, so that percents
+// and other ratios sharing `Scalar` do not pick up `to_degrees`. Wrapping
+// works on the stored revolutions, where a full turn is exactly 1, so it
+// never picks up the rounding of 2π.
+
+/// The angle in degrees
+pub fn to_degrees<V: FloatCore>(angle: Angle<V>) -> V {
+    angle.value * V::from(360.0).unwrap()
+}
+
+/// The angle in radians
+pub fn to_radians<V: FloatCore>(angle: Angle<V>) -> V {
+    angle.value * V::from(TAU).unwrap()
+}
+
+/// Wrap the angle into one turn, `[0, 2π)`
+///
+/// # Examples
+/// ```rust,ignore
+/// use num_units::si::angle::{self, Angle, Degree};
+///
+/// let heading = angle::normalize(Angle::from::<Degree>(-90.0));
+/// assert_eq!(angle::to_degrees(heading), 270.0);
+/// ```
+pub fn normalize<V: FloatCore>(angle: Angle<V>) -> Angle<V> {
+    let turns = angle.value - angle.value.floor();
+    // A tiny negative angle wraps to 1 - ε, which can round up to a full turn
+    Angle::from_base(if turns >= V::one() { V::zero() } else { turns })
+}
+
+/// Wrap the angle into half a turn either side of zero, `[-π, π)`
+pub fn normalize_signed<V: FloatCore>(angle: Angle<V>) -> Angle<V> {
+    let half = V::from(0.5).unwrap();
+    let turns = angle.value - (angle.value + half).floor();
+    Angle::from_base(if turns >= half {
+        turns - V::one()
+    } else {
+        turns
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::si::scalar::Scalar;
+
+    #[test]
+    fn test_surveying_and_astronomy_units() {
+        let right_angle = Scalar::from::<Degree>(90.0);
+        assert!((right_angle.to::<Gradian>() - 100.0_f64).abs() < 1e-12);
+        assert!((right_angle.to::<Arcminute>() - 5400.0_f64).abs() < 1e-9);
+        assert!((right_angle.to::<Arcsecond>() - 324_000.0_f64).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_angle_helpers() {
+        let right_angle = Angle::from::<Degree>(90.0);
+        assert!((to_degrees(right_angle) - 90.0_f64).abs() < 1e-12);
+        assert!((to_radians(right_angle) - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert_eq!(to_degrees(Angle::from_base(0.5_f32)), 180.0);
+    }
+
+    #[test]
+    fn test_angle_normalize() {
+        let wrapped = normalize(Angle::from::<Degree>(-90.0));
+        assert!((to_degrees(wrapped) - 270.0_f64).abs() < 1e-9);
+        assert_eq!(normalize(Angle::from_base(2.25)), Angle::from_base(0.25));
+        assert_eq!(normalize(Angle::from_base(1.0)), Angle::from_base(0.0));
+        assert_eq!(normalize(Angle::from_base(-1e-20)), Angle::from_base(0.0));
+
+        let signed = normalize_signed(Angle::from::<Degree>(270.0));
+        assert!((to_degrees(signed) + 90.0_f64).abs() < 1e-9);
+        assert_eq!(
+            normalize_signed(Angle::from_base(0.5)),
+            Angle::from_base(-0.5)
+        );
+        assert_eq!(
+            normalize_signed(Angle::from_base(-0.5)),
+            Angle::from_base(-0.5)
+        );
+        assert_eq!(
+            normalize_signed(Angle::from_base(-3.75)),
+            Angle::from_base(0.25)
+        );
+    }
+
+    #[test]
+    fn test_milliradian() {
+        let radian = Scalar::from::<Radian>(1.0);
+        assert!((radian.to::<Milliradian>() - 1000.0_f64).abs() < 1e-9);
+
+        // A full turn holds 2000π milliradians but only 6400 NATO mils
+        let turn = Scalar::from::<Revolution>(1.0);
+        assert!((turn.to::<Milliradian>() - 6283.185_307_f64).abs() < 1e-6);
+        assert!((turn.to::<Mil>() - 6400.0_f64).abs() < 1e-9);
+    }
 }
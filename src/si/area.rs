@@ -22,6 +22,13 @@
 /// - **Square Yard (yd²)**: 0.8361274 square meters
 /// - **Square Mile (mi²)**: 2,589,988 square meters
 ///
+/// ## Surveying Units
+///
+/// - **Rood (ro)**: 1/4 international acre = 1,011.714 square meters
+/// - **Square Rod (rd²)**: 272.25 ft² = 25.29285 square meters
+/// - **Acre (U.S. survey)**: 43,560 survey ft² = 4,046.873 square meters
+/// - **Township (twp)**: 36 survey square miles = 93,239,945 square meters
+///
 /// ## Usage
 ///
 /// ```rust,ignore
@@ -89,12 +96,20 @@ units! {
     Barn: "b", "barn";
     CircularMil: "cmil", "circular mil";
     Hectare: "ha", "hectare";
-    SquareFoot: "ft²", "square foot";
-    SquareInch: "in²", "square inch";
+    SquareFoot: "ft²", "square foot", "square feet";
+    SquareInch: "in²", "square inch", "square inches";
     SquareMile: "mi²", "square mile";
     SquareYard: "yd²", "square yard";
 }
 
+// Surveying units
+units! {
+    Rood: "ro", "rood";
+    SquareRod: "rd²", "square rod";
+    AcreSurvey: "ac (U.S. survey)", "acre (U.S. survey)", "acres (U.S. survey)";
+    Township: "twp", "township";
+}
+
 // Unit conversions using convert_linear! with exact UOM coefficients
 crate::convert_linear! {
     // SI prefixed units - using squared prefixes
@@ -129,10 +144,31 @@ crate::convert_linear! {
     SquareInch => SquareMeter: 6.451_6_E-4;
     SquareMile => SquareMeter: 2.589_988_E6;
     SquareYard => SquareMeter: 8.361_274_E-1;
+
+    // Surveying units - exact definitions in international and U.S. survey feet
+    Rood => SquareMeter: 1.011_714_105_6_E3;
+    SquareRod => SquareMeter: 2.529_285_264_E1;
+    AcreSurvey => SquareMeter: 4.046_872_609_874_251_E3;
+    Township => SquareMeter: 9.323_994_493_150_276_E7;
 }
 
-crate::convert_matrix! {
-    SquareMeter => SquareYottameter, SquareZettameter, SquareExameter, SquarePetameter, SquareTerameter, SquareGigameter, SquareMegameter, SquareKilometer, SquareHectometer, SquareDecameter, SquareDecimeter, SquareCentimeter, SquareMillimeter, SquareMicrometer, SquareNanometer, SquarePicometer, SquareFemtometer, SquareAttometer, SquareZeptometer, SquareYoctometer, Acre, Are, Barn, CircularMil, Hectare, SquareFoot, SquareInch, SquareMile, SquareYard
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    SquareMeter => SquareYottameter, SquareZettameter, SquareExameter, SquarePetameter,
+                   SquareTerameter, SquareGigameter, SquareMegameter, SquareKilometer,
+                   SquareMillimeter, SquareMicrometer, SquareNanometer, SquarePicometer,
+                   SquareFemtometer, SquareAttometer, SquareZeptometer, SquareYoctometer
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    SquareMeter => SquareYottameter, SquareZettameter, SquareExameter, SquarePetameter,
+                   SquareTerameter, SquareGigameter, SquareMegameter, SquareKilometer,
+                   SquareHectometer, SquareDecameter, SquareDecimeter, SquareCentimeter,
+                   SquareMillimeter, SquareMicrometer, SquareNanometer, SquarePicometer,
+                   SquareFemtometer, SquareAttometer, SquareZeptometer, SquareYoctometer, Acre, Are,
+                   Barn, CircularMil, Hectare, SquareFoot, SquareInch, SquareMile, SquareYard, Rood,
+                   SquareRod, AcreSurvey, Township
 }
 
 // Area quantity definition
@@ -191,6 +227,19 @@ mod tests {
     test_uom_area!(SquareInch, square_inch);
     test_uom_area!(SquareMile, square_mile);
     test_uom_area!(SquareYard, square_yard);
+
+    #[test]
+    fn test_surveying_units() {
+        use crate::si::area::*;
+
+        let acre = Area::from::<AcreSurvey>(1.0);
+        assert!((acre.to::<Rood>() - 4.000_016_f64).abs() < 1e-6);
+        assert!((acre.to::<SquareRod>() - 160.000_64_f64).abs() < 1e-5);
+        assert!((acre.to::<Acre>() - 1.0_f64).abs() < 1e-6);
+
+        let township = Area::from::<Township>(1.0);
+        assert!((township.to::<AcreSurvey>() - 23_040.0_f64).abs() < 1e-8);
+    }
 }
 
 // Re-export types for convenience
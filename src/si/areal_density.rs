@@ -0,0 +1,113 @@
+/// # Areal Density Units - SI Areal Mass Density Measurements
+///
+/// This module defines SI areal density units and their conversions. Areal density (surface
+/// density, grammage) is the mass per unit area of a sheet material, with kilogram per square
+/// meter as the SI base unit. Paper, film and textile weights are specified this way.
+///
+/// ## Base Unit
+///
+/// - **KilogramPerSquareMeter (kg/m²)**: The SI base unit of areal density
+///
+/// ## Other Units
+///
+/// - **GramPerSquareMeter (g/m²)**: Grammage, often written "gsm" in paper and textiles
+/// - **GramPerSquareCentimeter (g/cm²)**: 10 kilograms per square meter
+/// - **OuncePerSquareYard (oz/yd²)**: Fabric weight, ≈ 33.906 grams per square meter
+/// - **OuncePerSquareFoot (oz/ft²)**: ≈ 305.15 grams per square meter
+/// - **PoundPerSquareFoot (lb/ft²)**: ≈ 4.8824 kilograms per square meter
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::areal_density::{ArealDensity, GramPerSquareMeter, OuncePerSquareYard};
+///
+/// // Denim fabric weight
+/// let denim = ArealDensity::from::<OuncePerSquareYard>(12.0);
+///
+/// // Convert between units
+/// let denim_gsm = denim.to::<GramPerSquareMeter>(); // ≈ 406.87 g/m²
+/// ```
+use crate::prefix::{CENTI, MILLI};
+use typenum::*;
+
+// SI base unit
+units! {
+    KilogramPerSquareMeter: "kg/m²", "kilogram per square meter", "kilograms per square meter";
+}
+
+// Other units
+units! {
+    GramPerSquareMeter: "g/m²", "gram per square meter", "grams per square meter";
+    GramPerSquareCentimeter: "g/cm²", "gram per square centimeter", "grams per square centimeter";
+    OuncePerSquareYard: "oz/yd²", "ounce per square yard", "ounces per square yard";
+    OuncePerSquareFoot: "oz/ft²", "ounce per square foot", "ounces per square foot";
+    PoundPerSquareFoot: "lb/ft²", "pound per square foot", "pounds per square foot";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // Other units
+    GramPerSquareMeter => KilogramPerSquareMeter: MILLI;
+    GramPerSquareCentimeter => KilogramPerSquareMeter: MILLI / CENTI / CENTI;
+    OuncePerSquareYard => KilogramPerSquareMeter: 2.834_952_E-2 / 8.361_274_E-1;
+    OuncePerSquareFoot => KilogramPerSquareMeter: 2.834_952_E-2 / 9.290_304_E-2;
+    PoundPerSquareFoot => KilogramPerSquareMeter: 4.535_924_E-1 / 9.290_304_E-2;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    KilogramPerSquareMeter => GramPerSquareMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    KilogramPerSquareMeter => GramPerSquareMeter, GramPerSquareCentimeter, OuncePerSquareYard,
+                              OuncePerSquareFoot, PoundPerSquareFoot
+}
+
+// Areal density quantity definition (Mass/Length²)
+use super::{ISQ, SiScale};
+quantity!(ArealDensity, ISQ<N2, P1, Z0, Z0, Z0, Z0, Z0>, SiScale, KilogramPerSquareMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_areal_density {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::areal_density,
+                uom::si::areal_mass_density,
+                ArealDensity,
+                ArealMassDensity,
+                KilogramPerSquareMeter,
+                $num_units_unit,
+                kilogram_per_square_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_areal_density!(KilogramPerSquareMeter, kilogram_per_square_meter);
+    test_uom_areal_density!(GramPerSquareMeter, gram_per_square_meter);
+    test_uom_areal_density!(GramPerSquareCentimeter, gram_per_square_centimeter);
+    test_uom_areal_density!(OuncePerSquareFoot, ounce_per_square_foot);
+
+    #[test]
+    fn test_mass_over_area() {
+        use crate::si::area::{Area, SquareFoot, SquareYard};
+        use crate::si::areal_density::*;
+        use crate::si::mass::{Mass, Ounce, Pound};
+
+        let fabric: ArealDensity<f64> = Mass::from::<Ounce>(12.0) / Area::from::<SquareYard>(1.0);
+        assert!((fabric.to::<OuncePerSquareYard>() - 12.0_f64).abs() < 1e-12);
+        assert!((fabric.to::<GramPerSquareMeter>() - 406.868_905_384_514_f64).abs() < 1e-6);
+
+        let decking: ArealDensity<f64> = Mass::from::<Pound>(5.0) / Area::from::<SquareFoot>(1.0);
+        assert!((decking.to::<PoundPerSquareFoot>() - 5.0_f64).abs() < 1e-12);
+    }
+}
+
+// Re-export types for convenience
+pub use areal_density::ArealDensity;
+pub use areal_density::*;
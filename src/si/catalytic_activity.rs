@@ -0,0 +1,186 @@
+/// # Catalytic Activity Units - SI Catalytic Activity Measurements
+///
+/// This module defines SI catalytic activity units and their conversions. Catalytic activity
+/// is a derived quantity in the SI system with dimensions of amount of substance × time⁻¹,
+/// with the katal as its base unit.
+///
+/// ## Base Unit
+///
+/// - **Katal (kat)**: One mole of substrate converted per second
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for katals:
+/// - **Yottakatal (Ykat)**: 10²⁴ katals
+/// - ... down to ...
+/// - **Yoctokatal (ykat)**: 10⁻²⁴ katals
+///
+/// ## Enzyme Units
+///
+/// The enzyme unit is the customary unit of enzyme activity in biochemistry:
+/// - **EnzymeUnit (U)**: 1 μmol/min, about 16.67 nanokatals
+/// - **KiloEnzymeUnit (kU)**, **MilliEnzymeUnit (mU)**, **MicroEnzymeUnit (μU)**,
+///   **NanoEnzymeUnit (nU)** and **PicoEnzymeUnit (pU)**: prefixed enzyme units
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::catalytic_activity::{CatalyticActivity, EnzymeUnit, Nanokatal};
+///
+/// // Create catalytic activity quantities
+/// let activity = CatalyticActivity::from::<EnzymeUnit>(3.0);
+///
+/// // Convert between units
+/// let activity_nkat = activity.to::<Nanokatal>(); // 50.0 nkat
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, MINUTE, NANO, PETA,
+    PICO, TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    Katal: "kat", "katal";
+}
+
+// SI prefixed katals
+units! {
+    Yottakatal: "Ykat", "yottakatal";
+    Zettakatal: "Zkat", "zettakatal";
+    Exakatal: "Ekat", "exakatal";
+    Petakatal: "Pkat", "petakatal";
+    Terakatal: "Tkat", "terakatal";
+    Gigakatal: "Gkat", "gigakatal";
+    Megakatal: "Mkat", "megakatal";
+    Kilokatal: "kkat", "kilokatal";
+    Hectokatal: "hkat", "hectokatal";
+    Decakatal: "dakat", "decakatal";
+    Decikatal: "dkat", "decikatal";
+    Centikatal: "ckat", "centikatal";
+    Millikatal: "mkat", "millikatal";
+    Microkatal: "µkat", "microkatal";
+    Nanokatal: "nkat", "nanokatal";
+    Picokatal: "pkat", "picokatal";
+    Femtokatal: "fkat", "femtokatal";
+    Attokatal: "akat", "attokatal";
+    Zeptokatal: "zkat", "zeptokatal";
+    Yoctokatal: "ykat", "yoctokatal";
+}
+
+// Enzyme units
+units! {
+    EnzymeUnit: "U", "enzyme unit";
+    KiloEnzymeUnit: "kU", "kilo enzyme unit";
+    MilliEnzymeUnit: "mU", "milli enzyme unit";
+    MicroEnzymeUnit: "μU", "micro enzyme unit";
+    NanoEnzymeUnit: "nU", "nano enzyme unit";
+    PicoEnzymeUnit: "pU", "pico enzyme unit";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed katals
+    Yottakatal => Katal: YOTTA;
+    Zettakatal => Katal: ZETTA;
+    Exakatal => Katal: EXA;
+    Petakatal => Katal: PETA;
+    Terakatal => Katal: TERA;
+    Gigakatal => Katal: GIGA;
+    Megakatal => Katal: MEGA;
+    Kilokatal => Katal: KILO;
+    Hectokatal => Katal: HECTO;
+    Decakatal => Katal: DECA;
+    Decikatal => Katal: DECI;
+    Centikatal => Katal: CENTI;
+    Millikatal => Katal: MILLI;
+    Microkatal => Katal: MICRO;
+    Nanokatal => Katal: NANO;
+    Picokatal => Katal: PICO;
+    Femtokatal => Katal: FEMTO;
+    Attokatal => Katal: ATTO;
+    Zeptokatal => Katal: ZEPTO;
+    Yoctokatal => Katal: YOCTO;
+
+    // Enzyme units (1 U = 1 μmol/min)
+    EnzymeUnit => Katal: MICRO / MINUTE;
+    KiloEnzymeUnit => Katal: KILO * MICRO / MINUTE;
+    MilliEnzymeUnit => Katal: MILLI * MICRO / MINUTE;
+    MicroEnzymeUnit => Katal: MICRO * MICRO / MINUTE;
+    NanoEnzymeUnit => Katal: NANO * MICRO / MINUTE;
+    PicoEnzymeUnit => Katal: PICO * MICRO / MINUTE;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Katal => Yottakatal, Zettakatal, Exakatal, Petakatal, Terakatal, Gigakatal, Megakatal,
+             Kilokatal, Millikatal, Microkatal, Nanokatal, Picokatal, Femtokatal, Attokatal,
+             Zeptokatal, Yoctokatal
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    Katal => Yottakatal, Zettakatal, Exakatal, Petakatal, Terakatal, Gigakatal, Megakatal,
+             Kilokatal, Hectokatal, Decakatal, Decikatal, Centikatal, Millikatal, Microkatal,
+             Nanokatal, Picokatal, Femtokatal, Attokatal, Zeptokatal, Yoctokatal, EnzymeUnit,
+             KiloEnzymeUnit, MilliEnzymeUnit, MicroEnzymeUnit, NanoEnzymeUnit, PicoEnzymeUnit
+}
+
+// Catalytic activity quantity definition (Amount/Time)
+use super::{ISQ, SiScale};
+quantity!(CatalyticActivity, ISQ<Z0, Z0, N1, Z0, Z0, P1, Z0>, SiScale, Katal);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_catalytic_activity {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::catalytic_activity,
+                uom::si::catalytic_activity,
+                CatalyticActivity,
+                CatalyticActivity,
+                Katal,
+                $num_units_unit,
+                katal,
+                $uom_unit
+            );
+        };
+    }
+
+    // Test SI prefixed katals
+    test_uom_catalytic_activity!(Yottakatal, yottakatal);
+    test_uom_catalytic_activity!(Zettakatal, zettakatal);
+    test_uom_catalytic_activity!(Exakatal, exakatal);
+    test_uom_catalytic_activity!(Petakatal, petakatal);
+    test_uom_catalytic_activity!(Terakatal, terakatal);
+    test_uom_catalytic_activity!(Gigakatal, gigakatal);
+    test_uom_catalytic_activity!(Megakatal, megakatal);
+    test_uom_catalytic_activity!(Kilokatal, kilokatal);
+    test_uom_catalytic_activity!(Hectokatal, hectokatal);
+    test_uom_catalytic_activity!(Decakatal, decakatal);
+    test_uom_catalytic_activity!(Katal, katal);
+    test_uom_catalytic_activity!(Decikatal, decikatal);
+    test_uom_catalytic_activity!(Centikatal, centikatal);
+    test_uom_catalytic_activity!(Millikatal, millikatal);
+    test_uom_catalytic_activity!(Microkatal, microkatal);
+    test_uom_catalytic_activity!(Nanokatal, nanokatal);
+    test_uom_catalytic_activity!(Picokatal, picokatal);
+    test_uom_catalytic_activity!(Femtokatal, femtokatal);
+    test_uom_catalytic_activity!(Attokatal, attokatal);
+    test_uom_catalytic_activity!(Zeptokatal, zeptokatal);
+    test_uom_catalytic_activity!(Yoctokatal, yoctokatal);
+
+    // Test enzyme units
+    test_uom_catalytic_activity!(EnzymeUnit, enzyme_unit);
+    test_uom_catalytic_activity!(KiloEnzymeUnit, kilo_enzyme_unit);
+    test_uom_catalytic_activity!(MilliEnzymeUnit, milli_enzyme_unit);
+    test_uom_catalytic_activity!(MicroEnzymeUnit, micro_enzyme_unit);
+    test_uom_catalytic_activity!(NanoEnzymeUnit, nano_enzyme_unit);
+    test_uom_catalytic_activity!(PicoEnzymeUnit, pico_enzyme_unit);
+}
+
+// Re-export types for convenience
+pub use catalytic_activity::CatalyticActivity;
+pub use catalytic_activity::*;
@@ -0,0 +1,92 @@
+/// # `chrono` Interop
+///
+/// Conversions between the `Time` quantity and `chrono::TimeDelta`, and
+/// arithmetic of `Time` on `chrono::DateTime`. Enabled by the `chrono` feature.
+///
+/// ## Example Usage
+///
+/// ```rust,ignore
+/// use chrono::{TimeDelta, TimeZone, Utc};
+/// use num_units::si::time::{Minute, Time};
+///
+/// let elapsed: Time = TimeDelta::milliseconds(1500).into();
+/// let start = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+/// let end = start + Time::from::<Minute>(1.5);
+/// ```
+use super::time::{Time, split_seconds};
+use ::chrono::{DateTime, TimeDelta, TimeZone};
+use core::ops::{Add, Sub};
+
+impl From<TimeDelta> for Time<f64> {
+    fn from(delta: TimeDelta) -> Self {
+        // `subsec_nanos` carries the same sign as `num_seconds`
+        Time::from_base(delta.num_seconds() as f64 + f64::from(delta.subsec_nanos()) * 1e-9)
+    }
+}
+
+impl Time<f64> {
+    /// Convert to a `chrono::TimeDelta`, rounded to the nearest nanosecond
+    ///
+    /// Returns `None` for non-finite times and times outside the `TimeDelta`
+    /// range.
+    pub fn try_into_time_delta(self) -> Option<TimeDelta> {
+        let (seconds, nanos) = split_seconds(self.value)?;
+        TimeDelta::new(seconds, nanos)
+    }
+}
+
+impl<Tz: TimeZone> Add<Time<f64>> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    /// # Panics
+    /// If the time does not fit a `TimeDelta` or the result overflows, like
+    /// `DateTime + TimeDelta`.
+    fn add(self, time: Time<f64>) -> Self::Output {
+        self + time
+            .try_into_time_delta()
+            .expect("time out of range for chrono::TimeDelta")
+    }
+}
+
+impl<Tz: TimeZone> Sub<Time<f64>> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    /// # Panics
+    /// If the time does not fit a `TimeDelta` or the result overflows, like
+    /// `DateTime - TimeDelta`.
+    fn sub(self, time: Time<f64>) -> Self::Output {
+        self - time
+            .try_into_time_delta()
+            .expect("time out of range for chrono::TimeDelta")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::time::{Millisecond, Minute, Time};
+    use ::chrono::{TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn test_time_delta_round_trip() {
+        let time: Time<f64> = TimeDelta::milliseconds(-1500).into();
+        assert_eq!(time.to::<Millisecond>(), -1500.0);
+        assert_eq!(
+            time.try_into_time_delta(),
+            Some(TimeDelta::milliseconds(-1500))
+        );
+        assert_eq!(
+            Time::from_base(0.25).try_into_time_delta(),
+            Some(TimeDelta::milliseconds(250))
+        );
+        assert_eq!(Time::from_base(f64::INFINITY).try_into_time_delta(), None);
+        assert_eq!(Time::from_base(1e300).try_into_time_delta(), None);
+    }
+
+    #[test]
+    fn test_add_time_to_date_time() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        let end = start + Time::from::<Minute>(1.5);
+        assert_eq!(end, Utc.with_ymd_and_hms(2024, 1, 1, 12, 1, 30).unwrap());
+        assert_eq!(end - Time::from::<Minute>(1.5), start);
+    }
+}
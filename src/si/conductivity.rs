@@ -0,0 +1,174 @@
+/// # Conductivity Units - SI Electrical Conductivity Measurements
+///
+/// This module defines SI electrical conductivity units and their conversions. Conductivity
+/// is the reciprocal of resistivity and measures how easily a material carries an electric
+/// current, with siemens per meter as the SI base unit. Water quality probes report it in
+/// microsiemens or millisiemens per centimeter.
+///
+/// ## Base Unit
+///
+/// - **SiemensPerMeter (S/m)**: The SI base unit of electrical conductivity
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for siemens per meter:
+/// - **YottasiemensPerMeter (YS/m)**: 10²⁴ siemens per meter
+/// - ... down to ...
+/// - **YoctosiemensPerMeter (yS/m)**: 10⁻²⁴ siemens per meter
+///
+/// ## Other Units
+///
+/// - **SiemensPerCentimeter (S/cm)**: 100 siemens per meter
+/// - **MillisiemensPerCentimeter (mS/cm)**: 0.1 siemens per meter
+/// - **MicrosiemensPerCentimeter (µS/cm)**: 10⁻⁴ siemens per meter
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::conductivity::{ElectricalConductivity, MicrosiemensPerCentimeter};
+/// use num_units::si::resistivity::OhmMeter;
+///
+/// // Tap water
+/// let water = ElectricalConductivity::from::<MicrosiemensPerCentimeter>(500.0);
+///
+/// // Resistivity is the reciprocal
+/// let resistivity = 1.0 / water; // ElectricalResistivity, 20 Ω·m
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    SiemensPerMeter: "S/m", "siemens per meter", "siemens per meter";
+}
+
+// SI prefixed siemens per meter
+units! {
+    YottasiemensPerMeter: "YS/m", "yottasiemens per meter", "yottasiemens per meter";
+    ZettasiemensPerMeter: "ZS/m", "zettasiemens per meter", "zettasiemens per meter";
+    ExasiemensPerMeter: "ES/m", "exasiemens per meter", "exasiemens per meter";
+    PetasiemensPerMeter: "PS/m", "petasiemens per meter", "petasiemens per meter";
+    TerasiemensPerMeter: "TS/m", "terasiemens per meter", "terasiemens per meter";
+    GigasiemensPerMeter: "GS/m", "gigasiemens per meter", "gigasiemens per meter";
+    MegasiemensPerMeter: "MS/m", "megasiemens per meter", "megasiemens per meter";
+    KilosiemensPerMeter: "kS/m", "kilosiemens per meter", "kilosiemens per meter";
+    HectosiemensPerMeter: "hS/m", "hectosiemens per meter", "hectosiemens per meter";
+    DecasiemensPerMeter: "daS/m", "decasiemens per meter", "decasiemens per meter";
+    DecisiemensPerMeter: "dS/m", "decisiemens per meter", "decisiemens per meter";
+    CentisiemensPerMeter: "cS/m", "centisiemens per meter", "centisiemens per meter";
+    MillisiemensPerMeter: "mS/m", "millisiemens per meter", "millisiemens per meter";
+    MicrosiemensPerMeter: "µS/m", "microsiemens per meter", "microsiemens per meter";
+    NanosiemensPerMeter: "nS/m", "nanosiemens per meter", "nanosiemens per meter";
+    PicosiemensPerMeter: "pS/m", "picosiemens per meter", "picosiemens per meter";
+    FemtosiemensPerMeter: "fS/m", "femtosiemens per meter", "femtosiemens per meter";
+    AttosiemensPerMeter: "aS/m", "attosiemens per meter", "attosiemens per meter";
+    ZeptosiemensPerMeter: "zS/m", "zeptosiemens per meter", "zeptosiemens per meter";
+    YoctosiemensPerMeter: "yS/m", "yoctosiemens per meter", "yoctosiemens per meter";
+}
+
+// Other units
+units! {
+    SiemensPerCentimeter: "S/cm", "siemens per centimeter", "siemens per centimeter";
+    MillisiemensPerCentimeter: "mS/cm", "millisiemens per centimeter", "millisiemens per centimeter";
+    MicrosiemensPerCentimeter: "µS/cm", "microsiemens per centimeter", "microsiemens per centimeter";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed siemens per meter
+    YottasiemensPerMeter => SiemensPerMeter: YOTTA;
+    ZettasiemensPerMeter => SiemensPerMeter: ZETTA;
+    ExasiemensPerMeter => SiemensPerMeter: EXA;
+    PetasiemensPerMeter => SiemensPerMeter: PETA;
+    TerasiemensPerMeter => SiemensPerMeter: TERA;
+    GigasiemensPerMeter => SiemensPerMeter: GIGA;
+    MegasiemensPerMeter => SiemensPerMeter: MEGA;
+    KilosiemensPerMeter => SiemensPerMeter: KILO;
+    HectosiemensPerMeter => SiemensPerMeter: HECTO;
+    DecasiemensPerMeter => SiemensPerMeter: DECA;
+    DecisiemensPerMeter => SiemensPerMeter: DECI;
+    CentisiemensPerMeter => SiemensPerMeter: CENTI;
+    MillisiemensPerMeter => SiemensPerMeter: MILLI;
+    MicrosiemensPerMeter => SiemensPerMeter: MICRO;
+    NanosiemensPerMeter => SiemensPerMeter: NANO;
+    PicosiemensPerMeter => SiemensPerMeter: PICO;
+    FemtosiemensPerMeter => SiemensPerMeter: FEMTO;
+    AttosiemensPerMeter => SiemensPerMeter: ATTO;
+    ZeptosiemensPerMeter => SiemensPerMeter: ZEPTO;
+    YoctosiemensPerMeter => SiemensPerMeter: YOCTO;
+
+    // Other units
+    SiemensPerCentimeter => SiemensPerMeter: 1.0 / CENTI;
+    MillisiemensPerCentimeter => SiemensPerMeter: MILLI / CENTI;
+    MicrosiemensPerCentimeter => SiemensPerMeter: MICRO / CENTI;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    SiemensPerMeter => YottasiemensPerMeter, ZettasiemensPerMeter, ExasiemensPerMeter,
+                       PetasiemensPerMeter, TerasiemensPerMeter, GigasiemensPerMeter,
+                       MegasiemensPerMeter, KilosiemensPerMeter, MillisiemensPerMeter,
+                       MicrosiemensPerMeter, NanosiemensPerMeter, PicosiemensPerMeter,
+                       FemtosiemensPerMeter, AttosiemensPerMeter, ZeptosiemensPerMeter,
+                       YoctosiemensPerMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    SiemensPerMeter => YottasiemensPerMeter, ZettasiemensPerMeter, ExasiemensPerMeter,
+                       PetasiemensPerMeter, TerasiemensPerMeter, GigasiemensPerMeter,
+                       MegasiemensPerMeter, KilosiemensPerMeter, HectosiemensPerMeter,
+                       DecasiemensPerMeter, DecisiemensPerMeter, CentisiemensPerMeter,
+                       MillisiemensPerMeter, MicrosiemensPerMeter, NanosiemensPerMeter,
+                       PicosiemensPerMeter, FemtosiemensPerMeter, AttosiemensPerMeter,
+                       ZeptosiemensPerMeter, YoctosiemensPerMeter, SiemensPerCentimeter,
+                       MillisiemensPerCentimeter, MicrosiemensPerCentimeter
+}
+
+// Electrical conductivity quantity definition (1/Resistivity)
+use super::{ISQ, SiScale};
+quantity!(ElectricalConductivity, ISQ<N3, N1, P3, P2, Z0, Z0, Z0>, SiScale, SiemensPerMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_conductivity {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::conductivity,
+                uom::si::electrical_conductivity,
+                ElectricalConductivity,
+                ElectricalConductivity,
+                SiemensPerMeter,
+                $num_units_unit,
+                siemens_per_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_conductivity!(SiemensPerMeter, siemens_per_meter);
+    test_uom_conductivity!(SiemensPerCentimeter, siemens_per_centimeter);
+
+    #[test]
+    fn test_reciprocal_of_resistivity() {
+        use crate::si::conductivity::*;
+        use crate::si::resistivity::{ElectricalResistivity, OhmMeter};
+        use crate::si::scalar::{Scalar, Unitless};
+
+        let water = ElectricalConductivity::from::<MicrosiemensPerCentimeter>(500.0);
+        assert!((water.to::<SiemensPerMeter>() - 0.05_f64).abs() < 1e-15);
+        assert!((water.to::<MillisiemensPerCentimeter>() - 0.5_f64).abs() < 1e-15);
+
+        let resistivity: ElectricalResistivity<f64> = Scalar::from::<Unitless>(1.0) / water;
+        assert!((resistivity.to::<OhmMeter>() - 20.0_f64).abs() < 1e-12);
+    }
+}
+
+// Re-export types for convenience
+pub use electrical_conductivity::ElectricalConductivity;
+pub use electrical_conductivity::*;
@@ -16,6 +16,13 @@
 /// - **Abampere (abA)**: 10 amperes (CGS unit)
 /// - **Statampere (statA)**: 3.335641×10⁻¹⁰ amperes (CGS-ESU unit)
 ///
+/// ## Natural and Atomic Units
+///
+/// UOM only provides the atomic unit of charge per second; the remaining units use CODATA 2018:
+/// - **AtomicUnitOfCurrent (e·Eₕ/ħ)**: 6.623618237510×10⁻³ amperes
+/// - **NaturalUnitOfCurrent (e·mₑc²/ħ)**: 124.384 amperes
+/// - **PlanckCurrent (I_P)**: 3.478873×10²⁵ amperes
+///
 /// ## Usage
 ///
 /// ```rust,ignore
@@ -78,8 +85,15 @@ units! {
     Abampere: "abA", "abampere";
     Gilbert: "Gi", "gilbert";
     Statampere: "statA", "statampere";
-    ElementaryChargePerSecond: "e/s", "elementary charge per second";
-    AtomicUnitOfChargePerSecond: "a.u. of charge/s", "atomic unit of charge per second";
+    ElementaryChargePerSecond: "e/s", "elementary charge per second", "elementary charges per second";
+    AtomicUnitOfChargePerSecond: "a.u. of charge/s", "atomic unit of charge per second", "atomic units of charge per second";
+}
+
+// Natural and atomic units
+units! {
+    AtomicUnitOfCurrent: "a.u. of current", "atomic unit of current", "atomic units of current";
+    NaturalUnitOfCurrent: "n.u. of current", "natural unit of current", "natural units of current";
+    PlanckCurrent: "I_P", "Planck current";
 }
 
 // Unit conversions using convert_linear! with exact UOM coefficients
@@ -112,10 +126,27 @@ crate::convert_linear! {
     Statampere => Ampere: 3.335_641_E-10;
     ElementaryChargePerSecond => Ampere: 1.602_176_634_E-19;
     AtomicUnitOfChargePerSecond => Ampere: 1.602_176_634_E-19;
+
+    // Natural and atomic units - elementary charge per unit of time, CODATA 2018
+    AtomicUnitOfCurrent => Ampere: 6.623_618_237_510_E-3;
+    NaturalUnitOfCurrent => Ampere: 1.243_840_329_914_E2;
+    PlanckCurrent => Ampere: 3.478_873_E25;
 }
 
-crate::convert_matrix! {
-    Ampere => Yottaampere, Zettaampere, Exaampere, Petaampere, Teraampere, Gigaampere, Megaampere, Kiloampere, Hectoampere, Decaampere, Deciampere, Centiampere, Milliampere, Microampere, Nanoampere, Picoampere, Femtoampere, Attoampere, Zeptoampere, Yoctoampere, Abampere, Gilbert, Statampere, ElementaryChargePerSecond, AtomicUnitOfChargePerSecond
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Ampere => Yottaampere, Zettaampere, Exaampere, Petaampere, Teraampere, Gigaampere, Megaampere,
+              Kiloampere, Milliampere, Microampere, Nanoampere, Picoampere, Femtoampere, Attoampere,
+              Zeptoampere, Yoctoampere
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    Ampere => Yottaampere, Zettaampere, Exaampere, Petaampere, Teraampere, Gigaampere, Megaampere,
+              Kiloampere, Hectoampere, Decaampere, Deciampere, Centiampere, Milliampere,
+              Microampere, Nanoampere, Picoampere, Femtoampere, Attoampere, Zeptoampere,
+              Yoctoampere, Abampere, Gilbert, Statampere, ElementaryChargePerSecond,
+              AtomicUnitOfChargePerSecond, AtomicUnitOfCurrent, NaturalUnitOfCurrent, PlanckCurrent
 }
 
 // Current quantity definition
@@ -172,6 +203,25 @@ mod tests {
         AtomicUnitOfChargePerSecond,
         atomic_unit_of_charge_per_second
     );
+
+    #[test]
+    fn test_natural_and_atomic_units() {
+        use crate::si::current::*;
+        use crate::si::time::{AtomicUnitOfTime, NaturalUnitOfTime, Time};
+
+        // One elementary charge per atomic (natural) unit of time
+        let atomic = Current::from::<AtomicUnitOfCurrent>(1.0);
+        let per_atomic_time = 1.602_176_634e-19_f64 / Time::from::<AtomicUnitOfTime>(1.0).value;
+        assert!((atomic.to::<Ampere>() - per_atomic_time).abs() < 1e-15);
+        assert!((atomic.to::<Milliampere>() - 6.623_618_f64).abs() < 1e-6);
+
+        let natural = Current::from::<NaturalUnitOfCurrent>(1.0);
+        let per_natural_time = 1.602_176_634e-19_f64 / Time::from::<NaturalUnitOfTime>(1.0).value;
+        assert!((natural.to::<Ampere>() - per_natural_time).abs() < 1e-9);
+
+        let planck = Current::from::<PlanckCurrent>(1.0);
+        assert!((planck.to::<Yottaampere>() - 34.788_73_f64).abs() < 1e-9);
+    }
 }
 
 // Re-export types for convenience
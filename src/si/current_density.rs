@@ -0,0 +1,192 @@
+/// # Current Density Units - SI Current Density Measurements
+///
+/// This module defines SI current density units and their conversions. Current density is the
+/// electric current flowing through a unit of cross-sectional area, with ampere per square
+/// meter as the SI base unit. PCB trace sizing, busbar design and electroplating all work
+/// in terms of current density.
+///
+/// ## Base Unit
+///
+/// - **AmperePerSquareMeter (A/m²)**: The SI base unit of current density
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for amperes per square meter:
+/// - **YottaamperePerSquareMeter (YA/m²)**: 10²⁴ amperes per square meter
+/// - ... down to ...
+/// - **YoctoamperePerSquareMeter (yA/m²)**: 10⁻²⁴ amperes per square meter
+///
+/// ## Other Units
+///
+/// - **AmperePerSquareCentimeter (A/cm²)**: 10⁴ amperes per square meter
+/// - **MilliamperePerSquareCentimeter (mA/cm²)**: 10 amperes per square meter, common in
+///   electrochemistry
+/// - **AmperePerSquareMillimeter (A/mm²)**: 10⁶ amperes per square meter, used for conductor
+///   sizing
+/// - **AmperePerSquareFoot (A/ft²)**: ≈ 10.764 amperes per square meter, used in plating
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::current_density::{AmperePerSquareMillimeter, CurrentDensity, AmperePerSquareMeter};
+///
+/// // Create current density quantities
+/// let trace = CurrentDensity::from::<AmperePerSquareMillimeter>(35.0);
+///
+/// // Convert between units
+/// let trace_si = trace.to::<AmperePerSquareMeter>(); // 3.5e7 A/m²
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    AmperePerSquareMeter: "A/m²", "ampere per square meter", "amperes per square meter";
+}
+
+// SI prefixed amperes per square meter
+units! {
+    YottaamperePerSquareMeter: "YA/m²", "yottaampere per square meter", "yottaamperes per square meter";
+    ZettaamperePerSquareMeter: "ZA/m²", "zettaampere per square meter", "zettaamperes per square meter";
+    ExaamperePerSquareMeter: "EA/m²", "exaampere per square meter", "exaamperes per square meter";
+    PetaamperePerSquareMeter: "PA/m²", "petaampere per square meter", "petaamperes per square meter";
+    TeraamperePerSquareMeter: "TA/m²", "teraampere per square meter", "teraamperes per square meter";
+    GigaamperePerSquareMeter: "GA/m²", "gigaampere per square meter", "gigaamperes per square meter";
+    MegaamperePerSquareMeter: "MA/m²", "megaampere per square meter", "megaamperes per square meter";
+    KiloamperePerSquareMeter: "kA/m²", "kiloampere per square meter", "kiloamperes per square meter";
+    HectoamperePerSquareMeter: "hA/m²", "hectoampere per square meter", "hectoamperes per square meter";
+    DecaamperePerSquareMeter: "daA/m²", "decaampere per square meter", "decaamperes per square meter";
+    DeciamperePerSquareMeter: "dA/m²", "deciampere per square meter", "deciamperes per square meter";
+    CentiamperePerSquareMeter: "cA/m²", "centiampere per square meter", "centiamperes per square meter";
+    MilliamperePerSquareMeter: "mA/m²", "milliampere per square meter", "milliamperes per square meter";
+    MicroamperePerSquareMeter: "µA/m²", "microampere per square meter", "microamperes per square meter";
+    NanoamperePerSquareMeter: "nA/m²", "nanoampere per square meter", "nanoamperes per square meter";
+    PicoamperePerSquareMeter: "pA/m²", "picoampere per square meter", "picoamperes per square meter";
+    FemtoamperePerSquareMeter: "fA/m²", "femtoampere per square meter", "femtoamperes per square meter";
+    AttoamperePerSquareMeter: "aA/m²", "attoampere per square meter", "attoamperes per square meter";
+    ZeptoamperePerSquareMeter: "zA/m²", "zeptoampere per square meter", "zeptoamperes per square meter";
+    YoctoamperePerSquareMeter: "yA/m²", "yoctoampere per square meter", "yoctoamperes per square meter";
+}
+
+// Other units
+units! {
+    AmperePerSquareCentimeter: "A/cm²", "ampere per square centimeter", "amperes per square centimeter";
+    MilliamperePerSquareCentimeter: "mA/cm²", "milliampere per square centimeter", "milliamperes per square centimeter";
+    AmperePerSquareMillimeter: "A/mm²", "ampere per square millimeter", "amperes per square millimeter";
+    AmperePerSquareFoot: "A/ft²", "ampere per square foot", "amperes per square foot";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed amperes per square meter
+    YottaamperePerSquareMeter => AmperePerSquareMeter: YOTTA;
+    ZettaamperePerSquareMeter => AmperePerSquareMeter: ZETTA;
+    ExaamperePerSquareMeter => AmperePerSquareMeter: EXA;
+    PetaamperePerSquareMeter => AmperePerSquareMeter: PETA;
+    TeraamperePerSquareMeter => AmperePerSquareMeter: TERA;
+    GigaamperePerSquareMeter => AmperePerSquareMeter: GIGA;
+    MegaamperePerSquareMeter => AmperePerSquareMeter: MEGA;
+    KiloamperePerSquareMeter => AmperePerSquareMeter: KILO;
+    HectoamperePerSquareMeter => AmperePerSquareMeter: HECTO;
+    DecaamperePerSquareMeter => AmperePerSquareMeter: DECA;
+    DeciamperePerSquareMeter => AmperePerSquareMeter: DECI;
+    CentiamperePerSquareMeter => AmperePerSquareMeter: CENTI;
+    MilliamperePerSquareMeter => AmperePerSquareMeter: MILLI;
+    MicroamperePerSquareMeter => AmperePerSquareMeter: MICRO;
+    NanoamperePerSquareMeter => AmperePerSquareMeter: NANO;
+    PicoamperePerSquareMeter => AmperePerSquareMeter: PICO;
+    FemtoamperePerSquareMeter => AmperePerSquareMeter: FEMTO;
+    AttoamperePerSquareMeter => AmperePerSquareMeter: ATTO;
+    ZeptoamperePerSquareMeter => AmperePerSquareMeter: ZEPTO;
+    YoctoamperePerSquareMeter => AmperePerSquareMeter: YOCTO;
+
+    // Other units
+    AmperePerSquareCentimeter => AmperePerSquareMeter: 1.0 / CENTI / CENTI;
+    MilliamperePerSquareCentimeter => AmperePerSquareMeter: MILLI / CENTI / CENTI;
+    AmperePerSquareMillimeter => AmperePerSquareMeter: 1.0 / MILLI / MILLI;
+    // ft² = 9.290304 × 10⁻² m²
+    AmperePerSquareFoot => AmperePerSquareMeter: 1.0 / 9.290_304_E-2;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    AmperePerSquareMeter => YottaamperePerSquareMeter, ZettaamperePerSquareMeter,
+                            ExaamperePerSquareMeter, PetaamperePerSquareMeter,
+                            TeraamperePerSquareMeter, GigaamperePerSquareMeter,
+                            MegaamperePerSquareMeter, KiloamperePerSquareMeter,
+                            MilliamperePerSquareMeter, MicroamperePerSquareMeter,
+                            NanoamperePerSquareMeter, PicoamperePerSquareMeter,
+                            FemtoamperePerSquareMeter, AttoamperePerSquareMeter,
+                            ZeptoamperePerSquareMeter, YoctoamperePerSquareMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    AmperePerSquareMeter => YottaamperePerSquareMeter, ZettaamperePerSquareMeter,
+                            ExaamperePerSquareMeter, PetaamperePerSquareMeter,
+                            TeraamperePerSquareMeter, GigaamperePerSquareMeter,
+                            MegaamperePerSquareMeter, KiloamperePerSquareMeter,
+                            HectoamperePerSquareMeter, DecaamperePerSquareMeter,
+                            DeciamperePerSquareMeter, CentiamperePerSquareMeter,
+                            MilliamperePerSquareMeter, MicroamperePerSquareMeter,
+                            NanoamperePerSquareMeter, PicoamperePerSquareMeter,
+                            FemtoamperePerSquareMeter, AttoamperePerSquareMeter,
+                            ZeptoamperePerSquareMeter, YoctoamperePerSquareMeter,
+                            AmperePerSquareCentimeter, MilliamperePerSquareCentimeter,
+                            AmperePerSquareMillimeter, AmperePerSquareFoot
+}
+
+// Current density quantity definition (Current/Length²)
+use super::{ISQ, SiScale};
+quantity!(CurrentDensity, ISQ<N2, Z0, Z0, P1, Z0, Z0, Z0>, SiScale, AmperePerSquareMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_current_density {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::current_density,
+                uom::si::electric_current_density,
+                CurrentDensity,
+                ElectricCurrentDensity,
+                AmperePerSquareMeter,
+                $num_units_unit,
+                ampere_per_square_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_current_density!(AmperePerSquareMeter, ampere_per_square_meter);
+    test_uom_current_density!(AmperePerSquareCentimeter, ampere_per_square_centimeter);
+    test_uom_current_density!(AmperePerSquareMillimeter, ampere_per_square_millimeter);
+
+    #[test]
+    fn test_current_over_area() {
+        use crate::si::area::{Area, SquareMillimeter};
+        use crate::si::current::{Ampere, Current};
+        use crate::si::current_density::*;
+
+        let density: CurrentDensity<f64> =
+            Current::from::<Ampere>(10.0) / Area::from::<SquareMillimeter>(2.0);
+        assert!((density.to::<AmperePerSquareMillimeter>() - 5.0_f64).abs() < 1e-9);
+        assert!((density.to::<MilliamperePerSquareCentimeter>() - 5.0e5_f64).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_ampere_per_square_foot() {
+        use crate::si::current_density::*;
+
+        let plating = CurrentDensity::from::<AmperePerSquareFoot>(1.0);
+        assert!((plating.to::<AmperePerSquareMeter>() - 10.763_910_416_709_722_f64).abs() < 1e-12);
+    }
+}
+
+// Re-export types for convenience
+pub use current_density::CurrentDensity;
+pub use current_density::*;
@@ -0,0 +1,276 @@
+/// # Dynamic Quantities - Runtime-Checked SI Dimensions
+///
+/// A `DynQuantity` carries its SI dimension as a runtime exponent vector instead
+/// of a type parameter. It is what the unit-expression parser in `si::parse`
+/// produces when the dimension of the input is not known at compile time, and it
+/// converts into a typed `Quantity` once the expected dimension is known.
+///
+/// ## Example Usage
+///
+/// ```rust,ignore
+/// use num_units::si::dyn_quantity::DynQuantity;
+/// use num_units::si::force::Force;
+///
+/// let parsed: DynQuantity = "3.0 kg*m/s^2".parse().unwrap();
+/// assert_eq!(parsed.dimension(), [1, 1, -2, 0, 0, 0, 0]);
+/// let force = Force::try_from(parsed).unwrap();
+/// ```
+use super::SiScale;
+use super::parse::{ParseError, parse_unit};
+use crate::quantity::Quantity;
+use crate::system::DimensionVector;
+use core::fmt;
+use core::ops::{Div, Mul, Neg};
+
+/// Number of SI base dimensions (L, M, T, I, Θ, N, J)
+pub const SI_DIMENSIONS: usize = 7;
+
+/// A value in SI base units with a dimension known only at runtime
+///
+/// Arithmetic checks dimensions at runtime: `checked_add`/`checked_sub`
+/// require equal dimensions, while `*` and `/` combine them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynQuantity {
+    value: f64,
+    dimension: [i8; SI_DIMENSIONS],
+}
+
+impl DynQuantity {
+    /// Create a dynamic quantity from a base-unit value and its exponents in
+    /// `[L, M, T, I, Θ, N, J]` order
+    pub const fn new(value: f64, dimension: [i8; SI_DIMENSIONS]) -> Self {
+        Self { value, dimension }
+    }
+
+    /// The value expressed in SI base units
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The exponents of the SI base dimensions, in `[L, M, T, I, Θ, N, J]` order
+    pub const fn dimension(&self) -> [i8; SI_DIMENSIONS] {
+        self.dimension
+    }
+
+    /// Whether this quantity has the dimension `D`
+    pub fn has_dimension<D: DimensionVector>(&self) -> bool {
+        D::EXPONENTS == self.dimension
+    }
+
+    /// Whether both quantities have the same dimension
+    pub fn same_dimension(&self, other: &Self) -> bool {
+        self.dimension == other.dimension
+    }
+
+    /// Sum of two quantities of the same dimension
+    pub fn checked_add(self, other: Self) -> Result<Self, DimensionMismatch> {
+        self.expect_dimension(&other)?;
+        Ok(Self::new(self.value + other.value, self.dimension))
+    }
+
+    /// Difference of two quantities of the same dimension
+    pub fn checked_sub(self, other: Self) -> Result<Self, DimensionMismatch> {
+        self.expect_dimension(&other)?;
+        Ok(Self::new(self.value - other.value, self.dimension))
+    }
+
+    /// Product, whose dimension exponents are the sums of the factors';
+    /// `None` if an exponent overflows
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let dimension = combine(self.dimension, other.dimension, i8::checked_add)?;
+        Some(Self::new(self.value * other.value, dimension))
+    }
+
+    /// Quotient, whose dimension exponents are the differences of the
+    /// operands'; `None` if an exponent overflows
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        let dimension = combine(self.dimension, other.dimension, i8::checked_sub)?;
+        Some(Self::new(self.value / other.value, dimension))
+    }
+
+    /// The value in the unit given by a unit expression, e.g. `"km/h"`
+    ///
+    /// Fails if the expression does not parse or has a different dimension.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::dyn_quantity::DynQuantity;
+    ///
+    /// let speed: DynQuantity = "20 m/s".parse().unwrap();
+    /// assert_eq!(speed.convert_to("km/h").unwrap(), 72.0);
+    /// ```
+    pub fn convert_to(&self, unit: &str) -> Result<f64, ParseError> {
+        let unit = parse_unit(unit)?;
+        unit.expect_dimension(self)?;
+        Ok(self.value / unit.value)
+    }
+
+    fn expect_dimension(&self, found: &Self) -> Result<(), DimensionMismatch> {
+        if self.same_dimension(found) {
+            Ok(())
+        } else {
+            Err(DimensionMismatch {
+                expected: self.dimension,
+                found: found.dimension,
+            })
+        }
+    }
+}
+
+fn combine(
+    lhs: [i8; SI_DIMENSIONS],
+    rhs: [i8; SI_DIMENSIONS],
+    op: fn(i8, i8) -> Option<i8>,
+) -> Option<[i8; SI_DIMENSIONS]> {
+    let mut dimension = [0; SI_DIMENSIONS];
+    for ((out, a), b) in dimension.iter_mut().zip(lhs).zip(rhs) {
+        *out = op(a, b)?;
+    }
+    Some(dimension)
+}
+
+// Multiplying and dividing always make sense dimensionally; only an exponent
+// beyond the `i8` range fails, which the operators treat like integer overflow.
+impl Mul for DynQuantity {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(other)
+            .expect("dimension exponent overflow in DynQuantity multiplication")
+    }
+}
+
+impl Div for DynQuantity {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.checked_div(other)
+            .expect("dimension exponent overflow in DynQuantity division")
+    }
+}
+
+impl Mul<f64> for DynQuantity {
+    type Output = Self;
+
+    fn mul(self, factor: f64) -> Self {
+        Self::new(self.value * factor, self.dimension)
+    }
+}
+
+impl Div<f64> for DynQuantity {
+    type Output = Self;
+
+    fn div(self, divisor: f64) -> Self {
+        Self::new(self.value / divisor, self.dimension)
+    }
+}
+
+impl Neg for DynQuantity {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value, self.dimension)
+    }
+}
+
+impl<D: DimensionVector> From<Quantity<f64, D, SiScale>> for DynQuantity {
+    fn from(quantity: Quantity<f64, D, SiScale>) -> Self {
+        let mut dimension = [0; SI_DIMENSIONS];
+        dimension.copy_from_slice(D::EXPONENTS);
+        Self::new(quantity.value, dimension)
+    }
+}
+
+impl<D: DimensionVector> TryFrom<DynQuantity> for Quantity<f64, D, SiScale> {
+    type Error = DimensionMismatch;
+
+    fn try_from(quantity: DynQuantity) -> Result<Self, Self::Error> {
+        if quantity.has_dimension::<D>() {
+            Ok(Quantity::from_base(quantity.value))
+        } else {
+            let mut expected = [0; SI_DIMENSIONS];
+            expected.copy_from_slice(D::EXPONENTS);
+            Err(DimensionMismatch {
+                expected,
+                found: quantity.dimension,
+            })
+        }
+    }
+}
+
+/// Error returned when a `DynQuantity` does not have the requested dimension
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DimensionMismatch {
+    pub expected: [i8; SI_DIMENSIONS],
+    pub found: [i8; SI_DIMENSIONS],
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "dimension mismatch: expected {:?}, found {:?}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl core::error::Error for DimensionMismatch {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::si::length::Length;
+    use crate::si::time::Time;
+    use crate::si::velocity::Velocity;
+
+    #[test]
+    fn test_round_trip_through_typed_quantity() {
+        let speed: Velocity<f64> = Length::from_base(100.0) / Time::from_base(8.0);
+        let dynamic = DynQuantity::from(speed);
+        assert_eq!(dynamic.value(), 12.5);
+        assert_eq!(dynamic.dimension(), [1, 0, -1, 0, 0, 0, 0]);
+        assert_eq!(*Velocity::try_from(dynamic).unwrap().base(), 12.5);
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let dynamic = DynQuantity::new(3.0, [1, 0, 0, 0, 0, 0, 0]);
+        let err = Time::try_from(dynamic).unwrap_err();
+        assert_eq!(err.expected, [0, 0, 1, 0, 0, 0, 0]);
+        assert_eq!(err.found, [1, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let a = DynQuantity::new(2.0, [1, 0, 0, 0, 0, 0, 0]);
+        let b = DynQuantity::new(0.5, [1, 0, 0, 0, 0, 0, 0]);
+        let t = DynQuantity::new(4.0, [0, 0, 1, 0, 0, 0, 0]);
+        assert_eq!(a.checked_add(b).unwrap().value(), 2.5);
+        assert_eq!(a.checked_sub(b).unwrap().value(), 1.5);
+        let err = a.checked_add(t).unwrap_err();
+        assert_eq!(err.expected, [1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(err.found, [0, 0, 1, 0, 0, 0, 0]);
+
+        let speed = a / t;
+        assert_eq!(speed, DynQuantity::new(0.5, [1, 0, -1, 0, 0, 0, 0]));
+        assert_eq!((a * b * 2.0).dimension(), [2, 0, 0, 0, 0, 0, 0]);
+        assert_eq!((-a / 4.0).value(), -0.5);
+
+        let extreme = DynQuantity::new(1.0, [127, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(extreme.checked_mul(a), None);
+        assert_eq!(extreme.checked_div(extreme).unwrap().dimension(), [0; 7]);
+    }
+
+    #[test]
+    fn test_convert_to_runtime_unit() {
+        let speed: DynQuantity = "20 m/s".parse().unwrap();
+        assert!((speed.convert_to("km/h").unwrap() - 72.0).abs() < 1e-12);
+        assert!((speed.convert_to("mm / s").unwrap() - 20_000.0).abs() < 1e-9);
+        assert!(matches!(
+            speed.convert_to("kg"),
+            Err(ParseError::DimensionMismatch(_))
+        ));
+        assert_eq!(speed.convert_to("furlong"), Err(ParseError::UnknownUnit(0)));
+    }
+}
@@ -0,0 +1,187 @@
+/// # Electric Field Strength Units - SI Electric Field Measurements
+///
+/// This module defines SI electric field strength units and their conversions. Electric field
+/// strength is the force per unit charge exerted on a test charge, with volt per meter as the
+/// SI base unit.
+///
+/// ## Base Unit
+///
+/// - **VoltPerMeter (V/m)**: The SI base unit of electric field strength
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for volts per meter:
+/// - **YottavoltPerMeter (YV/m)**: 10²⁴ volts per meter
+/// - ... down to ...
+/// - **YoctovoltPerMeter (yV/m)**: 10⁻²⁴ volts per meter
+///
+/// ## Other Units
+///
+/// - **VoltPerCentimeter (V/cm)**, **VoltPerMillimeter (V/mm)**, **VoltPerMicrometer (V/μm)**
+/// - **KilovoltPerCentimeter (kV/cm)**, **KilovoltPerMillimeter (kV/mm)**: Dielectric strengths
+/// - **MegavoltPerCentimeter (MV/cm)**: 10⁸ volts per meter
+/// - **VoltPerMil (V/mil)**: Insulation breakdown ratings per thousandth of an inch
+/// - **StatvoltPerCentimeter (statV/cm)**: The Gaussian CGS unit, 29979.2458 volts per meter
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::electric_field::{ElectricFieldStrength, KilovoltPerMillimeter, VoltPerMeter};
+///
+/// // Create electric field strength quantities
+/// let breakdown = ElectricFieldStrength::from::<KilovoltPerMillimeter>(3.0);
+///
+/// // Convert between units
+/// let breakdown_si = breakdown.to::<VoltPerMeter>(); // 3.0e6 V/m
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    VoltPerMeter: "V/m", "volt per meter", "volts per meter";
+}
+
+// SI prefixed volts per meter
+units! {
+    YottavoltPerMeter: "YV/m", "yottavolt per meter", "yottavolts per meter";
+    ZettavoltPerMeter: "ZV/m", "zettavolt per meter", "zettavolts per meter";
+    ExavoltPerMeter: "EV/m", "exavolt per meter", "exavolts per meter";
+    PetavoltPerMeter: "PV/m", "petavolt per meter", "petavolts per meter";
+    TeravoltPerMeter: "TV/m", "teravolt per meter", "teravolts per meter";
+    GigavoltPerMeter: "GV/m", "gigavolt per meter", "gigavolts per meter";
+    MegavoltPerMeter: "MV/m", "megavolt per meter", "megavolts per meter";
+    KilovoltPerMeter: "kV/m", "kilovolt per meter", "kilovolts per meter";
+    HectovoltPerMeter: "hV/m", "hectovolt per meter", "hectovolts per meter";
+    DecavoltPerMeter: "daV/m", "decavolt per meter", "decavolts per meter";
+    DecivoltPerMeter: "dV/m", "decivolt per meter", "decivolts per meter";
+    CentivoltPerMeter: "cV/m", "centivolt per meter", "centivolts per meter";
+    MillivoltPerMeter: "mV/m", "millivolt per meter", "millivolts per meter";
+    MicrovoltPerMeter: "µV/m", "microvolt per meter", "microvolts per meter";
+    NanovoltPerMeter: "nV/m", "nanovolt per meter", "nanovolts per meter";
+    PicovoltPerMeter: "pV/m", "picovolt per meter", "picovolts per meter";
+    FemtovoltPerMeter: "fV/m", "femtovolt per meter", "femtovolts per meter";
+    AttovoltPerMeter: "aV/m", "attovolt per meter", "attovolts per meter";
+    ZeptovoltPerMeter: "zV/m", "zeptovolt per meter", "zeptovolts per meter";
+    YoctovoltPerMeter: "yV/m", "yoctovolt per meter", "yoctovolts per meter";
+}
+
+// Other units
+units! {
+    VoltPerCentimeter: "V/cm", "volt per centimeter", "volts per centimeter";
+    VoltPerMillimeter: "V/mm", "volt per millimeter", "volts per millimeter";
+    VoltPerMicrometer: "V/μm", "volt per micrometer", "volts per micrometer";
+    KilovoltPerCentimeter: "kV/cm", "kilovolt per centimeter", "kilovolts per centimeter";
+    KilovoltPerMillimeter: "kV/mm", "kilovolt per millimeter", "kilovolts per millimeter";
+    MegavoltPerCentimeter: "MV/cm", "megavolt per centimeter", "megavolts per centimeter";
+    VoltPerMil: "V/mil", "volt per mil", "volts per mil";
+    StatvoltPerCentimeter: "statV/cm", "statvolt per centimeter", "statvolts per centimeter";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed volts per meter
+    YottavoltPerMeter => VoltPerMeter: YOTTA;
+    ZettavoltPerMeter => VoltPerMeter: ZETTA;
+    ExavoltPerMeter => VoltPerMeter: EXA;
+    PetavoltPerMeter => VoltPerMeter: PETA;
+    TeravoltPerMeter => VoltPerMeter: TERA;
+    GigavoltPerMeter => VoltPerMeter: GIGA;
+    MegavoltPerMeter => VoltPerMeter: MEGA;
+    KilovoltPerMeter => VoltPerMeter: KILO;
+    HectovoltPerMeter => VoltPerMeter: HECTO;
+    DecavoltPerMeter => VoltPerMeter: DECA;
+    DecivoltPerMeter => VoltPerMeter: DECI;
+    CentivoltPerMeter => VoltPerMeter: CENTI;
+    MillivoltPerMeter => VoltPerMeter: MILLI;
+    MicrovoltPerMeter => VoltPerMeter: MICRO;
+    NanovoltPerMeter => VoltPerMeter: NANO;
+    PicovoltPerMeter => VoltPerMeter: PICO;
+    FemtovoltPerMeter => VoltPerMeter: FEMTO;
+    AttovoltPerMeter => VoltPerMeter: ATTO;
+    ZeptovoltPerMeter => VoltPerMeter: ZEPTO;
+    YoctovoltPerMeter => VoltPerMeter: YOCTO;
+
+    // Other units
+    VoltPerCentimeter => VoltPerMeter: 1.0 / CENTI;
+    VoltPerMillimeter => VoltPerMeter: 1.0 / MILLI;
+    VoltPerMicrometer => VoltPerMeter: 1.0 / MICRO;
+    KilovoltPerCentimeter => VoltPerMeter: KILO / CENTI;
+    KilovoltPerMillimeter => VoltPerMeter: KILO / MILLI;
+    MegavoltPerCentimeter => VoltPerMeter: MEGA / CENTI;
+    VoltPerMil => VoltPerMeter: 1.0 / 2.54_E-5;
+
+    // Gaussian CGS: 1 statV = 299.792458 V
+    StatvoltPerCentimeter => VoltPerMeter: 2.997_924_58_E2 / CENTI;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    VoltPerMeter => YottavoltPerMeter, ZettavoltPerMeter, ExavoltPerMeter, PetavoltPerMeter,
+                    TeravoltPerMeter, GigavoltPerMeter, MegavoltPerMeter, KilovoltPerMeter,
+                    MillivoltPerMeter, MicrovoltPerMeter, NanovoltPerMeter, PicovoltPerMeter,
+                    FemtovoltPerMeter, AttovoltPerMeter, ZeptovoltPerMeter, YoctovoltPerMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    VoltPerMeter => YottavoltPerMeter, ZettavoltPerMeter, ExavoltPerMeter, PetavoltPerMeter,
+                    TeravoltPerMeter, GigavoltPerMeter, MegavoltPerMeter, KilovoltPerMeter,
+                    HectovoltPerMeter, DecavoltPerMeter, DecivoltPerMeter, CentivoltPerMeter,
+                    MillivoltPerMeter, MicrovoltPerMeter, NanovoltPerMeter, PicovoltPerMeter,
+                    FemtovoltPerMeter, AttovoltPerMeter, ZeptovoltPerMeter, YoctovoltPerMeter,
+                    VoltPerCentimeter, VoltPerMillimeter, VoltPerMicrometer, KilovoltPerCentimeter,
+                    KilovoltPerMillimeter, MegavoltPerCentimeter, VoltPerMil, StatvoltPerCentimeter
+}
+
+// Electric field strength quantity definition (Voltage/Length)
+use super::{ISQ, SiScale};
+quantity!(ElectricFieldStrength, ISQ<P1, P1, N3, N1, Z0, Z0, Z0>, SiScale, VoltPerMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_electric_field {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::electric_field,
+                uom::si::electric_field,
+                ElectricFieldStrength,
+                ElectricField,
+                VoltPerMeter,
+                $num_units_unit,
+                volt_per_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_electric_field!(VoltPerMeter, volt_per_meter);
+    test_uom_electric_field!(MegavoltPerMeter, megavolt_per_meter);
+    test_uom_electric_field!(VoltPerCentimeter, volt_per_centimeter);
+    test_uom_electric_field!(VoltPerMillimeter, volt_per_millimeter);
+    test_uom_electric_field!(VoltPerMicrometer, volt_per_micrometer);
+    test_uom_electric_field!(KilovoltPerMillimeter, kilovolt_per_millimeter);
+    test_uom_electric_field!(MegavoltPerCentimeter, megavolt_per_centimeter);
+    test_uom_electric_field!(VoltPerMil, volt_per_mil);
+
+    #[test]
+    fn test_prefixed_and_cgs_units() {
+        use crate::si::electric_field::*;
+
+        let field = ElectricFieldStrength::from::<KilovoltPerCentimeter>(1.0);
+        assert_eq!(field.to::<KilovoltPerMeter>(), 100.0);
+        assert_eq!(field.to::<MillivoltPerMeter>(), 1.0e8);
+
+        let gaussian = ElectricFieldStrength::from::<StatvoltPerCentimeter>(1.0);
+        assert!((gaussian.to::<VoltPerMeter>() - 29_979.245_8_f64).abs() < 1e-9);
+    }
+}
+
+// Re-export types for convenience
+pub use electric_field_strength::ElectricFieldStrength;
+pub use electric_field_strength::*;
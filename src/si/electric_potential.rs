@@ -0,0 +1,183 @@
+/// # Electric Potential Units - SI Voltage Measurements
+///
+/// This module defines SI electric potential units and their conversions. Electric potential
+/// difference (voltage) is the work done per unit charge, with the volt as the SI base unit.
+///
+/// ## Base Unit
+///
+/// - **Volt (V)**: The SI base unit of electric potential, one joule per coulomb
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for volts:
+/// - **Yottavolt (YV)**: 10²⁴ volts
+/// - ... down to ...
+/// - **Yoctovolt (yV)**: 10⁻²⁴ volts
+///
+/// ## Other Units
+///
+/// - **Abvolt (abV)**: The electromagnetic CGS unit, 10⁻⁸ volts
+/// - **Statvolt (statV)**: The Gaussian CGS unit, 299.792458 volts
+///
+/// ## Logarithmic Levels
+///
+/// Voltage levels are field quantities, so they take 20 dB per decade:
+/// - **DecibelVolt (dBV)**: level relative to 1 V, so 0 dBV = 1 V and 20 dBV = 10 V
+/// - **DecibelMicrovolt (dBµV)**: level relative to 1 µV
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::electric_potential::{DecibelVolt, ElectricPotential, Millivolt, Volt};
+///
+/// // Create electric potential quantities
+/// let supply = ElectricPotential::from::<Millivolt>(3300.0);
+///
+/// // Convert between units
+/// let volts = supply.to::<Volt>(); // 3.3 V
+/// let level = supply.to::<DecibelVolt>(); // about 10.4 dBV
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    Volt: "V", "volt", "volts";
+}
+
+// SI prefixed volts
+units! {
+    Yottavolt: "YV", "yottavolt", "yottavolts";
+    Zettavolt: "ZV", "zettavolt", "zettavolts";
+    Exavolt: "EV", "exavolt", "exavolts";
+    Petavolt: "PV", "petavolt", "petavolts";
+    Teravolt: "TV", "teravolt", "teravolts";
+    Gigavolt: "GV", "gigavolt", "gigavolts";
+    Megavolt: "MV", "megavolt", "megavolts";
+    Kilovolt: "kV", "kilovolt", "kilovolts";
+    Hectovolt: "hV", "hectovolt", "hectovolts";
+    Decavolt: "daV", "decavolt", "decavolts";
+    Decivolt: "dV", "decivolt", "decivolts";
+    Centivolt: "cV", "centivolt", "centivolts";
+    Millivolt: "mV", "millivolt", "millivolts";
+    Microvolt: "µV", "microvolt", "microvolts";
+    Nanovolt: "nV", "nanovolt", "nanovolts";
+    Picovolt: "pV", "picovolt", "picovolts";
+    Femtovolt: "fV", "femtovolt", "femtovolts";
+    Attovolt: "aV", "attovolt", "attovolts";
+    Zeptovolt: "zV", "zeptovolt", "zeptovolts";
+    Yoctovolt: "yV", "yoctovolt", "yoctovolts";
+}
+
+// Other units
+units! {
+    Abvolt: "abV", "abvolt", "abvolts";
+    Statvolt: "statV", "statvolt", "statvolts";
+}
+
+// Logarithmic voltage levels
+units! {
+    DecibelVolt: "dBV", "decibel-volt";
+    DecibelMicrovolt: "dBµV", "decibel-microvolt";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed volts
+    Yottavolt => Volt: YOTTA;
+    Zettavolt => Volt: ZETTA;
+    Exavolt => Volt: EXA;
+    Petavolt => Volt: PETA;
+    Teravolt => Volt: TERA;
+    Gigavolt => Volt: GIGA;
+    Megavolt => Volt: MEGA;
+    Kilovolt => Volt: KILO;
+    Hectovolt => Volt: HECTO;
+    Decavolt => Volt: DECA;
+    Decivolt => Volt: DECI;
+    Centivolt => Volt: CENTI;
+    Millivolt => Volt: MILLI;
+    Microvolt => Volt: MICRO;
+    Nanovolt => Volt: NANO;
+    Picovolt => Volt: PICO;
+    Femtovolt => Volt: FEMTO;
+    Attovolt => Volt: ATTO;
+    Zeptovolt => Volt: ZEPTO;
+    Yoctovolt => Volt: YOCTO;
+
+    // CGS units
+    Abvolt => Volt: 1.0_E-8;
+    Statvolt => Volt: 2.997_925_E2;
+}
+
+// Voltage levels: U = reference * 10^(level / 20)
+crate::convert_log! {
+    DecibelVolt => Volt: 1.0, 10.0, 20.0;
+    DecibelMicrovolt => Volt: 1.0E-6, 10.0, 20.0;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Volt => Yottavolt, Zettavolt, Exavolt, Petavolt, Teravolt, Gigavolt, Megavolt, Kilovolt,
+            Millivolt, Microvolt, Nanovolt, Picovolt, Femtovolt, Attovolt, Zeptovolt, Yoctovolt
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    Volt => Yottavolt, Zettavolt, Exavolt, Petavolt, Teravolt, Gigavolt, Megavolt, Kilovolt,
+            Hectovolt, Decavolt, Decivolt, Centivolt, Millivolt, Microvolt, Nanovolt, Picovolt,
+            Femtovolt, Attovolt, Zeptovolt, Yoctovolt, Abvolt, Statvolt, DecibelVolt,
+            DecibelMicrovolt
+}
+
+// Electric potential quantity definition (Mass×Length²/(Time³×Current))
+use super::{ISQ, SiScale};
+quantity!(ElectricPotential, ISQ<P2, P1, N3, N1, Z0, Z0, Z0>, SiScale, Volt);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_electric_potential {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::electric_potential,
+                uom::si::electric_potential,
+                ElectricPotential,
+                ElectricPotential,
+                Volt,
+                $num_units_unit,
+                volt,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_electric_potential!(Volt, volt);
+    test_uom_electric_potential!(Kilovolt, kilovolt);
+    test_uom_electric_potential!(Millivolt, millivolt);
+    test_uom_electric_potential!(Microvolt, microvolt);
+    test_uom_electric_potential!(Abvolt, abvolt);
+    test_uom_electric_potential!(Statvolt, statvolt);
+
+    #[test]
+    fn test_voltage_levels() {
+        use crate::si::electric_potential::*;
+
+        let level = ElectricPotential::from::<DecibelVolt>(20.0);
+        assert!((level.to::<Volt>() - 10.0_f64).abs() < 1e-12);
+        assert!((level.to::<DecibelMicrovolt>() - 140.0_f64).abs() < 1e-9);
+        let reference = ElectricPotential::from::<Volt>(1.0_f64);
+        assert!(reference.to::<DecibelVolt>().abs() < 1e-12);
+        let signal = ElectricPotential::from::<Millivolt>(1.0_f32);
+        assert!((signal.to::<DecibelMicrovolt>() - 60.0).abs() < 1e-4);
+        assert!((signal.to::<DecibelVolt>() + 60.0).abs() < 1e-4);
+    }
+}
+
+// Re-export types for convenience
+pub use electric_potential::ElectricPotential;
+pub use electric_potential::*;
@@ -70,6 +70,12 @@
 /// - **Therm**: Various definitions
 /// - **Ton of TNT**: 4.184 × 10⁹ joules
 /// - **Watt Second**: 1 joule
+/// - **Rydberg (Ry)**: Eₕ/2 = 2.1798723611035 × 10⁻¹⁸ joules
+///
+/// ## Oil-Equivalent Units
+///
+/// - **Ton of Oil Equivalent (toe)**: 41.868 GJ (IEA/OECD definition, 10⁷ kcal (IT))
+/// - **Barrel of Oil Equivalent (BOE)**: 5.8 × 10⁶ Btu (IT) ≈ 6.119 GJ
 ///
 /// ## Usage
 ///
@@ -161,27 +167,34 @@ units! {
 // Other energy units
 units! {
     Hartree: "Eₕ", "hartree";
-    BtuIt: "Btu (IT)", "British thermal unit (IT)";
+    BtuIt: "Btu (IT)", "British thermal unit (IT)", "British thermal units (IT)";
     Btu: "Btu", "British thermal unit";
-    Btu39: "Btu₃₉", "British thermal unit (39 °F)";
-    Btu59: "Btu₅₉", "British thermal unit (59 °F)";
-    Btu60: "Btu₆₀", "British thermal unit (60 °F)";
-    CalorieIt: "cal (IT)", "calorie (IT)";
+    Btu39: "Btu₃₉", "British thermal unit (39 °F)", "British thermal units (39 °F)";
+    Btu59: "Btu₅₉", "British thermal unit (59 °F)", "British thermal units (59 °F)";
+    Btu60: "Btu₆₀", "British thermal unit (60 °F)", "British thermal units (60 °F)";
+    CalorieIt: "cal (IT)", "calorie (IT)", "calories (IT)";
     Calorie: "cal", "calorie";
-    Calorie15: "cal₁₅", "calorie (15 °C)";
-    Calorie20: "cal₂₀", "calorie (20 °C)";
-    CalorieItNutrition: "Cal (IT)", "Calorie (IT)";
+    Calorie15: "cal₁₅", "calorie (15 °C)", "calories (15 °C)";
+    Calorie20: "cal₂₀", "calorie (20 °C)", "calories (20 °C)";
+    CalorieItNutrition: "Cal (IT)", "Calorie (IT)", "Calories (IT)";
     CalorieNutrition: "Cal", "Calorie";
     Erg: "erg", "erg";
     FootPoundal: "ft·pdl", "foot poundal";
-    FootPound: "ft·lbf", "foot pound-force";
-    KilocalorieIt: "kcal (IT)", "kilocalorie (IT)";
+    FootPound: "ft·lbf", "foot pound-force", "foot pounds-force";
+    KilocalorieIt: "kcal (IT)", "kilocalorie (IT)", "kilocalories (IT)";
     Kilocalorie: "kcal", "kilocalorie";
     Quad: "10¹⁵ Btu (IT)", "quad";
-    ThermEc: "thm (EC)", "therm (EC)";
+    ThermEc: "thm (EC)", "therm (EC)", "therms (EC)";
     ThermUs: "thm", "therm";
-    TonTnt: "t of TNT", "ton of TNT";
+    TonTnt: "t of TNT", "ton of TNT", "tons of TNT";
     WattSecond: "W·s", "watt second";
+    Rydberg: "Ry", "rydberg";
+}
+
+// Oil-equivalent energy units
+units! {
+    TonOilEquivalent: "toe", "ton of oil equivalent", "tons of oil equivalent";
+    BarrelOilEquivalent: "BOE", "barrel of oil equivalent", "barrels of oil equivalent";
 }
 
 // ===== CONVERSION RELATIONSHIPS =====
@@ -255,10 +268,32 @@ crate::convert_linear! {
     ThermUs => Joule: 1.054_804_E8;
     TonTnt => Joule: 4.184_E9;
     WattSecond => Joule: 1.0_E0;
+    Rydberg => Joule: 2.179_872_361_103_55_E-18;
+
+    // Oil-equivalent units - 10⁷ kcal (IT) and 5.8 × 10⁶ Btu (IT)
+    TonOilEquivalent => Joule: 4.186_8_E10;
+    BarrelOilEquivalent => Joule: 6.119_323_945_196_E9;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Joule => Yottajoule, Zettajoule, Exajoule, Petajoule, Terajoule, Gigajoule, Megajoule,
+             Kilojoule, Millijoule, Microjoule, Nanojoule, Picojoule, Femtojoule, Attojoule,
+             Zeptojoule, Yoctojoule
 }
 
-crate::convert_matrix! {
-    Joule => Yottajoule, Zettajoule, Exajoule, Petajoule, Terajoule, Gigajoule, Megajoule, Kilojoule, Hectojoule, Decajoule, Decijoule, Centijoule, Millijoule, Microjoule, Nanojoule, Picojoule, Femtojoule, Attojoule, Zeptojoule, Yoctojoule, PetawattHour, TerawattHour, GigawattHour, MegawattHour, KilowattHour, HectowattHour, DecawattHour, WattHour, MilliwattHour, MicrowattHour, Petaelectronvolt, Teraelectronvolt, Gigaelectronvolt, Megaelectronvolt, Kiloelectronvolt, Hectoelectronvolt, Decaelectronvolt, Electronvolt, Hartree, BtuIt, Btu, Btu39, Btu59, Btu60, CalorieIt, Calorie, Calorie15, Calorie20, CalorieItNutrition, CalorieNutrition, Erg, FootPoundal, FootPound, KilocalorieIt, Kilocalorie, Quad, ThermEc, ThermUs, TonTnt, WattSecond
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    Joule => Yottajoule, Zettajoule, Exajoule, Petajoule, Terajoule, Gigajoule, Megajoule,
+             Kilojoule, Hectojoule, Decajoule, Decijoule, Centijoule, Millijoule, Microjoule,
+             Nanojoule, Picojoule, Femtojoule, Attojoule, Zeptojoule, Yoctojoule, PetawattHour,
+             TerawattHour, GigawattHour, MegawattHour, KilowattHour, HectowattHour, DecawattHour,
+             WattHour, MilliwattHour, MicrowattHour, Petaelectronvolt, Teraelectronvolt,
+             Gigaelectronvolt, Megaelectronvolt, Kiloelectronvolt, Hectoelectronvolt,
+             Decaelectronvolt, Electronvolt, Hartree, BtuIt, Btu, Btu39, Btu59, Btu60, CalorieIt,
+             Calorie, Calorie15, Calorie20, CalorieItNutrition, CalorieNutrition, Erg, FootPoundal,
+             FootPound, KilocalorieIt, Kilocalorie, Quad, ThermEc, ThermUs, TonTnt, WattSecond,
+             Rydberg, TonOilEquivalent, BarrelOilEquivalent
 }
 
 // Energy quantity definition (Mass×Length²/Time²)
@@ -352,6 +387,29 @@ mod tests {
     test_uom_energy!(ThermUs, therm_us);
     test_uom_energy!(TonTnt, ton_tnt);
     test_uom_energy!(WattSecond, watt_second);
+
+    #[test]
+    fn test_rydberg() {
+        use crate::si::energy::*;
+
+        let rydberg = Energy::from::<Rydberg>(2.0);
+        assert!((rydberg.to::<Hartree>() - 1.0_f64).abs() < 1e-12);
+        assert!((Energy::from::<Rydberg>(1.0).to::<Electronvolt>() - 13.605_693_f64).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_oil_equivalent() {
+        use crate::si::energy::*;
+
+        let toe = Energy::from::<TonOilEquivalent>(1.0);
+        assert!((toe.to::<Gigajoule>() - 41.868_f64).abs() < 1e-9);
+        assert!((toe.to::<KilocalorieIt>() - 1.0e7_f64).abs() < 1e-3);
+
+        let boe = Energy::from::<BarrelOilEquivalent>(1.0);
+        // BtuIt uses the rounded UOM coefficient, so allow for its truncation
+        assert!((boe.to::<BtuIt>() - 5.8e6_f64).abs() < 1.0);
+        assert!((toe.to::<BarrelOilEquivalent>() - 6.842_f64).abs() < 1e-3);
+    }
 }
 
 // Re-export types for convenience
@@ -0,0 +1,144 @@
+/// # Energy Density Units - SI Volumetric Energy Density Measurements
+///
+/// This module defines SI energy density units and their conversions. Energy density is the
+/// energy stored per unit volume, with joule per cubic meter as the SI base unit. Battery
+/// cells and HVAC fuels are commonly rated this way.
+///
+/// Energy density has the same dimension as pressure (J/m³ = Pa), so quantities of either
+/// kind share the `EnergyDensity` type.
+///
+/// ## Base Unit
+///
+/// - **JoulePerCubicMeter (J/m³)**: The SI base unit of energy density
+///
+/// ## SI Prefixed Units
+///
+/// - **KilojoulePerCubicMeter (kJ/m³)**: 10³ joules per cubic meter
+/// - **MegajoulePerCubicMeter (MJ/m³)**: 10⁶ joules per cubic meter
+/// - **GigajoulePerCubicMeter (GJ/m³)**: 10⁹ joules per cubic meter
+///
+/// ## Other Units
+///
+/// - **JoulePerLiter (J/L)**, **KilojoulePerLiter (kJ/L)**, **MegajoulePerLiter (MJ/L)**:
+///   Per-liter energy densities
+/// - **WattHourPerLiter (Wh/L)**: 3.6 × 10⁶ joules per cubic meter
+/// - **KilowattHourPerLiter (kWh/L)**: 3.6 × 10⁹ joules per cubic meter
+/// - **KilowattHourPerCubicMeter (kWh/m³)**: 3.6 × 10⁶ joules per cubic meter
+/// - **BtuPerCubicFoot (Btu/ft³)**: International Table BTU per cubic foot
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::energy_density::{EnergyDensity, MegajoulePerLiter, WattHourPerLiter};
+///
+/// // Create energy density quantities
+/// let cell = EnergyDensity::from::<WattHourPerLiter>(700.0);
+///
+/// // Convert between units
+/// let cell_mj = cell.to::<MegajoulePerLiter>(); // 2.52 MJ/L
+/// ```
+use crate::prefix::{GIGA, HOUR, KILO, MEGA, MILLI};
+use typenum::*;
+
+// SI base unit
+units! {
+    JoulePerCubicMeter: "J/m³", "joule per cubic meter", "joules per cubic meter";
+}
+
+// SI prefixed joules per cubic meter
+units! {
+    KilojoulePerCubicMeter: "kJ/m³", "kilojoule per cubic meter", "kilojoules per cubic meter";
+    MegajoulePerCubicMeter: "MJ/m³", "megajoule per cubic meter", "megajoules per cubic meter";
+    GigajoulePerCubicMeter: "GJ/m³", "gigajoule per cubic meter", "gigajoules per cubic meter";
+}
+
+// Other units
+units! {
+    JoulePerLiter: "J/L", "joule per liter", "joules per liter";
+    KilojoulePerLiter: "kJ/L", "kilojoule per liter", "kilojoules per liter";
+    MegajoulePerLiter: "MJ/L", "megajoule per liter", "megajoules per liter";
+    WattHourPerLiter: "Wh/L", "watt hour per liter", "watt hours per liter";
+    KilowattHourPerLiter: "kWh/L", "kilowatt hour per liter", "kilowatt hours per liter";
+    KilowattHourPerCubicMeter: "kWh/m³", "kilowatt hour per cubic meter", "kilowatt hours per cubic meter";
+    BtuPerCubicFoot: "Btu/ft³", "British thermal unit per cubic foot", "British thermal units per cubic foot";
+}
+
+// Unit conversions using convert_linear!
+crate::convert_linear! {
+    // SI prefixed joules per cubic meter
+    KilojoulePerCubicMeter => JoulePerCubicMeter: KILO;
+    MegajoulePerCubicMeter => JoulePerCubicMeter: MEGA;
+    GigajoulePerCubicMeter => JoulePerCubicMeter: GIGA;
+
+    // Per-liter units (1 L = 10⁻³ m³)
+    JoulePerLiter => JoulePerCubicMeter: 1.0 / MILLI;
+    KilojoulePerLiter => JoulePerCubicMeter: KILO / MILLI;
+    MegajoulePerLiter => JoulePerCubicMeter: MEGA / MILLI;
+    WattHourPerLiter => JoulePerCubicMeter: HOUR / MILLI;
+    KilowattHourPerLiter => JoulePerCubicMeter: KILO * HOUR / MILLI;
+    KilowattHourPerCubicMeter => JoulePerCubicMeter: KILO * HOUR;
+
+    // Btu (IT) = 1.055056 × 10³ J, ft³ = 2.831685 × 10⁻² m³
+    BtuPerCubicFoot => JoulePerCubicMeter: 1.055_056_E3 / 2.831_685_E-2;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    JoulePerCubicMeter => GigajoulePerCubicMeter, MegajoulePerCubicMeter, KilojoulePerCubicMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    JoulePerCubicMeter => KilojoulePerCubicMeter, MegajoulePerCubicMeter, GigajoulePerCubicMeter,
+                          JoulePerLiter, KilojoulePerLiter, MegajoulePerLiter, WattHourPerLiter,
+                          KilowattHourPerLiter, KilowattHourPerCubicMeter, BtuPerCubicFoot
+}
+
+// Energy density quantity definition (Energy/Volume)
+use super::{ISQ, SiScale};
+quantity!(EnergyDensity, ISQ<N1, P1, N2, Z0, Z0, Z0, Z0>, SiScale, JoulePerCubicMeter);
+
+#[cfg(test)]
+mod tests {
+    use crate::si::energy::{BtuIt, Energy, Kilojoule, WattHour};
+    use crate::si::energy_density::*;
+    use crate::si::volume::{CubicFoot, Liter, Volume};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= expected.abs() * 1e-12,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_energy_per_volume() {
+        let density: EnergyDensity<f64> =
+            Energy::from::<Kilojoule>(3.0) / Volume::from::<Liter>(1.0);
+        assert_close(density.to::<KilojoulePerLiter>(), 3.0);
+        assert_close(density.to::<MegajoulePerCubicMeter>(), 3.0);
+    }
+
+    #[test]
+    fn test_watt_hour_per_liter() {
+        let cell = EnergyDensity::from::<WattHourPerLiter>(700.0);
+        assert_close(cell.to::<MegajoulePerLiter>(), 2.52);
+        assert_close(cell.to::<KilowattHourPerCubicMeter>(), 700.0);
+        assert_close(cell.to::<KilowattHourPerLiter>(), 0.7);
+    }
+
+    #[test]
+    fn test_btu_per_cubic_foot() {
+        let from_parts: EnergyDensity<f64> =
+            Energy::from::<BtuIt>(1.0) / Volume::from::<CubicFoot>(1.0);
+        assert_close(from_parts.to::<BtuPerCubicFoot>(), 1.0);
+
+        let gas = EnergyDensity::from::<BtuPerCubicFoot>(1000.0);
+        let wh = Energy::from::<WattHour>(1.0).to::<BtuIt>();
+        assert_close(gas.to::<WattHourPerLiter>(), 1000.0 / wh / 28.316_85);
+    }
+}
+
+// Re-export types for convenience
+pub use energy_density::EnergyDensity;
+pub use energy_density::*;
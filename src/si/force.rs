@@ -108,13 +108,13 @@ units! {
 // Other force units
 units! {
     Dyne: "dyn", "dyne";
-    KilogramForce: "kgf", "kilogram-force";
-    GramForce: "gf", "gram-force";
+    KilogramForce: "kgf", "kilogram-force", "kilograms-force";
+    GramForce: "gf", "gram-force", "grams-force";
     Kip: "kip", "kip";
-    OunceForce: "ozf", "ounce-force";
+    OunceForce: "ozf", "ounce-force", "ounces-force";
     Poundal: "pdl", "poundal";
-    PoundForce: "lbf", "pound-force";
-    TonForce: "2000 lbf", "ton-force";
+    PoundForce: "lbf", "pound-force", "pounds-force";
+    TonForce: "2000 lbf", "ton-force", "tons-force";
 }
 
 // ===== CONVERSION RELATIONSHIPS =====
@@ -154,8 +154,20 @@ crate::convert_linear! {
     TonForce => Newton: 8.896_443_E3;
 }
 
-crate::convert_matrix! {
-    Newton => Yottanewton, Zettanewton, Exanewton, Petanewton, Teranewton, Giganewton, Meganewton, Kilonewton, Hectonewton, Decanewton, Decinewton, Centinewton, Millinewton, Micronewton, Nanonewton, Piconewton, Femtonewton, Attonewton, Zeptonewton, Yoctonewton, Dyne, KilogramForce, GramForce, Kip, OunceForce, Poundal, PoundForce, TonForce
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Newton => Yottanewton, Zettanewton, Exanewton, Petanewton, Teranewton, Giganewton, Meganewton,
+              Kilonewton, Millinewton, Micronewton, Nanonewton, Piconewton, Femtonewton, Attonewton,
+              Zeptonewton, Yoctonewton
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    Newton => Yottanewton, Zettanewton, Exanewton, Petanewton, Teranewton, Giganewton, Meganewton,
+              Kilonewton, Hectonewton, Decanewton, Decinewton, Centinewton, Millinewton,
+              Micronewton, Nanonewton, Piconewton, Femtonewton, Attonewton, Zeptonewton,
+              Yoctonewton, Dyne, KilogramForce, GramForce, Kip, OunceForce, Poundal, PoundForce,
+              TonForce
 }
 
 // Force quantity definition (Mass×Length/Time²)
@@ -0,0 +1,142 @@
+/// # Fuel Economy Units - Distance per Volume Measurements
+///
+/// This module defines fuel economy units and their conversions. Fuel economy is the distance
+/// traveled per unit volume of fuel, with dimensions of length⁻² in the SI system. The
+/// coherent SI unit is the meter per cubic meter, which serves as the base unit here.
+///
+/// ## Consumption vs. Economy
+///
+/// Fuel consumption (volume per distance, e.g. L/100 km) is the reciprocal of fuel economy.
+/// Consumption units are provided as units of `FuelEconomy` whose conversions are inversely
+/// proportional (y = k / x) instead of linear, so `to::<LiterPer100Kilometer>()` on a
+/// higher economy yields a lower consumption. A zero value in one form maps to infinity in
+/// the other.
+///
+/// Dividing a `Volume` by a `Length` yields a value with the dimension of area, not a
+/// `FuelEconomy`; divide the `Length` by the `Volume` instead.
+///
+/// ## Base Unit
+///
+/// - **MeterPerCubicMeter (m/m³)**: The coherent SI unit of fuel economy
+///
+/// ## Economy Units
+///
+/// - **KilometerPerLiter (km/L)**: 10⁶ meters per cubic meter
+/// - **MilePerGallon (mpg)**: US miles per gallon, ≈ 0.4251 km/L
+/// - **MilePerGallonImperial (mpg (UK))**: Miles per imperial gallon, ≈ 0.3540 km/L
+///
+/// ## Consumption Units
+///
+/// - **LiterPer100Kilometer (L/100 km)**: 100 / (km/L)
+/// - **GallonPer100Mile (gal/100 mi)**: 100 / mpg
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::fuel_economy::{FuelEconomy, LiterPer100Kilometer, MilePerGallon};
+///
+/// // Rated consumption of a car
+/// let car = FuelEconomy::from::<LiterPer100Kilometer>(6.5);
+///
+/// // Convert to economy units
+/// let car_mpg = car.to::<MilePerGallon>(); // ≈ 36.19 mpg
+/// ```
+use crate::prefix::{KILO, MILLI};
+use typenum::*;
+
+// SI base unit
+units! {
+    MeterPerCubicMeter: "m/m³", "meter per cubic meter", "meters per cubic meter";
+}
+
+// Economy units (distance per volume)
+units! {
+    KilometerPerLiter: "km/L", "kilometer per liter", "kilometers per liter";
+    MilePerGallon: "mpg", "mile per gallon", "miles per gallon";
+    MilePerGallonImperial: "mpg (UK)", "mile per imperial gallon", "miles per imperial gallon";
+}
+
+// Consumption units (volume per distance)
+units! {
+    LiterPer100Kilometer: "L/100 km", "liter per 100 kilometers", "liters per 100 kilometers";
+    GallonPer100Mile: "gal/100 mi", "gallon per 100 miles", "gallons per 100 miles";
+}
+
+// Economy unit conversions (1 mi = 1609.344 m, 1 gal = 3.785412 L, 1 gal (UK) = 4.54609 L)
+crate::convert_linear! {
+    KilometerPerLiter => MeterPerCubicMeter: KILO / MILLI;
+    MilePerGallon => MeterPerCubicMeter: 1.609_344_E3 / 3.785_412_E-3;
+    MilePerGallonImperial => MeterPerCubicMeter: 1.609_344_E3 / 4.546_09_E-3;
+}
+
+// Consumption unit conversions: x L/100 km = 100 km / x L = 10⁸ / x m/m³
+crate::convert_reciprocal! {
+    LiterPer100Kilometer => MeterPerCubicMeter: 100.0 * KILO / MILLI;
+    GallonPer100Mile => MeterPerCubicMeter: 100.0 * 1.609_344_E3 / 3.785_412_E-3;
+}
+
+// No engineering-prefixed units; `Quantity::format_si` keeps the base unit
+crate::si_prefixed!(MeterPerCubicMeter);
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    MeterPerCubicMeter => KilometerPerLiter, MilePerGallon, MilePerGallonImperial,
+                          LiterPer100Kilometer, GallonPer100Mile
+}
+
+// Fuel economy quantity definition (Length/Volume)
+use super::{ISQ, SiScale};
+quantity!(FuelEconomy, ISQ<N2, Z0, Z0, Z0, Z0, Z0, Z0>, SiScale, MeterPerCubicMeter);
+
+#[cfg(test)]
+mod tests {
+    use crate::si::fuel_economy::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= expected.abs() * 1e-12,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_economy_units() {
+        let car = FuelEconomy::from::<KilometerPerLiter>(20.0);
+        assert_close(car.to::<MeterPerCubicMeter>(), 2.0e7);
+        assert_close(car.to::<MilePerGallon>(), 47.042_919_350_990_22);
+        assert_close(car.to::<MilePerGallonImperial>(), 56.496_187_266_364_44);
+    }
+
+    #[test]
+    fn test_consumption_is_reciprocal() {
+        let car = FuelEconomy::from::<LiterPer100Kilometer>(5.0);
+        assert_close(car.to::<KilometerPerLiter>(), 20.0);
+        assert_close(car.to::<LiterPer100Kilometer>(), 5.0);
+
+        let thirstier = FuelEconomy::from::<LiterPer100Kilometer>(10.0);
+        assert!(thirstier.to::<KilometerPerLiter>() < car.to::<KilometerPerLiter>());
+
+        let truck = FuelEconomy::from::<MilePerGallon>(25.0);
+        assert_close(truck.to::<GallonPer100Mile>(), 4.0);
+    }
+
+    #[test]
+    fn test_length_over_volume() {
+        use crate::si::length::{Kilometer, Length};
+        use crate::si::volume::{Liter, Volume};
+
+        let trip: FuelEconomy<f64> = Length::from::<Kilometer>(600.0) / Volume::from::<Liter>(40.0);
+        assert_close(trip.to::<KilometerPerLiter>(), 15.0);
+        assert_close(trip.to::<LiterPer100Kilometer>(), 6.666_666_666_666_667);
+    }
+
+    #[test]
+    fn test_zero_consumption() {
+        let electric = FuelEconomy::from::<LiterPer100Kilometer>(0.0_f64);
+        assert!(electric.to::<KilometerPerLiter>().is_infinite());
+    }
+}
+
+// Re-export types for convenience
+pub use fuel_economy::FuelEconomy;
+pub use fuel_economy::*;
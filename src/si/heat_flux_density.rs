@@ -0,0 +1,204 @@
+/// # Heat Flux Density Units - SI Heat Flux Density Measurements
+///
+/// This module defines SI heat flux density units and their conversions. Heat flux density is
+/// the rate of heat transfer through a unit of area, with watt per square meter as the SI base
+/// unit. The same dimension also covers irradiance and other power-per-area quantities.
+///
+/// ## Base Unit
+///
+/// - **WattPerSquareMeter (W/m²)**: The SI base unit of heat flux density
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for watts per square meter:
+/// - **YottawattPerSquareMeter (YW/m²)**: 10²⁴ watts per square meter
+/// - ... down to ...
+/// - **YoctowattPerSquareMeter (yW/m²)**: 10⁻²⁴ watts per square meter
+///
+/// ## Other Units
+///
+/// - **WattPerSquareCentimeter (W/cm²)**: 10⁴ watts per square meter
+/// - **WattPerSquareMillimeter (W/mm²)**: 10⁶ watts per square meter
+/// - **BtuPerHourSquareFoot (Btu/(h·ft²))**: International Table BTU per hour and square foot,
+///   ≈ 3.1546 watts per square meter
+/// - **SolarConstant (S☉)**: Mean solar irradiance at 1 AU, 1361 watts per square meter
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::heat_flux_density::{BtuPerHourSquareFoot, HeatFluxDensity, WattPerSquareMeter};
+///
+/// // Heat loss through a wall section
+/// let loss = HeatFluxDensity::from::<WattPerSquareMeter>(25.0);
+///
+/// // Convert between units
+/// let loss_imperial = loss.to::<BtuPerHourSquareFoot>(); // ≈ 7.925 Btu/(h·ft²)
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, HOUR, KILO, MEGA, MICRO, MILLI, NANO, PETA,
+    PICO, TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    WattPerSquareMeter: "W/m²", "watt per square meter", "watts per square meter";
+}
+
+// SI prefixed watts per square meter
+units! {
+    YottawattPerSquareMeter: "YW/m²", "yottawatt per square meter", "yottawatts per square meter";
+    ZettawattPerSquareMeter: "ZW/m²", "zettawatt per square meter", "zettawatts per square meter";
+    ExawattPerSquareMeter: "EW/m²", "exawatt per square meter", "exawatts per square meter";
+    PetawattPerSquareMeter: "PW/m²", "petawatt per square meter", "petawatts per square meter";
+    TerawattPerSquareMeter: "TW/m²", "terawatt per square meter", "terawatts per square meter";
+    GigawattPerSquareMeter: "GW/m²", "gigawatt per square meter", "gigawatts per square meter";
+    MegawattPerSquareMeter: "MW/m²", "megawatt per square meter", "megawatts per square meter";
+    KilowattPerSquareMeter: "kW/m²", "kilowatt per square meter", "kilowatts per square meter";
+    HectowattPerSquareMeter: "hW/m²", "hectowatt per square meter", "hectowatts per square meter";
+    DecawattPerSquareMeter: "daW/m²", "decawatt per square meter", "decawatts per square meter";
+    DeciwattPerSquareMeter: "dW/m²", "deciwatt per square meter", "deciwatts per square meter";
+    CentiwattPerSquareMeter: "cW/m²", "centiwatt per square meter", "centiwatts per square meter";
+    MilliwattPerSquareMeter: "mW/m²", "milliwatt per square meter", "milliwatts per square meter";
+    MicrowattPerSquareMeter: "µW/m²", "microwatt per square meter", "microwatts per square meter";
+    NanowattPerSquareMeter: "nW/m²", "nanowatt per square meter", "nanowatts per square meter";
+    PicowattPerSquareMeter: "pW/m²", "picowatt per square meter", "picowatts per square meter";
+    FemtowattPerSquareMeter: "fW/m²", "femtowatt per square meter", "femtowatts per square meter";
+    AttowattPerSquareMeter: "aW/m²", "attowatt per square meter", "attowatts per square meter";
+    ZeptowattPerSquareMeter: "zW/m²", "zeptowatt per square meter", "zeptowatts per square meter";
+    YoctowattPerSquareMeter: "yW/m²", "yoctowatt per square meter", "yoctowatts per square meter";
+}
+
+// Other units
+units! {
+    WattPerSquareCentimeter: "W/cm²", "watt per square centimeter", "watts per square centimeter";
+    WattPerSquareMillimeter: "W/mm²", "watt per square millimeter", "watts per square millimeter";
+    BtuPerHourSquareFoot: "Btu/(h·ft²)", "British thermal unit per hour square foot", "British thermal units per hour square foot";
+    SolarConstant: "S☉", "solar constant", "solar constants";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed watts per square meter
+    YottawattPerSquareMeter => WattPerSquareMeter: YOTTA;
+    ZettawattPerSquareMeter => WattPerSquareMeter: ZETTA;
+    ExawattPerSquareMeter => WattPerSquareMeter: EXA;
+    PetawattPerSquareMeter => WattPerSquareMeter: PETA;
+    TerawattPerSquareMeter => WattPerSquareMeter: TERA;
+    GigawattPerSquareMeter => WattPerSquareMeter: GIGA;
+    MegawattPerSquareMeter => WattPerSquareMeter: MEGA;
+    KilowattPerSquareMeter => WattPerSquareMeter: KILO;
+    HectowattPerSquareMeter => WattPerSquareMeter: HECTO;
+    DecawattPerSquareMeter => WattPerSquareMeter: DECA;
+    DeciwattPerSquareMeter => WattPerSquareMeter: DECI;
+    CentiwattPerSquareMeter => WattPerSquareMeter: CENTI;
+    MilliwattPerSquareMeter => WattPerSquareMeter: MILLI;
+    MicrowattPerSquareMeter => WattPerSquareMeter: MICRO;
+    NanowattPerSquareMeter => WattPerSquareMeter: NANO;
+    PicowattPerSquareMeter => WattPerSquareMeter: PICO;
+    FemtowattPerSquareMeter => WattPerSquareMeter: FEMTO;
+    AttowattPerSquareMeter => WattPerSquareMeter: ATTO;
+    ZeptowattPerSquareMeter => WattPerSquareMeter: ZEPTO;
+    YoctowattPerSquareMeter => WattPerSquareMeter: YOCTO;
+
+    // Other units
+    WattPerSquareCentimeter => WattPerSquareMeter: 1.0 / CENTI / CENTI;
+    WattPerSquareMillimeter => WattPerSquareMeter: 1.0 / MILLI / MILLI;
+    BtuPerHourSquareFoot => WattPerSquareMeter: 1.055_056_E3 / HOUR / 9.290_304_E-2;
+    SolarConstant => WattPerSquareMeter: 1.361_E3;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    WattPerSquareMeter => YottawattPerSquareMeter, ZettawattPerSquareMeter, ExawattPerSquareMeter,
+                          PetawattPerSquareMeter, TerawattPerSquareMeter, GigawattPerSquareMeter,
+                          MegawattPerSquareMeter, KilowattPerSquareMeter, MilliwattPerSquareMeter,
+                          MicrowattPerSquareMeter, NanowattPerSquareMeter, PicowattPerSquareMeter,
+                          FemtowattPerSquareMeter, AttowattPerSquareMeter, ZeptowattPerSquareMeter,
+                          YoctowattPerSquareMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    WattPerSquareMeter => YottawattPerSquareMeter, ZettawattPerSquareMeter, ExawattPerSquareMeter,
+                          PetawattPerSquareMeter, TerawattPerSquareMeter, GigawattPerSquareMeter,
+                          MegawattPerSquareMeter, KilowattPerSquareMeter, HectowattPerSquareMeter,
+                          DecawattPerSquareMeter, DeciwattPerSquareMeter, CentiwattPerSquareMeter,
+                          MilliwattPerSquareMeter, MicrowattPerSquareMeter, NanowattPerSquareMeter,
+                          PicowattPerSquareMeter, FemtowattPerSquareMeter, AttowattPerSquareMeter,
+                          ZeptowattPerSquareMeter, YoctowattPerSquareMeter, WattPerSquareCentimeter,
+                          WattPerSquareMillimeter, BtuPerHourSquareFoot, SolarConstant
+}
+
+// Heat flux density quantity definition (Power/Length²)
+use super::{ISQ, SiScale};
+quantity!(HeatFluxDensity, ISQ<Z0, P1, N3, Z0, Z0, Z0, Z0>, SiScale, WattPerSquareMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_heat_flux_density {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::heat_flux_density,
+                uom::si::heat_flux_density,
+                HeatFluxDensity,
+                HeatFluxDensity,
+                WattPerSquareMeter,
+                $num_units_unit,
+                watt_per_square_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_heat_flux_density!(WattPerSquareMeter, watt_per_square_meter);
+    test_uom_heat_flux_density!(YottawattPerSquareMeter, yottawatt_per_square_meter);
+    test_uom_heat_flux_density!(ZettawattPerSquareMeter, zettawatt_per_square_meter);
+    test_uom_heat_flux_density!(ExawattPerSquareMeter, exawatt_per_square_meter);
+    test_uom_heat_flux_density!(PetawattPerSquareMeter, petawatt_per_square_meter);
+    test_uom_heat_flux_density!(TerawattPerSquareMeter, terawatt_per_square_meter);
+    test_uom_heat_flux_density!(GigawattPerSquareMeter, gigawatt_per_square_meter);
+    test_uom_heat_flux_density!(MegawattPerSquareMeter, megawatt_per_square_meter);
+    test_uom_heat_flux_density!(KilowattPerSquareMeter, kilowatt_per_square_meter);
+    test_uom_heat_flux_density!(HectowattPerSquareMeter, hectowatt_per_square_meter);
+    test_uom_heat_flux_density!(DecawattPerSquareMeter, decawatt_per_square_meter);
+    test_uom_heat_flux_density!(DeciwattPerSquareMeter, deciwatt_per_square_meter);
+    test_uom_heat_flux_density!(CentiwattPerSquareMeter, centiwatt_per_square_meter);
+    test_uom_heat_flux_density!(MilliwattPerSquareMeter, milliwatt_per_square_meter);
+    test_uom_heat_flux_density!(MicrowattPerSquareMeter, microwatt_per_square_meter);
+    test_uom_heat_flux_density!(NanowattPerSquareMeter, nanowatt_per_square_meter);
+    test_uom_heat_flux_density!(PicowattPerSquareMeter, picowatt_per_square_meter);
+    test_uom_heat_flux_density!(FemtowattPerSquareMeter, femtowatt_per_square_meter);
+    test_uom_heat_flux_density!(AttowattPerSquareMeter, attowatt_per_square_meter);
+    test_uom_heat_flux_density!(ZeptowattPerSquareMeter, zeptowatt_per_square_meter);
+    test_uom_heat_flux_density!(YoctowattPerSquareMeter, yoctowatt_per_square_meter);
+    test_uom_heat_flux_density!(WattPerSquareCentimeter, watt_per_square_centimeter);
+    test_uom_heat_flux_density!(WattPerSquareMillimeter, watt_per_square_millimeter);
+
+    #[test]
+    fn test_power_over_area() {
+        use crate::si::area::{Area, SquareFoot};
+        use crate::si::heat_flux_density::*;
+        use crate::si::power::{Power, Watt};
+
+        let loss: HeatFluxDensity<f64> = Power::from::<Watt>(25.0) / Area::from::<SquareFoot>(1.0);
+        assert!((loss.to::<WattPerSquareMeter>() - 269.097_760_417_743_f64).abs() < 1e-9);
+
+        let flux = HeatFluxDensity::from::<BtuPerHourSquareFoot>(1.0);
+        assert!((flux.to::<WattPerSquareMeter>() - 3.154_591_185_725_581_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_solar_constant() {
+        use crate::si::heat_flux_density::*;
+
+        let sun = HeatFluxDensity::from::<SolarConstant>(1.0);
+        assert_eq!(sun.to::<KilowattPerSquareMeter>(), 1.361);
+    }
+}
+
+// Re-export types for convenience
+pub use heat_flux_density::HeatFluxDensity;
+pub use heat_flux_density::*;
@@ -0,0 +1,211 @@
+/// # Impulse Units - SI Impulse Measurements
+///
+/// This module defines SI impulse units and their conversions. Impulse is the integral of a
+/// force over the time it acts, with newton second as the SI base unit. Multiplying a `Force`
+/// by a `Time` yields an `Impulse`.
+///
+/// ## Aliasing With Momentum
+///
+/// Impulse and linear momentum share the dimension mass × length × time⁻¹ (1 N·s = 1 kg·m/s).
+/// Quantities in num-units are identified by dimension alone, so `Mass * Velocity` also
+/// resolves to `Impulse`; the two differ only in name. The kilogram meter per second is
+/// provided below for values that are conceptually momentum.
+///
+/// ## Base Unit
+///
+/// - **NewtonSecond (N·s)**: The SI base unit of impulse
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for newton seconds:
+/// - **YottanewtonSecond (YN·s)**: 10²⁴ newton seconds
+/// - ... down to ...
+/// - **YoctonewtonSecond (yN·s)**: 10⁻²⁴ newton seconds
+///
+/// ## Other Units
+///
+/// - **KilogramMeterPerSecond (kg·m/s)**: Identical to the newton second
+/// - **DyneSecond (dyn·s)**: 10⁻⁵ newton seconds
+/// - **PoundForceSecond (lbf·s)**: 4.448222 newton seconds
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::force::{Force, Newton};
+/// use num_units::si::impulse::{Impulse, PoundForceSecond};
+/// use num_units::si::time::{Millisecond, Time};
+///
+/// // Force applied over a time interval
+/// let impulse: Impulse<f64> = Force::from::<Newton>(500.0) * Time::from::<Millisecond>(20.0);
+///
+/// // Convert between units
+/// let impulse_lbf = impulse.to::<PoundForceSecond>(); // ≈ 2.248 lbf·s
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    NewtonSecond: "N·s", "newton second", "newton seconds";
+}
+
+// SI prefixed newton seconds
+units! {
+    YottanewtonSecond: "YN·s", "yottanewton second", "yottanewton seconds";
+    ZettanewtonSecond: "ZN·s", "zettanewton second", "zettanewton seconds";
+    ExanewtonSecond: "EN·s", "exanewton second", "exanewton seconds";
+    PetanewtonSecond: "PN·s", "petanewton second", "petanewton seconds";
+    TeranewtonSecond: "TN·s", "teranewton second", "teranewton seconds";
+    GiganewtonSecond: "GN·s", "giganewton second", "giganewton seconds";
+    MeganewtonSecond: "MN·s", "meganewton second", "meganewton seconds";
+    KilonewtonSecond: "kN·s", "kilonewton second", "kilonewton seconds";
+    HectonewtonSecond: "hN·s", "hectonewton second", "hectonewton seconds";
+    DecanewtonSecond: "daN·s", "decanewton second", "decanewton seconds";
+    DecinewtonSecond: "dN·s", "decinewton second", "decinewton seconds";
+    CentinewtonSecond: "cN·s", "centinewton second", "centinewton seconds";
+    MillinewtonSecond: "mN·s", "millinewton second", "millinewton seconds";
+    MicronewtonSecond: "µN·s", "micronewton second", "micronewton seconds";
+    NanonewtonSecond: "nN·s", "nanonewton second", "nanonewton seconds";
+    PiconewtonSecond: "pN·s", "piconewton second", "piconewton seconds";
+    FemtonewtonSecond: "fN·s", "femtonewton second", "femtonewton seconds";
+    AttonewtonSecond: "aN·s", "attonewton second", "attonewton seconds";
+    ZeptonewtonSecond: "zN·s", "zeptonewton second", "zeptonewton seconds";
+    YoctonewtonSecond: "yN·s", "yoctonewton second", "yoctonewton seconds";
+}
+
+// Other units
+units! {
+    KilogramMeterPerSecond: "kg·m/s", "kilogram meter per second", "kilogram meters per second";
+    DyneSecond: "dyn·s", "dyne second", "dyne seconds";
+    PoundForceSecond: "lbf·s", "pound-force second", "pound-force seconds";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed newton seconds
+    YottanewtonSecond => NewtonSecond: YOTTA;
+    ZettanewtonSecond => NewtonSecond: ZETTA;
+    ExanewtonSecond => NewtonSecond: EXA;
+    PetanewtonSecond => NewtonSecond: PETA;
+    TeranewtonSecond => NewtonSecond: TERA;
+    GiganewtonSecond => NewtonSecond: GIGA;
+    MeganewtonSecond => NewtonSecond: MEGA;
+    KilonewtonSecond => NewtonSecond: KILO;
+    HectonewtonSecond => NewtonSecond: HECTO;
+    DecanewtonSecond => NewtonSecond: DECA;
+    DecinewtonSecond => NewtonSecond: DECI;
+    CentinewtonSecond => NewtonSecond: CENTI;
+    MillinewtonSecond => NewtonSecond: MILLI;
+    MicronewtonSecond => NewtonSecond: MICRO;
+    NanonewtonSecond => NewtonSecond: NANO;
+    PiconewtonSecond => NewtonSecond: PICO;
+    FemtonewtonSecond => NewtonSecond: FEMTO;
+    AttonewtonSecond => NewtonSecond: ATTO;
+    ZeptonewtonSecond => NewtonSecond: ZEPTO;
+    YoctonewtonSecond => NewtonSecond: YOCTO;
+
+    // Other units
+    KilogramMeterPerSecond => NewtonSecond: 1.0;
+    DyneSecond => NewtonSecond: 1.0_E-5;
+    PoundForceSecond => NewtonSecond: 4.448_222_E0;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    NewtonSecond => YottanewtonSecond, ZettanewtonSecond, ExanewtonSecond, PetanewtonSecond,
+                    TeranewtonSecond, GiganewtonSecond, MeganewtonSecond, KilonewtonSecond,
+                    MillinewtonSecond, MicronewtonSecond, NanonewtonSecond, PiconewtonSecond,
+                    FemtonewtonSecond, AttonewtonSecond, ZeptonewtonSecond, YoctonewtonSecond
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    NewtonSecond => YottanewtonSecond, ZettanewtonSecond, ExanewtonSecond, PetanewtonSecond,
+                    TeranewtonSecond, GiganewtonSecond, MeganewtonSecond, KilonewtonSecond,
+                    HectonewtonSecond, DecanewtonSecond, DecinewtonSecond, CentinewtonSecond,
+                    MillinewtonSecond, MicronewtonSecond, NanonewtonSecond, PiconewtonSecond,
+                    FemtonewtonSecond, AttonewtonSecond, ZeptonewtonSecond, YoctonewtonSecond,
+                    KilogramMeterPerSecond, DyneSecond, PoundForceSecond
+}
+
+// Impulse quantity definition (Force × Time, same dimension as momentum)
+use super::{ISQ, SiScale};
+quantity!(Impulse, ISQ<P1, P1, N1, Z0, Z0, Z0, Z0>, SiScale, NewtonSecond);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_impulse {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::impulse,
+                uom::si::momentum,
+                Impulse,
+                Momentum,
+                NewtonSecond,
+                $num_units_unit,
+                kilogram_meter_per_second,
+                $uom_unit
+            );
+        };
+    }
+
+    // Test SI prefixed newton seconds against the equivalent momentum units
+    test_uom_impulse!(YottanewtonSecond, kilogram_yottameter_per_second);
+    test_uom_impulse!(ZettanewtonSecond, kilogram_zettameter_per_second);
+    test_uom_impulse!(ExanewtonSecond, kilogram_exameter_per_second);
+    test_uom_impulse!(PetanewtonSecond, kilogram_petameter_per_second);
+    test_uom_impulse!(TeranewtonSecond, kilogram_terameter_per_second);
+    test_uom_impulse!(GiganewtonSecond, kilogram_gigameter_per_second);
+    test_uom_impulse!(MeganewtonSecond, kilogram_megameter_per_second);
+    test_uom_impulse!(KilonewtonSecond, kilogram_kilometer_per_second);
+    test_uom_impulse!(HectonewtonSecond, kilogram_hectometer_per_second);
+    test_uom_impulse!(DecanewtonSecond, kilogram_decameter_per_second);
+    test_uom_impulse!(DecinewtonSecond, kilogram_decimeter_per_second);
+    test_uom_impulse!(CentinewtonSecond, kilogram_centimeter_per_second);
+    test_uom_impulse!(MillinewtonSecond, kilogram_millimeter_per_second);
+    test_uom_impulse!(MicronewtonSecond, kilogram_micrometer_per_second);
+    test_uom_impulse!(NanonewtonSecond, kilogram_nanometer_per_second);
+    test_uom_impulse!(PiconewtonSecond, kilogram_picometer_per_second);
+    test_uom_impulse!(FemtonewtonSecond, kilogram_femtometer_per_second);
+    test_uom_impulse!(AttonewtonSecond, kilogram_attometer_per_second);
+    test_uom_impulse!(ZeptonewtonSecond, kilogram_zeptometer_per_second);
+    test_uom_impulse!(YoctonewtonSecond, kilogram_yoctometer_per_second);
+
+    // Test other units
+    test_uom_impulse!(KilogramMeterPerSecond, kilogram_meter_per_second);
+
+    #[test]
+    fn test_force_times_time() {
+        use crate::si::force::{Force, Newton, PoundForce};
+        use crate::si::impulse::*;
+        use crate::si::time::{Millisecond, Second, Time};
+
+        let impulse: Impulse<f64> = Force::from::<Newton>(500.0) * Time::from::<Millisecond>(20.0);
+        assert!((impulse.to::<NewtonSecond>() - 10.0_f64).abs() < 1e-12);
+
+        let impulse: Impulse<f64> = Force::from::<PoundForce>(3.0) * Time::from::<Second>(2.0);
+        assert!((impulse.to::<PoundForceSecond>() - 6.0_f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_momentum_alias() {
+        use crate::si::impulse::*;
+        use crate::si::mass::{Kilogram, Mass};
+        use crate::si::velocity::{MeterPerSecond, Velocity};
+
+        let momentum: Impulse<f64> =
+            Mass::from::<Kilogram>(2.0) * Velocity::from::<MeterPerSecond>(3.0);
+        assert_eq!(momentum.to::<KilogramMeterPerSecond>(), 6.0);
+        assert_eq!(momentum.to::<NewtonSecond>(), 6.0);
+        assert_eq!(momentum.to::<DyneSecond>(), 6.0e5);
+    }
+}
+
+// Re-export types for convenience
+pub use impulse::Impulse;
+pub use impulse::*;
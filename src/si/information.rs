@@ -63,7 +63,7 @@
 /// - **Nibble**: 4 bits
 /// - **Crumb**: 2 bits
 /// - **Shannon (Sh)**: 1 bit (synonym for bit)
-/// - **Natural Unit of Information (nat)**: ln(2) bits ≈ 0.693 bits
+/// - **Natural Unit of Information (nat)**: 1/ln(2) = log₂(e) bits ≈ 1.443 bits
 /// - **Trit**: log₂(3) bits ≈ 1.585 bits
 /// - **Hartley (Hart)**: log₂(10) bits ≈ 3.322 bits
 /// - **Deciban**: log₂(10)/10 bits ≈ 0.332 bits
@@ -146,6 +146,15 @@ units! {
     Word: "word", "word";
 }
 
+// Logarithmic information units (entropy measured in other bases)
+units! {
+    Shannon: "Sh", "shannon";
+    NaturalUnitOfInformation: "nat", "natural unit of information", "natural units of information";
+    Trit: "trit", "trit";
+    Hartley: "Hart", "hartley";
+    Deciban: "deciban", "deciban";
+}
+
 // ===== CONVERSION RELATIONSHIPS =====
 
 // Information unit conversions using convert_linear! with exact UOM coefficients
@@ -194,11 +203,33 @@ crate::convert_linear! {
     Nibble => Unitless: TETRA;
     Crumb => Unitless: DUO;
     Word => Unitless: WORD;
-}
 
-crate::convert_matrix! {
-    Unitless => Yobibit, Yottabit, Zebibit, Zettabit, Exbibit, Exabit, Pebibit, Petabit, Tebibit, Terabit, Gibibit, Gigabit, Mebibit, Megabit, Kibibit, Kilobit, Bit, Yobibyte, Yottabyte, Zebibyte, Zettabyte, Exbibyte, Exabyte, Pebibyte, Petabyte, Tebibyte, Terabyte, Gibibyte, Gigabyte, Mebibyte, Megabyte, Kibibyte, Kilobyte, Byte, Octet, Nibble, Crumb, Shannon, NaturalUnitOfInformation, Trit, Hartley, Deciban
+    // Logarithmic units - one unit of base-k entropy is log₂(k) bits
+    Shannon => Unitless: ONE;
+    NaturalUnitOfInformation => Unitless: core::f64::consts::LOG2_E;
+    Trit => Unitless: 1.584_962_500_721_156;
+    Hartley => Unitless: core::f64::consts::LOG2_10;
+    Deciban => Unitless: DECI * core::f64::consts::LOG2_10;
 }
 
 // Import Unitless from scalar module
 use super::scalar::Unitless;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::si::scalar::Scalar;
+
+    #[test]
+    fn test_logarithmic_units() {
+        let bit = Scalar::from::<Shannon>(1.0);
+        assert!((bit.to::<Bit>() - 1.0_f64).abs() < 1e-15);
+
+        // A fair three-way choice carries one trit, a ten-way choice one hartley
+        let trit = Scalar::from::<Trit>(1.0);
+        assert!((trit.to::<Bit>() - 3.0_f64.log2()).abs() < 1e-12);
+        let hartley = Scalar::from::<Hartley>(1.0);
+        assert!((hartley.to::<Deciban>() - 10.0_f64).abs() < 1e-12);
+        assert!((hartley.to::<NaturalUnitOfInformation>() - 10.0_f64.ln()).abs() < 1e-12);
+    }
+}
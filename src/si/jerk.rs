@@ -0,0 +1,203 @@
+/// # Jerk Units - SI Jerk Measurements
+///
+/// This module defines SI jerk units and their conversions. Jerk is the rate of change of
+/// acceleration, with meter per second cubed as the SI base unit. Motion profiles for servo
+/// drives are commonly limited in jerk to keep mechanical stress low.
+///
+/// ## Base Unit
+///
+/// - **MeterPerSecondCubed (m/s³)**: The SI base unit of jerk
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for meters per second cubed:
+/// - **YottameterPerSecondCubed (Ym/s³)**: 10²⁴ meters per second cubed
+/// - ... down to ...
+/// - **YoctometerPerSecondCubed (ym/s³)**: 10⁻²⁴ meters per second cubed
+///
+/// ## Other Units
+///
+/// - **FootPerSecondCubed (ft/s³)**: 0.3048 meters per second cubed
+/// - **InchPerSecondCubed (in/s³)**: 0.0254 meters per second cubed
+/// - **KilometerPerMinuteCubed (km/min³)**: 1/216 meters per second cubed
+/// - **StandardGravityPerSecond (g₀/s)**: 9.80665 meters per second cubed
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::jerk::{Jerk, MeterPerSecondCubed, StandardGravityPerSecond};
+///
+/// // Create jerk quantities
+/// let jerk_limit = Jerk::from::<StandardGravityPerSecond>(2.0);
+///
+/// // Convert between units
+/// let jerk_limit_si = jerk_limit.to::<MeterPerSecondCubed>(); // 19.6133 m/s³
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    MeterPerSecondCubed: "m/s³", "meter per second cubed", "meters per second cubed";
+}
+
+// SI prefixed meters per second cubed
+units! {
+    YottameterPerSecondCubed: "Ym/s³", "yottameter per second cubed", "yottameters per second cubed";
+    ZettameterPerSecondCubed: "Zm/s³", "zettameter per second cubed", "zettameters per second cubed";
+    ExameterPerSecondCubed: "Em/s³", "exameter per second cubed", "exameters per second cubed";
+    PetameterPerSecondCubed: "Pm/s³", "petameter per second cubed", "petameters per second cubed";
+    TerameterPerSecondCubed: "Tm/s³", "terameter per second cubed", "terameters per second cubed";
+    GigameterPerSecondCubed: "Gm/s³", "gigameter per second cubed", "gigameters per second cubed";
+    MegameterPerSecondCubed: "Mm/s³", "megameter per second cubed", "megameters per second cubed";
+    KilometerPerSecondCubed: "km/s³", "kilometer per second cubed", "kilometers per second cubed";
+    HectometerPerSecondCubed: "hm/s³", "hectometer per second cubed", "hectometers per second cubed";
+    DecameterPerSecondCubed: "dam/s³", "decameter per second cubed", "decameters per second cubed";
+    DecimeterPerSecondCubed: "dm/s³", "decimeter per second cubed", "decimeters per second cubed";
+    CentimeterPerSecondCubed: "cm/s³", "centimeter per second cubed", "centimeters per second cubed";
+    MillimeterPerSecondCubed: "mm/s³", "millimeter per second cubed", "millimeters per second cubed";
+    MicrometerPerSecondCubed: "µm/s³", "micrometer per second cubed", "micrometers per second cubed";
+    NanometerPerSecondCubed: "nm/s³", "nanometer per second cubed", "nanometers per second cubed";
+    PicometerPerSecondCubed: "pm/s³", "picometer per second cubed", "picometers per second cubed";
+    FemtometerPerSecondCubed: "fm/s³", "femtometer per second cubed", "femtometers per second cubed";
+    AttometerPerSecondCubed: "am/s³", "attometer per second cubed", "attometers per second cubed";
+    ZeptometerPerSecondCubed: "zm/s³", "zeptometer per second cubed", "zeptometers per second cubed";
+    YoctometerPerSecondCubed: "ym/s³", "yoctometer per second cubed", "yoctometers per second cubed";
+}
+
+// Other units
+units! {
+    FootPerSecondCubed: "ft/s³", "foot per second cubed", "feet per second cubed";
+    InchPerSecondCubed: "in/s³", "inch per second cubed", "inches per second cubed";
+    KilometerPerMinuteCubed: "km/min³", "kilometer per minute cubed", "kilometers per minute cubed";
+    StandardGravityPerSecond: "g₀/s", "standard gravity per second", "standard gravities per second";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed meters per second cubed
+    YottameterPerSecondCubed => MeterPerSecondCubed: YOTTA;
+    ZettameterPerSecondCubed => MeterPerSecondCubed: ZETTA;
+    ExameterPerSecondCubed => MeterPerSecondCubed: EXA;
+    PetameterPerSecondCubed => MeterPerSecondCubed: PETA;
+    TerameterPerSecondCubed => MeterPerSecondCubed: TERA;
+    GigameterPerSecondCubed => MeterPerSecondCubed: GIGA;
+    MegameterPerSecondCubed => MeterPerSecondCubed: MEGA;
+    KilometerPerSecondCubed => MeterPerSecondCubed: KILO;
+    HectometerPerSecondCubed => MeterPerSecondCubed: HECTO;
+    DecameterPerSecondCubed => MeterPerSecondCubed: DECA;
+    DecimeterPerSecondCubed => MeterPerSecondCubed: DECI;
+    CentimeterPerSecondCubed => MeterPerSecondCubed: CENTI;
+    MillimeterPerSecondCubed => MeterPerSecondCubed: MILLI;
+    MicrometerPerSecondCubed => MeterPerSecondCubed: MICRO;
+    NanometerPerSecondCubed => MeterPerSecondCubed: NANO;
+    PicometerPerSecondCubed => MeterPerSecondCubed: PICO;
+    FemtometerPerSecondCubed => MeterPerSecondCubed: FEMTO;
+    AttometerPerSecondCubed => MeterPerSecondCubed: ATTO;
+    ZeptometerPerSecondCubed => MeterPerSecondCubed: ZEPTO;
+    YoctometerPerSecondCubed => MeterPerSecondCubed: YOCTO;
+
+    // Other units
+    FootPerSecondCubed => MeterPerSecondCubed: 3.048E-1;
+    InchPerSecondCubed => MeterPerSecondCubed: 2.54E-2;
+    KilometerPerMinuteCubed => MeterPerSecondCubed: 4.629_629_629_629_629E-3;
+    StandardGravityPerSecond => MeterPerSecondCubed: 9.80665;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    MeterPerSecondCubed => YottameterPerSecondCubed, ZettameterPerSecondCubed,
+                           ExameterPerSecondCubed, PetameterPerSecondCubed, TerameterPerSecondCubed,
+                           GigameterPerSecondCubed, MegameterPerSecondCubed,
+                           KilometerPerSecondCubed, MillimeterPerSecondCubed,
+                           MicrometerPerSecondCubed, NanometerPerSecondCubed,
+                           PicometerPerSecondCubed, FemtometerPerSecondCubed,
+                           AttometerPerSecondCubed, ZeptometerPerSecondCubed,
+                           YoctometerPerSecondCubed
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    MeterPerSecondCubed => YottameterPerSecondCubed, ZettameterPerSecondCubed,
+                           ExameterPerSecondCubed, PetameterPerSecondCubed, TerameterPerSecondCubed,
+                           GigameterPerSecondCubed, MegameterPerSecondCubed,
+                           KilometerPerSecondCubed, HectometerPerSecondCubed,
+                           DecameterPerSecondCubed, DecimeterPerSecondCubed,
+                           CentimeterPerSecondCubed, MillimeterPerSecondCubed,
+                           MicrometerPerSecondCubed, NanometerPerSecondCubed,
+                           PicometerPerSecondCubed, FemtometerPerSecondCubed,
+                           AttometerPerSecondCubed, ZeptometerPerSecondCubed,
+                           YoctometerPerSecondCubed, FootPerSecondCubed, InchPerSecondCubed,
+                           KilometerPerMinuteCubed, StandardGravityPerSecond
+}
+
+// Jerk quantity definition (Length/Time³)
+use super::{ISQ, SiScale};
+quantity!(Jerk, ISQ<P1, Z0, N3, Z0, Z0, Z0, Z0>, SiScale, MeterPerSecondCubed);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_jerk {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::jerk,
+                uom::si::jerk,
+                Jerk,
+                Jerk,
+                MeterPerSecondCubed,
+                $num_units_unit,
+                meter_per_second_cubed,
+                $uom_unit
+            );
+        };
+    }
+
+    // Test SI prefixed meters per second cubed
+    test_uom_jerk!(YottameterPerSecondCubed, yottameter_per_second_cubed);
+    test_uom_jerk!(ZettameterPerSecondCubed, zettameter_per_second_cubed);
+    test_uom_jerk!(ExameterPerSecondCubed, exameter_per_second_cubed);
+    test_uom_jerk!(PetameterPerSecondCubed, petameter_per_second_cubed);
+    test_uom_jerk!(TerameterPerSecondCubed, terameter_per_second_cubed);
+    test_uom_jerk!(GigameterPerSecondCubed, gigameter_per_second_cubed);
+    test_uom_jerk!(MegameterPerSecondCubed, megameter_per_second_cubed);
+    test_uom_jerk!(KilometerPerSecondCubed, kilometer_per_second_cubed);
+    test_uom_jerk!(HectometerPerSecondCubed, hectometer_per_second_cubed);
+    test_uom_jerk!(DecameterPerSecondCubed, decameter_per_second_cubed);
+    test_uom_jerk!(MeterPerSecondCubed, meter_per_second_cubed);
+    test_uom_jerk!(DecimeterPerSecondCubed, decimeter_per_second_cubed);
+    test_uom_jerk!(CentimeterPerSecondCubed, centimeter_per_second_cubed);
+    test_uom_jerk!(MillimeterPerSecondCubed, millimeter_per_second_cubed);
+    test_uom_jerk!(MicrometerPerSecondCubed, micrometer_per_second_cubed);
+    test_uom_jerk!(NanometerPerSecondCubed, nanometer_per_second_cubed);
+    test_uom_jerk!(PicometerPerSecondCubed, picometer_per_second_cubed);
+    test_uom_jerk!(FemtometerPerSecondCubed, femtometer_per_second_cubed);
+    test_uom_jerk!(AttometerPerSecondCubed, attometer_per_second_cubed);
+    test_uom_jerk!(ZeptometerPerSecondCubed, zeptometer_per_second_cubed);
+    test_uom_jerk!(YoctometerPerSecondCubed, yoctometer_per_second_cubed);
+
+    // Test other units
+    test_uom_jerk!(FootPerSecondCubed, foot_per_second_cubed);
+    test_uom_jerk!(InchPerSecondCubed, inch_per_second_cubed);
+    test_uom_jerk!(KilometerPerMinuteCubed, kilometer_per_minute_cubed);
+
+    #[test]
+    fn test_standard_gravity_per_second() {
+        use crate::si::acceleration::{Acceleration, StandardGravity};
+        use crate::si::jerk::*;
+        use crate::si::time::{Second, Time};
+
+        let ramp = Acceleration::from::<StandardGravity>(1.0) / Time::from::<Second>(1.0);
+        let jerk: Jerk<f64> = ramp;
+        assert_eq!(jerk.to::<StandardGravityPerSecond>(), 1.0);
+        assert_eq!(jerk.to::<MeterPerSecondCubed>(), 9.80665);
+    }
+}
+
+// Re-export types for convenience
+pub use jerk::Jerk;
+pub use jerk::*;
@@ -0,0 +1,143 @@
+/// # LaTeX Output - siunitx Markup for SI Quantities
+///
+/// `Quantity::format_latex` writes an SI quantity as siunitx markup for
+/// reports, spelling the unit out of the quantity's base-dimension exponents:
+/// positive powers first, then each negative power after `\per`.
+///
+/// ## Example Usage
+///
+/// ```rust,ignore
+/// use num_units::si::acceleration::Acceleration;
+///
+/// let g = Acceleration::from_base(9.81);
+/// assert_eq!(
+///     format!("{}", g.format_latex()),
+///     r"\SI{9.81}{\meter\per\second\squared}"
+/// );
+/// ```
+use super::SiScale;
+use super::dyn_quantity::SI_DIMENSIONS;
+use crate::quantity::Quantity;
+use crate::system::DimensionVector;
+use core::fmt;
+
+/// siunitx macros of the SI base units, in `[L, M, T, I, Θ, N, J]` order
+const BASE_UNIT_MACROS: [&str; SI_DIMENSIONS] = [
+    r"\meter",
+    r"\kilogram",
+    r"\second",
+    r"\ampere",
+    r"\kelvin",
+    r"\mole",
+    r"\candela",
+];
+
+/// Formatter adapter returned by [`Quantity::format_latex`]
+///
+/// Formatter flags (precision, sign) apply to the value inside `\SI{...}`.
+#[derive(Clone, Copy, Debug)]
+pub struct LatexDisplay<'a, V> {
+    value: &'a V,
+    exponents: &'static [i8],
+}
+
+impl<V: fmt::Display> fmt::Display for LatexDisplay<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Dimensionless values have no unit argument for `\SI`
+        if self.exponents.iter().all(|&exponent| exponent == 0) {
+            f.write_str(r"\num{")?;
+            fmt::Display::fmt(self.value, f)?;
+            return f.write_str("}");
+        }
+        f.write_str(r"\SI{")?;
+        fmt::Display::fmt(self.value, f)?;
+        f.write_str("}{")?;
+        for (name, &exponent) in BASE_UNIT_MACROS.iter().zip(self.exponents) {
+            if exponent > 0 {
+                write_power(f, name, exponent)?;
+            }
+        }
+        for (name, &exponent) in BASE_UNIT_MACROS.iter().zip(self.exponents) {
+            if exponent < 0 {
+                f.write_str(r"\per")?;
+                write_power(f, name, -exponent)?;
+            }
+        }
+        f.write_str("}")
+    }
+}
+
+fn write_power(f: &mut fmt::Formatter<'_>, name: &str, exponent: i8) -> fmt::Result {
+    f.write_str(name)?;
+    match exponent {
+        1 => Ok(()),
+        2 => f.write_str(r"\squared"),
+        3 => f.write_str(r"\cubed"),
+        _ => write!(f, r"\tothe{{{exponent}}}"),
+    }
+}
+
+impl<V, D> Quantity<V, D, SiScale>
+where
+    V: fmt::Display,
+    D: DimensionVector,
+{
+    /// Display this quantity as siunitx markup, e.g.
+    /// `\SI{9.81}{\meter\per\second\squared}`
+    ///
+    /// The value is printed in SI base units; dimensionless quantities become
+    /// `\num{...}`.
+    pub fn format_latex(&self) -> LatexDisplay<'_, V> {
+        LatexDisplay {
+            value: &self.value,
+            exponents: D::EXPONENTS,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::si::acceleration::Acceleration;
+    use crate::si::force::Force;
+    use crate::si::heat_flux_density::HeatFluxDensity;
+    use crate::si::length::Length;
+    use crate::si::molar_concentration::MolarConcentration;
+    use crate::si::scalar::Scalar;
+
+    #[test]
+    fn test_format_latex_units() {
+        assert_eq!(
+            format!("{}", Acceleration::from_base(9.81).format_latex()),
+            r"\SI{9.81}{\meter\per\second\squared}"
+        );
+        assert_eq!(
+            format!("{}", Force::from_base(12).format_latex()),
+            r"\SI{12}{\meter\kilogram\per\second\squared}"
+        );
+        assert_eq!(
+            format!("{}", MolarConcentration::from_base(0.5).format_latex()),
+            r"\SI{0.5}{\mole\per\meter\cubed}"
+        );
+        assert_eq!(
+            format!("{}", HeatFluxDensity::from_base(1.0).format_latex()),
+            r"\SI{1}{\kilogram\per\second\cubed}"
+        );
+        let hypervolume = Length::from_base(2.0) * Length::from_base(2.0);
+        assert_eq!(
+            format!("{}", (hypervolume * hypervolume).format_latex()),
+            r"\SI{16}{\meter\tothe{4}}"
+        );
+    }
+
+    #[test]
+    fn test_format_latex_flags_and_dimensionless() {
+        assert_eq!(
+            format!("{:.3}", Length::from_base(2.0).format_latex()),
+            r"\SI{2.000}{\meter}"
+        );
+        assert_eq!(
+            format!("{}", Scalar::from_base(0.25).format_latex()),
+            r"\num{0.25}"
+        );
+    }
+}
@@ -40,22 +40,28 @@ units! {
     Yoctometer: "ym", "yoctometer";
 
     // Imperial and US customary units
-    Foot: "ft", "foot";
-    Inch: "in", "inch";
+    Foot: "ft", "foot", "feet";
+    Inch: "in", "inch", "inches";
     Mile: "mi", "mile";
     Yard: "yd", "yard";
     Chain: "ch", "chain";
     Rod: "rd", "rod";
     Fathom: "fathom", "fathom";
-    FootSurvey: "ft (U.S. survey)", "foot (U.S. survey)";
-    MileSurvey: "mi (U.S. survey)", "mile (U.S. survey)";
+    FootSurvey: "ft (U.S. survey)", "foot (U.S. survey)", "feet (U.S. survey)";
+    MileSurvey: "mi (U.S. survey)", "mile (U.S. survey)", "miles (U.S. survey)";
     Mil: "0.001 in", "mil";
-    Microinch: "μin", "microinch";
+    Microinch: "μin", "microinch", "microinches";
+
+    // Surveying units
+    Furlong: "fur", "furlong";
+    League: "lea", "league";
+    Hand: "hh", "hand";
+    Link: "li", "link";
 
     // Scientific and specialized units
     Angstrom: "Å", "ångström";
-    BohrRadius: "a₀", "bohr radius";
-    AtomicUnitOfLength: "a.u. of length", "atomic unit of length";
+    BohrRadius: "a₀", "bohr radius", "bohr radii";
+    AtomicUnitOfLength: "a.u. of length", "atomic unit of length", "atomic units of length";
     AstronomicalUnit: "ua", "astronomical unit";
     LightYear: "l. y.", "light year";
     Parsec: "pc", "parsec";
@@ -63,60 +69,42 @@ units! {
     NauticalMile: "M", "nautical mile";
     Micron: "μ", "micron";
 
+    // Astronomical units
+    LightSecond: "ls", "light-second";
+    LightMinute: "lmin", "light-minute";
+    LightHour: "lh", "light-hour";
+    EarthRadius: "R⊕", "Earth radius", "Earth radii";
+    SolarRadius: "R☉", "solar radius", "solar radii";
+
     // Typography units
-    PicaComputer: "1/6 in (computer)", "pica (computer)";
-    PicaPrinters: "1/6 in", "pica (printer's)";
-    PointComputer: "1/72 in (computer)", "point (computer)";
-    PointPrinters: "1/72 in", "point (printer's)";
+    PicaComputer: "1/6 in (computer)", "pica (computer)", "picas (computer)";
+    PicaPrinters: "1/6 in", "pica (printer's)", "picas (printer's)";
+    PointComputer: "1/72 in (computer)", "point (computer)", "points (computer)";
+    PointPrinters: "1/72 in", "point (printer's)", "points (printer's)";
+    Twip: "twip", "twip";
+    Agate: "agate", "agate";
+    PointDidot: "p (Didot)", "point (Didot)", "points (Didot)";
+    Cicero: "c", "cicero";
 }
 
 // Meter is the SI base unit for length
 // Using convert_linear! with derived units on the left, base unit on the right
 
-use crate::prefix::{
-    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
-    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
-};
-
 // Unit conversions using convert_linear! with multiple conversions
 crate::convert_linear! {
-    // Large SI prefix units
-    Yottameter => Meter: YOTTA;    // 1 Ym = 10^24 m
-    Zettameter => Meter: ZETTA;    // 1 Zm = 10^21 m
-    Exameter => Meter: EXA;        // 1 Em = 10^18 m
-    Petameter => Meter: PETA;      // 1 Pm = 10^15 m
-    Terameter => Meter: TERA;      // 1 Tm = 10^12 m
-    Gigameter => Meter: GIGA;      // 1 Gm = 10^9 m
-    Megameter => Meter: MEGA;      // 1 Mm = 10^6 m
-    Kilometer => Meter: KILO;      // 1 km = 1000 m
-    Hectometer => Meter: HECTO;    // 1 hm = 100 m
-    Decameter => Meter: DECA;      // 1 dam = 10 m
-
-    // Small SI prefix units
-    Decimeter => Meter: DECI;      // 1 dm = 0.1 m
-    Centimeter => Meter: CENTI;    // 1 cm = 0.01 m
-    Millimeter => Meter: MILLI;    // 1 mm = 0.001 m
-    Micrometer => Meter: MICRO;    // 1 μm = 0.000001 m
-    Nanometer => Meter: NANO;      // 1 nm = 0.000000001 m
-    Picometer => Meter: PICO;      // 1 pm = 10^-12 m
-    Femtometer => Meter: FEMTO;    // 1 fm = 10^-15 m
-    Attometer => Meter: ATTO;      // 1 am = 10^-18 m
-    Zeptometer => Meter: ZEPTO;    // 1 zm = 10^-21 m
-    Yoctometer => Meter: YOCTO;    // 1 ym = 10^-24 m
-
     // Imperial and US customary units
-    Foot => Meter: 3.048E-1;       // 1 ft = 0.3048 m
-    Inch => Meter: 2.54E-2;        // 1 in = 0.0254 m
-    Mile => Meter: 1.609344E3;     // 1 mi = 1609.344 m
-    Yard => Meter: 9.144E-1;       // 1 yd = 0.9144 m
     Chain => Meter: 2.011684E1;    // 1 ch = 20.11684 m
     Rod => Meter: 5.02921E0;       // 1 rd = 5.02921 m
     Fathom => Meter: 1.828804E0;   // 1 fathom = 1.828804 m
     FootSurvey => Meter: 3.048006E-1;  // 1 ft (U.S. survey) = 0.3048006 m
     MileSurvey => Meter: 1.609347E3;   // 1 mi (U.S. survey) = 1609.347 m
-    Mil => Meter: 2.54E-5;         // 1 mil = 0.0000254 m
     Microinch => Meter: 2.54E-8;   // 1 μin = 0.0000000254 m
 
+    // Surveying units
+    Furlong => Meter: 2.01168E2;   // 1 fur = 220 yd = 201.168 m
+    League => Meter: 4.828032E3;   // 1 lea = 3 mi = 4828.032 m
+    Link => Meter: 2.011684E-1;    // 1 li = 1/100 ch = 0.2011684 m
+
     // Scientific and specialized units
     Angstrom => Meter: 1.0E-10;               // 1 Å = 10^-10 m
     BohrRadius => Meter: 5.291772109030E-11;   // 1 a₀ = 5.291772... × 10^-11 m
@@ -125,23 +113,75 @@ crate::convert_linear! {
     LightYear => Meter: 9.46073E15;           // 1 l.y. = 9.46073 × 10^15 m
     Parsec => Meter: 3.085678E16;             // 1 pc = 3.085678 × 10^16 m
     Fermi => Meter: 1.0E-15;                  // 1 fermi = 10^-15 m
-    NauticalMile => Meter: 1.852E3;           // 1 M = 1852 m
     Micron => Meter: 1.0E-6;                  // 1 μ = 10^-6 m (same as micrometer)
 
+    // Astronomical units
+    LightSecond => Meter: 2.99792458E8;       // 1 ls = 299 792 458 m (exact)
+    LightMinute => Meter: 1.798754748E10;     // 1 lmin = 60 ls
+    LightHour => Meter: 1.0792528488E12;      // 1 lh = 3600 ls
+    EarthRadius => Meter: 6.3781E6;           // IAU nominal equatorial Earth radius
+    SolarRadius => Meter: 6.957E8;            // IAU nominal solar radius
+
     // Typography units
     PicaComputer => Meter: 4.233333333333333E-3;  // 1/6 in (computer) = 4.233... × 10^-3 m
     PicaPrinters => Meter: 4.217518E-3;           // 1/6 in = 4.217518 × 10^-3 m
     PointComputer => Meter: 3.527778E-4;          // 1/72 in (computer) = 3.527778 × 10^-4 m
     PointPrinters => Meter: 3.514598E-4;          // 1/72 in = 3.514598 × 10^-4 m
+    Twip => Meter: 1.763888888888889E-5;          // 1/20 pt (computer) = 1/1440 in
+    Agate => Meter: 1.8142857142857142E-3;        // 1/14 in (newspaper column depth)
+    PointDidot => Meter: 3.76065E-4;              // 0.376065 mm (traditional Didot point; DIN 16507 rounds it to 0.375 mm)
+    Cicero => Meter: 4.51278E-3;                  // 12 Didot points
 }
 
-convert_matrix! {
+// SI prefixes and units defined by an exact fraction of a meter, so integer
+// lengths convert without going through f64
+crate::convert_int_linear! {
+    Yottameter => Meter: 1_000_000_000_000_000_000_000_000;
+    Zettameter => Meter: 1_000_000_000_000_000_000_000;
+    Exameter => Meter: 1_000_000_000_000_000_000;
+    Petameter => Meter: 1_000_000_000_000_000;
+    Terameter => Meter: 1_000_000_000_000;
+    Gigameter => Meter: 1_000_000_000;
+    Megameter => Meter: 1_000_000;
+    Kilometer => Meter: 1_000;
+    Hectometer => Meter: 100;
+    Decameter => Meter: 10;
+    Decimeter => Meter: 1 / 10;
+    Centimeter => Meter: 1 / 100;
+    Millimeter => Meter: 1 / 1_000;
+    Micrometer => Meter: 1 / 1_000_000;
+    Nanometer => Meter: 1 / 1_000_000_000;
+    Picometer => Meter: 1 / 1_000_000_000_000;
+    Femtometer => Meter: 1 / 1_000_000_000_000_000;
+    Attometer => Meter: 1 / 1_000_000_000_000_000_000;
+    Zeptometer => Meter: 1 / 1_000_000_000_000_000_000_000;
+    Yoctometer => Meter: 1 / 1_000_000_000_000_000_000_000_000;
+    Foot => Meter: 3048 / 10_000;
+    Inch => Meter: 254 / 10_000;
+    Yard => Meter: 9144 / 10_000;
+    Mile => Meter: 1_609_344 / 1_000;
+    Mil => Meter: 254 / 10_000_000;
+    Hand => Meter: 1016 / 10_000;
+    NauticalMile => Meter: 1852;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
     Meter => Yottameter, Zettameter, Exameter, Petameter, Terameter, Gigameter, Megameter,
-             Kilometer, Hectometer, Decameter, Decimeter, Centimeter, Millimeter,
-             Micrometer, Nanometer, Picometer, Femtometer, Attometer, Zeptometer, Yoctometer,
-             Foot, Inch, Mile, Yard, Chain, Rod, Fathom, FootSurvey, MileSurvey, Mil, Microinch,
-             Angstrom, BohrRadius, AtomicUnitOfLength, AstronomicalUnit, LightYear, Parsec,
-             Fermi, NauticalMile, Micron, PicaComputer, PicaPrinters, PointComputer, PointPrinters
+             Kilometer, Millimeter, Micrometer, Nanometer, Picometer, Femtometer, Attometer,
+             Zeptometer, Yoctometer
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    Meter => Yottameter, Zettameter, Exameter, Petameter, Terameter, Gigameter, Megameter,
+             Kilometer, Hectometer, Decameter, Decimeter, Centimeter, Millimeter, Micrometer,
+             Nanometer, Picometer, Femtometer, Attometer, Zeptometer, Yoctometer, Foot, Inch, Mile,
+             Yard, Chain, Rod, Fathom, FootSurvey, MileSurvey, Mil, Microinch, Furlong, League,
+             Hand, Link, Angstrom, BohrRadius, AtomicUnitOfLength, AstronomicalUnit, LightYear,
+             Parsec, Fermi, NauticalMile, Micron, LightSecond, LightMinute, LightHour, EarthRadius,
+             SolarRadius, PicaComputer, PicaPrinters, PointComputer, PointPrinters, Twip, Agate,
+             PointDidot, Cicero
 }
 
 // Length quantity definition
@@ -220,4 +260,111 @@ mod tests {
     test_uom_length!(PicaPrinters, pica_printers);
     test_uom_length!(PointComputer, point_computer);
     test_uom_length!(PointPrinters, point_printers);
+
+    // Surveying and astronomical units are not defined by UOM
+    #[test]
+    fn test_surveying_units() {
+        use crate::si::length::*;
+
+        let furlong = Length::from::<Furlong>(1.0);
+        assert!((furlong.to::<Yard>() - 220.0_f64).abs() < 1e-9);
+        assert!((Length::from::<Chain>(1.0).to::<Link>() - 100.0_f64).abs() < 1e-9);
+        assert!((Length::from::<League>(1.0).to::<Mile>() - 3.0_f64).abs() < 1e-12);
+        assert!((Length::from::<Hand>(1.0).to::<Inch>() - 4.0_f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_typography_units() {
+        use crate::si::length::*;
+
+        let point = Length::from::<PointComputer>(1.0);
+        assert!((point.to::<Twip>() - 20.0_f64).abs() < 1e-5);
+        assert!((Length::from::<Inch>(1.0).to::<Twip>() - 1440.0_f64).abs() < 1e-9);
+        assert!((Length::from::<Inch>(1.0).to::<Agate>() - 14.0_f64).abs() < 1e-12);
+
+        let cicero = Length::from::<Cicero>(1.0);
+        assert!((cicero.to::<PointDidot>() - 12.0_f64).abs() < 1e-12);
+        assert!((cicero.to::<Millimeter>() - 4.512_78_f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_astronomical_units() {
+        use crate::si::length::*;
+
+        let light_hour = Length::from::<LightHour>(1.0);
+        assert!((light_hour.to::<LightMinute>() - 60.0_f64).abs() < 1e-9);
+        assert!((light_hour.to::<LightSecond>() - 3600.0_f64).abs() < 1e-9);
+
+        // The Sun is roughly 109 Earth radii across
+        let ratio = Length::from::<SolarRadius>(1.0).to::<EarthRadius>();
+        assert!((ratio - 109.076_f64).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_unit_to_unit_routing() {
+        use crate::conversions::ConvertUnit;
+        use crate::si::length::*;
+
+        // Neither unit is the base; both route through the meter
+        assert!((<Foot as ConvertUnit<Inch>>::convert(1.0_f64) - 12.0).abs() < 1e-12);
+        assert!((<Mile as ConvertUnit<Kilometer, f32>>::convert(1.0) - 1.609_344).abs() < 1e-6);
+        assert_eq!(<Meter as ConvertUnit<Millimeter>>::convert(2.5), 2500.0);
+        assert_eq!(<Millimeter as ConvertUnit<Meter>>::convert(2500.0), 2.5);
+    }
+
+    #[test]
+    fn test_exact_factors_round_like_literals() {
+        use crate::conversions::ratio_to_f64;
+        use crate::si::length::*;
+        use crate::unit::UnitFactor;
+
+        // `1.0 / 1e24` is one ulp off 1e-24; the long division is not
+        assert_eq!(<Meter as UnitFactor<Yoctometer>>::FACTOR, 1e-24);
+        assert_eq!(<Meter as UnitFactor<Zeptometer>>::FACTOR, 1e-21);
+        assert_eq!(<Meter as UnitFactor<Yottameter>>::FACTOR, 1e24);
+        assert_eq!(<Meter as UnitFactor<Foot>>::FACTOR, 0.3048);
+        assert_eq!(<Meter as UnitFactor<Mil>>::FACTOR, 2.54e-5);
+        assert_eq!(ratio_to_f64(1, 3), 1.0 / 3.0);
+        assert_eq!(ratio_to_f64(10u128.pow(27), 1), 1e27);
+    }
+
+    #[test]
+    fn test_integer_native_conversions() {
+        use crate::conversions::ConvertIntUnit;
+        use crate::si::length::*;
+
+        // Direct factors, rounded half away from zero
+        assert_eq!(
+            <Micrometer as ConvertIntUnit<Millimeter, i64>>::convert_int(1_500),
+            2
+        );
+        assert_eq!(
+            <Micrometer as ConvertIntUnit<Millimeter, i64>>::convert_int(-1_499),
+            -1
+        );
+        assert_eq!(<Mile as ConvertIntUnit<Foot, u32>>::convert_int(3), 15_840);
+        assert_eq!(
+            <Inch as ConvertIntUnit<Micrometer, i32>>::convert_int(2),
+            50_800
+        );
+        // Beyond f64's 53-bit mantissa, yet exact
+        let far = 9_007_199_254_740_993_i64;
+        assert_eq!(
+            <Kilometer as ConvertIntUnit<Meter, i128>>::convert_int(far.into()),
+            i128::from(far) * 1_000
+        );
+
+        let track = Length::from::<Kilometer>(42_i64);
+        assert_eq!(*track.base(), 42_000);
+        assert_eq!(track.to::<Millimeter>(), 42_000_000);
+        assert_eq!(Length::from::<Foot>(10_000_i64).to::<Meter>(), 3_048);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn test_integer_conversion_overflow_panics() {
+        use crate::si::length::*;
+
+        let _ = Length::from::<Kilometer>(100_i8);
+    }
 }
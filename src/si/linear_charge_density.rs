@@ -0,0 +1,186 @@
+/// # Linear Charge Density Units - SI Linear Charge Density Measurements
+///
+/// This module defines SI linear charge density units and their conversions. Linear charge
+/// density is the electric charge per unit length along a wire, beam or filament, with
+/// coulomb per meter as the SI base unit.
+///
+/// ## Base Unit
+///
+/// - **CoulombPerMeter (C/m)**: The SI base unit of linear charge density
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for coulombs per meter:
+/// - **YottacoulombPerMeter (YC/m)**: 10²⁴ coulombs per meter
+/// - ... down to ...
+/// - **YoctocoulombPerMeter (yC/m)**: 10⁻²⁴ coulombs per meter
+///
+/// ## Other Units
+///
+/// - **CoulombPerCentimeter (C/cm)**: 100 coulombs per meter
+/// - **CoulombPerMillimeter (C/mm)**: 1000 coulombs per meter
+/// - **StatcoulombPerCentimeter (statC/cm)**: Gaussian CGS unit, ≈ 3.336 × 10⁻⁸ C/m
+/// - **AbcoulombPerCentimeter (abC/cm)**: Electromagnetic CGS unit, 1000 C/m
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::linear_charge_density::{CoulombPerMeter, LinearChargeDensity, NanocoulombPerMeter};
+///
+/// // Create linear charge density quantities
+/// let wire = LinearChargeDensity::from::<CoulombPerMeter>(3.0e-9);
+///
+/// // Convert between units
+/// let wire_nc = wire.to::<NanocoulombPerMeter>(); // 3.0 nC/m
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// Gaussian and electromagnetic CGS charge units in coulombs
+const STATCOULOMB: f64 = 1.0 / 2.997_924_58_E9;
+const ABCOULOMB: f64 = 10.0;
+
+// SI base unit
+units! {
+    CoulombPerMeter: "C/m", "coulomb per meter", "coulombs per meter";
+}
+
+// SI prefixed coulombs per meter
+units! {
+    YottacoulombPerMeter: "YC/m", "yottacoulomb per meter", "yottacoulombs per meter";
+    ZettacoulombPerMeter: "ZC/m", "zettacoulomb per meter", "zettacoulombs per meter";
+    ExacoulombPerMeter: "EC/m", "exacoulomb per meter", "exacoulombs per meter";
+    PetacoulombPerMeter: "PC/m", "petacoulomb per meter", "petacoulombs per meter";
+    TeracoulombPerMeter: "TC/m", "teracoulomb per meter", "teracoulombs per meter";
+    GigacoulombPerMeter: "GC/m", "gigacoulomb per meter", "gigacoulombs per meter";
+    MegacoulombPerMeter: "MC/m", "megacoulomb per meter", "megacoulombs per meter";
+    KilocoulombPerMeter: "kC/m", "kilocoulomb per meter", "kilocoulombs per meter";
+    HectocoulombPerMeter: "hC/m", "hectocoulomb per meter", "hectocoulombs per meter";
+    DecacoulombPerMeter: "daC/m", "decacoulomb per meter", "decacoulombs per meter";
+    DecicoulombPerMeter: "dC/m", "decicoulomb per meter", "decicoulombs per meter";
+    CenticoulombPerMeter: "cC/m", "centicoulomb per meter", "centicoulombs per meter";
+    MillicoulombPerMeter: "mC/m", "millicoulomb per meter", "millicoulombs per meter";
+    MicrocoulombPerMeter: "µC/m", "microcoulomb per meter", "microcoulombs per meter";
+    NanocoulombPerMeter: "nC/m", "nanocoulomb per meter", "nanocoulombs per meter";
+    PicocoulombPerMeter: "pC/m", "picocoulomb per meter", "picocoulombs per meter";
+    FemtocoulombPerMeter: "fC/m", "femtocoulomb per meter", "femtocoulombs per meter";
+    AttocoulombPerMeter: "aC/m", "attocoulomb per meter", "attocoulombs per meter";
+    ZeptocoulombPerMeter: "zC/m", "zeptocoulomb per meter", "zeptocoulombs per meter";
+    YoctocoulombPerMeter: "yC/m", "yoctocoulomb per meter", "yoctocoulombs per meter";
+}
+
+// Other units
+units! {
+    CoulombPerCentimeter: "C/cm", "coulomb per centimeter", "coulombs per centimeter";
+    CoulombPerMillimeter: "C/mm", "coulomb per millimeter", "coulombs per millimeter";
+    StatcoulombPerCentimeter: "statC/cm", "statcoulomb per centimeter", "statcoulombs per centimeter";
+    AbcoulombPerCentimeter: "abC/cm", "abcoulomb per centimeter", "abcoulombs per centimeter";
+}
+
+// Unit conversions using convert_linear!
+crate::convert_linear! {
+    // SI prefixed coulombs per meter
+    YottacoulombPerMeter => CoulombPerMeter: YOTTA;
+    ZettacoulombPerMeter => CoulombPerMeter: ZETTA;
+    ExacoulombPerMeter => CoulombPerMeter: EXA;
+    PetacoulombPerMeter => CoulombPerMeter: PETA;
+    TeracoulombPerMeter => CoulombPerMeter: TERA;
+    GigacoulombPerMeter => CoulombPerMeter: GIGA;
+    MegacoulombPerMeter => CoulombPerMeter: MEGA;
+    KilocoulombPerMeter => CoulombPerMeter: KILO;
+    HectocoulombPerMeter => CoulombPerMeter: HECTO;
+    DecacoulombPerMeter => CoulombPerMeter: DECA;
+    DecicoulombPerMeter => CoulombPerMeter: DECI;
+    CenticoulombPerMeter => CoulombPerMeter: CENTI;
+    MillicoulombPerMeter => CoulombPerMeter: MILLI;
+    MicrocoulombPerMeter => CoulombPerMeter: MICRO;
+    NanocoulombPerMeter => CoulombPerMeter: NANO;
+    PicocoulombPerMeter => CoulombPerMeter: PICO;
+    FemtocoulombPerMeter => CoulombPerMeter: FEMTO;
+    AttocoulombPerMeter => CoulombPerMeter: ATTO;
+    ZeptocoulombPerMeter => CoulombPerMeter: ZEPTO;
+    YoctocoulombPerMeter => CoulombPerMeter: YOCTO;
+
+    // Other units
+    CoulombPerCentimeter => CoulombPerMeter: 1.0 / CENTI;
+    CoulombPerMillimeter => CoulombPerMeter: 1.0 / MILLI;
+    StatcoulombPerCentimeter => CoulombPerMeter: STATCOULOMB / CENTI;
+    AbcoulombPerCentimeter => CoulombPerMeter: ABCOULOMB / CENTI;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    CoulombPerMeter => YottacoulombPerMeter, ZettacoulombPerMeter, ExacoulombPerMeter,
+                       PetacoulombPerMeter, TeracoulombPerMeter, GigacoulombPerMeter,
+                       MegacoulombPerMeter, KilocoulombPerMeter, MillicoulombPerMeter,
+                       MicrocoulombPerMeter, NanocoulombPerMeter, PicocoulombPerMeter,
+                       FemtocoulombPerMeter, AttocoulombPerMeter, ZeptocoulombPerMeter,
+                       YoctocoulombPerMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    CoulombPerMeter => YottacoulombPerMeter, ZettacoulombPerMeter, ExacoulombPerMeter,
+                       PetacoulombPerMeter, TeracoulombPerMeter, GigacoulombPerMeter,
+                       MegacoulombPerMeter, KilocoulombPerMeter, HectocoulombPerMeter,
+                       DecacoulombPerMeter, DecicoulombPerMeter, CenticoulombPerMeter,
+                       MillicoulombPerMeter, MicrocoulombPerMeter, NanocoulombPerMeter,
+                       PicocoulombPerMeter, FemtocoulombPerMeter, AttocoulombPerMeter,
+                       ZeptocoulombPerMeter, YoctocoulombPerMeter, CoulombPerCentimeter,
+                       CoulombPerMillimeter, StatcoulombPerCentimeter, AbcoulombPerCentimeter
+}
+
+// Linear Charge Density quantity definition (Charge/Length)
+use super::{ISQ, SiScale};
+quantity!(LinearChargeDensity, ISQ<N1, Z0, P1, P1, Z0, Z0, Z0>, SiScale, CoulombPerMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_linear_charge_density {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::linear_charge_density,
+                uom::si::electric_charge_linear_density,
+                LinearChargeDensity,
+                ElectricChargeLinearDensity,
+                CoulombPerMeter,
+                $num_units_unit,
+                coulomb_per_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_linear_charge_density!(CoulombPerMeter, coulomb_per_meter);
+    test_uom_linear_charge_density!(CoulombPerCentimeter, coulomb_per_centimeter);
+
+    #[test]
+    fn test_prefixed_units() {
+        use crate::si::linear_charge_density::*;
+
+        let density = LinearChargeDensity::from::<MillicoulombPerMeter>(1.0);
+        assert_eq!(density.to::<CoulombPerMeter>(), 1.0e-3);
+        let density = LinearChargeDensity::from::<KilocoulombPerMeter>(1.0);
+        assert_eq!(density.to::<CoulombPerMeter>(), 1000.0);
+    }
+
+    #[test]
+    fn test_cgs_units() {
+        use crate::si::linear_charge_density::*;
+
+        let gaussian = LinearChargeDensity::from::<StatcoulombPerCentimeter>(2.997_924_58e9);
+        assert!((gaussian.to::<CoulombPerCentimeter>() - 1.0_f64).abs() < 1e-12);
+
+        let electromagnetic = LinearChargeDensity::from::<AbcoulombPerCentimeter>(1.0);
+        assert!((electromagnetic.to::<CoulombPerCentimeter>() - 10.0_f64).abs() < 1e-12);
+    }
+}
+
+// Re-export types for convenience
+pub use linear_charge_density::LinearChargeDensity;
+pub use linear_charge_density::*;
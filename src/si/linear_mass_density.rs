@@ -0,0 +1,131 @@
+/// # Linear Mass Density Units - SI Linear Mass Density Measurements
+///
+/// This module defines SI linear mass density units and their conversions. Linear mass density
+/// is the mass per unit length of a fiber, yarn, wire or cable, with kilogram per meter as the
+/// SI base unit. Textile yarn counts use the tex family and the denier.
+///
+/// ## Base Unit
+///
+/// - **KilogramPerMeter (kg/m)**: The SI base unit of linear mass density
+///
+/// ## Textile Units
+///
+/// - **Tex (tex)**: One gram per kilometer
+/// - **Decitex (dtex)**: One gram per ten kilometers
+/// - **Denier (den)**: One gram per nine kilometers
+///
+/// ## Other Units
+///
+/// - **GramPerKilometer (g/km)**, **GramPerCentimeter (g/cm)**: Metric linear densities
+/// - **OuncePerFoot (oz/ft)**, **OuncePerInch (oz/in)**: Imperial ounce-based densities
+/// - **PoundPerYard (lb/yd)**, **PoundPerFoot (lb/ft)**, **PoundPerInch (lb/in)**: Imperial
+///   pound-based densities, used for rails and cables
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::linear_mass_density::{Decitex, Denier, LinearMassDensity};
+///
+/// // Polyester filament yarn
+/// let yarn = LinearMassDensity::from::<Denier>(150.0);
+///
+/// // Convert between units
+/// let yarn_dtex = yarn.to::<Decitex>(); // ≈ 166.7 dtex
+/// ```
+use crate::prefix::{CENTI, DECI, KILO, MILLI};
+use typenum::*;
+
+// SI base unit
+units! {
+    KilogramPerMeter: "kg/m", "kilogram per meter", "kilograms per meter";
+}
+
+// Other units
+units! {
+    Tex: "tex", "tex", "tex";
+    Decitex: "dtex", "decitex", "decitex";
+    Denier: "den", "denier", "denier";
+    GramPerKilometer: "g/km", "gram per kilometer", "grams per kilometer";
+    GramPerCentimeter: "g/cm", "gram per centimeter", "grams per centimeter";
+    OuncePerFoot: "oz/ft", "ounce per foot", "ounces per foot";
+    OuncePerInch: "oz/in", "ounce per inch", "ounces per inch";
+    PoundPerYard: "lb/yd", "pound per yard", "pounds per yard";
+    PoundPerFoot: "lb/ft", "pound per foot", "pounds per foot";
+    PoundPerInch: "lb/in", "pound per inch", "pounds per inch";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // Other units
+    Tex => KilogramPerMeter: MILLI / KILO;
+    Decitex => KilogramPerMeter: DECI * MILLI / KILO;
+    Denier => KilogramPerMeter: MILLI / (9.0 * KILO);
+    GramPerKilometer => KilogramPerMeter: MILLI / KILO;
+    GramPerCentimeter => KilogramPerMeter: MILLI / CENTI;
+    OuncePerFoot => KilogramPerMeter: 2.834_952_E-2 / 3.048_E-1;
+    OuncePerInch => KilogramPerMeter: 2.834_952_E-2 / 2.54_E-2;
+    PoundPerYard => KilogramPerMeter: 4.535_924_E-1 / 9.144_E-1;
+    PoundPerFoot => KilogramPerMeter: 4.535_924_E-1 / 3.048_E-1;
+    PoundPerInch => KilogramPerMeter: 4.535_924_E-1 / 2.54_E-2;
+}
+
+// No engineering-prefixed units; `Quantity::format_si` keeps the base unit
+crate::si_prefixed!(KilogramPerMeter);
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    KilogramPerMeter => Tex, Decitex, Denier, GramPerKilometer, GramPerCentimeter, OuncePerFoot,
+                        OuncePerInch, PoundPerYard, PoundPerFoot, PoundPerInch
+}
+
+// Linear mass density quantity definition (Mass/Length)
+use super::{ISQ, SiScale};
+quantity!(LinearMassDensity, ISQ<N1, P1, Z0, Z0, Z0, Z0, Z0>, SiScale, KilogramPerMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_linear_mass_density {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::linear_mass_density,
+                uom::si::linear_mass_density,
+                LinearMassDensity,
+                LinearMassDensity,
+                KilogramPerMeter,
+                $num_units_unit,
+                kilogram_per_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_linear_mass_density!(KilogramPerMeter, kilogram_per_meter);
+    test_uom_linear_mass_density!(GramPerKilometer, gram_per_kilometer);
+    test_uom_linear_mass_density!(GramPerCentimeter, gram_per_centimeter);
+    test_uom_linear_mass_density!(OuncePerFoot, ounce_per_foot);
+    test_uom_linear_mass_density!(OuncePerInch, ounce_per_inch);
+    test_uom_linear_mass_density!(PoundPerYard, pound_per_yard);
+    test_uom_linear_mass_density!(PoundPerFoot, pound_per_foot);
+    test_uom_linear_mass_density!(PoundPerInch, pound_per_inch);
+
+    #[test]
+    fn test_textile_units() {
+        use crate::si::length::{Kilometer, Length};
+        use crate::si::linear_mass_density::*;
+        use crate::si::mass::{Gram, Mass};
+
+        let yarn: LinearMassDensity<f64> = Mass::from::<Gram>(3.0) / Length::from::<Kilometer>(1.0);
+        assert!((yarn.to::<Tex>() - 3.0_f64).abs() < 1e-12);
+        assert!((yarn.to::<Decitex>() - 30.0_f64).abs() < 1e-12);
+        assert!((yarn.to::<Denier>() - 27.0_f64).abs() < 1e-12);
+
+        let filament = LinearMassDensity::from::<Denier>(150.0);
+        assert!((filament.to::<Decitex>() - 166.666_666_666_666_7_f64).abs() < 1e-9);
+    }
+}
+
+// Re-export types for convenience
+pub use linear_mass_density::LinearMassDensity;
+pub use linear_mass_density::*;
@@ -0,0 +1,213 @@
+/// # Luminance Units - SI Luminance Measurements
+///
+/// This module defines SI luminance units and their conversions. Luminance is the luminous
+/// intensity emitted or reflected per unit of projected area in a given direction, with
+/// candela per square meter as the SI base unit. Display brightness is specified in nits.
+///
+/// ## Base Unit
+///
+/// - **CandelaPerSquareMeter (cd/m²)**: The SI base unit of luminance
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for candelas per square meter:
+/// - **YottacandelaPerSquareMeter (Ycd/m²)**: 10²⁴ candelas per square meter
+/// - ... down to ...
+/// - **YoctocandelaPerSquareMeter (ycd/m²)**: 10⁻²⁴ candelas per square meter
+///
+/// ## Other Units
+///
+/// - **Nit (nt)**: Display-industry name for one candela per square meter
+/// - **CandelaPerSquareCentimeter (cd/cm²)**: 10⁴ candelas per square meter
+/// - **CandelaPerSquareInch (cd/in²)**: ≈ 1550 candelas per square meter
+/// - **CandelaPerSquareFoot (cd/ft²)**: ≈ 10.764 candelas per square meter
+/// - **Stilb (sb)**: The CGS unit, one candela per square centimeter
+/// - **Lambert (la)**: 10⁴/π candelas per square meter
+/// - **Footlambert (fl)**: 1/π candelas per square foot, ≈ 3.426 candelas per square meter
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::luminance::{Footlambert, Luminance, Nit};
+///
+/// // HDR monitor peak brightness
+/// let peak = Luminance::from::<Nit>(1000.0);
+///
+/// // Convert between units
+/// let peak_fl = peak.to::<Footlambert>(); // ≈ 291.9 fl
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    CandelaPerSquareMeter: "cd/m²", "candela per square meter", "candelas per square meter";
+}
+
+// SI prefixed candelas per square meter
+units! {
+    YottacandelaPerSquareMeter: "Ycd/m²", "yottacandela per square meter", "yottacandelas per square meter";
+    ZettacandelaPerSquareMeter: "Zcd/m²", "zettacandela per square meter", "zettacandelas per square meter";
+    ExacandelaPerSquareMeter: "Ecd/m²", "exacandela per square meter", "exacandelas per square meter";
+    PetacandelaPerSquareMeter: "Pcd/m²", "petacandela per square meter", "petacandelas per square meter";
+    TeracandelaPerSquareMeter: "Tcd/m²", "teracandela per square meter", "teracandelas per square meter";
+    GigacandelaPerSquareMeter: "Gcd/m²", "gigacandela per square meter", "gigacandelas per square meter";
+    MegacandelaPerSquareMeter: "Mcd/m²", "megacandela per square meter", "megacandelas per square meter";
+    KilocandelaPerSquareMeter: "kcd/m²", "kilocandela per square meter", "kilocandelas per square meter";
+    HectocandelaPerSquareMeter: "hcd/m²", "hectocandela per square meter", "hectocandelas per square meter";
+    DecacandelaPerSquareMeter: "dacd/m²", "decacandela per square meter", "decacandelas per square meter";
+    DecicandelaPerSquareMeter: "dcd/m²", "decicandela per square meter", "decicandelas per square meter";
+    CenticandelaPerSquareMeter: "ccd/m²", "centicandela per square meter", "centicandelas per square meter";
+    MillicandelaPerSquareMeter: "mcd/m²", "millicandela per square meter", "millicandelas per square meter";
+    MicrocandelaPerSquareMeter: "µcd/m²", "microcandela per square meter", "microcandelas per square meter";
+    NanocandelaPerSquareMeter: "ncd/m²", "nanocandela per square meter", "nanocandelas per square meter";
+    PicocandelaPerSquareMeter: "pcd/m²", "picocandela per square meter", "picocandelas per square meter";
+    FemtocandelaPerSquareMeter: "fcd/m²", "femtocandela per square meter", "femtocandelas per square meter";
+    AttocandelaPerSquareMeter: "acd/m²", "attocandela per square meter", "attocandelas per square meter";
+    ZeptocandelaPerSquareMeter: "zcd/m²", "zeptocandela per square meter", "zeptocandelas per square meter";
+    YoctocandelaPerSquareMeter: "ycd/m²", "yoctocandela per square meter", "yoctocandelas per square meter";
+}
+
+// Other units
+units! {
+    Nit: "nt", "nit", "nits";
+    CandelaPerSquareCentimeter: "cd/cm²", "candela per square centimeter", "candelas per square centimeter";
+    CandelaPerSquareInch: "cd/in²", "candela per square inch", "candelas per square inch";
+    CandelaPerSquareFoot: "cd/ft²", "candela per square foot", "candelas per square foot";
+    Stilb: "sb", "stilb", "stilbs";
+    Lambert: "la", "lambert", "lamberts";
+    Footlambert: "fl", "footlambert", "footlamberts";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed candelas per square meter
+    YottacandelaPerSquareMeter => CandelaPerSquareMeter: YOTTA;
+    ZettacandelaPerSquareMeter => CandelaPerSquareMeter: ZETTA;
+    ExacandelaPerSquareMeter => CandelaPerSquareMeter: EXA;
+    PetacandelaPerSquareMeter => CandelaPerSquareMeter: PETA;
+    TeracandelaPerSquareMeter => CandelaPerSquareMeter: TERA;
+    GigacandelaPerSquareMeter => CandelaPerSquareMeter: GIGA;
+    MegacandelaPerSquareMeter => CandelaPerSquareMeter: MEGA;
+    KilocandelaPerSquareMeter => CandelaPerSquareMeter: KILO;
+    HectocandelaPerSquareMeter => CandelaPerSquareMeter: HECTO;
+    DecacandelaPerSquareMeter => CandelaPerSquareMeter: DECA;
+    DecicandelaPerSquareMeter => CandelaPerSquareMeter: DECI;
+    CenticandelaPerSquareMeter => CandelaPerSquareMeter: CENTI;
+    MillicandelaPerSquareMeter => CandelaPerSquareMeter: MILLI;
+    MicrocandelaPerSquareMeter => CandelaPerSquareMeter: MICRO;
+    NanocandelaPerSquareMeter => CandelaPerSquareMeter: NANO;
+    PicocandelaPerSquareMeter => CandelaPerSquareMeter: PICO;
+    FemtocandelaPerSquareMeter => CandelaPerSquareMeter: FEMTO;
+    AttocandelaPerSquareMeter => CandelaPerSquareMeter: ATTO;
+    ZeptocandelaPerSquareMeter => CandelaPerSquareMeter: ZEPTO;
+    YoctocandelaPerSquareMeter => CandelaPerSquareMeter: YOCTO;
+
+    // Other units
+    Nit => CandelaPerSquareMeter: 1.0;
+    CandelaPerSquareCentimeter => CandelaPerSquareMeter: 1.0_E4;
+    CandelaPerSquareInch => CandelaPerSquareMeter: 1.550_003_100_006_200_2_E3;
+    CandelaPerSquareFoot => CandelaPerSquareMeter: 1.076_391_041_670_972_2_E1;
+    Stilb => CandelaPerSquareMeter: 1.0_E4;
+    Lambert => CandelaPerSquareMeter: 3.183_098_861_837_906_7_E3;
+    Footlambert => CandelaPerSquareMeter: 3.426_259_099_635_390_5_E0;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    CandelaPerSquareMeter => YottacandelaPerSquareMeter, ZettacandelaPerSquareMeter,
+                             ExacandelaPerSquareMeter, PetacandelaPerSquareMeter,
+                             TeracandelaPerSquareMeter, GigacandelaPerSquareMeter,
+                             MegacandelaPerSquareMeter, KilocandelaPerSquareMeter,
+                             MillicandelaPerSquareMeter, MicrocandelaPerSquareMeter,
+                             NanocandelaPerSquareMeter, PicocandelaPerSquareMeter,
+                             FemtocandelaPerSquareMeter, AttocandelaPerSquareMeter,
+                             ZeptocandelaPerSquareMeter, YoctocandelaPerSquareMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    CandelaPerSquareMeter => YottacandelaPerSquareMeter, ZettacandelaPerSquareMeter,
+                             ExacandelaPerSquareMeter, PetacandelaPerSquareMeter,
+                             TeracandelaPerSquareMeter, GigacandelaPerSquareMeter,
+                             MegacandelaPerSquareMeter, KilocandelaPerSquareMeter,
+                             HectocandelaPerSquareMeter, DecacandelaPerSquareMeter,
+                             DecicandelaPerSquareMeter, CenticandelaPerSquareMeter,
+                             MillicandelaPerSquareMeter, MicrocandelaPerSquareMeter,
+                             NanocandelaPerSquareMeter, PicocandelaPerSquareMeter,
+                             FemtocandelaPerSquareMeter, AttocandelaPerSquareMeter,
+                             ZeptocandelaPerSquareMeter, YoctocandelaPerSquareMeter, Nit,
+                             CandelaPerSquareCentimeter, CandelaPerSquareInch, CandelaPerSquareFoot,
+                             Stilb, Lambert, Footlambert
+}
+
+// Luminance quantity definition (Luminous intensity/Length²)
+use super::{ISQ, SiScale};
+quantity!(Luminance, ISQ<N2, Z0, Z0, Z0, Z0, Z0, P1>, SiScale, CandelaPerSquareMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_luminance {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::luminance,
+                uom::si::luminance,
+                Luminance,
+                Luminance,
+                CandelaPerSquareMeter,
+                $num_units_unit,
+                candela_per_square_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_luminance!(CandelaPerSquareMeter, candela_per_square_meter);
+    test_uom_luminance!(YottacandelaPerSquareMeter, yottacandela_per_square_meter);
+    test_uom_luminance!(ZettacandelaPerSquareMeter, zettacandela_per_square_meter);
+    test_uom_luminance!(ExacandelaPerSquareMeter, exacandela_per_square_meter);
+    test_uom_luminance!(PetacandelaPerSquareMeter, petacandela_per_square_meter);
+    test_uom_luminance!(TeracandelaPerSquareMeter, teracandela_per_square_meter);
+    test_uom_luminance!(GigacandelaPerSquareMeter, gigacandela_per_square_meter);
+    test_uom_luminance!(MegacandelaPerSquareMeter, megacandela_per_square_meter);
+    test_uom_luminance!(KilocandelaPerSquareMeter, kilocandela_per_square_meter);
+    test_uom_luminance!(HectocandelaPerSquareMeter, hectocandela_per_square_meter);
+    test_uom_luminance!(DecacandelaPerSquareMeter, decacandela_per_square_meter);
+    test_uom_luminance!(DecicandelaPerSquareMeter, decicandela_per_square_meter);
+    test_uom_luminance!(CenticandelaPerSquareMeter, centicandela_per_square_meter);
+    test_uom_luminance!(MillicandelaPerSquareMeter, millicandela_per_square_meter);
+    test_uom_luminance!(MicrocandelaPerSquareMeter, microcandela_per_square_meter);
+    test_uom_luminance!(NanocandelaPerSquareMeter, nanocandela_per_square_meter);
+    test_uom_luminance!(PicocandelaPerSquareMeter, picocandela_per_square_meter);
+    test_uom_luminance!(FemtocandelaPerSquareMeter, femtocandela_per_square_meter);
+    test_uom_luminance!(AttocandelaPerSquareMeter, attocandela_per_square_meter);
+    test_uom_luminance!(ZeptocandelaPerSquareMeter, zeptocandela_per_square_meter);
+    test_uom_luminance!(YoctocandelaPerSquareMeter, yoctocandela_per_square_meter);
+    test_uom_luminance!(CandelaPerSquareCentimeter, candela_per_square_centimeter);
+    test_uom_luminance!(CandelaPerSquareInch, candela_per_square_inch);
+    test_uom_luminance!(CandelaPerSquareFoot, candela_per_square_foot);
+    test_uom_luminance!(Stilb, stilb);
+    test_uom_luminance!(Lambert, lambert);
+    test_uom_luminance!(Footlambert, footlambert);
+
+    #[test]
+    fn test_intensity_over_area() {
+        use crate::si::area::{Area, SquareCentimeter};
+        use crate::si::luminance::*;
+        use crate::si::luminosity::{Candela, Luminosity};
+
+        let source: Luminance<f64> =
+            Luminosity::from::<Candela>(2.0) / Area::from::<SquareCentimeter>(1.0);
+        assert!((source.to::<Stilb>() - 2.0_f64).abs() < 1e-12);
+        assert!((source.to::<Nit>() - 2.0e4_f64).abs() < 1e-8);
+    }
+}
+
+// Re-export types for convenience
+pub use luminance::Luminance;
+pub use luminance::*;
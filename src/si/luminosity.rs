@@ -32,6 +32,12 @@
 /// - **Zeptocandela (zcd)**: 10⁻²¹ candelas
 /// - **Yoctocandela (ycd)**: 10⁻²⁴ candelas
 ///
+/// ## Historical Units
+///
+/// - **Candlepower (cp)**: 1 candela; since the candela replaced the international candle in
+///   1948, candlepower figures on lighting specifications are read as candelas
+/// - **HefnerKerze (HK)**: 0.920 candelas (0.903 international candles), the German flame standard
+///
 /// ## Usage
 ///
 /// ```rust,ignore
@@ -93,6 +99,12 @@ units! {
     Yoctocandela: "ycd", "yoctocandela";
 }
 
+// Historical units
+units! {
+    Candlepower: "cp", "candlepower";
+    HefnerKerze: "HK", "Hefnerkerze";
+}
+
 // Unit conversions using convert_linear! with exact UOM coefficients
 crate::convert_linear! {
     // SI prefixed candelas
@@ -116,10 +128,25 @@ crate::convert_linear! {
     Attocandela => Candela: ATTO;
     Zeptocandela => Candela: ZEPTO;
     Yoctocandela => Candela: YOCTO;
+
+    // Historical units
+    Candlepower => Candela: 1.0;
+    HefnerKerze => Candela: 9.2_E-1;
 }
 
-crate::convert_matrix! {
-    Candela => Yottacandela, Zettacandela, Exacandela, Petacandela, Teracandela, Gigacandela, Megacandela, Kilocandela, Hectocandela, Decacandela, Decicandela, Centicandela, Millicandela, Microcandela, Nanocandela, Picocandela, Femtocandela, Attocandela, Zeptocandela, Yoctocandela
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Candela => Yottacandela, Zettacandela, Exacandela, Petacandela, Teracandela, Gigacandela,
+               Megacandela, Kilocandela, Millicandela, Microcandela, Nanocandela, Picocandela,
+               Femtocandela, Attocandela, Zeptocandela, Yoctocandela
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    Candela => Yottacandela, Zettacandela, Exacandela, Petacandela, Teracandela, Gigacandela,
+               Megacandela, Kilocandela, Hectocandela, Decacandela, Decicandela, Centicandela,
+               Millicandela, Microcandela, Nanocandela, Picocandela, Femtocandela, Attocandela,
+               Zeptocandela, Yoctocandela, Candlepower, HefnerKerze
 }
 
 // Luminosity quantity definition (luminous intensity is the 7th base dimension)
@@ -167,6 +194,18 @@ mod tests {
     test_uom_luminosity!(Attocandela, attocandela);
     test_uom_luminosity!(Zeptocandela, zeptocandela);
     test_uom_luminosity!(Yoctocandela, yoctocandela);
+
+    #[test]
+    fn test_historical_units() {
+        use crate::si::luminosity::*;
+
+        let lamp = Luminosity::from::<Candlepower>(60.0);
+        assert!((lamp.to::<Candela>() - 60.0_f64).abs() < 1e-12);
+
+        let hefner = Luminosity::from::<HefnerKerze>(100.0);
+        assert!((hefner.to::<Candela>() - 92.0_f64).abs() < 1e-12);
+        assert!((hefner.to::<Candlepower>() - 92.0_f64).abs() < 1e-12);
+    }
 }
 
 // Re-export types for convenience
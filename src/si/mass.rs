@@ -49,6 +49,16 @@
 /// - **TonLong**: 1016.047 kg (2240 lb)
 /// - **TonShort**: 907.1847 kg (2000 lb)
 /// - **Ton (t)**: 1000 kg (metric ton)
+/// - **Stone (st)**: 6.35029318 kg (14 lb, exact)
+/// - **Quintal (q)**: 100 kg (metric quintal)
+/// - **Hyl (hyl)**: 9.80665 kg (metric slug or TME, 1 kgf·s²/m)
+///
+/// ## Astronomical and Physics Units
+///
+/// UOM does not define these; values follow IAU 2015 and CODATA 2018:
+/// - **SolarMass (M☉)**: 1.98847 × 10³⁰ kg
+/// - **EarthMass (M⊕)**: 5.97217 × 10²⁴ kg
+/// - **PlanckMass (mP)**: 2.176434 × 10⁻⁸ kg
 ///
 /// ## Usage
 ///
@@ -79,10 +89,7 @@
 /// - All mass operations are dimensionally consistent
 /// - Unit conversions are automatic and type-safe
 /// - Compile-time dimensional analysis prevents errors
-use crate::prefix::{
-    ATTO, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO, TERA,
-    YOCTO, YOTTA, ZEPTO, ZETTA,
-};
+use crate::prefix::{ATTO, KILO, MICRO, YOCTO};
 use typenum::*;
 
 // SI base unit
@@ -119,8 +126,8 @@ units! {
     Carat: "ct", "carat";
     Dalton: "Da", "dalton";
     Grain: "gr", "grain";
-    HundredweightLong: "cwt long", "hundredweight (long)";
-    HundredweightShort: "cwt short", "hundredweight (short)";
+    HundredweightLong: "cwt long", "hundredweight (long)", "hundredweight (long)";
+    HundredweightShort: "cwt short", "hundredweight (short)", "hundredweight (short)";
     Ounce: "oz", "ounce";
     OunceTroy: "oz t", "troy ounce";
     Pennyweight: "dwt", "pennyweight";
@@ -131,34 +138,21 @@ units! {
     TonLong: "2240 lb", "long ton";
     TonShort: "2000 lb", "short ton";
     Ton: "t", "ton";
+    Stone: "st", "stone";
+    Quintal: "q", "quintal";
+    Hyl: "hyl", "hyl";
+}
+
+// Astronomical and physics mass units
+units! {
+    SolarMass: "M☉", "solar mass", "solar masses";
+    EarthMass: "M⊕", "Earth mass", "Earth masses";
+    PlanckMass: "mP", "Planck mass", "Planck masses";
 }
 
 // Unit conversions using convert_linear! with exact UOM coefficients
 crate::convert_linear! {
-    // SI prefixed grams - exact UOM coefficients (relative to kilogram)
-    Yottagram => Kilogram: YOTTA / KILO;
-    Zettagram => Kilogram: ZETTA / KILO;
-    Exagram => Kilogram: EXA / KILO;
-    Petagram => Kilogram: PETA / KILO;
-    Teragram => Kilogram: TERA / KILO;
-    Gigagram => Kilogram: GIGA / KILO;
-    Megagram => Kilogram: MEGA / KILO;
-    Hectogram => Kilogram: HECTO / KILO;
-    Decagram => Kilogram: DECA / KILO;
-    Gram => Kilogram: 1.0 / KILO;
-    Decigram => Kilogram: DECI / KILO;
-    Centigram => Kilogram: crate::prefix::CENTI / KILO;
-    Milligram => Kilogram: MILLI / KILO;
-    Microgram => Kilogram: MICRO / KILO;
-    Nanogram => Kilogram: NANO / KILO;
-    Picogram => Kilogram: PICO / KILO;
-    Femtogram => Kilogram: FEMTO / KILO;
-    Attogram => Kilogram: ATTO / KILO;
-    Zeptogram => Kilogram: ZEPTO / KILO;
-    Yoctogram => Kilogram: YOCTO / KILO;
-
     // Other units - exact UOM coefficients
-    Carat => Kilogram: 2.0_E-4;
     Dalton => Kilogram: 1.660_539_066_60_E-27;
     Grain => Kilogram: 6.479_891_E-5;
     HundredweightLong => Kilogram: 5.080_235_E1;
@@ -172,11 +166,57 @@ crate::convert_linear! {
     TonAssay => Kilogram: 2.916_667_E-2;
     TonLong => Kilogram: 1.016_047_E3;
     TonShort => Kilogram: 9.071_847_E2;
-    Ton => Kilogram: 1.0_E3;
+    Stone => Kilogram: 6.350_293_18_E0;
+    Hyl => Kilogram: 9.806_65_E0;
+
+    // Astronomical and physics units
+    SolarMass => Kilogram: 1.988_47_E30;
+    EarthMass => Kilogram: 5.972_17_E24;
+    PlanckMass => Kilogram: 2.176_434_E-8;
+}
+
+// SI prefixed grams and units defined by an exact fraction of a kilogram, so
+// integer masses convert without going through f64
+// (f64 factors after a comma are rounded the way UOM rounds them)
+crate::convert_int_linear! {
+    Yottagram => Kilogram: 1_000_000_000_000_000_000_000;
+    Zettagram => Kilogram: 1_000_000_000_000_000_000;
+    Exagram => Kilogram: 1_000_000_000_000_000;
+    Petagram => Kilogram: 1_000_000_000_000;
+    Teragram => Kilogram: 1_000_000_000;
+    Gigagram => Kilogram: 1_000_000;
+    Megagram => Kilogram: 1_000;
+    Hectogram => Kilogram: 1 / 10;
+    Decagram => Kilogram: 1 / 100;
+    Gram => Kilogram: 1 / 1_000;
+    Decigram => Kilogram: 1 / 10_000;
+    Centigram => Kilogram: 1 / 100_000;
+    Milligram => Kilogram: 1 / 1_000_000;
+    Microgram => Kilogram: 1 / 1_000_000_000, MICRO / KILO;
+    Nanogram => Kilogram: 1 / 1_000_000_000_000;
+    Picogram => Kilogram: 1 / 1_000_000_000_000_000;
+    Femtogram => Kilogram: 1 / 1_000_000_000_000_000_000;
+    Attogram => Kilogram: 1 / 1_000_000_000_000_000_000_000, ATTO / KILO;
+    Zeptogram => Kilogram: 1 / 1_000_000_000_000_000_000_000_000;
+    Yoctogram => Kilogram: 1 / 1_000_000_000_000_000_000_000_000_000, YOCTO / KILO;
+    Carat => Kilogram: 2 / 10_000;
+    Quintal => Kilogram: 100;
+    Ton => Kilogram: 1_000;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Kilogram => Yottagram, Zettagram, Exagram, Petagram, Teragram, Gigagram, Megagram, Gram,
+                Milligram, Microgram, Nanogram, Picogram, Femtogram, Attogram, Zeptogram, Yoctogram
 }
 
-crate::convert_matrix! {
-    Kilogram => Yottagram, Zettagram, Exagram, Petagram, Teragram, Gigagram, Megagram, Hectogram, Decagram, Gram, Decigram, Centigram, Milligram, Microgram, Nanogram, Picogram, Femtogram, Attogram, Zeptogram, Yoctogram, Carat, Dalton, Grain, HundredweightLong, HundredweightShort, Ounce, OunceTroy, Pennyweight, Pound, PoundTroy, Slug, TonAssay, TonLong, TonShort, Ton
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    Kilogram => Yottagram, Zettagram, Exagram, Petagram, Teragram, Gigagram, Megagram, Hectogram,
+                Decagram, Gram, Decigram, Centigram, Milligram, Microgram, Nanogram, Picogram,
+                Femtogram, Attogram, Zeptogram, Yoctogram, Carat, Dalton, Grain, HundredweightLong,
+                HundredweightShort, Ounce, OunceTroy, Pennyweight, Pound, PoundTroy, Slug, TonAssay,
+                TonLong, TonShort, Ton, Stone, Quintal, Hyl, SolarMass, EarthMass, PlanckMass
 }
 
 // Mass quantity definition (Mass is the 2nd base dimension)
@@ -241,6 +281,43 @@ mod tests {
     test_uom_mass!(TonLong, ton_long);
     test_uom_mass!(TonShort, ton_short);
     test_uom_mass!(Ton, ton);
+
+    #[test]
+    fn test_stone() {
+        use crate::si::mass::*;
+
+        let stone = Mass::from::<Stone>(1.0);
+        assert!((stone.to::<Pound>() - 14.0_f64).abs() < 1e-5);
+        assert!((Mass::from::<Stone>(11.0).to::<Kilogram>() - 69.853_225_f64).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_quintal_and_hyl() {
+        use crate::si::acceleration::{Acceleration, MeterPerSecondSquared};
+        use crate::si::force::{Force, KilogramForce};
+        use crate::si::mass::*;
+
+        let harvest = Mass::from::<Quintal>(35.0);
+        assert!((harvest.to::<Ton>() - 3.5_f64).abs() < 1e-12);
+
+        // One kilogram-force accelerates one hyl at 1 m/s²
+        let hyl = Mass::from::<Hyl>(1.0);
+        assert!((hyl.to::<Kilogram>() - 9.806_65_f64).abs() < 1e-12);
+        let force: Force<f64> = hyl * Acceleration::from::<MeterPerSecondSquared>(1.0);
+        assert!((force.to::<KilogramForce>() - 1.0_f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_astronomical_and_physics_units() {
+        use crate::si::mass::*;
+
+        // The Sun is about 333 000 Earth masses
+        let sun = Mass::from::<SolarMass>(1.0);
+        assert!((sun.to::<EarthMass>() - 332_956.0_f64).abs() < 1.0);
+
+        let planck = Mass::from::<PlanckMass>(1.0);
+        assert!((planck.to::<Microgram>() - 21.764_34_f64).abs() < 1e-9);
+    }
 }
 
 // Re-export types for convenience
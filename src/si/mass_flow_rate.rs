@@ -0,0 +1,199 @@
+/// # Mass Flow Rate Units - SI Mass Flow Rate Measurements
+///
+/// This module defines SI mass flow rate units and their conversions. Mass flow rate is the
+/// mass of material passing per unit of time, with kilogram per second as the SI base unit.
+/// Extruder throughput and dosing in process control are typically specified this way.
+///
+/// ## Base Unit
+///
+/// - **KilogramPerSecond (kg/s)**: The SI base unit of mass flow rate
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for grams per second:
+/// - **YottagramPerSecond (Yg/s)**: 10²¹ kilograms per second
+/// - ... down to ...
+/// - **YoctogramPerSecond (yg/s)**: 10⁻²⁷ kilograms per second
+///
+/// ## Other Units
+///
+/// - **KilogramPerMinute (kg/min)**, **KilogramPerHour (kg/h)**: Metric process rates
+/// - **GramPerMinute (g/min)**, **GramPerHour (g/h)**: Small dosing rates
+/// - **TonPerHour (t/h)**: 1000 kilograms per hour
+/// - **PoundPerSecond (lb/s)**: 0.4535924 kilograms per second
+/// - **PoundPerMinute (lb/min)**, **PoundPerHour (lb/h)**: Imperial process rates
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::mass_flow_rate::{KilogramPerHour, MassFlowRate, PoundPerHour};
+///
+/// // Create mass flow rate quantities
+/// let throughput = MassFlowRate::from::<KilogramPerHour>(120.0);
+///
+/// // Convert between units
+/// let throughput_lb = throughput.to::<PoundPerHour>(); // ≈ 264.55 lb/h
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    KilogramPerSecond: "kg/s", "kilogram per second", "kilograms per second";
+}
+
+// SI prefixed grams per second
+units! {
+    YottagramPerSecond: "Yg/s", "yottagram per second", "yottagrams per second";
+    ZettagramPerSecond: "Zg/s", "zettagram per second", "zettagrams per second";
+    ExagramPerSecond: "Eg/s", "exagram per second", "exagrams per second";
+    PetagramPerSecond: "Pg/s", "petagram per second", "petagrams per second";
+    TeragramPerSecond: "Tg/s", "teragram per second", "teragrams per second";
+    GigagramPerSecond: "Gg/s", "gigagram per second", "gigagrams per second";
+    MegagramPerSecond: "Mg/s", "megagram per second", "megagrams per second";
+    HectogramPerSecond: "hg/s", "hectogram per second", "hectograms per second";
+    DecagramPerSecond: "dag/s", "decagram per second", "decagrams per second";
+    GramPerSecond: "g/s", "gram per second", "grams per second";
+    DecigramPerSecond: "dg/s", "decigram per second", "decigrams per second";
+    CentigramPerSecond: "cg/s", "centigram per second", "centigrams per second";
+    MilligramPerSecond: "mg/s", "milligram per second", "milligrams per second";
+    MicrogramPerSecond: "µg/s", "microgram per second", "micrograms per second";
+    NanogramPerSecond: "ng/s", "nanogram per second", "nanograms per second";
+    PicogramPerSecond: "pg/s", "picogram per second", "picograms per second";
+    FemtogramPerSecond: "fg/s", "femtogram per second", "femtograms per second";
+    AttogramPerSecond: "ag/s", "attogram per second", "attograms per second";
+    ZeptogramPerSecond: "zg/s", "zeptogram per second", "zeptograms per second";
+    YoctogramPerSecond: "yg/s", "yoctogram per second", "yoctograms per second";
+}
+
+// Other units
+units! {
+    KilogramPerMinute: "kg/min", "kilogram per minute", "kilograms per minute";
+    KilogramPerHour: "kg/h", "kilogram per hour", "kilograms per hour";
+    GramPerMinute: "g/min", "gram per minute", "grams per minute";
+    GramPerHour: "g/h", "gram per hour", "grams per hour";
+    TonPerHour: "t/h", "ton per hour", "tons per hour";
+    PoundPerSecond: "lb/s", "pound per second", "pounds per second";
+    PoundPerMinute: "lb/min", "pound per minute", "pounds per minute";
+    PoundPerHour: "lb/h", "pound per hour", "pounds per hour";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed grams per second
+    YottagramPerSecond => KilogramPerSecond: YOTTA / KILO;
+    ZettagramPerSecond => KilogramPerSecond: ZETTA / KILO;
+    ExagramPerSecond => KilogramPerSecond: EXA / KILO;
+    PetagramPerSecond => KilogramPerSecond: PETA / KILO;
+    TeragramPerSecond => KilogramPerSecond: TERA / KILO;
+    GigagramPerSecond => KilogramPerSecond: GIGA / KILO;
+    MegagramPerSecond => KilogramPerSecond: MEGA / KILO;
+    HectogramPerSecond => KilogramPerSecond: HECTO / KILO;
+    DecagramPerSecond => KilogramPerSecond: DECA / KILO;
+    GramPerSecond => KilogramPerSecond: 1.0 / KILO;
+    DecigramPerSecond => KilogramPerSecond: DECI / KILO;
+    CentigramPerSecond => KilogramPerSecond: CENTI / KILO;
+    MilligramPerSecond => KilogramPerSecond: MILLI / KILO;
+    MicrogramPerSecond => KilogramPerSecond: MICRO / KILO;
+    NanogramPerSecond => KilogramPerSecond: NANO / KILO;
+    PicogramPerSecond => KilogramPerSecond: PICO / KILO;
+    FemtogramPerSecond => KilogramPerSecond: FEMTO / KILO;
+    AttogramPerSecond => KilogramPerSecond: ATTO / KILO;
+    ZeptogramPerSecond => KilogramPerSecond: ZEPTO / KILO;
+    YoctogramPerSecond => KilogramPerSecond: YOCTO / KILO;
+
+    // Other units
+    KilogramPerMinute => KilogramPerSecond: 1.666_666_666_666_666_6_E-2;
+    KilogramPerHour => KilogramPerSecond: 2.777_777_777_777_778_E-4;
+    GramPerMinute => KilogramPerSecond: 1.666_666_666_666_666_6_E-5;
+    GramPerHour => KilogramPerSecond: 2.777_777_777_777_777_7_E-7;
+    TonPerHour => KilogramPerSecond: 2.777_777_777_777_778_E-1;
+    PoundPerSecond => KilogramPerSecond: 4.535_924_E-1;
+    PoundPerMinute => KilogramPerSecond: 7.559_873_333_333_333_E-3;
+    PoundPerHour => KilogramPerSecond: 1.259_978_888_888_888_8_E-4;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    KilogramPerSecond => YottagramPerSecond, ZettagramPerSecond, ExagramPerSecond,
+                         PetagramPerSecond, TeragramPerSecond, GigagramPerSecond, MegagramPerSecond,
+                         GramPerSecond, MilligramPerSecond, MicrogramPerSecond, NanogramPerSecond,
+                         PicogramPerSecond, FemtogramPerSecond, AttogramPerSecond,
+                         ZeptogramPerSecond, YoctogramPerSecond
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    KilogramPerSecond => YottagramPerSecond, ZettagramPerSecond, ExagramPerSecond,
+                         PetagramPerSecond, TeragramPerSecond, GigagramPerSecond, MegagramPerSecond,
+                         HectogramPerSecond, DecagramPerSecond, GramPerSecond, DecigramPerSecond,
+                         CentigramPerSecond, MilligramPerSecond, MicrogramPerSecond,
+                         NanogramPerSecond, PicogramPerSecond, FemtogramPerSecond,
+                         AttogramPerSecond, ZeptogramPerSecond, YoctogramPerSecond,
+                         KilogramPerMinute, KilogramPerHour, GramPerMinute, GramPerHour, TonPerHour,
+                         PoundPerSecond, PoundPerMinute, PoundPerHour
+}
+
+// Mass flow rate quantity definition (Mass/Time)
+use super::{ISQ, SiScale};
+quantity!(MassFlowRate, ISQ<Z0, P1, N1, Z0, Z0, Z0, Z0>, SiScale, KilogramPerSecond);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_mass_flow_rate {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::mass_flow_rate,
+                uom::si::mass_rate,
+                MassFlowRate,
+                MassRate,
+                KilogramPerSecond,
+                $num_units_unit,
+                kilogram_per_second,
+                $uom_unit
+            );
+        };
+    }
+
+    // Test SI prefixed grams per second
+    test_uom_mass_flow_rate!(YottagramPerSecond, yottagram_per_second);
+    test_uom_mass_flow_rate!(ZettagramPerSecond, zettagram_per_second);
+    test_uom_mass_flow_rate!(ExagramPerSecond, exagram_per_second);
+    test_uom_mass_flow_rate!(PetagramPerSecond, petagram_per_second);
+    test_uom_mass_flow_rate!(TeragramPerSecond, teragram_per_second);
+    test_uom_mass_flow_rate!(GigagramPerSecond, gigagram_per_second);
+    test_uom_mass_flow_rate!(MegagramPerSecond, megagram_per_second);
+    test_uom_mass_flow_rate!(KilogramPerSecond, kilogram_per_second);
+    test_uom_mass_flow_rate!(HectogramPerSecond, hectogram_per_second);
+    test_uom_mass_flow_rate!(DecagramPerSecond, decagram_per_second);
+    test_uom_mass_flow_rate!(GramPerSecond, gram_per_second);
+    test_uom_mass_flow_rate!(DecigramPerSecond, decigram_per_second);
+    test_uom_mass_flow_rate!(CentigramPerSecond, centigram_per_second);
+    test_uom_mass_flow_rate!(MilligramPerSecond, milligram_per_second);
+    test_uom_mass_flow_rate!(MicrogramPerSecond, microgram_per_second);
+    test_uom_mass_flow_rate!(NanogramPerSecond, nanogram_per_second);
+    test_uom_mass_flow_rate!(PicogramPerSecond, picogram_per_second);
+    test_uom_mass_flow_rate!(FemtogramPerSecond, femtogram_per_second);
+    test_uom_mass_flow_rate!(AttogramPerSecond, attogram_per_second);
+    test_uom_mass_flow_rate!(ZeptogramPerSecond, zeptogram_per_second);
+    test_uom_mass_flow_rate!(YoctogramPerSecond, yoctogram_per_second);
+
+    // Test other units
+    test_uom_mass_flow_rate!(KilogramPerMinute, kilogram_per_minute);
+    test_uom_mass_flow_rate!(KilogramPerHour, kilogram_per_hour);
+    test_uom_mass_flow_rate!(GramPerMinute, gram_per_minute);
+    test_uom_mass_flow_rate!(GramPerHour, gram_per_hour);
+    test_uom_mass_flow_rate!(TonPerHour, ton_per_hour);
+    test_uom_mass_flow_rate!(PoundPerSecond, pound_per_second);
+    test_uom_mass_flow_rate!(PoundPerMinute, pound_per_minute);
+    test_uom_mass_flow_rate!(PoundPerHour, pound_per_hour);
+}
+
+// Re-export types for convenience
+pub use mass_flow_rate::MassFlowRate;
+pub use mass_flow_rate::*;
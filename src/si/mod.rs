@@ -78,20 +78,56 @@ pub mod amount;
 pub mod angle;
 pub mod apple;
 pub mod area;
+pub mod areal_density;
+pub mod catalytic_activity;
+#[cfg(feature = "chrono")]
+pub mod chrono;
+pub mod conductivity;
 pub mod current;
+pub mod current_density;
+pub mod dyn_quantity;
+pub mod electric_field;
+pub mod electric_potential;
 pub mod energy;
+pub mod energy_density;
 pub mod force;
+pub mod fuel_economy;
+pub mod heat_flux_density;
+pub mod impulse;
 pub mod information;
+pub mod jerk;
+pub mod latex;
 pub mod length;
+pub mod linear_charge_density;
+pub mod linear_mass_density;
+pub mod luminance;
 pub mod luminosity;
 pub mod mass;
+pub mod mass_flow_rate;
+pub mod molar_concentration;
+pub mod parse;
+pub mod permeability;
+pub mod permittivity;
 pub mod power;
+pub mod radioactivity;
 pub mod ratio;
+pub mod resistivity;
 pub mod scalar;
+pub mod solid_angle;
+pub mod specific_energy;
+pub mod specific_volume;
+pub mod surface_charge_density;
 pub mod temperature;
+pub mod thermal_insulance;
+pub mod thermal_resistance;
 pub mod time;
+#[cfg(feature = "time")]
+pub mod time_crate;
 pub mod velocity;
 pub mod volume;
+pub mod volume_charge_density;
+pub mod volumetric_flow_rate;
+pub mod wavenumber;
 
 // Create the SI system with unit scaling using the new syntax
 system! {
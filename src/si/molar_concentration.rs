@@ -0,0 +1,252 @@
+/// # Molar Concentration Units - SI Molar Concentration Measurements
+///
+/// This module defines SI molar concentration units and their conversions. Molar concentration
+/// is a derived quantity in the SI system with dimensions of amount of substance × length⁻³,
+/// with the mole per cubic meter as its base unit.
+///
+/// ## Base Unit
+///
+/// - **MolePerCubicMeter (mol/m³)**: The SI derived unit of molar concentration
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for moles per cubic meter:
+/// - **YottamolePerCubicMeter (Ymol/m³)**: 10²⁴ moles per cubic meter
+/// - ... down to ...
+/// - **YoctomolePerCubicMeter (ymol/m³)**: 10⁻²⁴ moles per cubic meter
+///
+/// ## Per-Liter Units
+///
+/// Units commonly used in chemistry and biotechnology:
+/// - **MolePerCubicDecimeter (mol/dm³)**: 10³ moles per cubic meter
+/// - **MolePerLiter (mol/L)**: 10³ moles per cubic meter
+/// - **MillimolePerLiter (mmol/L)**: 1 mole per cubic meter
+/// - **MicromolePerLiter (μmol/L)**: 10⁻³ moles per cubic meter
+/// - **NanomolePerLiter (nmol/L)**: 10⁻⁶ moles per cubic meter
+///
+/// ## Molarity Units
+///
+/// - **Molar (M)**: 1 mole per liter
+/// - **Millimolar (mM)**: 1 millimole per liter
+/// - **Micromolar (μM)**: 1 micromole per liter
+/// - **Nanomolar (nM)**: 1 nanomole per liter
+///
+/// ## Logarithmic Units
+///
+/// - **PotentialOfHydrogen (pH)**: -log10 of the hydrogen ion concentration in mol/L.
+///   The quantity is the concentration of H⁺ itself, so pH 7 is 10⁻⁴ mol/m³; activity
+///   coefficients are taken as one.
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::molar_concentration::{MolarConcentration, Molar, Millimolar};
+///
+/// // Create molar concentration quantities
+/// let stock = MolarConcentration::from::<Molar>(1.5);
+///
+/// // Convert between units
+/// let stock_mm = stock.to::<Millimolar>(); // 1500.0 mM
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    MolePerCubicMeter: "mol/m³", "mole per cubic meter", "moles per cubic meter";
+}
+
+// SI prefixed moles per cubic meter
+units! {
+    YottamolePerCubicMeter: "Ymol/m³", "yottamole per cubic meter", "yottamoles per cubic meter";
+    ZettamolePerCubicMeter: "Zmol/m³", "zettamole per cubic meter", "zettamoles per cubic meter";
+    ExamolePerCubicMeter: "Emol/m³", "examole per cubic meter", "examoles per cubic meter";
+    PetamolePerCubicMeter: "Pmol/m³", "petamole per cubic meter", "petamoles per cubic meter";
+    TeramolePerCubicMeter: "Tmol/m³", "teramole per cubic meter", "teramoles per cubic meter";
+    GigamolePerCubicMeter: "Gmol/m³", "gigamole per cubic meter", "gigamoles per cubic meter";
+    MegamolePerCubicMeter: "Mmol/m³", "megamole per cubic meter", "megamoles per cubic meter";
+    KilomolePerCubicMeter: "kmol/m³", "kilomole per cubic meter", "kilomoles per cubic meter";
+    HectomolePerCubicMeter: "hmol/m³", "hectomole per cubic meter", "hectomoles per cubic meter";
+    DecamolePerCubicMeter: "damol/m³", "decamole per cubic meter", "decamoles per cubic meter";
+    DecimolePerCubicMeter: "dmol/m³", "decimole per cubic meter", "decimoles per cubic meter";
+    CentimolePerCubicMeter: "cmol/m³", "centimole per cubic meter", "centimoles per cubic meter";
+    MillimolePerCubicMeter: "mmol/m³", "millimole per cubic meter", "millimoles per cubic meter";
+    MicromolePerCubicMeter: "μmol/m³", "micromole per cubic meter", "micromoles per cubic meter";
+    NanomolePerCubicMeter: "nmol/m³", "nanomole per cubic meter", "nanomoles per cubic meter";
+    PicomolePerCubicMeter: "pmol/m³", "picomole per cubic meter", "picomoles per cubic meter";
+    FemtomolePerCubicMeter: "fmol/m³", "femtomole per cubic meter", "femtomoles per cubic meter";
+    AttomolePerCubicMeter: "amol/m³", "attomole per cubic meter", "attomoles per cubic meter";
+    ZeptomolePerCubicMeter: "zmol/m³", "zeptomole per cubic meter", "zeptomoles per cubic meter";
+    YoctomolePerCubicMeter: "ymol/m³", "yoctomole per cubic meter", "yoctomoles per cubic meter";
+}
+
+// Per-liter units
+units! {
+    MolePerCubicDecimeter: "mol/dm³", "mole per cubic decimeter", "moles per cubic decimeter";
+    MolePerLiter: "mol/L", "mole per liter", "moles per liter";
+    MillimolePerLiter: "mmol/L", "millimole per liter", "millimoles per liter";
+    MicromolePerLiter: "μmol/L", "micromole per liter", "micromoles per liter";
+    NanomolePerLiter: "nmol/L", "nanomole per liter", "nanomoles per liter";
+}
+
+// Molarity units
+units! {
+    Molar: "M", "molar";
+    Millimolar: "mM", "millimolar";
+    Micromolar: "μM", "micromolar";
+    Nanomolar: "nM", "nanomolar";
+}
+
+// Logarithmic units
+units! {
+    PotentialOfHydrogen: "pH", "pH", "pH";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed moles per cubic meter
+    YottamolePerCubicMeter => MolePerCubicMeter: YOTTA;
+    ZettamolePerCubicMeter => MolePerCubicMeter: ZETTA;
+    ExamolePerCubicMeter => MolePerCubicMeter: EXA;
+    PetamolePerCubicMeter => MolePerCubicMeter: PETA;
+    TeramolePerCubicMeter => MolePerCubicMeter: TERA;
+    GigamolePerCubicMeter => MolePerCubicMeter: GIGA;
+    MegamolePerCubicMeter => MolePerCubicMeter: MEGA;
+    KilomolePerCubicMeter => MolePerCubicMeter: KILO;
+    HectomolePerCubicMeter => MolePerCubicMeter: HECTO;
+    DecamolePerCubicMeter => MolePerCubicMeter: DECA;
+    DecimolePerCubicMeter => MolePerCubicMeter: DECI;
+    CentimolePerCubicMeter => MolePerCubicMeter: CENTI;
+    MillimolePerCubicMeter => MolePerCubicMeter: MILLI;
+    MicromolePerCubicMeter => MolePerCubicMeter: MICRO;
+    NanomolePerCubicMeter => MolePerCubicMeter: NANO;
+    PicomolePerCubicMeter => MolePerCubicMeter: PICO;
+    FemtomolePerCubicMeter => MolePerCubicMeter: FEMTO;
+    AttomolePerCubicMeter => MolePerCubicMeter: ATTO;
+    ZeptomolePerCubicMeter => MolePerCubicMeter: ZEPTO;
+    YoctomolePerCubicMeter => MolePerCubicMeter: YOCTO;
+
+    // Per-liter units - exact UOM coefficients
+    MolePerCubicDecimeter => MolePerCubicMeter: 1.0 / DECI / DECI / DECI;
+    MolePerLiter => MolePerCubicMeter: 1.0 / MILLI;
+    MillimolePerLiter => MolePerCubicMeter: 1.0;
+    MicromolePerLiter => MolePerCubicMeter: MICRO / MILLI;
+    NanomolePerLiter => MolePerCubicMeter: NANO / MILLI;
+
+    // Molarity units (1 M = 1 mol/L)
+    Molar => MolePerCubicMeter: 1.0 / MILLI;
+    Millimolar => MolePerCubicMeter: 1.0;
+    Micromolar => MolePerCubicMeter: MICRO / MILLI;
+    Nanomolar => MolePerCubicMeter: NANO / MILLI;
+}
+
+// pH = -log10([H⁺] / 1 mol/L), treating the concentration as the activity
+crate::convert_log! {
+    PotentialOfHydrogen => MolePerCubicMeter: 1.0E3, 0.1, 1.0;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    MolePerCubicMeter => YottamolePerCubicMeter, ZettamolePerCubicMeter, ExamolePerCubicMeter,
+                         PetamolePerCubicMeter, TeramolePerCubicMeter, GigamolePerCubicMeter,
+                         MegamolePerCubicMeter, KilomolePerCubicMeter, MillimolePerCubicMeter,
+                         MicromolePerCubicMeter, NanomolePerCubicMeter, PicomolePerCubicMeter,
+                         FemtomolePerCubicMeter, AttomolePerCubicMeter, ZeptomolePerCubicMeter,
+                         YoctomolePerCubicMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    MolePerCubicMeter => YottamolePerCubicMeter, ZettamolePerCubicMeter, ExamolePerCubicMeter,
+                         PetamolePerCubicMeter, TeramolePerCubicMeter, GigamolePerCubicMeter,
+                         MegamolePerCubicMeter, KilomolePerCubicMeter, HectomolePerCubicMeter,
+                         DecamolePerCubicMeter, DecimolePerCubicMeter, CentimolePerCubicMeter,
+                         MillimolePerCubicMeter, MicromolePerCubicMeter, NanomolePerCubicMeter,
+                         PicomolePerCubicMeter, FemtomolePerCubicMeter, AttomolePerCubicMeter,
+                         ZeptomolePerCubicMeter, YoctomolePerCubicMeter, MolePerCubicDecimeter,
+                         MolePerLiter, MillimolePerLiter, MicromolePerLiter, NanomolePerLiter,
+                         Molar, Millimolar, Micromolar, Nanomolar, PotentialOfHydrogen
+}
+
+// Molar concentration quantity definition (Amount/Length³)
+use super::{ISQ, SiScale};
+quantity!(MolarConcentration, ISQ<N3, Z0, Z0, Z0, Z0, P1, Z0>, SiScale, MolePerCubicMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_molar_concentration {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::molar_concentration,
+                uom::si::molar_concentration,
+                MolarConcentration,
+                MolarConcentration,
+                MolePerCubicMeter,
+                $num_units_unit,
+                mole_per_cubic_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    // Test SI prefixed moles per cubic meter
+    test_uom_molar_concentration!(YottamolePerCubicMeter, yottamole_per_cubic_meter);
+    test_uom_molar_concentration!(ZettamolePerCubicMeter, zettamole_per_cubic_meter);
+    test_uom_molar_concentration!(ExamolePerCubicMeter, examole_per_cubic_meter);
+    test_uom_molar_concentration!(PetamolePerCubicMeter, petamole_per_cubic_meter);
+    test_uom_molar_concentration!(TeramolePerCubicMeter, teramole_per_cubic_meter);
+    test_uom_molar_concentration!(GigamolePerCubicMeter, gigamole_per_cubic_meter);
+    test_uom_molar_concentration!(MegamolePerCubicMeter, megamole_per_cubic_meter);
+    test_uom_molar_concentration!(KilomolePerCubicMeter, kilomole_per_cubic_meter);
+    test_uom_molar_concentration!(HectomolePerCubicMeter, hectomole_per_cubic_meter);
+    test_uom_molar_concentration!(DecamolePerCubicMeter, decamole_per_cubic_meter);
+    test_uom_molar_concentration!(MolePerCubicMeter, mole_per_cubic_meter);
+    test_uom_molar_concentration!(DecimolePerCubicMeter, decimole_per_cubic_meter);
+    test_uom_molar_concentration!(CentimolePerCubicMeter, centimole_per_cubic_meter);
+    test_uom_molar_concentration!(MillimolePerCubicMeter, millimole_per_cubic_meter);
+    test_uom_molar_concentration!(MicromolePerCubicMeter, micromole_per_cubic_meter);
+    test_uom_molar_concentration!(NanomolePerCubicMeter, nanomole_per_cubic_meter);
+    test_uom_molar_concentration!(PicomolePerCubicMeter, picomole_per_cubic_meter);
+    test_uom_molar_concentration!(FemtomolePerCubicMeter, femtomole_per_cubic_meter);
+    test_uom_molar_concentration!(AttomolePerCubicMeter, attomole_per_cubic_meter);
+    test_uom_molar_concentration!(ZeptomolePerCubicMeter, zeptomole_per_cubic_meter);
+    test_uom_molar_concentration!(YoctomolePerCubicMeter, yoctomole_per_cubic_meter);
+
+    // Test per-liter units
+    test_uom_molar_concentration!(MolePerCubicDecimeter, mole_per_cubic_decimeter);
+    test_uom_molar_concentration!(MolePerLiter, mole_per_liter);
+    test_uom_molar_concentration!(MillimolePerLiter, millimole_per_liter);
+    test_uom_molar_concentration!(MicromolePerLiter, micromole_per_liter);
+    test_uom_molar_concentration!(NanomolePerLiter, nanomole_per_liter);
+
+    #[test]
+    fn test_molarity_units() {
+        use crate::si::molar_concentration::*;
+
+        let stock = MolarConcentration::from::<Molar>(1.5);
+        assert_eq!(stock.to::<MolePerLiter>(), 1.5);
+        assert_eq!(stock.to::<Millimolar>(), stock.to::<MillimolePerLiter>());
+        assert_eq!(stock.to::<Micromolar>(), stock.to::<MicromolePerLiter>());
+        assert_eq!(stock.to::<Nanomolar>(), stock.to::<NanomolePerLiter>());
+    }
+
+    #[test]
+    fn test_ph_scale() {
+        use crate::si::molar_concentration::*;
+
+        let neutral = MolarConcentration::from::<PotentialOfHydrogen>(7.0_f64);
+        assert!((*neutral.base() - 1.0E-4_f64).abs() < 1e-18);
+        assert!((neutral.to::<Nanomolar>() - 100.0).abs() < 1e-9);
+        let acid = MolarConcentration::from::<Millimolar>(10.0_f64);
+        assert!((acid.to::<PotentialOfHydrogen>() - 2.0).abs() < 1e-12);
+    }
+}
+
+// Re-export types for convenience
+pub use molar_concentration::MolarConcentration;
+pub use molar_concentration::*;
@@ -0,0 +1,531 @@
+/// # Unit-Expression Parser
+///
+/// Parses strings such as `"3.0 kg*m/s^2"`, `"9.81 m/s²"` or `"72 km/h"` into
+/// a [`DynQuantity`] holding the value in SI base units, which in turn converts
+/// into a typed quantity after a dimension check.
+///
+/// ## Grammar
+///
+/// - A number, optionally followed by a unit expression (no unit = dimensionless)
+/// - Unit symbols: SI base and named derived units (`m`, `g`, `s`, `A`, `K`,
+///   `mol`, `cd`, `N`, `Pa`, `J`, `W`, `Hz`, `C`, `V`, `Ω`, `S`, `F`, `Wb`,
+///   `T`, `H`, `lm`, `lx`, `Bq`, `Gy`, `Sv`, `kat`), plus `L`, `t`, `min`,
+///   `h` and `d`; all but the last three accept SI prefixes (`µ`/`u` for micro)
+/// - Multiplication with `*`, `·`, `⋅` or whitespace; `/` divides by the next factor
+/// - Exponents up to ±127 as `^2`, `^-1` or superscripts (`²`, `⁻¹`); they apply to the
+///   prefixed unit, so `mm²` is 10⁻⁶ m²
+/// - Parentheses for grouping, and `1` as a unit (`1/s`)
+///
+/// ## Example Usage
+///
+/// ```rust,ignore
+/// use num_units::si::acceleration::Acceleration;
+/// use num_units::si::parse::parse;
+///
+/// let g: Acceleration<f64> = "9.81 m/s²".parse().unwrap();
+/// let dynamic = parse("3.0 kg*m/s^2").unwrap(); // dimension [1, 1, -2, 0, 0, 0, 0]
+/// ```
+use super::SiScale;
+use super::dyn_quantity::{DimensionMismatch, DynQuantity, SI_DIMENSIONS};
+use crate::quantity::Quantity;
+use crate::system::DimensionVector;
+use core::fmt;
+use core::str::FromStr;
+
+type Exponents = [i32; SI_DIMENSIONS];
+
+// Symbol, size in SI base units, exponents [L, M, T, I, Θ, N, J], accepts prefixes
+const UNITS: &[(&str, f64, Exponents, bool)] = &[
+    ("m", 1.0, [1, 0, 0, 0, 0, 0, 0], true),
+    ("g", 1e-3, [0, 1, 0, 0, 0, 0, 0], true),
+    ("s", 1.0, [0, 0, 1, 0, 0, 0, 0], true),
+    ("A", 1.0, [0, 0, 0, 1, 0, 0, 0], true),
+    ("K", 1.0, [0, 0, 0, 0, 1, 0, 0], true),
+    ("mol", 1.0, [0, 0, 0, 0, 0, 1, 0], true),
+    ("cd", 1.0, [0, 0, 0, 0, 0, 0, 1], true),
+    ("Hz", 1.0, [0, 0, -1, 0, 0, 0, 0], true),
+    ("N", 1.0, [1, 1, -2, 0, 0, 0, 0], true),
+    ("Pa", 1.0, [-1, 1, -2, 0, 0, 0, 0], true),
+    ("J", 1.0, [2, 1, -2, 0, 0, 0, 0], true),
+    ("W", 1.0, [2, 1, -3, 0, 0, 0, 0], true),
+    ("C", 1.0, [0, 0, 1, 1, 0, 0, 0], true),
+    ("V", 1.0, [2, 1, -3, -1, 0, 0, 0], true),
+    ("Ω", 1.0, [2, 1, -3, -2, 0, 0, 0], true),
+    ("S", 1.0, [-2, -1, 3, 2, 0, 0, 0], true),
+    ("F", 1.0, [-2, -1, 4, 2, 0, 0, 0], true),
+    ("Wb", 1.0, [2, 1, -2, -1, 0, 0, 0], true),
+    ("T", 1.0, [0, 1, -2, -1, 0, 0, 0], true),
+    ("H", 1.0, [2, 1, -2, -2, 0, 0, 0], true),
+    ("lm", 1.0, [0, 0, 0, 0, 0, 0, 1], true),
+    ("lx", 1.0, [-2, 0, 0, 0, 0, 0, 1], true),
+    ("Bq", 1.0, [0, 0, -1, 0, 0, 0, 0], true),
+    ("Gy", 1.0, [2, 0, -2, 0, 0, 0, 0], true),
+    ("Sv", 1.0, [2, 0, -2, 0, 0, 0, 0], true),
+    ("kat", 1.0, [0, 0, -1, 0, 0, 1, 0], true),
+    ("L", 1e-3, [3, 0, 0, 0, 0, 0, 0], true),
+    ("l", 1e-3, [3, 0, 0, 0, 0, 0, 0], true),
+    ("t", 1e3, [0, 1, 0, 0, 0, 0, 0], true),
+    ("min", 60.0, [0, 0, 1, 0, 0, 0, 0], false),
+    ("h", 3600.0, [0, 0, 1, 0, 0, 0, 0], false),
+    ("d", 86400.0, [0, 0, 1, 0, 0, 0, 0], false),
+];
+
+const PREFIXES: &[(&str, f64)] = &[
+    ("Y", 1e24),
+    ("Z", 1e21),
+    ("E", 1e18),
+    ("P", 1e15),
+    ("T", 1e12),
+    ("G", 1e9),
+    ("M", 1e6),
+    ("k", 1e3),
+    ("h", 1e2),
+    ("da", 1e1),
+    ("d", 1e-1),
+    ("c", 1e-2),
+    ("m", 1e-3),
+    ("μ", 1e-6),
+    ("µ", 1e-6),
+    ("u", 1e-6),
+    ("n", 1e-9),
+    ("p", 1e-12),
+    ("f", 1e-15),
+    ("a", 1e-18),
+    ("z", 1e-21),
+    ("y", 1e-24),
+];
+
+/// Error returned when a unit expression cannot be parsed
+///
+/// Positions are byte offsets into the input string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input does not start with a number
+    InvalidNumber,
+    /// The unit symbol starting at this position is not known
+    UnknownUnit(usize),
+    /// An unexpected character at this position
+    UnexpectedCharacter(usize),
+    /// The input ended where a unit or exponent was expected
+    UnexpectedEnd,
+    /// An exponent is out of range for the dimension vector
+    ExponentOverflow,
+    /// The expression parsed, but has a different dimension than requested
+    DimensionMismatch(DimensionMismatch),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidNumber => write!(f, "expected a number"),
+            ParseError::UnknownUnit(at) => write!(f, "unknown unit at byte {at}"),
+            ParseError::UnexpectedCharacter(at) => write!(f, "unexpected character at byte {at}"),
+            ParseError::UnexpectedEnd => write!(f, "unexpected end of unit expression"),
+            ParseError::ExponentOverflow => write!(f, "unit exponent out of range"),
+            ParseError::DimensionMismatch(mismatch) => mismatch.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+impl From<DimensionMismatch> for ParseError {
+    fn from(mismatch: DimensionMismatch) -> Self {
+        ParseError::DimensionMismatch(mismatch)
+    }
+}
+
+/// Parse a number followed by an optional unit expression
+pub fn parse(input: &str) -> Result<DynQuantity, ParseError> {
+    let mut parser = Parser { input, pos: 0 };
+    parser.skip_whitespace();
+    let value = parser.number()?;
+    parser.skip_whitespace();
+    let (size, dimension) = if parser.peek().is_some() {
+        parser.unit()?
+    } else {
+        (1.0, [0; SI_DIMENSIONS])
+    };
+    Ok(DynQuantity::new(value * size, dimension))
+}
+
+/// Parse a unit expression without a number, e.g. `"km/h"`
+///
+/// Returns the size of one such unit in SI base units, so `parse_unit("km/h")`
+/// is the same as `parse("1 km/h")`. Used by [`DynQuantity::convert_to`].
+pub fn parse_unit(input: &str) -> Result<DynQuantity, ParseError> {
+    let mut parser = Parser { input, pos: 0 };
+    parser.skip_whitespace();
+    let (size, dimension) = parser.unit()?;
+    Ok(DynQuantity::new(size, dimension))
+}
+
+impl FromStr for DynQuantity {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+impl<D: DimensionVector> FromStr for Quantity<f64, D, SiScale> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::try_from(parse(s)?)?)
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn eat_digits(&mut self) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+        }
+        self.pos - start
+    }
+
+    fn number(&mut self) -> Result<f64, ParseError> {
+        let start = self.pos;
+        if matches!(self.peek(), Some('+' | '-')) {
+            self.bump();
+        }
+        let mut digits = self.eat_digits();
+        if self.peek() == Some('.') {
+            self.bump();
+            digits += self.eat_digits();
+        }
+        if digits == 0 {
+            return Err(ParseError::InvalidNumber);
+        }
+        // Only treat `e` as an exponent marker when digits follow
+        if matches!(self.peek(), Some('e' | 'E')) {
+            let mantissa_end = self.pos;
+            self.bump();
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.bump();
+            }
+            if self.eat_digits() == 0 {
+                self.pos = mantissa_end;
+            }
+        }
+        self.input[start..self.pos]
+            .parse()
+            .map_err(|_| ParseError::InvalidNumber)
+    }
+
+    // unit := expression, then the end of the input
+    fn unit(&mut self) -> Result<(f64, [i8; SI_DIMENSIONS]), ParseError> {
+        let (size, exponents) = self.expression()?;
+        self.skip_whitespace();
+        if self.peek().is_some() {
+            return Err(ParseError::UnexpectedCharacter(self.pos));
+        }
+        let mut dimension = [0; SI_DIMENSIONS];
+        for (out, exponent) in dimension.iter_mut().zip(exponents) {
+            *out = i8::try_from(exponent).map_err(|_| ParseError::ExponentOverflow)?;
+        }
+        Ok((size, dimension))
+    }
+
+    // expression := factor (('*' | '·' | '⋅' | ' ' | '/') factor)*
+    fn expression(&mut self) -> Result<(f64, Exponents), ParseError> {
+        let (mut size, mut exponents) = self.factor()?;
+        loop {
+            let before_space = self.pos;
+            self.skip_whitespace();
+            let divide = match self.peek() {
+                Some('*' | '·' | '⋅') => false,
+                Some('/') => true,
+                Some(c) if self.pos > before_space && starts_factor(c) => {
+                    let (next_size, next) = self.factor()?;
+                    size *= next_size;
+                    add_scaled(&mut exponents, &next, 1)?;
+                    continue;
+                }
+                _ => {
+                    self.pos = before_space;
+                    return Ok((size, exponents));
+                }
+            };
+            self.bump();
+            self.skip_whitespace();
+            let (next_size, next) = self.factor()?;
+            if divide {
+                size /= next_size;
+                add_scaled(&mut exponents, &next, -1)?;
+            } else {
+                size *= next_size;
+                add_scaled(&mut exponents, &next, 1)?;
+            }
+        }
+    }
+
+    // factor := (symbol | '1' | '(' expression ')') exponent?
+    fn factor(&mut self) -> Result<(f64, Exponents), ParseError> {
+        let start = self.pos;
+        let (size, exponents) = match self.peek() {
+            None => return Err(ParseError::UnexpectedEnd),
+            Some('(') => {
+                self.bump();
+                self.skip_whitespace();
+                let inner = self.expression()?;
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(')') => self.bump(),
+                    Some(_) => return Err(ParseError::UnexpectedCharacter(self.pos)),
+                    None => return Err(ParseError::UnexpectedEnd),
+                }
+                inner
+            }
+            Some('1') => {
+                self.bump();
+                (1.0, [0; SI_DIMENSIONS])
+            }
+            Some(c) if is_symbol_char(c) => {
+                while self.peek().is_some_and(is_symbol_char) {
+                    self.bump();
+                }
+                lookup(&self.input[start..self.pos]).ok_or(ParseError::UnknownUnit(start))?
+            }
+            Some(_) => return Err(ParseError::UnexpectedCharacter(start)),
+        };
+        let power = self.exponent()?;
+        let mut powered = [0; SI_DIMENSIONS];
+        add_scaled(&mut powered, &exponents, power)?;
+        Ok((powi(size, power), powered))
+    }
+
+    // exponent := '^' [+-]? digits | superscript digits
+    fn exponent(&mut self) -> Result<i32, ParseError> {
+        if self.peek() == Some('^') {
+            self.bump();
+            let negative = match self.peek() {
+                Some('-') => true,
+                Some('+') => false,
+                _ => {
+                    return self.unsigned_exponent(false);
+                }
+            };
+            self.bump();
+            return self.unsigned_exponent(negative);
+        }
+
+        let negative = self.peek() == Some('⁻');
+        if negative {
+            self.bump();
+        }
+        let mut power: Option<i32> = None;
+        while let Some(digit) = self.peek().and_then(superscript_digit) {
+            self.bump();
+            power = Some(power.unwrap_or(0) * 10 + digit);
+            if power > Some(i8::MAX as i32) {
+                return Err(ParseError::ExponentOverflow);
+            }
+        }
+        match power {
+            Some(power) if negative => Ok(-power),
+            Some(power) => Ok(power),
+            None if negative => Err(self.unexpected()),
+            None => Ok(1),
+        }
+    }
+
+    fn unsigned_exponent(&mut self, negative: bool) -> Result<i32, ParseError> {
+        let start = self.pos;
+        if self.eat_digits() == 0 {
+            return Err(self.unexpected());
+        }
+        // Capped like superscript exponents, which also bounds `powi`
+        let power = self.input[start..self.pos]
+            .parse::<i8>()
+            .map_err(|_| ParseError::ExponentOverflow)?;
+        let power = i32::from(power);
+        Ok(if negative { -power } else { power })
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(_) => ParseError::UnexpectedCharacter(self.pos),
+            None => ParseError::UnexpectedEnd,
+        }
+    }
+}
+
+fn is_symbol_char(c: char) -> bool {
+    c.is_alphabetic() && superscript_digit(c).is_none()
+}
+
+fn starts_factor(c: char) -> bool {
+    c == '(' || c == '1' || is_symbol_char(c)
+}
+
+fn superscript_digit(c: char) -> Option<i32> {
+    match c {
+        '⁰' => Some(0),
+        '¹' => Some(1),
+        '²' => Some(2),
+        '³' => Some(3),
+        '⁴' => Some(4),
+        '⁵' => Some(5),
+        '⁶' => Some(6),
+        '⁷' => Some(7),
+        '⁸' => Some(8),
+        '⁹' => Some(9),
+        _ => None,
+    }
+}
+
+// Exact symbols win over prefixed readings, so `min` is minutes and `cd` candela
+fn lookup(symbol: &str) -> Option<(f64, Exponents)> {
+    if let Some(&(_, size, exponents, _)) = UNITS.iter().find(|unit| unit.0 == symbol) {
+        return Some((size, exponents));
+    }
+    PREFIXES.iter().find_map(|&(prefix, factor)| {
+        let unit = symbol.strip_prefix(prefix)?;
+        UNITS
+            .iter()
+            .find(|candidate| candidate.0 == unit && candidate.3)
+            .map(|&(_, size, exponents, _)| (factor * size, exponents))
+    })
+}
+
+fn add_scaled(target: &mut Exponents, source: &Exponents, scale: i32) -> Result<(), ParseError> {
+    for (t, s) in target.iter_mut().zip(source) {
+        *t = s
+            .checked_mul(scale)
+            .and_then(|scaled| t.checked_add(scaled))
+            .ok_or(ParseError::ExponentOverflow)?;
+    }
+    Ok(())
+}
+
+fn powi(base: f64, power: i32) -> f64 {
+    let mut result = 1.0;
+    for _ in 0..power.unsigned_abs() {
+        result *= base;
+    }
+    if power < 0 { 1.0 / result } else { result }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::si::acceleration::Acceleration;
+    use crate::si::force::Force;
+    use crate::si::scalar::Scalar;
+    use crate::si::time::Time;
+    use crate::si::velocity::Velocity;
+    use crate::si::volume::Volume;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= 1e-12 * expected.abs().max(1.0),
+            "{actual} != {expected}"
+        );
+    }
+
+    #[test]
+    fn test_parse_compound_expressions() {
+        let force: Force<f64> = "3.0 kg*m/s^2".parse().unwrap();
+        assert_close(*force.base(), 3.0);
+
+        let g: Acceleration<f64> = "9.81 m/s²".parse().unwrap();
+        assert_close(*g.base(), 9.81);
+
+        let speed: Velocity<f64> = "72 km/h".parse().unwrap();
+        assert_close(*speed.base(), 20.0);
+
+        let force: Force<f64> = "2 (kg m)/(s·s)".parse().unwrap();
+        assert_close(*force.base(), 2.0);
+    }
+
+    #[test]
+    fn test_parse_prefixes_and_exponents() {
+        let volume: Volume<f64> = "2 mm³".parse().unwrap();
+        assert_close(*volume.base(), 2e-9);
+
+        let volume: Volume<f64> = "1.5 L".parse().unwrap();
+        assert_close(*volume.base(), 1.5e-3);
+
+        let current = parse("420 µA").unwrap();
+        assert_close(current.value(), 4.2e-4);
+        assert_eq!(parse("420 uA").unwrap(), current);
+
+        let rate = parse("5 1/s").unwrap();
+        assert_eq!(rate.dimension(), [0, 0, -1, 0, 0, 0, 0]);
+        assert_eq!(parse("5 s^-1").unwrap(), rate);
+        assert_eq!(parse("5 s⁻¹").unwrap(), rate);
+    }
+
+    #[test]
+    fn test_parse_dynamic_and_dimensionless() {
+        let energy = parse("1.5e3 N·m").unwrap();
+        assert_eq!(energy.dimension(), [2, 1, -2, 0, 0, 0, 0]);
+        assert_close(energy.value(), 1500.0);
+
+        let ratio: Scalar<f64> = " 0.25 ".parse().unwrap();
+        assert_close(*ratio.base(), 0.25);
+
+        let minutes: Time<f64> = "-1.5min".parse().unwrap();
+        assert_close(*minutes.base(), -90.0);
+    }
+
+    #[test]
+    fn test_parse_exponent_overflow() {
+        assert_eq!(parse("3 m^127").unwrap().dimension()[0], 127);
+        assert_eq!(parse("3 m^-127").unwrap().dimension()[0], -127);
+        // Nested and repeated exponents overflow the dimension vector, not i32
+        assert_eq!(parse("3 (m^127)^127"), Err(ParseError::ExponentOverflow));
+        assert_eq!(
+            parse("3 ((((m^127)^127)^127)^127)^127"),
+            Err(ParseError::ExponentOverflow)
+        );
+        assert_eq!(
+            parse("3 (m^99999)^99999"),
+            Err(ParseError::ExponentOverflow)
+        );
+        assert_eq!(parse("3 m^2147483647 m"), Err(ParseError::ExponentOverflow));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("m/s"), Err(ParseError::InvalidNumber));
+        assert_eq!(parse("3 furlong"), Err(ParseError::UnknownUnit(2)));
+        assert_eq!(parse("3 m/"), Err(ParseError::UnexpectedEnd));
+        assert_eq!(parse("3 m^x"), Err(ParseError::UnexpectedCharacter(4)));
+        assert_eq!(parse("3 (m"), Err(ParseError::UnexpectedEnd));
+        assert_eq!(parse("3 m^128"), Err(ParseError::ExponentOverflow));
+        assert_eq!(parse("1 m^2147483647"), Err(ParseError::ExponentOverflow));
+        assert_eq!(parse("3 s⁻¹²⁸"), Err(ParseError::ExponentOverflow));
+        assert!(matches!(
+            "3 m".parse::<Time<f64>>(),
+            Err(ParseError::DimensionMismatch(_))
+        ));
+    }
+}
@@ -0,0 +1,173 @@
+/// # Permeability Units - SI Magnetic Permeability Measurements
+///
+/// This module defines SI magnetic permeability units and their conversions. Permeability
+/// measures how readily a material supports the formation of a magnetic field within itself,
+/// with henry per meter as the SI base unit.
+///
+/// ## Base Unit
+///
+/// - **HenryPerMeter (H/m)**: The SI base unit of permeability
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for henries per meter:
+/// - **YottahenryPerMeter (YH/m)**: 10²⁴ henries per meter
+/// - ... down to ...
+/// - **YoctohenryPerMeter (yH/m)**: 10⁻²⁴ henries per meter
+///
+/// ## Other Units
+///
+/// - **VacuumPermeability (μ₀)**: The magnetic constant, ≈ 1.2566 × 10⁻⁶ H/m. Core materials
+///   are usually specified by their relative permeability, which is a value in this unit.
+///
+/// ## Constants
+///
+/// - **VACUUM_PERMEABILITY**: μ₀ as a typed `Permeability<f64>`
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::permeability::{HenryPerMeter, Permeability, VacuumPermeability};
+///
+/// // Ferrite core with a relative permeability of 2300
+/// let ferrite = Permeability::from::<VacuumPermeability>(2300.0);
+///
+/// // Absolute permeability
+/// let mu = ferrite.to::<HenryPerMeter>(); // ≈ 2.89e-3 H/m
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// Vacuum permeability μ₀ in henries per meter (CODATA 2018)
+const MU_0: f64 = 1.256_637_062_12_E-6;
+
+// SI base unit
+units! {
+    HenryPerMeter: "H/m", "henry per meter", "henries per meter";
+}
+
+// SI prefixed henries per meter
+units! {
+    YottahenryPerMeter: "YH/m", "yottahenry per meter", "yottahenries per meter";
+    ZettahenryPerMeter: "ZH/m", "zettahenry per meter", "zettahenries per meter";
+    ExahenryPerMeter: "EH/m", "exahenry per meter", "exahenries per meter";
+    PetahenryPerMeter: "PH/m", "petahenry per meter", "petahenries per meter";
+    TerahenryPerMeter: "TH/m", "terahenry per meter", "terahenries per meter";
+    GigahenryPerMeter: "GH/m", "gigahenry per meter", "gigahenries per meter";
+    MegahenryPerMeter: "MH/m", "megahenry per meter", "megahenries per meter";
+    KilohenryPerMeter: "kH/m", "kilohenry per meter", "kilohenries per meter";
+    HectohenryPerMeter: "hH/m", "hectohenry per meter", "hectohenries per meter";
+    DecahenryPerMeter: "daH/m", "decahenry per meter", "decahenries per meter";
+    DecihenryPerMeter: "dH/m", "decihenry per meter", "decihenries per meter";
+    CentihenryPerMeter: "cH/m", "centihenry per meter", "centihenries per meter";
+    MillihenryPerMeter: "mH/m", "millihenry per meter", "millihenries per meter";
+    MicrohenryPerMeter: "µH/m", "microhenry per meter", "microhenries per meter";
+    NanohenryPerMeter: "nH/m", "nanohenry per meter", "nanohenries per meter";
+    PicohenryPerMeter: "pH/m", "picohenry per meter", "picohenries per meter";
+    FemtohenryPerMeter: "fH/m", "femtohenry per meter", "femtohenries per meter";
+    AttohenryPerMeter: "aH/m", "attohenry per meter", "attohenries per meter";
+    ZeptohenryPerMeter: "zH/m", "zeptohenry per meter", "zeptohenries per meter";
+    YoctohenryPerMeter: "yH/m", "yoctohenry per meter", "yoctohenries per meter";
+}
+
+// Other units
+units! {
+    VacuumPermeability: "μ₀", "vacuum permeability", "vacuum permeabilities";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed henries per meter
+    YottahenryPerMeter => HenryPerMeter: YOTTA;
+    ZettahenryPerMeter => HenryPerMeter: ZETTA;
+    ExahenryPerMeter => HenryPerMeter: EXA;
+    PetahenryPerMeter => HenryPerMeter: PETA;
+    TerahenryPerMeter => HenryPerMeter: TERA;
+    GigahenryPerMeter => HenryPerMeter: GIGA;
+    MegahenryPerMeter => HenryPerMeter: MEGA;
+    KilohenryPerMeter => HenryPerMeter: KILO;
+    HectohenryPerMeter => HenryPerMeter: HECTO;
+    DecahenryPerMeter => HenryPerMeter: DECA;
+    DecihenryPerMeter => HenryPerMeter: DECI;
+    CentihenryPerMeter => HenryPerMeter: CENTI;
+    MillihenryPerMeter => HenryPerMeter: MILLI;
+    MicrohenryPerMeter => HenryPerMeter: MICRO;
+    NanohenryPerMeter => HenryPerMeter: NANO;
+    PicohenryPerMeter => HenryPerMeter: PICO;
+    FemtohenryPerMeter => HenryPerMeter: FEMTO;
+    AttohenryPerMeter => HenryPerMeter: ATTO;
+    ZeptohenryPerMeter => HenryPerMeter: ZEPTO;
+    YoctohenryPerMeter => HenryPerMeter: YOCTO;
+
+    // Other units
+    VacuumPermeability => HenryPerMeter: MU_0;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    HenryPerMeter => YottahenryPerMeter, ZettahenryPerMeter, ExahenryPerMeter, PetahenryPerMeter,
+                     TerahenryPerMeter, GigahenryPerMeter, MegahenryPerMeter, KilohenryPerMeter,
+                     MillihenryPerMeter, MicrohenryPerMeter, NanohenryPerMeter, PicohenryPerMeter,
+                     FemtohenryPerMeter, AttohenryPerMeter, ZeptohenryPerMeter, YoctohenryPerMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    HenryPerMeter => YottahenryPerMeter, ZettahenryPerMeter, ExahenryPerMeter, PetahenryPerMeter,
+                     TerahenryPerMeter, GigahenryPerMeter, MegahenryPerMeter, KilohenryPerMeter,
+                     HectohenryPerMeter, DecahenryPerMeter, DecihenryPerMeter, CentihenryPerMeter,
+                     MillihenryPerMeter, MicrohenryPerMeter, NanohenryPerMeter, PicohenryPerMeter,
+                     FemtohenryPerMeter, AttohenryPerMeter, ZeptohenryPerMeter, YoctohenryPerMeter,
+                     VacuumPermeability
+}
+
+// Permeability quantity definition (Inductance/Length)
+use super::{ISQ, SiScale};
+quantity!(Permeability, ISQ<P1, P1, N2, N2, Z0, Z0, Z0>, SiScale, HenryPerMeter);
+
+/// Vacuum permeability μ₀, the magnetic constant.
+pub const VACUUM_PERMEABILITY: Permeability<f64> = Permeability::from_base(MU_0);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_permeability {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::permeability,
+                uom::si::magnetic_permeability,
+                Permeability,
+                MagneticPermeability,
+                HenryPerMeter,
+                $num_units_unit,
+                henry_per_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_permeability!(HenryPerMeter, henry_per_meter);
+    test_uom_permeability!(VacuumPermeability, vacuum_magnetic_permeability);
+
+    #[test]
+    fn test_vacuum_permeability() {
+        use crate::si::permeability::*;
+
+        assert_eq!(VACUUM_PERMEABILITY.to::<VacuumPermeability>(), 1.0);
+        assert_eq!(
+            VACUUM_PERMEABILITY.to::<HenryPerMeter>(),
+            1.256_637_062_12e-6
+        );
+
+        let ferrite = Permeability::from::<VacuumPermeability>(2300.0);
+        assert!((ferrite.to::<MillihenryPerMeter>() - 2.890_265_242_876_f64).abs() < 1e-9);
+    }
+}
+
+// Re-export types for convenience
+pub use permeability::Permeability;
+pub use permeability::*;
@@ -0,0 +1,176 @@
+/// # Permittivity Units - SI Electric Permittivity Measurements
+///
+/// This module defines SI permittivity units and their conversions. Permittivity describes how
+/// strongly a medium opposes the formation of an electric field, with farad per meter as the
+/// SI base unit.
+///
+/// ## Base Unit
+///
+/// - **FaradPerMeter (F/m)**: The SI base unit of permittivity
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for farads per meter:
+/// - **YottafaradPerMeter (YF/m)**: 10²⁴ farads per meter
+/// - ... down to ...
+/// - **YoctofaradPerMeter (yF/m)**: 10⁻²⁴ farads per meter
+///
+/// ## Other Units
+///
+/// - **VacuumPermittivity (ε₀)**: The electric constant, ≈ 8.854 × 10⁻¹² F/m. Measuring in
+///   this unit yields relative permittivity (dielectric constant) directly.
+///
+/// ## Constants
+///
+/// - **VACUUM_PERMITTIVITY**: ε₀ as a typed `Permittivity<f64>`
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::permittivity::{Permittivity, VacuumPermittivity, VACUUM_PERMITTIVITY};
+///
+/// // Permittivity of a dielectric with relative permittivity 4.4 (FR-4)
+/// let fr4 = Permittivity::from::<VacuumPermittivity>(4.4);
+///
+/// // Relative permittivity back out of an absolute value
+/// let eps_r = fr4.to::<VacuumPermittivity>(); // 4.4
+///
+/// // ε₀ as a typed constant for Coulomb's-law style computations
+/// let k = 1.0 / (4.0 * core::f64::consts::PI * VACUUM_PERMITTIVITY.value);
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// Vacuum permittivity ε₀ in farads per meter (CODATA 2018)
+const EPSILON_0: f64 = 8.854_187_812_8_E-12;
+
+// SI base unit
+units! {
+    FaradPerMeter: "F/m", "farad per meter", "farads per meter";
+}
+
+// SI prefixed farads per meter
+units! {
+    YottafaradPerMeter: "YF/m", "yottafarad per meter", "yottafarads per meter";
+    ZettafaradPerMeter: "ZF/m", "zettafarad per meter", "zettafarads per meter";
+    ExafaradPerMeter: "EF/m", "exafarad per meter", "exafarads per meter";
+    PetafaradPerMeter: "PF/m", "petafarad per meter", "petafarads per meter";
+    TerafaradPerMeter: "TF/m", "terafarad per meter", "terafarads per meter";
+    GigafaradPerMeter: "GF/m", "gigafarad per meter", "gigafarads per meter";
+    MegafaradPerMeter: "MF/m", "megafarad per meter", "megafarads per meter";
+    KilofaradPerMeter: "kF/m", "kilofarad per meter", "kilofarads per meter";
+    HectofaradPerMeter: "hF/m", "hectofarad per meter", "hectofarads per meter";
+    DecafaradPerMeter: "daF/m", "decafarad per meter", "decafarads per meter";
+    DecifaradPerMeter: "dF/m", "decifarad per meter", "decifarads per meter";
+    CentifaradPerMeter: "cF/m", "centifarad per meter", "centifarads per meter";
+    MillifaradPerMeter: "mF/m", "millifarad per meter", "millifarads per meter";
+    MicrofaradPerMeter: "µF/m", "microfarad per meter", "microfarads per meter";
+    NanofaradPerMeter: "nF/m", "nanofarad per meter", "nanofarads per meter";
+    PicofaradPerMeter: "pF/m", "picofarad per meter", "picofarads per meter";
+    FemtofaradPerMeter: "fF/m", "femtofarad per meter", "femtofarads per meter";
+    AttofaradPerMeter: "aF/m", "attofarad per meter", "attofarads per meter";
+    ZeptofaradPerMeter: "zF/m", "zeptofarad per meter", "zeptofarads per meter";
+    YoctofaradPerMeter: "yF/m", "yoctofarad per meter", "yoctofarads per meter";
+}
+
+// Other units
+units! {
+    VacuumPermittivity: "ε₀", "vacuum permittivity", "vacuum permittivities";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed farads per meter
+    YottafaradPerMeter => FaradPerMeter: YOTTA;
+    ZettafaradPerMeter => FaradPerMeter: ZETTA;
+    ExafaradPerMeter => FaradPerMeter: EXA;
+    PetafaradPerMeter => FaradPerMeter: PETA;
+    TerafaradPerMeter => FaradPerMeter: TERA;
+    GigafaradPerMeter => FaradPerMeter: GIGA;
+    MegafaradPerMeter => FaradPerMeter: MEGA;
+    KilofaradPerMeter => FaradPerMeter: KILO;
+    HectofaradPerMeter => FaradPerMeter: HECTO;
+    DecafaradPerMeter => FaradPerMeter: DECA;
+    DecifaradPerMeter => FaradPerMeter: DECI;
+    CentifaradPerMeter => FaradPerMeter: CENTI;
+    MillifaradPerMeter => FaradPerMeter: MILLI;
+    MicrofaradPerMeter => FaradPerMeter: MICRO;
+    NanofaradPerMeter => FaradPerMeter: NANO;
+    PicofaradPerMeter => FaradPerMeter: PICO;
+    FemtofaradPerMeter => FaradPerMeter: FEMTO;
+    AttofaradPerMeter => FaradPerMeter: ATTO;
+    ZeptofaradPerMeter => FaradPerMeter: ZEPTO;
+    YoctofaradPerMeter => FaradPerMeter: YOCTO;
+
+    // Other units
+    VacuumPermittivity => FaradPerMeter: EPSILON_0;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    FaradPerMeter => YottafaradPerMeter, ZettafaradPerMeter, ExafaradPerMeter, PetafaradPerMeter,
+                     TerafaradPerMeter, GigafaradPerMeter, MegafaradPerMeter, KilofaradPerMeter,
+                     MillifaradPerMeter, MicrofaradPerMeter, NanofaradPerMeter, PicofaradPerMeter,
+                     FemtofaradPerMeter, AttofaradPerMeter, ZeptofaradPerMeter, YoctofaradPerMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    FaradPerMeter => YottafaradPerMeter, ZettafaradPerMeter, ExafaradPerMeter, PetafaradPerMeter,
+                     TerafaradPerMeter, GigafaradPerMeter, MegafaradPerMeter, KilofaradPerMeter,
+                     HectofaradPerMeter, DecafaradPerMeter, DecifaradPerMeter, CentifaradPerMeter,
+                     MillifaradPerMeter, MicrofaradPerMeter, NanofaradPerMeter, PicofaradPerMeter,
+                     FemtofaradPerMeter, AttofaradPerMeter, ZeptofaradPerMeter, YoctofaradPerMeter,
+                     VacuumPermittivity
+}
+
+// Permittivity quantity definition (Capacitance/Length)
+use super::{ISQ, SiScale};
+quantity!(Permittivity, ISQ<N3, N1, P4, P2, Z0, Z0, Z0>, SiScale, FaradPerMeter);
+
+/// Vacuum permittivity ε₀, the electric constant.
+pub const VACUUM_PERMITTIVITY: Permittivity<f64> = Permittivity::from_base(EPSILON_0);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_permittivity {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::permittivity,
+                uom::si::electric_permittivity,
+                Permittivity,
+                ElectricPermittivity,
+                FaradPerMeter,
+                $num_units_unit,
+                farad_per_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_permittivity!(FaradPerMeter, farad_per_meter);
+    test_uom_permittivity!(VacuumPermittivity, vacuum_electric_permittivity);
+
+    #[test]
+    fn test_vacuum_permittivity() {
+        use crate::si::permittivity::*;
+
+        assert_eq!(VACUUM_PERMITTIVITY.to::<VacuumPermittivity>(), 1.0);
+        assert_eq!(
+            VACUUM_PERMITTIVITY.to::<FaradPerMeter>(),
+            8.854_187_812_8e-12
+        );
+
+        let fr4 = Permittivity::from::<VacuumPermittivity>(4.4);
+        assert!((fr4.to::<PicofaradPerMeter>() - 38.958_426_376_32_f64).abs() < 1e-9);
+    }
+}
+
+// Re-export types for convenience
+pub use permittivity::Permittivity;
+pub use permittivity::*;
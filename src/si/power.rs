@@ -44,6 +44,14 @@
 /// - **HorsepowerMetric**: 735.4988 watts (metric horsepower)
 /// - **HorsepowerImperial**: 745.70 watts (Imperial horsepower)
 /// - **HydraulicHorsepower**: 746.043 watts (hydraulic horsepower)
+/// - **BtuItPerHour**: 0.29307107017 watts (Btu (IT) per hour, HVAC ratings)
+/// - **TonRefrigeration**: 3516.8528420667 watts (12,000 Btu (IT) per hour)
+/// - **SolarLuminosity**: 3.828 × 10²⁶ watts (IAU 2015 nominal value)
+///
+/// ## Logarithmic Units
+///
+/// - **DecibelMilliwatt (dBm)**: level relative to 1 mW, so 0 dBm = 1 mW and 30 dBm = 1 W
+/// - **DecibelWatt (dBW)**: level relative to 1 W
 ///
 /// ## Usage
 ///
@@ -109,16 +117,25 @@ units! {
 
 // Other power units
 units! {
-    ErgPerSecond: "erg/s", "erg per second";
-    FootPoundPerHour: "ft·lbf/h", "foot pound-force per hour";
-    FootPoundPerMinute: "ft·lbf/min", "foot pound-force per minute";
-    FootPoundPerSecond: "ft·lbf/s", "foot pound-force per second";
-    Horsepower: "hp", "horsepower";
-    HorsepowerBoiler: "hp (S)", "horsepower (boiler)";
-    HorsepowerElectric: "hp (E)", "horsepower (electric)";
-    HorsepowerMetric: "hp (M)", "metric horsepower";
-    HorsepowerImperial: "hp (I)", "horsepower (Imperial)";
-    HydraulicHorsepower: "hp (hydraulic)", "hydraulic horsepower";
+    ErgPerSecond: "erg/s", "erg per second", "ergs per second";
+    FootPoundPerHour: "ft·lbf/h", "foot pound-force per hour", "foot pounds-force per hour";
+    FootPoundPerMinute: "ft·lbf/min", "foot pound-force per minute", "foot pounds-force per minute";
+    FootPoundPerSecond: "ft·lbf/s", "foot pound-force per second", "foot pounds-force per second";
+    Horsepower: "hp", "horsepower", "horsepower";
+    HorsepowerBoiler: "hp (S)", "horsepower (boiler)", "horsepower (boiler)";
+    HorsepowerElectric: "hp (E)", "horsepower (electric)", "horsepower (electric)";
+    HorsepowerMetric: "hp (M)", "metric horsepower", "metric horsepower";
+    HorsepowerImperial: "hp (I)", "horsepower (Imperial)", "horsepower (Imperial)";
+    HydraulicHorsepower: "hp (hydraulic)", "hydraulic horsepower", "hydraulic horsepower";
+    BtuItPerHour: "Btu (IT)/h", "British thermal unit (IT) per hour", "British thermal units (IT) per hour";
+    TonRefrigeration: "TR", "ton of refrigeration", "tons of refrigeration";
+    SolarLuminosity: "L☉", "solar luminosity", "solar luminosities";
+}
+
+// Logarithmic power levels
+units! {
+    DecibelMilliwatt: "dBm", "decibel-milliwatt";
+    DecibelWatt: "dBW", "decibel-watt";
 }
 
 // Unit conversions using convert_linear! with exact UOM coefficients
@@ -156,10 +173,33 @@ crate::convert_linear! {
     HorsepowerMetric => Watt: 7.354_988_E2;
     HorsepowerImperial => Watt: 7.457_0_E2;
     HydraulicHorsepower => Watt: 7.460_43_E2;
+
+    // Refrigeration and astronomical units - derived from Btu (IT) = 1055.05585262 J
+    BtuItPerHour => Watt: 2.930_710_701_722_222_E-1;
+    TonRefrigeration => Watt: 3.516_852_842_066_667_E3;
+    SolarLuminosity => Watt: 3.828_E26;
+}
+
+// Power levels: P = reference * 10^(level / 10)
+crate::convert_log! {
+    DecibelMilliwatt => Watt: 1.0E-3, 10.0, 10.0;
+    DecibelWatt => Watt: 1.0, 10.0, 10.0;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Watt => Yottawatt, Zettawatt, Exawatt, Petawatt, Terawatt, Gigawatt, Megawatt, Kilowatt,
+            Milliwatt, Microwatt, Nanowatt, Picowatt, Femtowatt, Attowatt, Zeptowatt, Yoctowatt
 }
 
-crate::convert_matrix! {
-    Watt => Yottawatt, Zettawatt, Exawatt, Petawatt, Terawatt, Gigawatt, Megawatt, Kilowatt, Hectowatt, Decawatt, Deciwatt, Centiwatt, Milliwatt, Microwatt, Nanowatt, Picowatt, Femtowatt, Attowatt, Zeptowatt, Yoctowatt, ErgPerSecond, FootPoundPerHour, FootPoundPerMinute, FootPoundPerSecond, Horsepower, HorsepowerBoiler, HorsepowerElectric, HorsepowerMetric, HorsepowerImperial, HydraulicHorsepower
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    Watt => Yottawatt, Zettawatt, Exawatt, Petawatt, Terawatt, Gigawatt, Megawatt, Kilowatt,
+            Hectowatt, Decawatt, Deciwatt, Centiwatt, Milliwatt, Microwatt, Nanowatt, Picowatt,
+            Femtowatt, Attowatt, Zeptowatt, Yoctowatt, ErgPerSecond, FootPoundPerHour,
+            FootPoundPerMinute, FootPoundPerSecond, Horsepower, HorsepowerBoiler,
+            HorsepowerElectric, HorsepowerMetric, HorsepowerImperial, HydraulicHorsepower,
+            BtuItPerHour, TonRefrigeration, SolarLuminosity, DecibelMilliwatt, DecibelWatt
 }
 
 // Power quantity definition (Mass×Length²/Time³)
@@ -219,6 +259,34 @@ mod tests {
     test_uom_power!(HorsepowerMetric, horsepower_metric);
     test_uom_power!(HorsepowerImperial, horsepower_imperial);
     test_uom_power!(HydraulicHorsepower, hydraulic_horsepower);
+
+    #[test]
+    fn test_refrigeration_units() {
+        use crate::si::power::*;
+
+        let chiller = Power::from::<TonRefrigeration>(1.0);
+        assert!((chiller.to::<BtuItPerHour>() - 12_000.0_f64).abs() < 1e-8);
+        assert!((chiller.to::<Kilowatt>() - 3.516_852_842_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_solar_luminosity() {
+        use crate::si::power::*;
+
+        let sun = Power::from::<SolarLuminosity>(1.0);
+        assert!((sun.to::<Yottawatt>() - 382.8_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_power_levels() {
+        use crate::si::power::*;
+
+        let transmitter = Power::from::<DecibelMilliwatt>(30.0);
+        assert!((transmitter.to::<Watt>() - 1.0_f64).abs() < 1e-12);
+        assert!((transmitter.to::<DecibelWatt>() - 0.0_f64).abs() < 1e-12);
+        let receiver = Power::from::<Nanowatt>(1.0_f32);
+        assert!((receiver.to::<DecibelMilliwatt>() + 60.0).abs() < 1e-4);
+    }
 }
 
 // Re-export types for convenience
@@ -0,0 +1,174 @@
+/// # Radioactivity Units - SI Activity Measurements
+///
+/// This module defines SI radioactivity units and their conversions. Radioactivity (activity)
+/// is a derived quantity in the SI system with dimensions of time⁻¹, with the becquerel
+/// as its base unit. Although it shares its dimension with frequency, it is exposed here as
+/// a quantity of its own.
+///
+/// ## Base Unit
+///
+/// - **Becquerel (Bq)**: One decay per second
+///
+/// ## SI Prefixed Units
+///
+/// SI prefixes from nano- to yotta- are supported for becquerels:
+/// - **Yottabecquerel (YBq)**: 10²⁴ becquerels
+/// - ... down to ...
+/// - **Nanobecquerel (nBq)**: 10⁻⁹ becquerels
+///
+/// ## Other Units
+///
+/// - **Curie (Ci)**: 3.7 × 10¹⁰ becquerels, with prefixed forms from nCi to GCi
+/// - **DisintegrationsPerMinute (dpm)**: 1/60 becquerel
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::radioactivity::{Radioactivity, Curie, Megabecquerel};
+///
+/// // Create radioactivity quantities
+/// let source = Radioactivity::from::<Curie>(0.001);
+///
+/// // Convert between units
+/// let source_mbq = source.to::<Megabecquerel>(); // 37.0 MBq
+/// ```
+use crate::prefix::{
+    DECA, EXA, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, MINUTE, NANO, PETA, TERA, YOTTA, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    Becquerel: "Bq", "becquerel";
+}
+
+// SI prefixed becquerels
+units! {
+    Yottabecquerel: "YBq", "yottabecquerel";
+    Zettabecquerel: "ZBq", "zettabecquerel";
+    Exabecquerel: "EBq", "exabecquerel";
+    Petabecquerel: "PBq", "petabecquerel";
+    Terabecquerel: "TBq", "terabecquerel";
+    Gigabecquerel: "GBq", "gigabecquerel";
+    Megabecquerel: "MBq", "megabecquerel";
+    Kilobecquerel: "kBq", "kilobecquerel";
+    Hectobecquerel: "hBq", "hectobecquerel";
+    Decabecquerel: "daBq", "decabecquerel";
+    Millibecquerel: "mBq", "millibecquerel";
+    Microbecquerel: "µBq", "microbecquerel";
+    Nanobecquerel: "nBq", "nanobecquerel";
+}
+
+// Curies
+units! {
+    Gigacurie: "GCi", "gigacurie";
+    Megacurie: "MCi", "megacurie";
+    Kilocurie: "kCi", "kilocurie";
+    Curie: "Ci", "curie";
+    Millicurie: "mCi", "millicurie";
+    Microcurie: "µCi", "microcurie";
+    Nanocurie: "nCi", "nanocurie";
+}
+
+// Counting units
+units! {
+    DisintegrationsPerMinute: "dpm", "disintegration per minute", "disintegrations per minute";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed becquerels
+    Yottabecquerel => Becquerel: YOTTA;
+    Zettabecquerel => Becquerel: ZETTA;
+    Exabecquerel => Becquerel: EXA;
+    Petabecquerel => Becquerel: PETA;
+    Terabecquerel => Becquerel: TERA;
+    Gigabecquerel => Becquerel: GIGA;
+    Megabecquerel => Becquerel: MEGA;
+    Kilobecquerel => Becquerel: KILO;
+    Hectobecquerel => Becquerel: HECTO;
+    Decabecquerel => Becquerel: DECA;
+    Millibecquerel => Becquerel: MILLI;
+    Microbecquerel => Becquerel: MICRO;
+    Nanobecquerel => Becquerel: NANO;
+
+    // Curies (1 Ci = 3.7 × 10¹⁰ Bq)
+    Gigacurie => Becquerel: GIGA * 3.7e10;
+    Megacurie => Becquerel: MEGA * 3.7e10;
+    Kilocurie => Becquerel: KILO * 3.7e10;
+    Curie => Becquerel: 3.7e10;
+    Millicurie => Becquerel: MILLI * 3.7e10;
+    Microcurie => Becquerel: MICRO * 3.7e10;
+    Nanocurie => Becquerel: NANO * 3.7e10;
+
+    DisintegrationsPerMinute => Becquerel: 1.0 / MINUTE;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Becquerel => Yottabecquerel, Zettabecquerel, Exabecquerel, Petabecquerel, Terabecquerel,
+                 Gigabecquerel, Megabecquerel, Kilobecquerel, Millibecquerel, Microbecquerel,
+                 Nanobecquerel
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    Becquerel => Yottabecquerel, Zettabecquerel, Exabecquerel, Petabecquerel, Terabecquerel,
+                 Gigabecquerel, Megabecquerel, Kilobecquerel, Hectobecquerel, Decabecquerel,
+                 Millibecquerel, Microbecquerel, Nanobecquerel, Gigacurie, Megacurie, Kilocurie,
+                 Curie, Millicurie, Microcurie, Nanocurie, DisintegrationsPerMinute
+}
+
+// Radioactivity quantity definition (1/Time)
+use super::{ISQ, SiScale};
+quantity!(Radioactivity, ISQ<Z0, Z0, N1, Z0, Z0, Z0, Z0>, SiScale, Becquerel);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_radioactivity {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::radioactivity,
+                uom::si::radioactivity,
+                Radioactivity,
+                Radioactivity,
+                Becquerel,
+                $num_units_unit,
+                becquerel,
+                $uom_unit
+            );
+        };
+    }
+
+    // Test SI prefixed becquerels
+    test_uom_radioactivity!(Yottabecquerel, yottabecquerel);
+    test_uom_radioactivity!(Zettabecquerel, zettabecquerel);
+    test_uom_radioactivity!(Exabecquerel, exabecquerel);
+    test_uom_radioactivity!(Petabecquerel, petabecquerel);
+    test_uom_radioactivity!(Terabecquerel, terabecquerel);
+    test_uom_radioactivity!(Gigabecquerel, gigabecquerel);
+    test_uom_radioactivity!(Megabecquerel, megabecquerel);
+    test_uom_radioactivity!(Kilobecquerel, kilobecquerel);
+    test_uom_radioactivity!(Hectobecquerel, hectobecquerel);
+    test_uom_radioactivity!(Decabecquerel, decabecquerel);
+    test_uom_radioactivity!(Becquerel, becquerel);
+    test_uom_radioactivity!(Millibecquerel, millibecquerel);
+    test_uom_radioactivity!(Microbecquerel, microbecquerel);
+    test_uom_radioactivity!(Nanobecquerel, nanobecquerel);
+
+    // Test curies and other units
+    test_uom_radioactivity!(Gigacurie, gigacurie);
+    test_uom_radioactivity!(Megacurie, megacurie);
+    test_uom_radioactivity!(Kilocurie, kilocurie);
+    test_uom_radioactivity!(Curie, curie);
+    test_uom_radioactivity!(Millicurie, millicurie);
+    test_uom_radioactivity!(Microcurie, microcurie);
+    test_uom_radioactivity!(Nanocurie, nanocurie);
+    test_uom_radioactivity!(DisintegrationsPerMinute, disintegrations_per_minute);
+}
+
+// Re-export types for convenience
+pub use radioactivity::Radioactivity;
+pub use radioactivity::*;
@@ -29,6 +29,15 @@
 /// - **PartPerTrillion**: 0.000000000001 (ppt)
 /// - **PartPerQuadrillion**: 0.000000000000001 (ppq)
 ///
+/// ## Logarithmic Units
+///
+/// Levels of a power ratio (`convert_log!`), so `Unitless` holds the ratio itself:
+/// - **Bel (B)**: ratio = 10^B
+/// - **Decibel (dB)**: ratio = 10^(dB/10)
+/// - **Neper (Np)**: ratio = e^(2·Np), so 1 Np ≈ 8.686 dB
+///
+/// For amplitude (root-power) ratios, halve the level: 6 dB is twice the voltage.
+///
 /// ## Usage
 ///
 /// ```rust,ignore
@@ -59,17 +68,24 @@ use crate::prefix::*;
 // Ratio units (dimensionless)
 units! {
     Ratio: "", "ratio";
-    PartPerHundred: "parts per hundred", "part per hundred";
-    Percent: "%", "percent";
-    PartPerThousand: "parts per thousand", "part per thousand";
-    PerMille: "‰", "per mille";
-    Promille: "‰", "promille";
-    PartPerTenThousand: "parts per ten thousand", "part per ten thousand";
+    PartPerHundred: "parts per hundred", "part per hundred", "parts per hundred";
+    Percent: "%", "percent", "percent";
+    PartPerThousand: "parts per thousand", "part per thousand", "parts per thousand";
+    PerMille: "‰", "per mille", "per mille";
+    Promille: "‰", "promille", "promille";
+    PartPerTenThousand: "parts per ten thousand", "part per ten thousand", "parts per ten thousand";
     BasisPoint: "bp", "basis point";
-    PartPerMillion: "ppm", "part per million";
-    PartPerBillion: "ppb", "part per billion";
-    PartPerTrillion: "ppt", "part per trillion";
-    PartPerQuadrillion: "ppq", "part per quadrillion";
+    PartPerMillion: "ppm", "part per million", "parts per million";
+    PartPerBillion: "ppb", "part per billion", "parts per billion";
+    PartPerTrillion: "ppt", "part per trillion", "parts per trillion";
+    PartPerQuadrillion: "ppq", "part per quadrillion", "parts per quadrillion";
+}
+
+// Logarithmic units (power-ratio levels)
+units! {
+    Bel: "B", "bel";
+    Decibel: "dB", "decibel";
+    Neper: "Np", "neper";
 }
 
 // Unit conversions using convert_linear! with exact UOM coefficients
@@ -89,9 +105,38 @@ crate::convert_linear! {
     PartPerQuadrillion => Unitless: FEMTO;
 }
 
-crate::convert_matrix! {
-    Unitless => Ratio, PartPerHundred, Percent, PartPerThousand, PerMille, PartPerTenThousand, BasisPoint, PartPerMillion, PartPerBillion, PartPerTrillion, PartPerQuadrillion
+// Levels of a power ratio; a neper counts e-folds of the amplitude, so the
+// power ratio is e^(2·Np) and 1 Np ≈ 8.686 dB
+crate::convert_log! {
+    Bel => Unitless: 1.0, 10.0, 1.0;
+    Decibel => Unitless: 1.0, 10.0, 10.0;
+    Neper => Unitless: 1.0, core::f64::consts::E, 0.5;
 }
 
 // Import Unitless from scalar module
 use super::scalar::Unitless;
+
+#[cfg(test)]
+mod tests {
+    use super::{Bel, Decibel, Neper};
+    use crate::si::scalar::Scalar;
+
+    #[test]
+    fn test_log_ratio_levels() {
+        let close = |actual: f64, expected: f64| (actual - expected).abs() < 1e-9;
+        let gain = Scalar::from::<Decibel>(20.0);
+        assert!(close(*gain.base(), 100.0));
+        assert!(close(gain.to::<Bel>(), 2.0));
+        assert!(close(
+            *Scalar::from::<Decibel>(3.0).base(),
+            1.9952623149688795
+        ));
+        assert!(close(
+            Scalar::from::<Neper>(1.0).to::<Decibel>(),
+            8.685889638065035
+        ));
+        assert!(close(Scalar::from_base(1.0).to::<Neper>(), 0.0));
+        assert_eq!(Scalar::from_base(0.0).to::<Decibel>(), f64::NEG_INFINITY);
+        assert!(Scalar::from_base(-1.0_f64).to::<Decibel>().is_nan());
+    }
+}
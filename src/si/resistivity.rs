@@ -0,0 +1,190 @@
+/// # Resistivity Units - SI Electrical Resistivity Measurements
+///
+/// This module defines SI electrical resistivity units and their conversions. Resistivity is
+/// the intrinsic property of a material to oppose electric current, independent of the shape
+/// of the conductor, with ohm meter as the SI base unit. Its reciprocal, electrical
+/// conductivity, is defined in the sibling `conductivity` module.
+///
+/// ## Base Unit
+///
+/// - **OhmMeter (Ω·m)**: The SI base unit of electrical resistivity
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for ohm meters:
+/// - **YottaohmMeter (YΩ·m)**: 10²⁴ ohm meters
+/// - ... down to ...
+/// - **YoctoohmMeter (yΩ·m)**: 10⁻²⁴ ohm meters
+///
+/// ## Other Units
+///
+/// - **OhmCentimeter (Ω·cm)**: 10⁻² ohm meters, common for semiconductors
+/// - **MicroohmCentimeter (µΩ·cm)**: 10⁻⁸ ohm meters, common for metals
+/// - **OhmSquareMillimeterPerMeter (Ω·mm²/m)**: 10⁻⁶ ohm meters, used for wire tables
+/// - **OhmInch (Ω·in)**, **OhmFoot (Ω·ft)**: Imperial resistivity units
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::resistivity::{ElectricalResistivity, MicroohmCentimeter, OhmMeter};
+///
+/// // Annealed copper
+/// let copper = ElectricalResistivity::from::<MicroohmCentimeter>(1.724);
+///
+/// // Convert between units
+/// let copper_si = copper.to::<OhmMeter>(); // 1.724e-8 Ω·m
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    OhmMeter: "Ω·m", "ohm meter", "ohm meters";
+}
+
+// SI prefixed ohm meters
+units! {
+    YottaohmMeter: "YΩ·m", "yottaohm meter", "yottaohm meters";
+    ZettaohmMeter: "ZΩ·m", "zettaohm meter", "zettaohm meters";
+    ExaohmMeter: "EΩ·m", "exaohm meter", "exaohm meters";
+    PetaohmMeter: "PΩ·m", "petaohm meter", "petaohm meters";
+    TeraohmMeter: "TΩ·m", "teraohm meter", "teraohm meters";
+    GigaohmMeter: "GΩ·m", "gigaohm meter", "gigaohm meters";
+    MegaohmMeter: "MΩ·m", "megaohm meter", "megaohm meters";
+    KiloohmMeter: "kΩ·m", "kiloohm meter", "kiloohm meters";
+    HectoohmMeter: "hΩ·m", "hectoohm meter", "hectoohm meters";
+    DecaohmMeter: "daΩ·m", "decaohm meter", "decaohm meters";
+    DeciohmMeter: "dΩ·m", "deciohm meter", "deciohm meters";
+    CentiohmMeter: "cΩ·m", "centiohm meter", "centiohm meters";
+    MilliohmMeter: "mΩ·m", "milliohm meter", "milliohm meters";
+    MicroohmMeter: "µΩ·m", "microohm meter", "microohm meters";
+    NanoohmMeter: "nΩ·m", "nanoohm meter", "nanoohm meters";
+    PicoohmMeter: "pΩ·m", "picoohm meter", "picoohm meters";
+    FemtoohmMeter: "fΩ·m", "femtoohm meter", "femtoohm meters";
+    AttoohmMeter: "aΩ·m", "attoohm meter", "attoohm meters";
+    ZeptoohmMeter: "zΩ·m", "zeptoohm meter", "zeptoohm meters";
+    YoctoohmMeter: "yΩ·m", "yoctoohm meter", "yoctoohm meters";
+}
+
+// Other units
+units! {
+    OhmCentimeter: "Ω·cm", "ohm centimeter", "ohm centimeters";
+    MicroohmCentimeter: "µΩ·cm", "microohm centimeter", "microohm centimeters";
+    OhmSquareMillimeterPerMeter: "Ω·mm²/m", "ohm square millimeter per meter", "ohm square millimeters per meter";
+    OhmInch: "Ω·in", "ohm inch", "ohm inches";
+    OhmFoot: "Ω·ft", "ohm foot", "ohm feet";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed ohm meters
+    YottaohmMeter => OhmMeter: YOTTA;
+    ZettaohmMeter => OhmMeter: ZETTA;
+    ExaohmMeter => OhmMeter: EXA;
+    PetaohmMeter => OhmMeter: PETA;
+    TeraohmMeter => OhmMeter: TERA;
+    GigaohmMeter => OhmMeter: GIGA;
+    MegaohmMeter => OhmMeter: MEGA;
+    KiloohmMeter => OhmMeter: KILO;
+    HectoohmMeter => OhmMeter: HECTO;
+    DecaohmMeter => OhmMeter: DECA;
+    DeciohmMeter => OhmMeter: DECI;
+    CentiohmMeter => OhmMeter: CENTI;
+    MilliohmMeter => OhmMeter: MILLI;
+    MicroohmMeter => OhmMeter: MICRO;
+    NanoohmMeter => OhmMeter: NANO;
+    PicoohmMeter => OhmMeter: PICO;
+    FemtoohmMeter => OhmMeter: FEMTO;
+    AttoohmMeter => OhmMeter: ATTO;
+    ZeptoohmMeter => OhmMeter: ZEPTO;
+    YoctoohmMeter => OhmMeter: YOCTO;
+
+    // Other units
+    OhmCentimeter => OhmMeter: CENTI;
+    MicroohmCentimeter => OhmMeter: MICRO * CENTI;
+    OhmSquareMillimeterPerMeter => OhmMeter: MILLI * MILLI;
+    OhmInch => OhmMeter: 2.54_E-2;
+    OhmFoot => OhmMeter: 3.048_E-1;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    OhmMeter => YottaohmMeter, ZettaohmMeter, ExaohmMeter, PetaohmMeter, TeraohmMeter, GigaohmMeter,
+                MegaohmMeter, KiloohmMeter, MilliohmMeter, MicroohmMeter, NanoohmMeter,
+                PicoohmMeter, FemtoohmMeter, AttoohmMeter, ZeptoohmMeter, YoctoohmMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    OhmMeter => YottaohmMeter, ZettaohmMeter, ExaohmMeter, PetaohmMeter, TeraohmMeter, GigaohmMeter,
+                MegaohmMeter, KiloohmMeter, HectoohmMeter, DecaohmMeter, DeciohmMeter,
+                CentiohmMeter, MilliohmMeter, MicroohmMeter, NanoohmMeter, PicoohmMeter,
+                FemtoohmMeter, AttoohmMeter, ZeptoohmMeter, YoctoohmMeter, OhmCentimeter,
+                MicroohmCentimeter, OhmSquareMillimeterPerMeter, OhmInch, OhmFoot
+}
+
+// Electrical resistivity quantity definition (Resistance × Length)
+use super::{ISQ, SiScale};
+quantity!(ElectricalResistivity, ISQ<P3, P1, N3, N2, Z0, Z0, Z0>, SiScale, OhmMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_resistivity {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::resistivity,
+                uom::si::electrical_resistivity,
+                ElectricalResistivity,
+                ElectricalResistivity,
+                OhmMeter,
+                $num_units_unit,
+                ohm_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_resistivity!(OhmMeter, ohm_meter);
+    test_uom_resistivity!(YottaohmMeter, yottaohm_meter);
+    test_uom_resistivity!(ZettaohmMeter, zettaohm_meter);
+    test_uom_resistivity!(ExaohmMeter, exaohm_meter);
+    test_uom_resistivity!(PetaohmMeter, petaohm_meter);
+    test_uom_resistivity!(TeraohmMeter, teraohm_meter);
+    test_uom_resistivity!(GigaohmMeter, gigaohm_meter);
+    test_uom_resistivity!(MegaohmMeter, megaohm_meter);
+    test_uom_resistivity!(KiloohmMeter, kiloohm_meter);
+    test_uom_resistivity!(HectoohmMeter, hectoohm_meter);
+    test_uom_resistivity!(DecaohmMeter, decaohm_meter);
+    test_uom_resistivity!(DeciohmMeter, deciohm_meter);
+    test_uom_resistivity!(CentiohmMeter, centiohm_meter);
+    test_uom_resistivity!(MilliohmMeter, milliohm_meter);
+    test_uom_resistivity!(MicroohmMeter, microohm_meter);
+    test_uom_resistivity!(NanoohmMeter, nanoohm_meter);
+    test_uom_resistivity!(PicoohmMeter, picoohm_meter);
+    test_uom_resistivity!(FemtoohmMeter, femtoohm_meter);
+    test_uom_resistivity!(AttoohmMeter, attoohm_meter);
+    test_uom_resistivity!(ZeptoohmMeter, zeptoohm_meter);
+    test_uom_resistivity!(YoctoohmMeter, yoctoohm_meter);
+    test_uom_resistivity!(OhmCentimeter, ohm_centimeter);
+    test_uom_resistivity!(OhmSquareMillimeterPerMeter, ohm_square_millimeter_per_meter);
+    test_uom_resistivity!(OhmInch, ohm_inch);
+    test_uom_resistivity!(OhmFoot, ohm_foot);
+
+    #[test]
+    fn test_metal_units() {
+        use crate::si::resistivity::*;
+
+        let copper = ElectricalResistivity::from::<MicroohmCentimeter>(1.724);
+        assert!((copper.to::<OhmMeter>() - 1.724e-8_f64).abs() < 1e-20);
+        assert!((copper.to::<OhmSquareMillimeterPerMeter>() - 0.017_24_f64).abs() < 1e-14);
+    }
+}
+
+// Re-export types for convenience
+pub use electrical_resistivity::ElectricalResistivity;
+pub use electrical_resistivity::*;
@@ -2,7 +2,41 @@ use typenum::*;
 
 // Dimensionless scalar base unit
 units! {
-    Unitless: "", "unitless";
+    Unitless: "", "unitless", "unitless";
+}
+
+// No engineering-prefixed units; `Quantity::format_si` keeps the base unit
+crate::si_prefixed!(Unitless);
+
+// Units looked up by symbol or name at runtime, collected from the other
+// dimensionless modules since they all share `Unitless`. The logarithmic
+// ratio units stay out: a bel is no linear multiple of a percent, and its
+// symbol "B" would shadow the byte.
+crate::unit_registry! {
+    Unitless =>
+    super::ratio::Ratio, super::ratio::PartPerHundred, super::ratio::Percent,
+    super::ratio::PartPerThousand, super::ratio::PerMille, super::ratio::Promille,
+    super::ratio::PartPerTenThousand, super::ratio::BasisPoint, super::ratio::PartPerMillion,
+    super::ratio::PartPerBillion, super::ratio::PartPerTrillion, super::ratio::PartPerQuadrillion,
+    super::angle::Revolution, super::angle::Radian, super::angle::Degree, super::angle::Gon,
+    super::angle::Mil, super::angle::Minute, super::angle::Second, super::angle::Arcminute,
+    super::angle::Arcsecond, super::angle::Gradian, super::angle::Milliradian,
+    super::solid_angle::Steradian, super::solid_angle::SquareDegree, super::solid_angle::Spat,
+    super::information::Yobibit, super::information::Yottabit, super::information::Zebibit,
+    super::information::Zettabit, super::information::Exbibit, super::information::Exabit,
+    super::information::Pebibit, super::information::Petabit, super::information::Tebibit,
+    super::information::Terabit, super::information::Gibibit, super::information::Gigabit,
+    super::information::Mebibit, super::information::Megabit, super::information::Kibibit,
+    super::information::Kilobit, super::information::Bit, super::information::Yobibyte,
+    super::information::Yottabyte, super::information::Zebibyte, super::information::Zettabyte,
+    super::information::Exbibyte, super::information::Exabyte, super::information::Pebibyte,
+    super::information::Petabyte, super::information::Tebibyte, super::information::Terabyte,
+    super::information::Gibibyte, super::information::Gigabyte, super::information::Mebibyte,
+    super::information::Megabyte, super::information::Kibibyte, super::information::Kilobyte,
+    super::information::Byte, super::information::Octet, super::information::Nibble,
+    super::information::Crumb, super::information::Word, super::information::Shannon,
+    super::information::NaturalUnitOfInformation, super::information::Trit,
+    super::information::Hartley, super::information::Deciban
 }
 
 // Scalar quantity definition (dimensionless)
@@ -11,4 +45,55 @@ quantity!(Scalar, ISQ<Z0, Z0, Z0, Z0, Z0, Z0, Z0>, SiScale, Unitless);
 
 // Re-export types for convenience
 pub use scalar::Scalar;
-pub use scalar::*;
\ No newline at end of file
+pub use scalar::*;
+
+// ===== PLAIN NUMBER CONVERSIONS =====
+
+// Only the dimensionless quantity converts to and from bare numbers; a plain
+// `f64` carries no unit that could make it a `Length`. `Scalar::from` is the
+// unit conversion, so these are used through `into()`.
+impl<V> From<V> for Scalar<V> {
+    fn from(value: V) -> Self {
+        Scalar::from_base(value)
+    }
+}
+
+// `From<Scalar<V>> for V` cannot be written generically (orphan rule), so
+// the primitive numbers are listed
+macro_rules! impl_from_scalar {
+    ($($primitive:ty),+) => {
+        $(
+            impl From<Scalar<$primitive>> for $primitive {
+                fn from(scalar: Scalar<$primitive>) -> Self {
+                    scalar.value
+                }
+            }
+        )+
+    };
+}
+
+impl_from_scalar!(
+    f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+#[cfg(test)]
+mod tests {
+    use super::Scalar;
+    use crate::si::length::Length;
+
+    #[test]
+    fn test_plain_numbers_round_trip() {
+        let efficiency: Scalar<f64> = 0.85.into();
+        assert_eq!(*efficiency.base(), 0.85);
+        let plain: f64 = efficiency.into();
+        assert_eq!(plain, 0.85);
+        let count: u32 = Scalar::from_base(12_u32).into();
+        assert_eq!(count, 12);
+    }
+
+    #[test]
+    fn test_ratio_into_plain_number() {
+        let ratio = Length::from_base(3.0) / Length::from_base(12.0);
+        assert_eq!(f64::from(ratio), 0.25);
+    }
+}
@@ -0,0 +1,81 @@
+/// # Solid Angle Units - Solid Angle Measurements
+///
+/// This module defines solid angle units and their conversions. Solid angles are dimensionless
+/// quantities that measure the field of view an object covers from a given point, with the
+/// steradian as the SI unit.
+///
+/// ## Important Note
+///
+/// Like plane angles, solid angles have no separate quantity type because num-units has no
+/// "kind" system to tell them apart from other dimensionless values. They are built on the
+/// Unitless base quantity, so a solid angle is a plain dimensionless scalar at the type level
+/// and nothing stops it from being mixed with ratios or plane angles.
+///
+/// ## Base Unit
+///
+/// - **Steradian (sr)**: The SI unit of solid angle
+///
+/// ## Other Units
+///
+/// - **SquareDegree (°²)**: (π/180)² steradians
+/// - **Spat (sp)**: The solid angle of a full sphere, 4π steradians
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::scalar::Scalar;
+/// use num_units::si::solid_angle::{Spat, SquareDegree, Steradian};
+///
+/// // Create solid angle quantities as dimensionless scalars
+/// let field_of_view = Scalar::from::<SquareDegree>(100.0);
+///
+/// // Convert between units
+/// let field_of_view_sr = field_of_view.to::<Steradian>(); // ≈ 0.0305 sr
+/// let sky_fraction = field_of_view.to::<Spat>();          // ≈ 0.0024 sp
+/// ```
+use super::scalar::Unitless;
+
+// Base solid angle unit
+units! {
+    Steradian: "sr", "steradian";
+}
+
+// Other solid angle units
+units! {
+    SquareDegree: "°²", "square degree";
+    Spat: "sp", "spat";
+}
+
+// Solid angle unit conversions with Steradian as base unit
+// All conversions are to Unitless (dimensionless base)
+crate::convert_linear! {
+    Steradian => Unitless: 1.0;                            // Base unit
+    SquareDegree => Unitless: 3.046_174_197_867_086_E-4;   // (π/180)²
+    Spat => Unitless: 1.256_637_061_435_917_3_E1;          // 4π
+}
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_solid_angle {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::scalar,
+                uom::si::solid_angle,
+                Scalar,
+                SolidAngle,
+                Steradian,
+                $num_units_unit,
+                steradian,
+                $uom_unit
+            );
+        };
+    }
+
+    use super::{Spat, SquareDegree, Steradian};
+
+    test_uom_solid_angle!(Steradian, steradian);
+    test_uom_solid_angle!(SquareDegree, square_degree);
+    test_uom_solid_angle!(Spat, spat);
+}
@@ -0,0 +1,208 @@
+/// # Specific Energy Units - SI Specific Energy Measurements
+///
+/// This module defines SI specific energy units and their conversions. Specific energy is the
+/// energy per unit mass, with joule per kilogram as the SI base unit. It describes the
+/// gravimetric energy density of batteries and fuels, heating values and latent heats.
+///
+/// ## Base Unit
+///
+/// - **JoulePerKilogram (J/kg)**: The SI base unit of specific energy
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for joules per kilogram:
+/// - **YottajoulePerKilogram (YJ/kg)**: 10²⁴ joules per kilogram
+/// - ... down to ...
+/// - **YoctojoulePerKilogram (yJ/kg)**: 10⁻²⁴ joules per kilogram
+///
+/// ## Other Units
+///
+/// - **JoulePerGram (J/g)**: 10³ joules per kilogram
+/// - **WattHourPerKilogram (Wh/kg)**: 3600 joules per kilogram, used for battery cells
+/// - **KilowattHourPerKilogram (kWh/kg)**: 3.6 × 10⁶ joules per kilogram
+/// - **CaloriePerGram (cal/g)**: Thermochemical calorie per gram, 4184 joules per kilogram
+/// - **CalorieItPerGram (cal (IT)/g)**: International Table calorie per gram
+/// - **BtuItPerPound (Btu (IT)/lb)**: International Table BTU per pound, ≈ 2326 J/kg
+/// - **BtuPerPound (Btu/lb)**: BTU per pound, ≈ 2324.4 J/kg
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::specific_energy::{MegajoulePerKilogram, SpecificEnergy, WattHourPerKilogram};
+///
+/// // Lithium-ion cell
+/// let cell = SpecificEnergy::from::<WattHourPerKilogram>(250.0);
+///
+/// // Convert between units
+/// let cell_mj = cell.to::<MegajoulePerKilogram>(); // 0.9 MJ/kg
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, HOUR, KILO, MEGA, MICRO, MILLI, NANO, PETA,
+    PICO, TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    JoulePerKilogram: "J/kg", "joule per kilogram", "joules per kilogram";
+}
+
+// SI prefixed joules per kilogram
+units! {
+    YottajoulePerKilogram: "YJ/kg", "yottajoule per kilogram", "yottajoules per kilogram";
+    ZettajoulePerKilogram: "ZJ/kg", "zettajoule per kilogram", "zettajoules per kilogram";
+    ExajoulePerKilogram: "EJ/kg", "exajoule per kilogram", "exajoules per kilogram";
+    PetajoulePerKilogram: "PJ/kg", "petajoule per kilogram", "petajoules per kilogram";
+    TerajoulePerKilogram: "TJ/kg", "terajoule per kilogram", "terajoules per kilogram";
+    GigajoulePerKilogram: "GJ/kg", "gigajoule per kilogram", "gigajoules per kilogram";
+    MegajoulePerKilogram: "MJ/kg", "megajoule per kilogram", "megajoules per kilogram";
+    KilojoulePerKilogram: "kJ/kg", "kilojoule per kilogram", "kilojoules per kilogram";
+    HectojoulePerKilogram: "hJ/kg", "hectojoule per kilogram", "hectojoules per kilogram";
+    DecajoulePerKilogram: "daJ/kg", "decajoule per kilogram", "decajoules per kilogram";
+    DecijoulePerKilogram: "dJ/kg", "decijoule per kilogram", "decijoules per kilogram";
+    CentijoulePerKilogram: "cJ/kg", "centijoule per kilogram", "centijoules per kilogram";
+    MillijoulePerKilogram: "mJ/kg", "millijoule per kilogram", "millijoules per kilogram";
+    MicrojoulePerKilogram: "µJ/kg", "microjoule per kilogram", "microjoules per kilogram";
+    NanojoulePerKilogram: "nJ/kg", "nanojoule per kilogram", "nanojoules per kilogram";
+    PicojoulePerKilogram: "pJ/kg", "picojoule per kilogram", "picojoules per kilogram";
+    FemtojoulePerKilogram: "fJ/kg", "femtojoule per kilogram", "femtojoules per kilogram";
+    AttojoulePerKilogram: "aJ/kg", "attojoule per kilogram", "attojoules per kilogram";
+    ZeptojoulePerKilogram: "zJ/kg", "zeptojoule per kilogram", "zeptojoules per kilogram";
+    YoctojoulePerKilogram: "yJ/kg", "yoctojoule per kilogram", "yoctojoules per kilogram";
+}
+
+// Other units
+units! {
+    JoulePerGram: "J/g", "joule per gram", "joules per gram";
+    WattHourPerKilogram: "Wh/kg", "watt hour per kilogram", "watt hours per kilogram";
+    KilowattHourPerKilogram: "kWh/kg", "kilowatt hour per kilogram", "kilowatt hours per kilogram";
+    CaloriePerGram: "cal/g", "calorie per gram", "calories per gram";
+    CalorieItPerGram: "cal (IT)/g", "calorie (IT) per gram", "calories (IT) per gram";
+    BtuItPerPound: "Btu (IT)/lb", "British thermal unit (IT) per pound", "British thermal units (IT) per pound";
+    BtuPerPound: "Btu/lb", "British thermal unit per pound", "British thermal units per pound";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed joules per kilogram
+    YottajoulePerKilogram => JoulePerKilogram: YOTTA;
+    ZettajoulePerKilogram => JoulePerKilogram: ZETTA;
+    ExajoulePerKilogram => JoulePerKilogram: EXA;
+    PetajoulePerKilogram => JoulePerKilogram: PETA;
+    TerajoulePerKilogram => JoulePerKilogram: TERA;
+    GigajoulePerKilogram => JoulePerKilogram: GIGA;
+    MegajoulePerKilogram => JoulePerKilogram: MEGA;
+    KilojoulePerKilogram => JoulePerKilogram: KILO;
+    HectojoulePerKilogram => JoulePerKilogram: HECTO;
+    DecajoulePerKilogram => JoulePerKilogram: DECA;
+    DecijoulePerKilogram => JoulePerKilogram: DECI;
+    CentijoulePerKilogram => JoulePerKilogram: CENTI;
+    MillijoulePerKilogram => JoulePerKilogram: MILLI;
+    MicrojoulePerKilogram => JoulePerKilogram: MICRO;
+    NanojoulePerKilogram => JoulePerKilogram: NANO;
+    PicojoulePerKilogram => JoulePerKilogram: PICO;
+    FemtojoulePerKilogram => JoulePerKilogram: FEMTO;
+    AttojoulePerKilogram => JoulePerKilogram: ATTO;
+    ZeptojoulePerKilogram => JoulePerKilogram: ZEPTO;
+    YoctojoulePerKilogram => JoulePerKilogram: YOCTO;
+
+    // Other units
+    JoulePerGram => JoulePerKilogram: KILO;
+    WattHourPerKilogram => JoulePerKilogram: HOUR;
+    KilowattHourPerKilogram => JoulePerKilogram: KILO * HOUR;
+    CaloriePerGram => JoulePerKilogram: 4.184_E3;
+    CalorieItPerGram => JoulePerKilogram: 4.186_8_E3;
+    BtuItPerPound => JoulePerKilogram: 2.326_000_171_078_704_E3;
+    BtuPerPound => JoulePerKilogram: 2.324_443_707_610_621_E3;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    JoulePerKilogram => YottajoulePerKilogram, ZettajoulePerKilogram, ExajoulePerKilogram,
+                        PetajoulePerKilogram, TerajoulePerKilogram, GigajoulePerKilogram,
+                        MegajoulePerKilogram, KilojoulePerKilogram, MillijoulePerKilogram,
+                        MicrojoulePerKilogram, NanojoulePerKilogram, PicojoulePerKilogram,
+                        FemtojoulePerKilogram, AttojoulePerKilogram, ZeptojoulePerKilogram,
+                        YoctojoulePerKilogram
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    JoulePerKilogram => YottajoulePerKilogram, ZettajoulePerKilogram, ExajoulePerKilogram,
+                        PetajoulePerKilogram, TerajoulePerKilogram, GigajoulePerKilogram,
+                        MegajoulePerKilogram, KilojoulePerKilogram, HectojoulePerKilogram,
+                        DecajoulePerKilogram, DecijoulePerKilogram, CentijoulePerKilogram,
+                        MillijoulePerKilogram, MicrojoulePerKilogram, NanojoulePerKilogram,
+                        PicojoulePerKilogram, FemtojoulePerKilogram, AttojoulePerKilogram,
+                        ZeptojoulePerKilogram, YoctojoulePerKilogram, JoulePerGram,
+                        WattHourPerKilogram, KilowattHourPerKilogram, CaloriePerGram,
+                        CalorieItPerGram, BtuItPerPound, BtuPerPound
+}
+
+// Specific energy quantity definition (Energy/Mass)
+use super::{ISQ, SiScale};
+quantity!(SpecificEnergy, ISQ<P2, Z0, N2, Z0, Z0, Z0, Z0>, SiScale, JoulePerKilogram);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_specific_energy {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::specific_energy,
+                uom::si::available_energy,
+                SpecificEnergy,
+                AvailableEnergy,
+                JoulePerKilogram,
+                $num_units_unit,
+                joule_per_kilogram,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_specific_energy!(JoulePerKilogram, joule_per_kilogram);
+    test_uom_specific_energy!(YottajoulePerKilogram, yottajoule_per_kilogram);
+    test_uom_specific_energy!(ZettajoulePerKilogram, zettajoule_per_kilogram);
+    test_uom_specific_energy!(ExajoulePerKilogram, exajoule_per_kilogram);
+    test_uom_specific_energy!(PetajoulePerKilogram, petajoule_per_kilogram);
+    test_uom_specific_energy!(TerajoulePerKilogram, terajoule_per_kilogram);
+    test_uom_specific_energy!(GigajoulePerKilogram, gigajoule_per_kilogram);
+    test_uom_specific_energy!(MegajoulePerKilogram, megajoule_per_kilogram);
+    test_uom_specific_energy!(KilojoulePerKilogram, kilojoule_per_kilogram);
+    test_uom_specific_energy!(HectojoulePerKilogram, hectojoule_per_kilogram);
+    test_uom_specific_energy!(DecajoulePerKilogram, decajoule_per_kilogram);
+    test_uom_specific_energy!(DecijoulePerKilogram, decijoule_per_kilogram);
+    test_uom_specific_energy!(CentijoulePerKilogram, centijoule_per_kilogram);
+    test_uom_specific_energy!(MillijoulePerKilogram, millijoule_per_kilogram);
+    test_uom_specific_energy!(MicrojoulePerKilogram, microjoule_per_kilogram);
+    test_uom_specific_energy!(NanojoulePerKilogram, nanojoule_per_kilogram);
+    test_uom_specific_energy!(PicojoulePerKilogram, picojoule_per_kilogram);
+    test_uom_specific_energy!(FemtojoulePerKilogram, femtojoule_per_kilogram);
+    test_uom_specific_energy!(AttojoulePerKilogram, attojoule_per_kilogram);
+    test_uom_specific_energy!(ZeptojoulePerKilogram, zeptojoule_per_kilogram);
+    test_uom_specific_energy!(YoctojoulePerKilogram, yoctojoule_per_kilogram);
+    test_uom_specific_energy!(JoulePerGram, joule_per_gram);
+    test_uom_specific_energy!(CaloriePerGram, calorie_per_gram);
+    test_uom_specific_energy!(CalorieItPerGram, calorie_it_per_gram);
+    test_uom_specific_energy!(BtuItPerPound, btu_it_per_pound);
+    test_uom_specific_energy!(BtuPerPound, btu_per_pound);
+
+    #[test]
+    fn test_energy_over_mass() {
+        use crate::si::energy::{Energy, WattHour};
+        use crate::si::mass::{Kilogram, Mass};
+        use crate::si::specific_energy::*;
+
+        let cell: SpecificEnergy<f64> =
+            Energy::from::<WattHour>(500.0) / Mass::from::<Kilogram>(2.0);
+        assert!((cell.to::<WattHourPerKilogram>() - 250.0_f64).abs() < 1e-9);
+        assert!((cell.to::<MegajoulePerKilogram>() - 0.9_f64).abs() < 1e-12);
+        assert!((cell.to::<KilowattHourPerKilogram>() - 0.25_f64).abs() < 1e-12);
+    }
+}
+
+// Re-export types for convenience
+pub use specific_energy::SpecificEnergy;
+pub use specific_energy::*;
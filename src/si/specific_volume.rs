@@ -0,0 +1,121 @@
+/// # Specific Volume Units - SI Specific Volume Measurements
+///
+/// This module defines SI specific volume units and their conversions. Specific volume is the
+/// volume occupied by a unit of mass, the reciprocal of mass density, with cubic meter per
+/// kilogram as the SI base unit. Steam tables and thermodynamic property charts list it
+/// alongside pressure and temperature.
+///
+/// ## Base Unit
+///
+/// - **CubicMeterPerKilogram (m³/kg)**: The SI base unit of specific volume
+///
+/// ## Other Units
+///
+/// - **CubicMeterPerGram (m³/g)**: 10³ cubic meters per kilogram
+/// - **CubicMeterPerTon (m³/t)**: 10⁻³ cubic meters per kilogram
+/// - **LiterPerKilogram (L/kg)**: 10⁻³ cubic meters per kilogram
+/// - **CubicCentimeterPerGram (cm³/g)**: 10⁻³ cubic meters per kilogram
+/// - **CubicFootPerPound (ft³/lb)**: ≈ 0.06243 cubic meters per kilogram
+/// - **CubicInchPerPound (in³/lb)**: ≈ 3.613 × 10⁻⁵ cubic meters per kilogram
+/// - **GallonPerPound (gal/lb)**: US liquid gallon per pound
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::specific_volume::{CubicFootPerPound, CubicMeterPerKilogram, SpecificVolume};
+///
+/// // Saturated steam at 1 bar
+/// let steam = SpecificVolume::from::<CubicMeterPerKilogram>(1.694);
+///
+/// // Convert between units
+/// let steam_imperial = steam.to::<CubicFootPerPound>(); // ≈ 27.13 ft³/lb
+/// ```
+use crate::prefix::{CENTI, KILO, MILLI};
+use typenum::*;
+
+// SI base unit
+units! {
+    CubicMeterPerKilogram: "m³/kg", "cubic meter per kilogram", "cubic meters per kilogram";
+}
+
+// Other units
+units! {
+    CubicMeterPerGram: "m³/g", "cubic meter per gram", "cubic meters per gram";
+    CubicMeterPerTon: "m³/t", "cubic meter per ton", "cubic meters per ton";
+    LiterPerKilogram: "L/kg", "liter per kilogram", "liters per kilogram";
+    CubicCentimeterPerGram: "cm³/g", "cubic centimeter per gram", "cubic centimeters per gram";
+    CubicFootPerPound: "ft³/lb", "cubic foot per pound", "cubic feet per pound";
+    CubicInchPerPound: "in³/lb", "cubic inch per pound", "cubic inches per pound";
+    GallonPerPound: "gal/lb", "gallon per pound", "gallons per pound";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // Other units
+    CubicMeterPerGram => CubicMeterPerKilogram: KILO;
+    CubicMeterPerTon => CubicMeterPerKilogram: 1.0_E-3;
+    LiterPerKilogram => CubicMeterPerKilogram: MILLI;
+    CubicCentimeterPerGram => CubicMeterPerKilogram: CENTI * CENTI * CENTI * KILO;
+    CubicFootPerPound => CubicMeterPerKilogram: 6.242_796_396_059_546_E-2;
+    CubicInchPerPound => CubicMeterPerKilogram: 3.612_728_079_218_259_E-5;
+    GallonPerPound => CubicMeterPerKilogram: 8.345_404_376_263_8_E-3;
+}
+
+// No engineering-prefixed units; `Quantity::format_si` keeps the base unit
+crate::si_prefixed!(CubicMeterPerKilogram);
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    CubicMeterPerKilogram => CubicMeterPerGram, CubicMeterPerTon, LiterPerKilogram,
+                             CubicCentimeterPerGram, CubicFootPerPound, CubicInchPerPound,
+                             GallonPerPound
+}
+
+// Specific volume quantity definition (Volume/Mass)
+use super::{ISQ, SiScale};
+quantity!(SpecificVolume, ISQ<P3, N1, Z0, Z0, Z0, Z0, Z0>, SiScale, CubicMeterPerKilogram);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_specific_volume {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::specific_volume,
+                uom::si::specific_volume,
+                SpecificVolume,
+                SpecificVolume,
+                CubicMeterPerKilogram,
+                $num_units_unit,
+                cubic_meter_per_kilogram,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_specific_volume!(CubicMeterPerKilogram, cubic_meter_per_kilogram);
+    test_uom_specific_volume!(CubicMeterPerGram, cubic_meter_per_gram);
+    test_uom_specific_volume!(CubicMeterPerTon, cubic_meter_per_ton);
+    test_uom_specific_volume!(CubicCentimeterPerGram, cubic_centimeter_per_gram);
+    test_uom_specific_volume!(CubicFootPerPound, cubic_foot_per_pound);
+    test_uom_specific_volume!(CubicInchPerPound, cubic_inch_per_pound);
+    test_uom_specific_volume!(GallonPerPound, gallon_per_pound);
+
+    #[test]
+    fn test_volume_over_mass() {
+        use crate::si::mass::{Kilogram, Mass};
+        use crate::si::specific_volume::*;
+        use crate::si::volume::{Liter, Volume};
+
+        let steam: SpecificVolume<f64> =
+            Volume::from::<Liter>(1694.0) / Mass::from::<Kilogram>(1.0);
+        assert!((steam.to::<CubicMeterPerKilogram>() - 1.694_f64).abs() < 1e-12);
+        assert!((steam.to::<LiterPerKilogram>() - 1694.0_f64).abs() < 1e-9);
+        assert!((steam.to::<CubicFootPerPound>() - 27.134_8_f64).abs() < 1e-3);
+    }
+}
+
+// Re-export types for convenience
+pub use specific_volume::SpecificVolume;
+pub use specific_volume::*;
@@ -0,0 +1,192 @@
+/// # Surface Charge Density Units - SI Surface Charge Density Measurements
+///
+/// This module defines SI surface charge density units and their conversions. Surface charge
+/// density is the electric charge per unit area, such as the charge held on a capacitor plate
+/// or a conductor surface, with coulomb per square meter as the SI base unit.
+///
+/// ## Base Unit
+///
+/// - **CoulombPerSquareMeter (C/m²)**: The SI base unit of surface charge density
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for coulombs per square meter:
+/// - **YottacoulombPerSquareMeter (YC/m²)**: 10²⁴ coulombs per square meter
+/// - ... down to ...
+/// - **YoctocoulombPerSquareMeter (yC/m²)**: 10⁻²⁴ coulombs per square meter
+///
+/// ## Other Units
+///
+/// - **CoulombPerSquareCentimeter (C/cm²)**: 10⁴ coulombs per square meter
+/// - **CoulombPerSquareMillimeter (C/mm²)**: 10⁶ coulombs per square meter
+/// - **StatcoulombPerSquareCentimeter (statC/cm²)**: Gaussian CGS unit, ≈ 3.336 × 10⁻⁶ C/m²
+/// - **AbcoulombPerSquareCentimeter (abC/cm²)**: Electromagnetic CGS unit, 10⁵ C/m²
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::surface_charge_density::{CoulombPerSquareMeter, MicrocoulombPerSquareMeter, SurfaceChargeDensity};
+///
+/// // Create surface charge density quantities
+/// let plate = SurfaceChargeDensity::from::<MicrocoulombPerSquareMeter>(25.0);
+///
+/// // Convert between units
+/// let plate_si = plate.to::<CoulombPerSquareMeter>(); // 2.5e-5 C/m²
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// Gaussian and electromagnetic CGS charge units in coulombs
+const STATCOULOMB: f64 = 1.0 / 2.997_924_58_E9;
+const ABCOULOMB: f64 = 10.0;
+
+// SI base unit
+units! {
+    CoulombPerSquareMeter: "C/m²", "coulomb per square meter", "coulombs per square meter";
+}
+
+// SI prefixed coulombs per square meter
+units! {
+    YottacoulombPerSquareMeter: "YC/m²", "yottacoulomb per square meter", "yottacoulombs per square meter";
+    ZettacoulombPerSquareMeter: "ZC/m²", "zettacoulomb per square meter", "zettacoulombs per square meter";
+    ExacoulombPerSquareMeter: "EC/m²", "exacoulomb per square meter", "exacoulombs per square meter";
+    PetacoulombPerSquareMeter: "PC/m²", "petacoulomb per square meter", "petacoulombs per square meter";
+    TeracoulombPerSquareMeter: "TC/m²", "teracoulomb per square meter", "teracoulombs per square meter";
+    GigacoulombPerSquareMeter: "GC/m²", "gigacoulomb per square meter", "gigacoulombs per square meter";
+    MegacoulombPerSquareMeter: "MC/m²", "megacoulomb per square meter", "megacoulombs per square meter";
+    KilocoulombPerSquareMeter: "kC/m²", "kilocoulomb per square meter", "kilocoulombs per square meter";
+    HectocoulombPerSquareMeter: "hC/m²", "hectocoulomb per square meter", "hectocoulombs per square meter";
+    DecacoulombPerSquareMeter: "daC/m²", "decacoulomb per square meter", "decacoulombs per square meter";
+    DecicoulombPerSquareMeter: "dC/m²", "decicoulomb per square meter", "decicoulombs per square meter";
+    CenticoulombPerSquareMeter: "cC/m²", "centicoulomb per square meter", "centicoulombs per square meter";
+    MillicoulombPerSquareMeter: "mC/m²", "millicoulomb per square meter", "millicoulombs per square meter";
+    MicrocoulombPerSquareMeter: "µC/m²", "microcoulomb per square meter", "microcoulombs per square meter";
+    NanocoulombPerSquareMeter: "nC/m²", "nanocoulomb per square meter", "nanocoulombs per square meter";
+    PicocoulombPerSquareMeter: "pC/m²", "picocoulomb per square meter", "picocoulombs per square meter";
+    FemtocoulombPerSquareMeter: "fC/m²", "femtocoulomb per square meter", "femtocoulombs per square meter";
+    AttocoulombPerSquareMeter: "aC/m²", "attocoulomb per square meter", "attocoulombs per square meter";
+    ZeptocoulombPerSquareMeter: "zC/m²", "zeptocoulomb per square meter", "zeptocoulombs per square meter";
+    YoctocoulombPerSquareMeter: "yC/m²", "yoctocoulomb per square meter", "yoctocoulombs per square meter";
+}
+
+// Other units
+units! {
+    CoulombPerSquareCentimeter: "C/cm²", "coulomb per square centimeter", "coulombs per square centimeter";
+    CoulombPerSquareMillimeter: "C/mm²", "coulomb per square millimeter", "coulombs per square millimeter";
+    StatcoulombPerSquareCentimeter: "statC/cm²", "statcoulomb per square centimeter", "statcoulombs per square centimeter";
+    AbcoulombPerSquareCentimeter: "abC/cm²", "abcoulomb per square centimeter", "abcoulombs per square centimeter";
+}
+
+// Unit conversions using convert_linear!
+crate::convert_linear! {
+    // SI prefixed coulombs per square meter
+    YottacoulombPerSquareMeter => CoulombPerSquareMeter: YOTTA;
+    ZettacoulombPerSquareMeter => CoulombPerSquareMeter: ZETTA;
+    ExacoulombPerSquareMeter => CoulombPerSquareMeter: EXA;
+    PetacoulombPerSquareMeter => CoulombPerSquareMeter: PETA;
+    TeracoulombPerSquareMeter => CoulombPerSquareMeter: TERA;
+    GigacoulombPerSquareMeter => CoulombPerSquareMeter: GIGA;
+    MegacoulombPerSquareMeter => CoulombPerSquareMeter: MEGA;
+    KilocoulombPerSquareMeter => CoulombPerSquareMeter: KILO;
+    HectocoulombPerSquareMeter => CoulombPerSquareMeter: HECTO;
+    DecacoulombPerSquareMeter => CoulombPerSquareMeter: DECA;
+    DecicoulombPerSquareMeter => CoulombPerSquareMeter: DECI;
+    CenticoulombPerSquareMeter => CoulombPerSquareMeter: CENTI;
+    MillicoulombPerSquareMeter => CoulombPerSquareMeter: MILLI;
+    MicrocoulombPerSquareMeter => CoulombPerSquareMeter: MICRO;
+    NanocoulombPerSquareMeter => CoulombPerSquareMeter: NANO;
+    PicocoulombPerSquareMeter => CoulombPerSquareMeter: PICO;
+    FemtocoulombPerSquareMeter => CoulombPerSquareMeter: FEMTO;
+    AttocoulombPerSquareMeter => CoulombPerSquareMeter: ATTO;
+    ZeptocoulombPerSquareMeter => CoulombPerSquareMeter: ZEPTO;
+    YoctocoulombPerSquareMeter => CoulombPerSquareMeter: YOCTO;
+
+    // Other units
+    CoulombPerSquareCentimeter => CoulombPerSquareMeter: 1.0 / CENTI / CENTI;
+    CoulombPerSquareMillimeter => CoulombPerSquareMeter: 1.0 / MILLI / MILLI;
+    StatcoulombPerSquareCentimeter => CoulombPerSquareMeter: STATCOULOMB / CENTI / CENTI;
+    AbcoulombPerSquareCentimeter => CoulombPerSquareMeter: ABCOULOMB / CENTI / CENTI;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    CoulombPerSquareMeter => YottacoulombPerSquareMeter, ZettacoulombPerSquareMeter,
+                             ExacoulombPerSquareMeter, PetacoulombPerSquareMeter,
+                             TeracoulombPerSquareMeter, GigacoulombPerSquareMeter,
+                             MegacoulombPerSquareMeter, KilocoulombPerSquareMeter,
+                             MillicoulombPerSquareMeter, MicrocoulombPerSquareMeter,
+                             NanocoulombPerSquareMeter, PicocoulombPerSquareMeter,
+                             FemtocoulombPerSquareMeter, AttocoulombPerSquareMeter,
+                             ZeptocoulombPerSquareMeter, YoctocoulombPerSquareMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    CoulombPerSquareMeter => YottacoulombPerSquareMeter, ZettacoulombPerSquareMeter,
+                             ExacoulombPerSquareMeter, PetacoulombPerSquareMeter,
+                             TeracoulombPerSquareMeter, GigacoulombPerSquareMeter,
+                             MegacoulombPerSquareMeter, KilocoulombPerSquareMeter,
+                             HectocoulombPerSquareMeter, DecacoulombPerSquareMeter,
+                             DecicoulombPerSquareMeter, CenticoulombPerSquareMeter,
+                             MillicoulombPerSquareMeter, MicrocoulombPerSquareMeter,
+                             NanocoulombPerSquareMeter, PicocoulombPerSquareMeter,
+                             FemtocoulombPerSquareMeter, AttocoulombPerSquareMeter,
+                             ZeptocoulombPerSquareMeter, YoctocoulombPerSquareMeter,
+                             CoulombPerSquareCentimeter, CoulombPerSquareMillimeter,
+                             StatcoulombPerSquareCentimeter, AbcoulombPerSquareCentimeter
+}
+
+// Surface Charge Density quantity definition (Charge/Length²)
+use super::{ISQ, SiScale};
+quantity!(SurfaceChargeDensity, ISQ<N2, Z0, P1, P1, Z0, Z0, Z0>, SiScale, CoulombPerSquareMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_surface_charge_density {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::surface_charge_density,
+                uom::si::electric_charge_areal_density,
+                SurfaceChargeDensity,
+                ElectricChargeArealDensity,
+                CoulombPerSquareMeter,
+                $num_units_unit,
+                coulomb_per_square_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_surface_charge_density!(CoulombPerSquareMeter, coulomb_per_square_meter);
+    test_uom_surface_charge_density!(CoulombPerSquareCentimeter, coulomb_per_square_centimeter);
+
+    #[test]
+    fn test_prefixed_units() {
+        use crate::si::surface_charge_density::*;
+
+        let density = SurfaceChargeDensity::from::<MillicoulombPerSquareMeter>(1.0);
+        assert_eq!(density.to::<CoulombPerSquareMeter>(), 1.0e-3);
+        let density = SurfaceChargeDensity::from::<KilocoulombPerSquareMeter>(1.0);
+        assert_eq!(density.to::<CoulombPerSquareMeter>(), 1000.0);
+    }
+
+    #[test]
+    fn test_cgs_units() {
+        use crate::si::surface_charge_density::*;
+
+        let gaussian = SurfaceChargeDensity::from::<StatcoulombPerSquareCentimeter>(2.997_924_58e9);
+        assert!((gaussian.to::<CoulombPerSquareCentimeter>() - 1.0_f64).abs() < 1e-12);
+
+        let electromagnetic = SurfaceChargeDensity::from::<AbcoulombPerSquareCentimeter>(1.0);
+        assert!((electromagnetic.to::<CoulombPerSquareCentimeter>() - 10.0_f64).abs() < 1e-12);
+    }
+}
+
+// Re-export types for convenience
+pub use surface_charge_density::SurfaceChargeDensity;
+pub use surface_charge_density::*;
@@ -17,12 +17,21 @@ use crate::prefix::*;
 /// - **Degree Celsius (°C)**: Equal to kelvin for intervals
 /// - **Degree Fahrenheit (°F)**: 5/9 of kelvin
 /// - **Degree Rankine (°R)**: 5/9 of kelvin
+/// - **Degree Réaumur (°Ré)**: 5/4 of kelvin (water freezes at 0 °Ré and boils at 80 °Ré)
+/// - **Degree Delisle (°De)**: 2/3 of kelvin. The historical Delisle scale runs backwards
+///   (water boils at 0 °De and freezes at 150 °De); as an interval unit only the magnitude of
+///   the step is represented, so a rise of 1 °De here is a rise of 2/3 K
 ///
 /// ## Implementation Notes
 ///
 /// This implementation follows UOM's temperature_interval quantity, which uses linear
 /// conversions without offsets. This differs from absolute temperature scales that require
 /// affine transformations (scaling + offset).
+///
+/// Absolute temperatures use the separate affine path instead:
+/// `Temperature::from_absolute::<DegreeCelsius>(20.0)` is 293.15 K and `to_absolute`
+/// reads a temperature back on any of the scales. Both kinds share the `Temperature`
+/// type, so the caller picks the conversion that matches the meaning of the value.
 use typenum::*;
 
 // ===== SI BASE UNIT =====
@@ -51,9 +60,11 @@ units! {
     Yoctokelvin: "yK", "yoctokelvin";
 
     // Temperature scales (linear conversions only)
-    DegreeCelsius: "°C", "degree Celsius";
-    DegreeFahrenheit: "°F", "degree Fahrenheit";
-    DegreeRankine: "°R", "degree Rankine";
+    DegreeCelsius: "°C", "degree Celsius", "degrees Celsius";
+    DegreeFahrenheit: "°F", "degree Fahrenheit", "degrees Fahrenheit";
+    DegreeRankine: "°R", "degree Rankine", "degrees Rankine";
+    DegreeReaumur: "°Ré", "degree Réaumur", "degrees Réaumur";
+    DegreeDelisle: "°De", "degree Delisle", "degrees Delisle";
 }
 
 // ===== CONVERSION RELATIONSHIPS =====
@@ -87,13 +98,36 @@ convert_linear! {
     DegreeCelsius => Kelvin: 1.0;
     DegreeFahrenheit => Kelvin: 5.0 / 9.0;
     DegreeRankine => Kelvin: 5.0 / 9.0;
+    DegreeReaumur => Kelvin: 5.0 / 4.0;
+    DegreeDelisle => Kelvin: 2.0 / 3.0;
+}
+
+// Temperature scales (affine conversions for absolute temperatures)
+crate::convert_affine! {
+    Kelvin => Kelvin: 1.0, 0.0;
+    DegreeCelsius => Kelvin: 1.0, 273.15;
+    DegreeFahrenheit => Kelvin: 5.0 / 9.0, 459.67 * 5.0 / 9.0;
+    DegreeRankine => Kelvin: 5.0 / 9.0, 0.0;
+    DegreeReaumur => Kelvin: 5.0 / 4.0, 273.15;
+    // The Delisle scale runs backwards from the boiling point of water
+    DegreeDelisle => Kelvin: -2.0 / 3.0, 373.15;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Kelvin => Yottakelvin, Zettakelvin, Exakelvin, Petakelvin, Terakelvin, Gigakelvin, Megakelvin,
+              Kilokelvin, Millikelvin, Microkelvin, Nanokelvin, Picokelvin, Femtokelvin, Attokelvin,
+              Zeptokelvin, Yoctokelvin
 }
 
-convert_matrix! {
-    Kelvin => Yottakelvin, Zettakelvin, Exakelvin, Petakelvin, Terakelvin, Gigakelvin,
-        Megakelvin, Kilokelvin, Hectokelvin, Decakelvin, Decikelvin, Centikelvin,
-        Millikelvin, Microkelvin, Nanokelvin, Picokelvin, Femtokelvin, Attokelvin,
-        Zeptokelvin, Yoctokelvin, DegreeCelsius, DegreeFahrenheit, DegreeRankine
+// Units looked up by symbol or name at runtime. Strings name readings such as
+// "20 °C", so the scales convert as absolute temperatures
+crate::unit_registry! {
+    Kelvin => Yottakelvin, Zettakelvin, Exakelvin, Petakelvin, Terakelvin, Gigakelvin, Megakelvin,
+              Kilokelvin, Hectokelvin, Decakelvin, Decikelvin, Centikelvin, Millikelvin,
+              Microkelvin, Nanokelvin, Picokelvin, Femtokelvin, Attokelvin, Zeptokelvin,
+              Yoctokelvin;
+    absolute: DegreeCelsius, DegreeFahrenheit, DegreeRankine, DegreeReaumur, DegreeDelisle
 }
 
 // Temperature quantity definition
@@ -148,4 +182,51 @@ mod tests {
     test_uom_temperature!(DegreeCelsius, degree_celsius);
     test_uom_temperature!(DegreeFahrenheit, degree_fahrenheit);
     test_uom_temperature!(DegreeRankine, degree_rankine);
+
+    #[test]
+    fn test_historical_scales() {
+        use crate::si::temperature::*;
+
+        // Freezing to boiling point of water spans 80 °Ré and 150 °De
+        let span = Temperature::from::<DegreeCelsius>(100.0);
+        assert!((span.to::<DegreeReaumur>() - 80.0_f64).abs() < 1e-12);
+        assert!((span.to::<DegreeDelisle>() - 150.0_f64).abs() < 1e-12);
+        assert!((span.to::<DegreeFahrenheit>() - 180.0_f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_absolute_temperatures() {
+        use crate::si::temperature::*;
+
+        let close = |actual: f64, expected: f64| (actual - expected).abs() < 1e-9;
+        let freezing = Temperature::from_absolute::<DegreeCelsius>(0.0);
+        assert!(close(*freezing.base(), 273.15));
+        assert!(close(freezing.to_absolute::<DegreeFahrenheit>(), 32.0));
+        assert!(close(freezing.to_absolute::<DegreeRankine>(), 491.67));
+        assert!(close(freezing.to_absolute::<DegreeReaumur>(), 0.0));
+        assert!(close(freezing.to_absolute::<DegreeDelisle>(), 150.0));
+        assert!(close(freezing.to_absolute::<Kelvin>(), 273.15));
+
+        let boiling = Temperature::from_absolute::<DegreeFahrenheit>(212.0);
+        assert!(close(boiling.to_absolute::<DegreeCelsius>(), 100.0));
+        assert!(close(boiling.to_absolute::<DegreeDelisle>(), 0.0));
+        assert!(close(
+            -40.0,
+            Temperature::from_absolute::<DegreeCelsius>(-40.0_f64)
+                .to_absolute::<DegreeFahrenheit>()
+        ));
+    }
+
+    #[test]
+    fn test_absolute_and_interval_differ() {
+        use crate::si::temperature::*;
+
+        // A reading of 20 °C versus a rise of 20 °C
+        let reading = Temperature::from_absolute::<DegreeCelsius>(20.0_f32);
+        let rise = Temperature::from::<DegreeCelsius>(20.0_f32);
+        assert!((*reading.base() - 293.15).abs() < 1e-4);
+        assert_eq!(*rise.base(), 20.0);
+        let warmer = reading + rise;
+        assert!((warmer.to_absolute::<DegreeCelsius>() - 40.0).abs() < 1e-4);
+    }
 }
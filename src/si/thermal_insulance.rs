@@ -0,0 +1,97 @@
+/// # Thermal Insulance Units - R-Value Measurements
+///
+/// This module defines thermal insulance units and their conversions. Thermal insulance,
+/// known in the building industry as the R-value, is the thermal resistance of a layer per
+/// unit area, so that the temperature drop across a wall equals R-value × heat flux density.
+/// Square meter kelvin per watt is the SI base unit (sometimes written RSI).
+///
+/// ## Base Unit
+///
+/// - **SquareMeterKelvinPerWatt (m²·K/W)**: The SI base unit of thermal insulance
+///
+/// ## Other Units
+///
+/// - **SquareMeterDegreeCelsiusPerWatt (m²·°C/W)**: Equal to the base unit
+/// - **SquareFootDegreeFahrenheitHourPerBtu (ft²·°F·h/BTU)**: US customary R-value,
+///   ≈ 0.1761 m²·K/W
+/// - **Clo (clo)**: 0.155 m²·K/W, insulation of clothing
+/// - **Tog (tog)**: 0.1 m²·K/W, insulation of textiles and bedding
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::thermal_insulance::{
+///     SquareFootDegreeFahrenheitHourPerBtu, SquareMeterKelvinPerWatt, ThermalInsulance,
+/// };
+///
+/// // An R-13 wall cavity batt
+/// let batt = ThermalInsulance::from::<SquareFootDegreeFahrenheitHourPerBtu>(13.0);
+/// let rsi = batt.to::<SquareMeterKelvinPerWatt>(); // ≈ 2.29 m²·K/W
+/// ```
+use typenum::*;
+
+// SI base unit
+units! {
+    SquareMeterKelvinPerWatt: "m²·K/W", "square meter kelvin per watt", "square meter kelvins per watt";
+}
+
+// Other units
+units! {
+    SquareMeterDegreeCelsiusPerWatt: "m²·°C/W", "square meter degree Celsius per watt", "square meter degrees Celsius per watt";
+    SquareFootDegreeFahrenheitHourPerBtu: "ft²·°F·h/BTU", "square foot degree Fahrenheit hour per BTU", "square foot degree Fahrenheit hours per BTU";
+    Clo: "clo", "clo", "clo";
+    Tog: "tog", "tog", "togs";
+}
+
+// Unit conversions using convert_linear!
+crate::convert_linear! {
+    // Other units
+    SquareMeterDegreeCelsiusPerWatt => SquareMeterKelvinPerWatt: 1.0;
+    SquareFootDegreeFahrenheitHourPerBtu => SquareMeterKelvinPerWatt: 1.761_101_836_823_058_E-1;
+    Clo => SquareMeterKelvinPerWatt: 1.55_E-1;
+    Tog => SquareMeterKelvinPerWatt: 1.0_E-1;
+}
+
+// No engineering-prefixed units; `Quantity::format_si` keeps the base unit
+crate::si_prefixed!(SquareMeterKelvinPerWatt);
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    SquareMeterKelvinPerWatt => SquareMeterDegreeCelsiusPerWatt,
+                                SquareFootDegreeFahrenheitHourPerBtu, Clo, Tog
+}
+
+// Thermal insulance quantity definition (Temperature × Area/Power)
+use super::{ISQ, SiScale};
+quantity!(ThermalInsulance, ISQ<Z0, N1, P3, Z0, P1, Z0, Z0>, SiScale, SquareMeterKelvinPerWatt);
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn test_r_value_conversions() {
+        use crate::si::thermal_insulance::*;
+
+        let batt = ThermalInsulance::from::<SquareFootDegreeFahrenheitHourPerBtu>(13.0);
+        assert!((batt.to::<SquareMeterKelvinPerWatt>() - 2.289_432_f64).abs() < 1e-6);
+
+        let duvet = ThermalInsulance::from::<Tog>(13.5);
+        assert!((duvet.to::<SquareMeterDegreeCelsiusPerWatt>() - 1.35_f64).abs() < 1e-12);
+        assert!((duvet.to::<Clo>() - 1.35_f64 / 0.155).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_temperature_drop_across_wall() {
+        use crate::si::heat_flux_density::{HeatFluxDensity, WattPerSquareMeter};
+        use crate::si::temperature::{Kelvin, Temperature};
+        use crate::si::thermal_insulance::*;
+
+        let wall = ThermalInsulance::from::<SquareMeterKelvinPerWatt>(2.5);
+        let drop: Temperature<f64> = wall * HeatFluxDensity::from::<WattPerSquareMeter>(8.0);
+        assert!((drop.to::<Kelvin>() - 20.0_f64).abs() < 1e-12);
+    }
+}
+
+// Re-export types for convenience
+pub use thermal_insulance::ThermalInsulance;
+pub use thermal_insulance::*;
@@ -0,0 +1,183 @@
+/// # Thermal Resistance Units - SI Thermal Resistance Measurements
+///
+/// This module defines SI thermal resistance units and their conversions. Thermal resistance
+/// is the temperature difference across a component per unit of heat flow through it, as
+/// quoted for heat sinks and semiconductor packages, with kelvin per watt as the SI base unit.
+/// The area-normalised R-value used for building insulation has a different dimension and is
+/// defined in the sibling `thermal_insulance` module.
+///
+/// ## Base Unit
+///
+/// - **KelvinPerWatt (K/W)**: The SI base unit of thermal resistance
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported on the watt:
+/// - **KelvinPerYottawatt (K/YW)**: 10⁻²⁴ kelvins per watt
+/// - ... down to ...
+/// - **KelvinPerYoctowatt (K/yW)**: 10²⁴ kelvins per watt
+///
+/// ## Other Units
+///
+/// - **DegreeCelsiusPerWatt (°C/W)**: Equal to kelvin per watt, the usual datasheet unit
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::power::{Power, Watt};
+/// use num_units::si::temperature::Kelvin;
+/// use num_units::si::thermal_resistance::{DegreeCelsiusPerWatt, ThermalResistance};
+///
+/// // Junction-to-ambient resistance of a TO-220 package
+/// let r_ja = ThermalResistance::from::<DegreeCelsiusPerWatt>(62.0);
+///
+/// // Temperature rise at 1.5 W dissipation
+/// let rise = r_ja * Power::from::<Watt>(1.5);
+/// let kelvin = rise.to::<Kelvin>(); // 93 K
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    KelvinPerWatt: "K/W", "kelvin per watt", "kelvins per watt";
+}
+
+// SI prefixed kelvins per watt
+units! {
+    KelvinPerYottawatt: "K/YW", "kelvin per yottawatt", "kelvins per yottawatt";
+    KelvinPerZettawatt: "K/ZW", "kelvin per zettawatt", "kelvins per zettawatt";
+    KelvinPerExawatt: "K/EW", "kelvin per exawatt", "kelvins per exawatt";
+    KelvinPerPetawatt: "K/PW", "kelvin per petawatt", "kelvins per petawatt";
+    KelvinPerTerawatt: "K/TW", "kelvin per terawatt", "kelvins per terawatt";
+    KelvinPerGigawatt: "K/GW", "kelvin per gigawatt", "kelvins per gigawatt";
+    KelvinPerMegawatt: "K/MW", "kelvin per megawatt", "kelvins per megawatt";
+    KelvinPerKilowatt: "K/kW", "kelvin per kilowatt", "kelvins per kilowatt";
+    KelvinPerHectowatt: "K/hW", "kelvin per hectowatt", "kelvins per hectowatt";
+    KelvinPerDecawatt: "K/daW", "kelvin per decawatt", "kelvins per decawatt";
+    KelvinPerDeciwatt: "K/dW", "kelvin per deciwatt", "kelvins per deciwatt";
+    KelvinPerCentiwatt: "K/cW", "kelvin per centiwatt", "kelvins per centiwatt";
+    KelvinPerMilliwatt: "K/mW", "kelvin per milliwatt", "kelvins per milliwatt";
+    KelvinPerMicrowatt: "K/µW", "kelvin per microwatt", "kelvins per microwatt";
+    KelvinPerNanowatt: "K/nW", "kelvin per nanowatt", "kelvins per nanowatt";
+    KelvinPerPicowatt: "K/pW", "kelvin per picowatt", "kelvins per picowatt";
+    KelvinPerFemtowatt: "K/fW", "kelvin per femtowatt", "kelvins per femtowatt";
+    KelvinPerAttowatt: "K/aW", "kelvin per attowatt", "kelvins per attowatt";
+    KelvinPerZeptowatt: "K/zW", "kelvin per zeptowatt", "kelvins per zeptowatt";
+    KelvinPerYoctowatt: "K/yW", "kelvin per yoctowatt", "kelvins per yoctowatt";
+}
+
+// Other units
+units! {
+    DegreeCelsiusPerWatt: "°C/W", "degree Celsius per watt", "degrees Celsius per watt";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed kelvins per watt
+    KelvinPerYottawatt => KelvinPerWatt: 1.0 / YOTTA;
+    KelvinPerZettawatt => KelvinPerWatt: 1.0 / ZETTA;
+    KelvinPerExawatt => KelvinPerWatt: 1.0 / EXA;
+    KelvinPerPetawatt => KelvinPerWatt: 1.0 / PETA;
+    KelvinPerTerawatt => KelvinPerWatt: 1.0 / TERA;
+    KelvinPerGigawatt => KelvinPerWatt: 1.0 / GIGA;
+    KelvinPerMegawatt => KelvinPerWatt: 1.0 / MEGA;
+    KelvinPerKilowatt => KelvinPerWatt: 1.0 / KILO;
+    KelvinPerHectowatt => KelvinPerWatt: 1.0 / HECTO;
+    KelvinPerDecawatt => KelvinPerWatt: 1.0 / DECA;
+    KelvinPerDeciwatt => KelvinPerWatt: 1.0 / DECI;
+    KelvinPerCentiwatt => KelvinPerWatt: 1.0 / CENTI;
+    KelvinPerMilliwatt => KelvinPerWatt: 1.0 / MILLI;
+    KelvinPerMicrowatt => KelvinPerWatt: 1.0 / MICRO;
+    KelvinPerNanowatt => KelvinPerWatt: 1.0 / NANO;
+    KelvinPerPicowatt => KelvinPerWatt: 1.0 / PICO;
+    KelvinPerFemtowatt => KelvinPerWatt: 1.0 / FEMTO;
+    KelvinPerAttowatt => KelvinPerWatt: 1.0 / ATTO;
+    KelvinPerZeptowatt => KelvinPerWatt: 1.0 / ZEPTO;
+    KelvinPerYoctowatt => KelvinPerWatt: 1.0 / YOCTO;
+
+    // Other units
+    DegreeCelsiusPerWatt => KelvinPerWatt: 1.0;
+}
+
+// No engineering-prefixed units; `Quantity::format_si` keeps the base unit
+crate::si_prefixed!(KelvinPerWatt);
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    KelvinPerWatt => KelvinPerYottawatt, KelvinPerZettawatt, KelvinPerExawatt, KelvinPerPetawatt,
+                     KelvinPerTerawatt, KelvinPerGigawatt, KelvinPerMegawatt, KelvinPerKilowatt,
+                     KelvinPerHectowatt, KelvinPerDecawatt, KelvinPerDeciwatt, KelvinPerCentiwatt,
+                     KelvinPerMilliwatt, KelvinPerMicrowatt, KelvinPerNanowatt, KelvinPerPicowatt,
+                     KelvinPerFemtowatt, KelvinPerAttowatt, KelvinPerZeptowatt, KelvinPerYoctowatt,
+                     DegreeCelsiusPerWatt
+}
+
+// Thermal resistance quantity definition (Temperature/Power)
+use super::{ISQ, SiScale};
+quantity!(ThermalResistance, ISQ<N2, N1, P3, Z0, P1, Z0, Z0>, SiScale, KelvinPerWatt);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_thermal_resistance {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::thermal_resistance,
+                uom::si::thermal_resistance,
+                ThermalResistance,
+                ThermalResistance,
+                KelvinPerWatt,
+                $num_units_unit,
+                kelvin_per_watt,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_thermal_resistance!(KelvinPerWatt, kelvin_per_watt);
+    test_uom_thermal_resistance!(KelvinPerYottawatt, kelvin_per_yottawatt);
+    test_uom_thermal_resistance!(KelvinPerZettawatt, kelvin_per_zettawatt);
+    test_uom_thermal_resistance!(KelvinPerExawatt, kelvin_per_exawatt);
+    test_uom_thermal_resistance!(KelvinPerPetawatt, kelvin_per_petawatt);
+    test_uom_thermal_resistance!(KelvinPerTerawatt, kelvin_per_terawatt);
+    test_uom_thermal_resistance!(KelvinPerGigawatt, kelvin_per_gigawatt);
+    test_uom_thermal_resistance!(KelvinPerMegawatt, kelvin_per_megawatt);
+    test_uom_thermal_resistance!(KelvinPerKilowatt, kelvin_per_kilowatt);
+    test_uom_thermal_resistance!(KelvinPerHectowatt, kelvin_per_hectowatt);
+    test_uom_thermal_resistance!(KelvinPerDecawatt, kelvin_per_decawatt);
+    test_uom_thermal_resistance!(KelvinPerDeciwatt, kelvin_per_deciwatt);
+    test_uom_thermal_resistance!(KelvinPerCentiwatt, kelvin_per_centiwatt);
+    test_uom_thermal_resistance!(KelvinPerMilliwatt, kelvin_per_milliwatt);
+    test_uom_thermal_resistance!(KelvinPerMicrowatt, kelvin_per_microwatt);
+    test_uom_thermal_resistance!(KelvinPerNanowatt, kelvin_per_nanowatt);
+    test_uom_thermal_resistance!(KelvinPerPicowatt, kelvin_per_picowatt);
+    test_uom_thermal_resistance!(KelvinPerFemtowatt, kelvin_per_femtowatt);
+    test_uom_thermal_resistance!(KelvinPerAttowatt, kelvin_per_attowatt);
+    test_uom_thermal_resistance!(KelvinPerZeptowatt, kelvin_per_zeptowatt);
+    test_uom_thermal_resistance!(KelvinPerYoctowatt, kelvin_per_yoctowatt);
+
+    #[test]
+    fn test_temperature_over_power() {
+        use crate::si::power::{Power, Watt};
+        use crate::si::temperature::{DegreeCelsius, Kelvin, Temperature};
+        use crate::si::thermal_resistance::*;
+
+        let r: ThermalResistance<f64> =
+            Temperature::from::<DegreeCelsius>(30.0) / Power::from::<Watt>(20.0);
+        assert!((r.to::<DegreeCelsiusPerWatt>() - 1.5_f64).abs() < 1e-12);
+        assert!((r.to::<KelvinPerKilowatt>() - 1500.0_f64).abs() < 1e-9);
+
+        let rise: Temperature<f64> =
+            ThermalResistance::from::<DegreeCelsiusPerWatt>(62.0) * Power::from::<Watt>(1.5);
+        assert!((rise.to::<Kelvin>() - 93.0_f64).abs() < 1e-12);
+    }
+}
+
+// Re-export types for convenience
+pub use thermal_resistance::ThermalResistance;
+pub use thermal_resistance::*;
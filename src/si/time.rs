@@ -1,4 +1,7 @@
-use crate::prefix::*;
+use crate::quantity::display::pad_whole;
+use core::fmt;
+use core::time::{Duration, TryFromFloatSecsError};
+use num_traits::ToPrimitive;
 /// # Time Units - Time Duration Measurements
 ///
 /// This module defines time units and their conversions. Time is measured in seconds
@@ -18,8 +21,20 @@ use crate::prefix::*;
 /// - **Day (d)**: 86400 seconds
 /// - **Year (a)**: 31536000 seconds (365 days)
 /// - **Shake**: 10 nanoseconds
+/// - **Week (wk)**, **Fortnight**: 7 and 14 days
+/// - **Month (mo)**: 30 days
+/// - **Decade**, **Century**, **Millennium**: 10, 100 and 1000 years of 365 days
 /// - Sidereal variants: second_sidereal, day_sidereal, hour_sidereal, year_sidereal
 /// - **Year_tropical**: Tropical year duration
+///
+/// ### Planetary Time Units
+/// - **Sol**: 88,775.244 s, the mean solar day on Mars
+/// - **Month (sidereal)**: 27.321661 days, one lunar orbit relative to the fixed stars
+///
+/// ### Physics Time Units
+/// - **Year (Julian)**: 365.25 days, the year used for the light year and in astronomy
+/// - **Planck time**, **atomic unit of time** and **natural unit of time** (CODATA 2018);
+///   UOM does not define these, so they are checked against their defining values instead
 use typenum::*;
 
 // ===== SI BASE UNIT =====
@@ -48,51 +63,42 @@ units! {
     Yoctosecond: "ys", "yoctosecond";
 
     // Conventional time units
-    SecondSidereal: "s (sidereal)", "second (sidereal)";
+    SecondSidereal: "s (sidereal)", "second (sidereal)", "seconds (sidereal)";
     Minute: "min", "minute";
     Hour: "h", "hour";
-    HourSidereal: "h (sidereal)", "hour (sidereal)";
+    HourSidereal: "h (sidereal)", "hour (sidereal)", "hours (sidereal)";
     Day: "d", "day";
-    DaySidereal: "d (sidereal)", "day (sidereal)";
+    DaySidereal: "d (sidereal)", "day (sidereal)", "days (sidereal)";
     Shake: "10.0 ns", "shake";
     Year: "a", "year";
-    YearSidereal: "a (sidereal)", "year (sidereal)";
-    YearTropical: "a (tropical)", "year (tropical)";
-}
+    YearSidereal: "a (sidereal)", "year (sidereal)", "years (sidereal)";
+    YearTropical: "a (tropical)", "year (tropical)", "years (tropical)";
 
-// ===== CONVERSION RELATIONSHIPS =====
+    // Calendar units
+    Week: "wk", "week";
+    Fortnight: "fn", "fortnight";
+    Month: "mo", "month";
+    Decade: "dec", "decade";
+    Century: "c", "century", "centuries";
+    Millennium: "ka", "millennium", "millennia";
 
-// SI prefixed units (linear conversions)
-convert_linear! {
-    Yottasecond => Second: YOTTA;
-    Zettasecond => Second: ZETTA;
-    Exasecond => Second: EXA;
-    Petasecond => Second: PETA;
-    Terasecond => Second: TERA;
-    Gigasecond => Second: GIGA;
-    Megasecond => Second: MEGA;
-    Kilosecond => Second: KILO;
-    Hectosecond => Second: HECTO;
-    Decasecond => Second: DECA;
-    Decisecond => Second: DECI;
-    Centisecond => Second: CENTI;
-    Millisecond => Second: MILLI;
-    Microsecond => Second: MICRO;
-    Nanosecond => Second: NANO;
-    Picosecond => Second: PICO;
-    Femtosecond => Second: FEMTO;
-    Attosecond => Second: ATTO;
-    Zeptosecond => Second: ZEPTO;
-    Yoctosecond => Second: YOCTO;
+    // Planetary time units
+    Sol: "sol", "sol";
+    MonthSidereal: "mo (sidereal)", "month (sidereal)", "months (sidereal)";
+
+    // Physics time units
+    YearJulian: "a (Julian)", "year (Julian)", "years (Julian)";
+    PlanckTime: "tP", "Planck time";
+    AtomicUnitOfTime: "a.u. of time", "atomic unit of time", "atomic units of time";
+    NaturalUnitOfTime: "n.u. of time", "natural unit of time", "natural units of time";
 }
 
+// ===== CONVERSION RELATIONSHIPS =====
+
 // Conventional time units (exact UOM conversion factors)
 convert_linear! {
     SecondSidereal => Second: 9.972696E-1;
-    Minute => Second: MINUTE;
-    Hour => Second: HOUR;
     HourSidereal => Second: 3.590170E3;
-    Day => Second: 8.64E4;
     DaySidereal => Second: 8.616409E4;
     Shake => Second: 1.0E-8;
     Year => Second: 3.1536E7;
@@ -100,12 +106,73 @@ convert_linear! {
     YearTropical => Second: 3.155693E7;
 }
 
-convert_matrix! {
-    Second => Yottasecond, Zettasecond, Exasecond, Petasecond, Terasecond, Gigasecond,
-        Megasecond, Kilosecond, Hectosecond, Decasecond, Decisecond, Centisecond,
-        Millisecond, Microsecond, Nanosecond, Picosecond, Femtosecond, Attosecond,
-        Zeptosecond, Yoctosecond, SecondSidereal, Minute, Hour, HourSidereal, Day,
-        DaySidereal, Shake, Year, YearSidereal, YearTropical
+// Calendar units (multiples of the 86 400 s day and the 365 day year)
+convert_linear! {
+    Month => Second: 2.592E6;
+    Decade => Second: 3.1536E8;
+    Century => Second: 3.1536E9;
+    Millennium => Second: 3.1536E10;
+}
+
+// Planetary time units
+convert_linear! {
+    Sol => Second: 8.8775244E4;
+    MonthSidereal => Second: 2.3605915104E6;
+}
+
+// Physics time units (CODATA 2018)
+convert_linear! {
+    YearJulian => Second: 3.15576E7;
+    PlanckTime => Second: 5.391247E-44;
+    AtomicUnitOfTime => Second: 2.4188843265857E-17;
+    NaturalUnitOfTime => Second: 1.28808866819E-21;
+}
+
+// SI prefixed seconds and units defined by a whole number of seconds, so
+// integer durations convert without going through f64
+crate::convert_int_linear! {
+    Yottasecond => Second: 1_000_000_000_000_000_000_000_000;
+    Zettasecond => Second: 1_000_000_000_000_000_000_000;
+    Exasecond => Second: 1_000_000_000_000_000_000;
+    Petasecond => Second: 1_000_000_000_000_000;
+    Terasecond => Second: 1_000_000_000_000;
+    Gigasecond => Second: 1_000_000_000;
+    Megasecond => Second: 1_000_000;
+    Kilosecond => Second: 1_000;
+    Hectosecond => Second: 100;
+    Decasecond => Second: 10;
+    Decisecond => Second: 1 / 10;
+    Centisecond => Second: 1 / 100;
+    Millisecond => Second: 1 / 1_000;
+    Microsecond => Second: 1 / 1_000_000;
+    Nanosecond => Second: 1 / 1_000_000_000;
+    Picosecond => Second: 1 / 1_000_000_000_000;
+    Femtosecond => Second: 1 / 1_000_000_000_000_000;
+    Attosecond => Second: 1 / 1_000_000_000_000_000_000;
+    Zeptosecond => Second: 1 / 1_000_000_000_000_000_000_000;
+    Yoctosecond => Second: 1 / 1_000_000_000_000_000_000_000_000;
+    Minute => Second: 60;
+    Hour => Second: 3600;
+    Day => Second: 86_400;
+    Week => Second: 604_800;
+    Fortnight => Second: 1_209_600;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    Second => Yottasecond, Zettasecond, Exasecond, Petasecond, Terasecond, Gigasecond, Megasecond,
+              Kilosecond, Millisecond, Microsecond, Nanosecond, Picosecond, Femtosecond, Attosecond,
+              Zeptosecond, Yoctosecond
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    Second => Yottasecond, Zettasecond, Exasecond, Petasecond, Terasecond, Gigasecond, Megasecond,
+              Kilosecond, Hectosecond, Decasecond, Decisecond, Centisecond, Millisecond,
+              Microsecond, Nanosecond, Picosecond, Femtosecond, Attosecond, Zeptosecond,
+              Yoctosecond, SecondSidereal, Minute, Hour, HourSidereal, Day, DaySidereal, Shake,
+              Year, YearSidereal, YearTropical, Week, Fortnight, Month, Decade, Century, Millennium,
+              Sol, MonthSidereal, YearJulian, PlanckTime, AtomicUnitOfTime, NaturalUnitOfTime
 }
 
 // Time quantity definition
@@ -113,8 +180,239 @@ use super::{ISQ, SiScale};
 quantity!(Time, ISQ<Z0, Z0, P1, Z0, Z0, Z0, Z0>, SiScale, Second);
 
 // Re-export types for convenience
-pub use time::Time;
-pub use time::*;
+pub use self::time::Time;
+pub use self::time::*;
+
+// ===== DURATION INTEROP =====
+
+impl From<Duration> for Time<f64> {
+    fn from(duration: Duration) -> Self {
+        Time::from_base(duration.as_secs_f64())
+    }
+}
+
+impl From<Duration> for Time<f32> {
+    fn from(duration: Duration) -> Self {
+        Time::from_base(duration.as_secs_f32())
+    }
+}
+
+impl TryFrom<Time<f64>> for Duration {
+    type Error = TryFromFloatSecsError;
+
+    fn try_from(time: Time<f64>) -> Result<Self, Self::Error> {
+        Duration::try_from_secs_f64(time.value)
+    }
+}
+
+impl Time<f64> {
+    /// Convert to a `Duration`, rounded to the nearest nanosecond
+    ///
+    /// Fails for negative, non-finite or too large times.
+    pub fn try_into_duration(self) -> Result<Duration, TryFromFloatSecsError> {
+        Duration::try_from(self)
+    }
+
+    /// Create a time from a nanosecond count, as used by timer APIs
+    pub fn from_nanos(nanos: u64) -> Self {
+        Duration::from_nanos(nanos).into()
+    }
+
+    /// The time as a whole number of nanoseconds, or `None` if it is negative,
+    /// non-finite or exceeds `u64::MAX` nanoseconds
+    pub fn try_into_nanos(self) -> Option<u64> {
+        let duration = self.try_into_duration().ok()?;
+        u64::try_from(duration.as_nanos()).ok()
+    }
+}
+
+// ===== HUMAN-READABLE FORMATTING =====
+
+impl<V: ToPrimitive> Time<V> {
+    /// Display as hours, minutes and seconds, e.g. `1h 23m 45.6s`
+    ///
+    /// Leading zero components are left out. The formatter precision sets the
+    /// decimals of the seconds; without it they are rounded to nanoseconds.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// use num_units::si::time::Time;
+    ///
+    /// assert_eq!(format!("{}", Time::from_base(5025.6).format_hms()), "1h 23m 45.6s");
+    /// assert_eq!(format!("{:.0}", Time::from_base(59.7).format_hms()), "1m 0s");
+    /// ```
+    pub fn format_hms(&self) -> HmsDisplay {
+        HmsDisplay {
+            seconds: self.value.to_f64().unwrap_or(f64::NAN),
+        }
+    }
+
+    /// Display as an ISO 8601 duration, e.g. `PT1H23M45.6S`
+    ///
+    /// Uses hours as the largest component, since days and longer are not of
+    /// fixed length in ISO 8601. Negative times get a leading `-`.
+    pub fn format_iso8601(&self) -> Iso8601Display {
+        Iso8601Display {
+            seconds: self.value.to_f64().unwrap_or(f64::NAN),
+        }
+    }
+}
+
+/// Formatter adapter returned by [`Time::format_hms`]
+///
+/// Precision sets the decimals of the seconds; width, fill and alignment pad
+/// the whole text, and `+` adds a sign to non-negative times.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HmsDisplay {
+    seconds: f64,
+}
+
+impl HmsDisplay {
+    fn render(&self, w: &mut dyn fmt::Write, precision: Option<usize>, plus: bool) -> fmt::Result {
+        let Some(parts) = HmsParts::split(self.seconds, precision) else {
+            return write!(w, "{}s", self.seconds);
+        };
+        parts.write_sign(w, plus)?;
+        if parts.hours > 0 {
+            write!(w, "{}h ", parts.hours)?;
+        }
+        if parts.hours > 0 || parts.minutes > 0 {
+            write!(w, "{}m ", parts.minutes)?;
+        }
+        parts.write_seconds(w)?;
+        w.write_str("s")
+    }
+}
+
+impl fmt::Display for HmsDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (precision, plus) = (f.precision(), f.sign_plus());
+        pad_whole(f, |w| self.render(w, precision, plus))
+    }
+}
+
+/// Formatter adapter returned by [`Time::format_iso8601`]
+///
+/// Takes the same formatter flags as [`HmsDisplay`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Iso8601Display {
+    seconds: f64,
+}
+
+impl Iso8601Display {
+    fn render(&self, w: &mut dyn fmt::Write, precision: Option<usize>, plus: bool) -> fmt::Result {
+        // Huge times still come out valid; NaN and infinity have no ISO 8601
+        // spelling and are written as they are rather than failing
+        let Some(parts) = HmsParts::split(self.seconds, precision) else {
+            return write!(w, "PT{}S", self.seconds);
+        };
+        parts.write_sign(w, plus)?;
+        w.write_str("PT")?;
+        if parts.hours > 0 {
+            write!(w, "{}H", parts.hours)?;
+        }
+        if parts.minutes > 0 {
+            write!(w, "{}M", parts.minutes)?;
+        }
+        if parts.seconds > 0 || parts.fraction > 0 || (parts.hours == 0 && parts.minutes == 0) {
+            parts.write_seconds(w)?;
+            w.write_str("S")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Iso8601Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (precision, plus) = (f.precision(), f.sign_plus());
+        pad_whole(f, |w| self.render(w, precision, plus))
+    }
+}
+
+/// A time split into whole hours, minutes and seconds, with the remaining
+/// fraction of a second as an integer of `digits` decimals
+struct HmsParts {
+    negative: bool,
+    hours: u128,
+    minutes: u128,
+    seconds: u128,
+    fraction: u128,
+    digits: usize,
+}
+
+impl HmsParts {
+    /// Round to `precision` decimals (nanoseconds if `None`) and split up.
+    /// `None` for non-finite times and times too large to split.
+    fn split(seconds: f64, precision: Option<usize>) -> Option<Self> {
+        use num_traits::float::FloatCore;
+
+        let digits = precision.unwrap_or(9).min(9);
+        let per_second = 10_u128.pow(digits as u32);
+        let scaled = FloatCore::round(FloatCore::abs(seconds) * per_second as f64);
+        if !scaled.is_finite() || scaled >= u128::MAX as f64 {
+            return None;
+        }
+        let ticks = scaled as u128;
+        let whole = ticks / per_second;
+        let mut parts = Self {
+            // -0.4s rounded to whole seconds is plain 0s
+            negative: seconds < 0.0 && ticks > 0,
+            hours: whole / 3600,
+            minutes: whole / 60 % 60,
+            seconds: whole % 60,
+            fraction: ticks % per_second,
+            digits,
+        };
+        if precision.is_none() {
+            while parts.digits > 0 && parts.fraction.is_multiple_of(10) {
+                parts.fraction /= 10;
+                parts.digits -= 1;
+            }
+        }
+        Some(parts)
+    }
+
+    fn write_sign(&self, w: &mut dyn fmt::Write, plus: bool) -> fmt::Result {
+        if self.negative {
+            w.write_str("-")
+        } else if plus {
+            w.write_str("+")
+        } else {
+            Ok(())
+        }
+    }
+
+    fn write_seconds(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        write!(w, "{}", self.seconds)?;
+        if self.digits > 0 {
+            write!(w, ".{:0width$}", self.fraction, width = self.digits)?;
+        }
+        Ok(())
+    }
+}
+
+/// Split a time in seconds into whole seconds (rounded down) and the
+/// nanoseconds past them, the representation of the `chrono` and `time`
+/// durations. `None` if the time is not finite or outside the `i64` range.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn split_seconds(seconds: f64) -> Option<(i64, u32)> {
+    use num_traits::float::FloatCore;
+
+    if !seconds.is_finite() {
+        return None;
+    }
+    let mut whole = FloatCore::floor(seconds);
+    let mut nanos = FloatCore::round((seconds - whole) * 1e9);
+    if nanos >= 1e9 {
+        whole += 1.0;
+        nanos = 0.0;
+    }
+    // i64::MAX as f64 rounds up to 2^63, which is already out of range
+    if whole < i64::MIN as f64 || whole >= i64::MAX as f64 {
+        return None;
+    }
+    Some((whole as i64, nanos as u32))
+}
 
 #[cfg(test)]
 mod tests {
@@ -168,4 +466,179 @@ mod tests {
     test_uom_time!(Year, year);
     test_uom_time!(YearSidereal, year_sidereal);
     test_uom_time!(YearTropical, year_tropical);
+
+    #[test]
+    fn test_calendar_units() {
+        use crate::si::time::*;
+
+        let fortnight = Time::from::<Fortnight>(1.0);
+        assert!((fortnight.to::<Week>() - 2.0_f64).abs() < 1e-12);
+        assert!((fortnight.to::<Day>() - 14.0_f64).abs() < 1e-12);
+        assert!((Time::from::<Month>(1.0).to::<Day>() - 30.0_f64).abs() < 1e-12);
+
+        let millennium = Time::from::<Millennium>(1.0);
+        assert!((millennium.to::<Century>() - 10.0_f64).abs() < 1e-12);
+        assert!((millennium.to::<Decade>() - 100.0_f64).abs() < 1e-12);
+        assert!((millennium.to::<Year>() - 1000.0_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_planetary_units() {
+        use crate::si::time::*;
+
+        let sol = Time::from::<Sol>(1.0);
+        assert!((sol.to::<Hour>() - 24.659_79_f64).abs() < 1e-5);
+
+        let month = Time::from::<MonthSidereal>(1.0);
+        assert!((month.to::<Day>() - 27.321_661_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_physics_units() {
+        use crate::si::time::*;
+
+        let julian = Time::from::<YearJulian>(1.0);
+        assert!((julian.to::<Day>() - 365.25_f64).abs() < 1e-9);
+
+        // ħ / E_h and ħ / (m_e c²)
+        let atomic = Time::from::<AtomicUnitOfTime>(1.0);
+        assert!((atomic.to::<Attosecond>() - 24.188_843_265_857_f64).abs() < 1e-9);
+        let natural = Time::from::<NaturalUnitOfTime>(1.0);
+        assert!((natural.to::<Zeptosecond>() - 1.288_088_668_19_f64).abs() < 1e-9);
+
+        let planck = Time::from::<PlanckTime>(1.0e44);
+        assert!((planck.to::<Second>() - 5.391_247_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_integer_durations() {
+        use crate::conversions::ConvertIntUnit;
+        use crate::si::time::*;
+
+        let shift = Time::from::<Hour>(8_u32);
+        assert_eq!(shift.to::<Minute>(), 480);
+        assert_eq!(Time::from::<Week>(2_i64).to::<Day>(), 14);
+        assert_eq!(
+            <Millisecond as ConvertIntUnit<Nanosecond, u64>>::convert_int(5),
+            5_000_000
+        );
+        assert_eq!(
+            <Microsecond as ConvertIntUnit<Millisecond, i32>>::convert_int(2_500),
+            3
+        );
+    }
+
+    #[test]
+    fn test_duration_round_trip() {
+        use crate::si::time::*;
+        use core::time::Duration;
+
+        // `Time::from` is the unit conversion, so go through `Into`
+        let time: Time<f64> = Duration::from_millis(1500).into();
+        assert_eq!(time.to::<Millisecond>(), 1500.0);
+        assert_eq!(time.try_into_duration(), Ok(Duration::from_millis(1500)));
+        assert_eq!(
+            *Into::<Time<f32>>::into(Duration::from_micros(250)).base(),
+            0.00025
+        );
+        assert_eq!(
+            Duration::try_from(Time::from::<Minute>(2.0)),
+            Ok(Duration::from_secs(120))
+        );
+    }
+
+    #[test]
+    fn test_duration_rejects_invalid_times() {
+        use crate::si::time::*;
+
+        assert!(Time::from_base(-1.0).try_into_duration().is_err());
+        assert!(Time::from_base(f64::NAN).try_into_duration().is_err());
+        assert_eq!(Time::from_base(-1.0).try_into_nanos(), None);
+        assert_eq!(Time::from_base(1e11).try_into_nanos(), None);
+    }
+
+    #[test]
+    fn test_nanosecond_counts() {
+        use crate::si::time::*;
+
+        let tick = Time::from_nanos(1_000_250);
+        assert_eq!(tick.to::<Nanosecond>(), 1_000_250.0);
+        assert_eq!(tick.try_into_nanos(), Some(1_000_250));
+        assert_eq!(Time::from::<Microsecond>(2.5).try_into_nanos(), Some(2500));
+    }
+
+    #[test]
+    fn test_format_hms() {
+        use crate::si::time::*;
+
+        assert_eq!(
+            format!("{}", Time::from_base(5025.6).format_hms()),
+            "1h 23m 45.6s"
+        );
+        assert_eq!(format!("{}", Time::from_base(61.0).format_hms()), "1m 1s");
+        assert_eq!(
+            format!("{}", Time::from_base(3600).format_hms()),
+            "1h 0m 0s"
+        );
+        assert_eq!(format!("{}", Time::from_base(0.25).format_hms()), "0.25s");
+        assert_eq!(
+            format!("{}", Time::from_base(-90.0).format_hms()),
+            "-1m 30s"
+        );
+        assert_eq!(
+            format!("{}", Time::from::<Day>(2.0).format_hms()),
+            "48h 0m 0s"
+        );
+        assert_eq!(
+            format!("{}", Time::from_base(f64::NAN).format_hms()),
+            "NaNs"
+        );
+    }
+
+    #[test]
+    fn test_format_hms_precision_rounds_up() {
+        use crate::si::time::*;
+
+        assert_eq!(
+            format!("{:.0}", Time::from_base(59.7).format_hms()),
+            "1m 0s"
+        );
+        assert_eq!(format!("{:.2}", Time::from_base(1.0).format_hms()), "1.00s");
+        assert_eq!(format!("{:.0}", Time::from_base(-0.4).format_hms()), "0s");
+    }
+
+    #[test]
+    fn test_format_iso8601() {
+        use crate::si::time::*;
+
+        assert_eq!(
+            format!("{}", Time::from_base(5025.6).format_iso8601()),
+            "PT1H23M45.6S"
+        );
+        assert_eq!(
+            format!("{}", Time::from::<Hour>(1.0).format_iso8601()),
+            "PT1H"
+        );
+        assert_eq!(format!("{}", Time::from_base(0.0).format_iso8601()), "PT0S");
+        assert_eq!(
+            format!("{}", Time::from_base(-3661.0).format_iso8601()),
+            "-PT1H1M1S"
+        );
+        assert_eq!(
+            format!("{:.3}", Time::from::<Millisecond>(1500.0).format_iso8601()),
+            "PT1.500S"
+        );
+    }
+
+    #[test]
+    fn test_time_formats_pad_as_a_whole() {
+        use crate::si::time::*;
+
+        let lap = Time::from_base(83.5);
+        assert_eq!(format!("{:>10}|", lap.format_hms()), "  1m 23.5s|");
+        assert_eq!(format!("{:<10}|", lap.format_hms()), "1m 23.5s  |");
+        assert_eq!(format!("{:*^12.0}", lap.format_hms()), "***1m 24s***");
+        assert_eq!(format!("{:+}", lap.format_iso8601()), "+PT1M23.5S");
+        assert_eq!(format!("{:>12}", lap.format_iso8601()), "   PT1M23.5S");
+    }
 }
@@ -0,0 +1,99 @@
+/// # `time` Crate Interop
+///
+/// Conversions between the `Time` quantity and `time::Duration`, and
+/// arithmetic of `Time` on `time::OffsetDateTime`/`PrimitiveDateTime`. Enabled by
+/// the `time` feature; the module is named `time_crate` to keep it apart from
+/// the `Time` quantity module.
+///
+/// ## Example Usage
+///
+/// ```rust,ignore
+/// use num_units::si::time::{Minute, Time};
+///
+/// let elapsed: Time = time::Duration::milliseconds(1500).into();
+/// let end = time::OffsetDateTime::UNIX_EPOCH + Time::from::<Minute>(1.5);
+/// ```
+use super::time::{Time, split_seconds};
+use ::time::{Duration, OffsetDateTime, PrimitiveDateTime};
+use core::ops::{Add, Sub};
+
+impl From<Duration> for Time<f64> {
+    fn from(duration: Duration) -> Self {
+        Time::from_base(duration.as_seconds_f64())
+    }
+}
+
+impl Time<f64> {
+    /// Convert to a `time::Duration`, rounded to the nearest nanosecond
+    ///
+    /// Returns `None` for non-finite times and times outside the `Duration`
+    /// range.
+    pub fn try_into_time_duration(self) -> Option<Duration> {
+        let (seconds, nanos) = split_seconds(self.value)?;
+        // `nanos` is below 10^9, so it fits i32 and never carries over
+        Some(Duration::new(seconds, nanos as i32))
+    }
+}
+
+macro_rules! impl_date_time_ops {
+    ($($date_time:ty),+) => {
+        $(
+            impl Add<Time<f64>> for $date_time {
+                type Output = $date_time;
+
+                /// # Panics
+                /// If the time does not fit a `Duration` or the result
+                /// overflows, like `+ Duration`.
+                fn add(self, time: Time<f64>) -> Self::Output {
+                    self + time
+                        .try_into_time_duration()
+                        .expect("time out of range for time::Duration")
+                }
+            }
+
+            impl Sub<Time<f64>> for $date_time {
+                type Output = $date_time;
+
+                /// # Panics
+                /// If the time does not fit a `Duration` or the result
+                /// overflows, like `- Duration`.
+                fn sub(self, time: Time<f64>) -> Self::Output {
+                    self - time
+                        .try_into_time_duration()
+                        .expect("time out of range for time::Duration")
+                }
+            }
+        )+
+    };
+}
+
+impl_date_time_ops!(OffsetDateTime, PrimitiveDateTime);
+
+#[cfg(test)]
+mod tests {
+    use crate::si::time::{Hour, Millisecond, Time};
+    use ::time::{Duration, OffsetDateTime};
+
+    #[test]
+    fn test_duration_round_trip() {
+        let time: Time<f64> = Duration::milliseconds(-1500).into();
+        assert_eq!(time.to::<Millisecond>(), -1500.0);
+        assert_eq!(
+            time.try_into_time_duration(),
+            Some(Duration::milliseconds(-1500))
+        );
+        assert_eq!(
+            Time::from_base(2.000_000_001).try_into_time_duration(),
+            Some(Duration::new(2, 1))
+        );
+        assert_eq!(Time::from_base(f64::NAN).try_into_time_duration(), None);
+    }
+
+    #[test]
+    fn test_add_time_to_offset_date_time() {
+        let epoch = OffsetDateTime::UNIX_EPOCH;
+        let later = epoch + Time::from::<Hour>(2.5);
+        assert_eq!(later.unix_timestamp(), 9000);
+        assert_eq!(later - Time::from::<Hour>(2.5), epoch);
+    }
+}
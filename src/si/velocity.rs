@@ -25,50 +25,60 @@ use crate::prefix::*;
 /// - **Speed of light in vacuum (c)**: 299,792,458 m/s
 /// - **Natural unit of velocity**: Same as speed of light
 /// - **Atomic unit of velocity**: Hartree atomic unit
+/// - **Percent of c (%c)**: 1/100 of the speed of light, 2,997,924.58 m/s
+///
+/// ### Mach Number
+/// - **Mach (Ma)**: 340.294 m/s, the speed of sound in the ICAO Standard Atmosphere at sea
+///   level (15 °C, 101.325 kPa). The true Mach number depends on the local air temperature;
+///   this unit is a fixed reference for quick estimates, not an altitude-corrected value.
 use typenum::*;
 
 // ===== SI DERIVED UNIT =====
 units! {
     // SI prefixed meters per second
-    YottameterPerSecond: "Ym/s", "yottameter per second";
-    ZettameterPerSecond: "Zm/s", "zettameter per second";
-    ExameterPerSecond: "Em/s", "exameter per second";
-    PetameterPerSecond: "Pm/s", "petameter per second";
-    TerameterPerSecond: "Tm/s", "terameter per second";
-    GigameterPerSecond: "Gm/s", "gigameter per second";
-    MegameterPerSecond: "Mm/s", "megameter per second";
-    KilometerPerSecond: "km/s", "kilometer per second";
-    HectometerPerSecond: "hm/s", "hectometer per second";
-    DecameterPerSecond: "dam/s", "decameter per second";
-    MeterPerSecond: "m/s", "meter per second";
-    DecimeterPerSecond: "dm/s", "decimeter per second";
-    CentimeterPerSecond: "cm/s", "centimeter per second";
-    MillimeterPerSecond: "mm/s", "millimeter per second";
-    MicrometerPerSecond: "µm/s", "micrometer per second";
-    NanometerPerSecond: "nm/s", "nanometer per second";
-    PicometerPerSecond: "pm/s", "picometer per second";
-    FemtometerPerSecond: "fm/s", "femtometer per second";
-    AttometerPerSecond: "am/s", "attometer per second";
-    ZeptometerPerSecond: "zm/s", "zeptometer per second";
-    YoctometerPerSecond: "ym/s", "yoctometer per second";
+    YottameterPerSecond: "Ym/s", "yottameter per second", "yottameters per second";
+    ZettameterPerSecond: "Zm/s", "zettameter per second", "zettameters per second";
+    ExameterPerSecond: "Em/s", "exameter per second", "exameters per second";
+    PetameterPerSecond: "Pm/s", "petameter per second", "petameters per second";
+    TerameterPerSecond: "Tm/s", "terameter per second", "terameters per second";
+    GigameterPerSecond: "Gm/s", "gigameter per second", "gigameters per second";
+    MegameterPerSecond: "Mm/s", "megameter per second", "megameters per second";
+    KilometerPerSecond: "km/s", "kilometer per second", "kilometers per second";
+    HectometerPerSecond: "hm/s", "hectometer per second", "hectometers per second";
+    DecameterPerSecond: "dam/s", "decameter per second", "decameters per second";
+    MeterPerSecond: "m/s", "meter per second", "meters per second";
+    DecimeterPerSecond: "dm/s", "decimeter per second", "decimeters per second";
+    CentimeterPerSecond: "cm/s", "centimeter per second", "centimeters per second";
+    MillimeterPerSecond: "mm/s", "millimeter per second", "millimeters per second";
+    MicrometerPerSecond: "µm/s", "micrometer per second", "micrometers per second";
+    NanometerPerSecond: "nm/s", "nanometer per second", "nanometers per second";
+    PicometerPerSecond: "pm/s", "picometer per second", "picometers per second";
+    FemtometerPerSecond: "fm/s", "femtometer per second", "femtometers per second";
+    AttometerPerSecond: "am/s", "attometer per second", "attometers per second";
+    ZeptometerPerSecond: "zm/s", "zeptometer per second", "zeptometers per second";
+    YoctometerPerSecond: "ym/s", "yoctometer per second", "yoctometers per second";
 
     // Conventional velocity units
-    FootPerHour: "ft/h", "foot per hour";
-    FootPerMinute: "ft/min", "foot per minute";
-    FootPerSecond: "ft/s", "foot per second";
-    InchPerSecond: "in/s", "inch per second";
-    InchPerMinute: "in/min", "inch per minute";
-    KilometerPerHour: "km/h", "kilometer per hour";
+    FootPerHour: "ft/h", "foot per hour", "feet per hour";
+    FootPerMinute: "ft/min", "foot per minute", "feet per minute";
+    FootPerSecond: "ft/s", "foot per second", "feet per second";
+    InchPerSecond: "in/s", "inch per second", "inches per second";
+    InchPerMinute: "in/min", "inch per minute", "inches per minute";
+    KilometerPerHour: "km/h", "kilometer per hour", "kilometers per hour";
     Knot: "kn", "knot";
-    MilePerHour: "mi/h", "mile per hour";
-    MilePerMinute: "mi/min", "mile per minute";
-    MilePerSecond: "mi/s", "mile per second";
-    MillimeterPerMinute: "mm/min", "millimeter per minute";
+    MilePerHour: "mi/h", "mile per hour", "miles per hour";
+    MilePerMinute: "mi/min", "mile per minute", "miles per minute";
+    MilePerSecond: "mi/s", "mile per second", "miles per second";
+    MillimeterPerMinute: "mm/min", "millimeter per minute", "millimeters per minute";
 
     // Special velocity units
-    AtomicUnitOfVelocity: "a₀ · Eₕ/ħ", "atomic unit of velocity";
-    NaturalUnitOfVelocity: "c", "natural unit of velocity";
-    SpeedOfLightInVacuum: "c", "speed of light in vacuum";
+    AtomicUnitOfVelocity: "a₀ · Eₕ/ħ", "atomic unit of velocity", "atomic units of velocity";
+    NaturalUnitOfVelocity: "c", "natural unit of velocity", "natural units of velocity";
+    SpeedOfLightInVacuum: "c", "speed of light in vacuum", "speeds of light in vacuum";
+    PercentSpeedOfLight: "%c", "percent of the speed of light", "percent of the speed of light";
+
+    // Mach number at ICAO standard sea-level conditions
+    Mach: "Ma", "mach", "mach";
 }
 
 // ===== CONVERSION RELATIONSHIPS =====
@@ -117,20 +127,36 @@ convert_linear! {
     AtomicUnitOfVelocity => MeterPerSecond: 2.18769126364E6;
     NaturalUnitOfVelocity => MeterPerSecond: 299792458.0;
     SpeedOfLightInVacuum => MeterPerSecond: 299792458.0;
+    PercentSpeedOfLight => MeterPerSecond: 2997924.58;
+    Mach => MeterPerSecond: 340.294;
 }
 
-convert_matrix! {
+use crate::prefix::{DECA, EXA, GIGA, HECTO, KILO, MEGA, PETA, TERA, YOTTA, ZETTA};
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
     MeterPerSecond => YottameterPerSecond, ZettameterPerSecond, ExameterPerSecond,
-        PetameterPerSecond, TerameterPerSecond, GigameterPerSecond, MegameterPerSecond,
-        KilometerPerSecond, HectometerPerSecond, DecameterPerSecond, DecimeterPerSecond,
-        CentimeterPerSecond, MillimeterPerSecond, MicrometerPerSecond, NanometerPerSecond,
-        PicometerPerSecond, FemtometerPerSecond, AttometerPerSecond, ZeptometerPerSecond,
-        YoctometerPerSecond, FootPerHour, FootPerMinute, FootPerSecond, InchPerSecond,
-        InchPerMinute, KilometerPerHour, Knot, MilePerHour, MilePerMinute, MilePerSecond,
-        MillimeterPerMinute, AtomicUnitOfVelocity, NaturalUnitOfVelocity, SpeedOfLightInVacuum
+                      PetameterPerSecond, TerameterPerSecond, GigameterPerSecond,
+                      MegameterPerSecond, KilometerPerSecond, MillimeterPerSecond,
+                      MicrometerPerSecond, NanometerPerSecond, PicometerPerSecond,
+                      FemtometerPerSecond, AttometerPerSecond, ZeptometerPerSecond,
+                      YoctometerPerSecond
 }
 
-use crate::prefix::{DECA, EXA, GIGA, HECTO, KILO, MEGA, PETA, TERA, YOTTA, ZETTA};
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    MeterPerSecond => YottameterPerSecond, ZettameterPerSecond, ExameterPerSecond,
+                      PetameterPerSecond, TerameterPerSecond, GigameterPerSecond,
+                      MegameterPerSecond, KilometerPerSecond, HectometerPerSecond,
+                      DecameterPerSecond, DecimeterPerSecond, CentimeterPerSecond,
+                      MillimeterPerSecond, MicrometerPerSecond, NanometerPerSecond,
+                      PicometerPerSecond, FemtometerPerSecond, AttometerPerSecond,
+                      ZeptometerPerSecond, YoctometerPerSecond, FootPerHour, FootPerMinute,
+                      FootPerSecond, InchPerSecond, InchPerMinute, KilometerPerHour, Knot,
+                      MilePerHour, MilePerMinute, MilePerSecond, MillimeterPerMinute,
+                      AtomicUnitOfVelocity, NaturalUnitOfVelocity, SpeedOfLightInVacuum,
+                      PercentSpeedOfLight, Mach
+}
 
 // Velocity quantity definition (Length/Time)
 use super::{ISQ, SiScale};
@@ -198,4 +224,17 @@ mod tests {
     test_uom_velocity!(AtomicUnitOfVelocity, atomic_unit_of_velocity);
     test_uom_velocity!(NaturalUnitOfVelocity, natural_unit_of_velocity);
     test_uom_velocity!(SpeedOfLightInVacuum, speed_of_light_in_vacuum);
+
+    #[test]
+    fn test_mach_and_percent_of_c() {
+        use crate::si::velocity::*;
+
+        let concorde = Velocity::from::<Mach>(2.04);
+        assert!((concorde.to::<MeterPerSecond>() - 694.199_76_f64).abs() < 1e-9);
+        let mach_one = Velocity::from::<Mach>(1.0);
+        assert!((mach_one.to::<KilometerPerHour>() - 1225.058_4_f64).abs() < 1e-9);
+
+        let beam = Velocity::from::<PercentSpeedOfLight>(50.0);
+        assert!((beam.to::<SpeedOfLightInVacuum>() - 0.5_f64).abs() < 1e-15);
+    }
 }
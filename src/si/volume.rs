@@ -22,11 +22,24 @@ use crate::prefix::*;
 /// - **Quart/Cup/Pint**: Fractional gallon units
 /// - **Tablespoon/Tablespoon**: Small cooking volume units
 /// - **Cubic foot/inch/yard**: Imperial volume units
+/// - **Fluid ounce (fl oz)**: US customary, 1/128 gallon
+///
+/// ### Imperial Volume Units
+/// - **Imperial gallon (gal (UK))**: 4.54609 L (exact)
+/// - **Imperial quart/pint/fluid ounce**: 1/4, 1/8 and 1/160 imperial gallon
+///
+/// ### Dry and Oil-Industry Units
+/// - **Bushel (bu)**: US bushel, 35.23907 L
+/// - **Barrel (bbl)**: Petroleum barrel, 42 US gallons
 use typenum::*;
 
 // ===== SI DERIVED UNIT =====
 units! {
-    // SI prefixed cubic meters (subset of most commonly used)
+    // SI prefixed cubic meters
+    CubicYottameter: "Ym³", "cubic yottameter";
+    CubicZettameter: "Zm³", "cubic zettameter";
+    CubicExameter: "Em³", "cubic exameter";
+    CubicPetameter: "Pm³", "cubic petameter";
     CubicTerameter: "Tm³", "cubic terameter";
     CubicGigameter: "Gm³", "cubic gigameter";
     CubicMegameter: "Mm³", "cubic megameter";
@@ -40,8 +53,16 @@ units! {
     CubicMicrometer: "µm³", "cubic micrometer";
     CubicNanometer: "nm³", "cubic nanometer";
     CubicPicometer: "pm³", "cubic picometer";
+    CubicFemtometer: "fm³", "cubic femtometer";
+    CubicAttometer: "am³", "cubic attometer";
+    CubicZeptometer: "zm³", "cubic zeptometer";
+    CubicYoctometer: "ym³", "cubic yoctometer";
 
-    // SI prefixed liters (subset)
+    // SI prefixed liters
+    Yottaliter: "YL", "yottaliter";
+    Zettaliter: "ZL", "zettaliter";
+    Exaliter: "EL", "exaliter";
+    Petaliter: "PL", "petaliter";
     Teraliter: "TL", "teraliter";
     Gigaliter: "GL", "gigaliter";
     Megaliter: "ML", "megaliter";
@@ -55,10 +76,14 @@ units! {
     Microliter: "µL", "microliter";
     Nanoliter: "nL", "nanoliter";
     Picoliter: "pL", "picoliter";
+    Femtoliter: "fL", "femtoliter";
+    Attoliter: "aL", "attoliter";
+    Zeptoliter: "zL", "zeptoliter";
+    Yoctoliter: "yL", "yoctoliter";
 
     // Conventional volume units
-    CubicFoot: "ft³", "cubic foot";
-    CubicInch: "in³", "cubic inch";
+    CubicFoot: "ft³", "cubic foot", "cubic feet";
+    CubicInch: "in³", "cubic inch", "cubic inches";
     CubicYard: "yd³", "cubic yard";
     Gallon: "gal", "gallon";
     QuartLiquid: "liq qt", "liquid quart";
@@ -66,12 +91,27 @@ units! {
     Cup: "cup", "cup";
     Tablespoon: "tbsp", "tablespoon";
     Teaspoon: "tsp", "teaspoon";
+    FluidOunce: "fl oz", "fluid ounce";
+
+    // Imperial volume units
+    GallonImperial: "gal (UK)", "Imperial gallon";
+    QuartImperial: "qt (UK)", "Imperial quart";
+    PintImperial: "pt (UK)", "Imperial pint";
+    FluidOunceImperial: "fl oz (UK)", "Imperial fluid ounce";
+
+    // Dry and oil-industry units
+    Bushel: "bu", "bushel";
+    Barrel: "bbl", "barrel";
 }
 
 // ===== CONVERSION RELATIONSHIPS =====
 
 // SI prefixed cubic meters (linear conversions)
 convert_linear! {
+    CubicYottameter => CubicMeter: YOTTA * YOTTA * YOTTA;
+    CubicZettameter => CubicMeter: ZETTA * ZETTA * ZETTA;
+    CubicExameter => CubicMeter: EXA * EXA * EXA;
+    CubicPetameter => CubicMeter: PETA * PETA * PETA;
     CubicTerameter => CubicMeter: TERA * TERA * TERA;
     CubicGigameter => CubicMeter: GIGA * GIGA * GIGA;
     CubicMegameter => CubicMeter: MEGA * MEGA * MEGA;
@@ -84,10 +124,18 @@ convert_linear! {
     CubicMicrometer => CubicMeter: MICRO * MICRO * MICRO;
     CubicNanometer => CubicMeter: NANO * NANO * NANO;
     CubicPicometer => CubicMeter: PICO * PICO * PICO;
+    CubicFemtometer => CubicMeter: FEMTO * FEMTO * FEMTO;
+    CubicAttometer => CubicMeter: ATTO * ATTO * ATTO;
+    CubicZeptometer => CubicMeter: ZEPTO * ZEPTO * ZEPTO;
+    CubicYoctometer => CubicMeter: YOCTO * YOCTO * YOCTO;
 }
 
 // SI prefixed liters (1 liter = 0.001 cubic meters)
 convert_linear! {
+    Yottaliter => CubicMeter: YOTTA * MILLI;
+    Zettaliter => CubicMeter: ZETTA * MILLI;
+    Exaliter => CubicMeter: EXA * MILLI;
+    Petaliter => CubicMeter: PETA * MILLI;
     Teraliter => CubicMeter: TERA * MILLI;
     Gigaliter => CubicMeter: GIGA * MILLI;
     Megaliter => CubicMeter: MEGA * MILLI;
@@ -101,6 +149,10 @@ convert_linear! {
     Microliter => CubicMeter: MICRO * MILLI;
     Nanoliter => CubicMeter: NANO * MILLI;
     Picoliter => CubicMeter: PICO * MILLI;
+    Femtoliter => CubicMeter: FEMTO * MILLI;
+    Attoliter => CubicMeter: ATTO * MILLI;
+    Zeptoliter => CubicMeter: ZEPTO * MILLI;
+    Yoctoliter => CubicMeter: YOCTO * MILLI;
 }
 
 // Conventional volume units (exact UOM conversion factors)
@@ -114,15 +166,43 @@ convert_linear! {
     Cup => CubicMeter: 2.365882E-4;
     Tablespoon => CubicMeter: 1.478676E-5;
     Teaspoon => CubicMeter: 4.928922E-6;
+    FluidOunce => CubicMeter: 2.957353E-5;
+}
+
+// Imperial volume units (imperial gallon defined as exactly 4.54609 L)
+convert_linear! {
+    GallonImperial => CubicMeter: 4.54609E-3;
+    QuartImperial => CubicMeter: 1.1365225E-3;
+    PintImperial => CubicMeter: 5.6826125E-4;
+    FluidOunceImperial => CubicMeter: 2.841306E-5;
+}
+
+// Dry and oil-industry units (exact UOM conversion factors)
+convert_linear! {
+    Bushel => CubicMeter: 3.523907E-2;
+    Barrel => CubicMeter: 1.589873E-1;
 }
 
-convert_matrix! {
-    CubicMeter => CubicTerameter, CubicGigameter, CubicMegameter, CubicKilometer,
-        CubicHectometer, CubicDecameter, CubicDecimeter, CubicCentimeter, CubicMillimeter,
-        CubicMicrometer, CubicNanometer, CubicPicometer, Teraliter, Gigaliter, Megaliter,
-        Kiloliter, Hectoliter, Decaliter, Liter, Deciliter, Centiliter, Milliliter,
-        Microliter, Nanoliter, Picoliter, CubicFoot, CubicInch, CubicYard, Gallon,
-        QuartLiquid, PintLiquid, Cup, Tablespoon, Teaspoon
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    CubicMeter => CubicYottameter, CubicZettameter, CubicExameter, CubicPetameter, CubicTerameter,
+                  CubicGigameter, CubicMegameter, CubicKilometer, CubicMillimeter, CubicMicrometer,
+                  CubicNanometer, CubicPicometer, CubicFemtometer, CubicAttometer, CubicZeptometer,
+                  CubicYoctometer
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    CubicMeter => CubicYottameter, CubicZettameter, CubicExameter, CubicPetameter, CubicTerameter,
+                  CubicGigameter, CubicMegameter, CubicKilometer, CubicHectometer, CubicDecameter,
+                  CubicDecimeter, CubicCentimeter, CubicMillimeter, CubicMicrometer, CubicNanometer,
+                  CubicPicometer, CubicFemtometer, CubicAttometer, CubicZeptometer, CubicYoctometer,
+                  Yottaliter, Zettaliter, Exaliter, Petaliter, Teraliter, Gigaliter, Megaliter,
+                  Kiloliter, Hectoliter, Decaliter, Liter, Deciliter, Centiliter, Milliliter,
+                  Microliter, Nanoliter, Picoliter, Femtoliter, Attoliter, Zeptoliter, Yoctoliter,
+                  CubicFoot, CubicInch, CubicYard, Gallon, QuartLiquid, PintLiquid, Cup, Tablespoon,
+                  Teaspoon, FluidOunce, GallonImperial, QuartImperial, PintImperial,
+                  FluidOunceImperial, Bushel, Barrel
 }
 
 // Volume quantity definition (Length³)
@@ -151,6 +231,10 @@ mod tests {
     }
 
     // Test SI prefixed cubic meters
+    test_uom_volume!(CubicYottameter, cubic_yottameter);
+    test_uom_volume!(CubicZettameter, cubic_zettameter);
+    test_uom_volume!(CubicExameter, cubic_exameter);
+    test_uom_volume!(CubicPetameter, cubic_petameter);
     test_uom_volume!(CubicTerameter, cubic_terameter);
     test_uom_volume!(CubicGigameter, cubic_gigameter);
     test_uom_volume!(CubicMegameter, cubic_megameter);
@@ -164,8 +248,16 @@ mod tests {
     test_uom_volume!(CubicMicrometer, cubic_micrometer);
     test_uom_volume!(CubicNanometer, cubic_nanometer);
     test_uom_volume!(CubicPicometer, cubic_picometer);
+    test_uom_volume!(CubicFemtometer, cubic_femtometer);
+    test_uom_volume!(CubicAttometer, cubic_attometer);
+    test_uom_volume!(CubicZeptometer, cubic_zeptometer);
+    test_uom_volume!(CubicYoctometer, cubic_yoctometer);
 
     // Test SI prefixed liters
+    test_uom_volume!(Yottaliter, yottaliter);
+    test_uom_volume!(Zettaliter, zettaliter);
+    test_uom_volume!(Exaliter, exaliter);
+    test_uom_volume!(Petaliter, petaliter);
     test_uom_volume!(Teraliter, teraliter);
     test_uom_volume!(Gigaliter, gigaliter);
     test_uom_volume!(Megaliter, megaliter);
@@ -179,6 +271,10 @@ mod tests {
     test_uom_volume!(Microliter, microliter);
     test_uom_volume!(Nanoliter, nanoliter);
     test_uom_volume!(Picoliter, picoliter);
+    test_uom_volume!(Femtoliter, femtoliter);
+    test_uom_volume!(Attoliter, attoliter);
+    test_uom_volume!(Zeptoliter, zeptoliter);
+    test_uom_volume!(Yoctoliter, yoctoliter);
 
     // Test conventional volume units
     test_uom_volume!(CubicFoot, cubic_foot);
@@ -190,4 +286,32 @@ mod tests {
     test_uom_volume!(Cup, cup);
     test_uom_volume!(Tablespoon, tablespoon);
     test_uom_volume!(Teaspoon, teaspoon);
+    test_uom_volume!(FluidOunce, fluid_ounce);
+
+    // Test imperial volume units
+    test_uom_volume!(GallonImperial, gallon_imperial);
+    test_uom_volume!(FluidOunceImperial, fluid_ounce_imperial);
+
+    // Test dry and oil-industry units
+    test_uom_volume!(Bushel, bushel);
+    test_uom_volume!(Barrel, barrel);
+
+    #[test]
+    fn test_imperial_subdivisions() {
+        use crate::si::volume::*;
+
+        let gallon = Volume::from::<GallonImperial>(1.0);
+        assert!((gallon.to::<QuartImperial>() - 4.0_f64).abs() < 1e-12);
+        assert!((gallon.to::<PintImperial>() - 8.0_f64).abs() < 1e-12);
+        assert!((gallon.to::<FluidOunceImperial>() - 160.0_f64).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_barrel_in_gallons() {
+        use crate::si::volume::*;
+
+        let barrel = Volume::from::<Barrel>(1.0);
+        assert!((barrel.to::<Gallon>() - 42.0_f64).abs() < 1e-4);
+        assert!((barrel.to::<Liter>() - 158.987_3_f64).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,189 @@
+/// # Volume Charge Density Units - SI Volume Charge Density Measurements
+///
+/// This module defines SI volume charge density units and their conversions. Volume charge
+/// density is the electric charge per unit volume, written ρ in Maxwell's equations, with
+/// coulomb per cubic meter as the SI base unit.
+///
+/// ## Base Unit
+///
+/// - **CoulombPerCubicMeter (C/m³)**: The SI base unit of volume charge density
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for coulombs per cubic meter:
+/// - **YottacoulombPerCubicMeter (YC/m³)**: 10²⁴ coulombs per cubic meter
+/// - ... down to ...
+/// - **YoctocoulombPerCubicMeter (yC/m³)**: 10⁻²⁴ coulombs per cubic meter
+///
+/// ## Other Units
+///
+/// - **CoulombPerCubicCentimeter (C/cm³)**: 10⁶ coulombs per cubic meter
+/// - **StatcoulombPerCubicCentimeter (statC/cm³)**: Gaussian CGS unit, ≈ 3.336 × 10⁻⁴ C/m³
+/// - **AbcoulombPerCubicCentimeter (abC/cm³)**: Electromagnetic CGS unit, 10⁷ C/m³
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::volume_charge_density::{CoulombPerCubicMeter, StatcoulombPerCubicCentimeter, VolumeChargeDensity};
+///
+/// // Create volume charge density quantities
+/// let plasma = VolumeChargeDensity::from::<StatcoulombPerCubicCentimeter>(2.0);
+///
+/// // Convert between units
+/// let plasma_si = plasma.to::<CoulombPerCubicMeter>(); // ≈ 6.671e-4 C/m³
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, KILO, MEGA, MICRO, MILLI, NANO, PETA, PICO,
+    TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// Gaussian and electromagnetic CGS charge units in coulombs
+const STATCOULOMB: f64 = 1.0 / 2.997_924_58_E9;
+const ABCOULOMB: f64 = 10.0;
+
+// SI base unit
+units! {
+    CoulombPerCubicMeter: "C/m³", "coulomb per cubic meter", "coulombs per cubic meter";
+}
+
+// SI prefixed coulombs per cubic meter
+units! {
+    YottacoulombPerCubicMeter: "YC/m³", "yottacoulomb per cubic meter", "yottacoulombs per cubic meter";
+    ZettacoulombPerCubicMeter: "ZC/m³", "zettacoulomb per cubic meter", "zettacoulombs per cubic meter";
+    ExacoulombPerCubicMeter: "EC/m³", "exacoulomb per cubic meter", "exacoulombs per cubic meter";
+    PetacoulombPerCubicMeter: "PC/m³", "petacoulomb per cubic meter", "petacoulombs per cubic meter";
+    TeracoulombPerCubicMeter: "TC/m³", "teracoulomb per cubic meter", "teracoulombs per cubic meter";
+    GigacoulombPerCubicMeter: "GC/m³", "gigacoulomb per cubic meter", "gigacoulombs per cubic meter";
+    MegacoulombPerCubicMeter: "MC/m³", "megacoulomb per cubic meter", "megacoulombs per cubic meter";
+    KilocoulombPerCubicMeter: "kC/m³", "kilocoulomb per cubic meter", "kilocoulombs per cubic meter";
+    HectocoulombPerCubicMeter: "hC/m³", "hectocoulomb per cubic meter", "hectocoulombs per cubic meter";
+    DecacoulombPerCubicMeter: "daC/m³", "decacoulomb per cubic meter", "decacoulombs per cubic meter";
+    DecicoulombPerCubicMeter: "dC/m³", "decicoulomb per cubic meter", "decicoulombs per cubic meter";
+    CenticoulombPerCubicMeter: "cC/m³", "centicoulomb per cubic meter", "centicoulombs per cubic meter";
+    MillicoulombPerCubicMeter: "mC/m³", "millicoulomb per cubic meter", "millicoulombs per cubic meter";
+    MicrocoulombPerCubicMeter: "µC/m³", "microcoulomb per cubic meter", "microcoulombs per cubic meter";
+    NanocoulombPerCubicMeter: "nC/m³", "nanocoulomb per cubic meter", "nanocoulombs per cubic meter";
+    PicocoulombPerCubicMeter: "pC/m³", "picocoulomb per cubic meter", "picocoulombs per cubic meter";
+    FemtocoulombPerCubicMeter: "fC/m³", "femtocoulomb per cubic meter", "femtocoulombs per cubic meter";
+    AttocoulombPerCubicMeter: "aC/m³", "attocoulomb per cubic meter", "attocoulombs per cubic meter";
+    ZeptocoulombPerCubicMeter: "zC/m³", "zeptocoulomb per cubic meter", "zeptocoulombs per cubic meter";
+    YoctocoulombPerCubicMeter: "yC/m³", "yoctocoulomb per cubic meter", "yoctocoulombs per cubic meter";
+}
+
+// Other units
+units! {
+    CoulombPerCubicCentimeter: "C/cm³", "coulomb per cubic centimeter", "coulombs per cubic centimeter";
+    StatcoulombPerCubicCentimeter: "statC/cm³", "statcoulomb per cubic centimeter", "statcoulombs per cubic centimeter";
+    AbcoulombPerCubicCentimeter: "abC/cm³", "abcoulomb per cubic centimeter", "abcoulombs per cubic centimeter";
+}
+
+// Unit conversions using convert_linear!
+crate::convert_linear! {
+    // SI prefixed coulombs per cubic meter
+    YottacoulombPerCubicMeter => CoulombPerCubicMeter: YOTTA;
+    ZettacoulombPerCubicMeter => CoulombPerCubicMeter: ZETTA;
+    ExacoulombPerCubicMeter => CoulombPerCubicMeter: EXA;
+    PetacoulombPerCubicMeter => CoulombPerCubicMeter: PETA;
+    TeracoulombPerCubicMeter => CoulombPerCubicMeter: TERA;
+    GigacoulombPerCubicMeter => CoulombPerCubicMeter: GIGA;
+    MegacoulombPerCubicMeter => CoulombPerCubicMeter: MEGA;
+    KilocoulombPerCubicMeter => CoulombPerCubicMeter: KILO;
+    HectocoulombPerCubicMeter => CoulombPerCubicMeter: HECTO;
+    DecacoulombPerCubicMeter => CoulombPerCubicMeter: DECA;
+    DecicoulombPerCubicMeter => CoulombPerCubicMeter: DECI;
+    CenticoulombPerCubicMeter => CoulombPerCubicMeter: CENTI;
+    MillicoulombPerCubicMeter => CoulombPerCubicMeter: MILLI;
+    MicrocoulombPerCubicMeter => CoulombPerCubicMeter: MICRO;
+    NanocoulombPerCubicMeter => CoulombPerCubicMeter: NANO;
+    PicocoulombPerCubicMeter => CoulombPerCubicMeter: PICO;
+    FemtocoulombPerCubicMeter => CoulombPerCubicMeter: FEMTO;
+    AttocoulombPerCubicMeter => CoulombPerCubicMeter: ATTO;
+    ZeptocoulombPerCubicMeter => CoulombPerCubicMeter: ZEPTO;
+    YoctocoulombPerCubicMeter => CoulombPerCubicMeter: YOCTO;
+
+    // Other units
+    CoulombPerCubicCentimeter => CoulombPerCubicMeter: 1.0 / CENTI / CENTI / CENTI;
+    StatcoulombPerCubicCentimeter => CoulombPerCubicMeter: STATCOULOMB / CENTI / CENTI / CENTI;
+    AbcoulombPerCubicCentimeter => CoulombPerCubicMeter: ABCOULOMB / CENTI / CENTI / CENTI;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    CoulombPerCubicMeter => YottacoulombPerCubicMeter, ZettacoulombPerCubicMeter,
+                            ExacoulombPerCubicMeter, PetacoulombPerCubicMeter,
+                            TeracoulombPerCubicMeter, GigacoulombPerCubicMeter,
+                            MegacoulombPerCubicMeter, KilocoulombPerCubicMeter,
+                            MillicoulombPerCubicMeter, MicrocoulombPerCubicMeter,
+                            NanocoulombPerCubicMeter, PicocoulombPerCubicMeter,
+                            FemtocoulombPerCubicMeter, AttocoulombPerCubicMeter,
+                            ZeptocoulombPerCubicMeter, YoctocoulombPerCubicMeter
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    CoulombPerCubicMeter => YottacoulombPerCubicMeter, ZettacoulombPerCubicMeter,
+                            ExacoulombPerCubicMeter, PetacoulombPerCubicMeter,
+                            TeracoulombPerCubicMeter, GigacoulombPerCubicMeter,
+                            MegacoulombPerCubicMeter, KilocoulombPerCubicMeter,
+                            HectocoulombPerCubicMeter, DecacoulombPerCubicMeter,
+                            DecicoulombPerCubicMeter, CenticoulombPerCubicMeter,
+                            MillicoulombPerCubicMeter, MicrocoulombPerCubicMeter,
+                            NanocoulombPerCubicMeter, PicocoulombPerCubicMeter,
+                            FemtocoulombPerCubicMeter, AttocoulombPerCubicMeter,
+                            ZeptocoulombPerCubicMeter, YoctocoulombPerCubicMeter,
+                            CoulombPerCubicCentimeter, StatcoulombPerCubicCentimeter,
+                            AbcoulombPerCubicCentimeter
+}
+
+// Volume Charge Density quantity definition (Charge/Length³)
+use super::{ISQ, SiScale};
+quantity!(VolumeChargeDensity, ISQ<N3, Z0, P1, P1, Z0, Z0, Z0>, SiScale, CoulombPerCubicMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_volume_charge_density {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::volume_charge_density,
+                uom::si::electric_charge_volumetric_density,
+                VolumeChargeDensity,
+                ElectricChargeVolumetricDensity,
+                CoulombPerCubicMeter,
+                $num_units_unit,
+                coulomb_per_cubic_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_volume_charge_density!(CoulombPerCubicMeter, coulomb_per_cubic_meter);
+    test_uom_volume_charge_density!(CoulombPerCubicCentimeter, coulomb_per_cubic_centimeter);
+
+    #[test]
+    fn test_prefixed_units() {
+        use crate::si::volume_charge_density::*;
+
+        let density = VolumeChargeDensity::from::<MillicoulombPerCubicMeter>(1.0);
+        assert_eq!(density.to::<CoulombPerCubicMeter>(), 1.0e-3);
+        let density = VolumeChargeDensity::from::<KilocoulombPerCubicMeter>(1.0);
+        assert_eq!(density.to::<CoulombPerCubicMeter>(), 1000.0);
+    }
+
+    #[test]
+    fn test_cgs_units() {
+        use crate::si::volume_charge_density::*;
+
+        let gaussian = VolumeChargeDensity::from::<StatcoulombPerCubicCentimeter>(2.997_924_58e9);
+        assert!((gaussian.to::<CoulombPerCubicCentimeter>() - 1.0_f64).abs() < 1e-12);
+
+        let electromagnetic = VolumeChargeDensity::from::<AbcoulombPerCubicCentimeter>(1.0);
+        assert!((electromagnetic.to::<CoulombPerCubicCentimeter>() - 10.0_f64).abs() < 1e-12);
+    }
+}
+
+// Re-export types for convenience
+pub use volume_charge_density::VolumeChargeDensity;
+pub use volume_charge_density::*;
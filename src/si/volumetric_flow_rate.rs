@@ -0,0 +1,204 @@
+/// # Volumetric Flow Rate Units - SI Volumetric Flow Rate Measurements
+///
+/// This module defines SI volumetric flow rate units and their conversions. Volumetric flow
+/// rate is the volume of fluid passing per unit of time, with cubic meter per second as the
+/// SI base unit.
+///
+/// ## Base Unit
+///
+/// - **CubicMeterPerSecond (m³/s)**: The SI base unit of volumetric flow rate
+///
+/// ## SI Prefixed Units
+///
+/// All SI prefixes from yocto- to yotta- are supported for liters per second:
+/// - **YottaliterPerSecond (YL/s)**: 10²¹ cubic meters per second
+/// - ... down to ...
+/// - **YoctoliterPerSecond (yL/s)**: 10⁻²⁷ cubic meters per second
+///
+/// ## Other Units
+///
+/// - **CubicMeterPerMinute (m³/min)**, **CubicMeterPerHour (m³/h)**: Metric plant rates
+/// - **LiterPerMinute (L/min)**, **LiterPerHour (L/h)**: Pump and dosing rates
+/// - **GallonPerSecond (gal/s)**, **GallonPerMinute (gal/min)**: US liquid gallon rates
+/// - **CubicFootPerSecond (ft³/s)**: 0.02831685 cubic meters per second
+/// - **CubicFootPerMinute (ft³/min)**: Air flow rate commonly written as CFM
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::volumetric_flow_rate::{CubicFootPerMinute, LiterPerMinute, VolumetricFlowRate};
+///
+/// // Create volumetric flow rate quantities
+/// let coolant = VolumetricFlowRate::from::<LiterPerMinute>(12.0);
+///
+/// // Convert between units
+/// let coolant_cfm = coolant.to::<CubicFootPerMinute>(); // ≈ 0.424 ft³/min
+/// ```
+use crate::prefix::{
+    ATTO, CENTI, DECA, DECI, EXA, FEMTO, GIGA, HECTO, HOUR, KILO, MEGA, MICRO, MILLI, MINUTE, NANO,
+    PETA, PICO, TERA, YOCTO, YOTTA, ZEPTO, ZETTA,
+};
+use typenum::*;
+
+// SI base unit
+units! {
+    CubicMeterPerSecond: "m³/s", "cubic meter per second", "cubic meters per second";
+}
+
+// SI prefixed liters per second
+units! {
+    YottaliterPerSecond: "YL/s", "yottaliter per second", "yottaliters per second";
+    ZettaliterPerSecond: "ZL/s", "zettaliter per second", "zettaliters per second";
+    ExaliterPerSecond: "EL/s", "exaliter per second", "exaliters per second";
+    PetaliterPerSecond: "PL/s", "petaliter per second", "petaliters per second";
+    TeraliterPerSecond: "TL/s", "teraliter per second", "teraliters per second";
+    GigaliterPerSecond: "GL/s", "gigaliter per second", "gigaliters per second";
+    MegaliterPerSecond: "ML/s", "megaliter per second", "megaliters per second";
+    KiloliterPerSecond: "kL/s", "kiloliter per second", "kiloliters per second";
+    HectoliterPerSecond: "hL/s", "hectoliter per second", "hectoliters per second";
+    DecaliterPerSecond: "daL/s", "decaliter per second", "decaliters per second";
+    LiterPerSecond: "L/s", "liter per second", "liters per second";
+    DeciliterPerSecond: "dL/s", "deciliter per second", "deciliters per second";
+    CentiliterPerSecond: "cL/s", "centiliter per second", "centiliters per second";
+    MilliliterPerSecond: "mL/s", "milliliter per second", "milliliters per second";
+    MicroliterPerSecond: "µL/s", "microliter per second", "microliters per second";
+    NanoliterPerSecond: "nL/s", "nanoliter per second", "nanoliters per second";
+    PicoliterPerSecond: "pL/s", "picoliter per second", "picoliters per second";
+    FemtoliterPerSecond: "fL/s", "femtoliter per second", "femtoliters per second";
+    AttoliterPerSecond: "aL/s", "attoliter per second", "attoliters per second";
+    ZeptoliterPerSecond: "zL/s", "zeptoliter per second", "zeptoliters per second";
+    YoctoliterPerSecond: "yL/s", "yoctoliter per second", "yoctoliters per second";
+}
+
+// Other units
+units! {
+    CubicMeterPerMinute: "m³/min", "cubic meter per minute", "cubic meters per minute";
+    CubicMeterPerHour: "m³/h", "cubic meter per hour", "cubic meters per hour";
+    LiterPerMinute: "L/min", "liter per minute", "liters per minute";
+    LiterPerHour: "L/h", "liter per hour", "liters per hour";
+    GallonPerSecond: "gal/s", "gallon per second", "gallons per second";
+    GallonPerMinute: "gal/min", "gallon per minute", "gallons per minute";
+    CubicFootPerSecond: "ft³/s", "cubic foot per second", "cubic feet per second";
+    CubicFootPerMinute: "ft³/min", "cubic foot per minute", "cubic feet per minute";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // SI prefixed liters per second
+    YottaliterPerSecond => CubicMeterPerSecond: MILLI * YOTTA;
+    ZettaliterPerSecond => CubicMeterPerSecond: MILLI * ZETTA;
+    ExaliterPerSecond => CubicMeterPerSecond: MILLI * EXA;
+    PetaliterPerSecond => CubicMeterPerSecond: MILLI * PETA;
+    TeraliterPerSecond => CubicMeterPerSecond: MILLI * TERA;
+    GigaliterPerSecond => CubicMeterPerSecond: MILLI * GIGA;
+    MegaliterPerSecond => CubicMeterPerSecond: MILLI * MEGA;
+    KiloliterPerSecond => CubicMeterPerSecond: MILLI * KILO;
+    HectoliterPerSecond => CubicMeterPerSecond: MILLI * HECTO;
+    DecaliterPerSecond => CubicMeterPerSecond: MILLI * DECA;
+    LiterPerSecond => CubicMeterPerSecond: MILLI;
+    DeciliterPerSecond => CubicMeterPerSecond: MILLI * DECI;
+    CentiliterPerSecond => CubicMeterPerSecond: MILLI * CENTI;
+    MilliliterPerSecond => CubicMeterPerSecond: MILLI * MILLI;
+    MicroliterPerSecond => CubicMeterPerSecond: MILLI * MICRO;
+    NanoliterPerSecond => CubicMeterPerSecond: MILLI * NANO;
+    PicoliterPerSecond => CubicMeterPerSecond: MILLI * PICO;
+    FemtoliterPerSecond => CubicMeterPerSecond: MILLI * FEMTO;
+    AttoliterPerSecond => CubicMeterPerSecond: MILLI * ATTO;
+    ZeptoliterPerSecond => CubicMeterPerSecond: MILLI * ZEPTO;
+    YoctoliterPerSecond => CubicMeterPerSecond: MILLI * YOCTO;
+
+    // Other units
+    CubicMeterPerMinute => CubicMeterPerSecond: 1.0 / MINUTE;
+    CubicMeterPerHour => CubicMeterPerSecond: 1.0 / HOUR;
+    LiterPerMinute => CubicMeterPerSecond: MILLI / MINUTE;
+    LiterPerHour => CubicMeterPerSecond: MILLI / HOUR;
+    GallonPerSecond => CubicMeterPerSecond: 3.785_412_E-3;
+    GallonPerMinute => CubicMeterPerSecond: 3.785_412_E-3 / MINUTE;
+    CubicFootPerSecond => CubicMeterPerSecond: 2.831_685_E-2;
+    CubicFootPerMinute => CubicMeterPerSecond: 2.831_685_E-2 / MINUTE;
+}
+
+// The prefixed liter-per-second units are a separate family from m³/s, so
+// `Quantity::format_si` keeps the base unit rather than mixing symbols
+crate::si_prefixed!(CubicMeterPerSecond);
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    CubicMeterPerSecond => YottaliterPerSecond, ZettaliterPerSecond, ExaliterPerSecond,
+                           PetaliterPerSecond, TeraliterPerSecond, GigaliterPerSecond,
+                           MegaliterPerSecond, KiloliterPerSecond, HectoliterPerSecond,
+                           DecaliterPerSecond, LiterPerSecond, DeciliterPerSecond,
+                           CentiliterPerSecond, MilliliterPerSecond, MicroliterPerSecond,
+                           NanoliterPerSecond, PicoliterPerSecond, FemtoliterPerSecond,
+                           AttoliterPerSecond, ZeptoliterPerSecond, YoctoliterPerSecond,
+                           CubicMeterPerMinute, CubicMeterPerHour, LiterPerMinute, LiterPerHour,
+                           GallonPerSecond, GallonPerMinute, CubicFootPerSecond, CubicFootPerMinute
+}
+
+// Volumetric flow rate quantity definition (Length³/Time)
+use super::{ISQ, SiScale};
+quantity!(VolumetricFlowRate, ISQ<P3, Z0, N1, Z0, Z0, Z0, Z0>, SiScale, CubicMeterPerSecond);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_volumetric_flow_rate {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::volumetric_flow_rate,
+                uom::si::volume_rate,
+                VolumetricFlowRate,
+                VolumeRate,
+                CubicMeterPerSecond,
+                $num_units_unit,
+                cubic_meter_per_second,
+                $uom_unit
+            );
+        };
+    }
+
+    // Test SI prefixed liters per second
+    test_uom_volumetric_flow_rate!(YottaliterPerSecond, yottaliter_per_second);
+    test_uom_volumetric_flow_rate!(ZettaliterPerSecond, zettaliter_per_second);
+    test_uom_volumetric_flow_rate!(ExaliterPerSecond, exaliter_per_second);
+    test_uom_volumetric_flow_rate!(PetaliterPerSecond, petaliter_per_second);
+    test_uom_volumetric_flow_rate!(TeraliterPerSecond, teraliter_per_second);
+    test_uom_volumetric_flow_rate!(GigaliterPerSecond, gigaliter_per_second);
+    test_uom_volumetric_flow_rate!(MegaliterPerSecond, megaliter_per_second);
+    test_uom_volumetric_flow_rate!(KiloliterPerSecond, kiloliter_per_second);
+    test_uom_volumetric_flow_rate!(HectoliterPerSecond, hectoliter_per_second);
+    test_uom_volumetric_flow_rate!(DecaliterPerSecond, decaliter_per_second);
+    test_uom_volumetric_flow_rate!(LiterPerSecond, liter_per_second);
+    test_uom_volumetric_flow_rate!(DeciliterPerSecond, deciliter_per_second);
+    test_uom_volumetric_flow_rate!(CentiliterPerSecond, centiliter_per_second);
+    test_uom_volumetric_flow_rate!(MilliliterPerSecond, milliliter_per_second);
+    test_uom_volumetric_flow_rate!(MicroliterPerSecond, microliter_per_second);
+    test_uom_volumetric_flow_rate!(NanoliterPerSecond, nanoliter_per_second);
+    test_uom_volumetric_flow_rate!(PicoliterPerSecond, picoliter_per_second);
+    test_uom_volumetric_flow_rate!(FemtoliterPerSecond, femtoliter_per_second);
+    test_uom_volumetric_flow_rate!(AttoliterPerSecond, attoliter_per_second);
+    test_uom_volumetric_flow_rate!(ZeptoliterPerSecond, zeptoliter_per_second);
+    test_uom_volumetric_flow_rate!(YoctoliterPerSecond, yoctoliter_per_second);
+
+    // Test other units
+    test_uom_volumetric_flow_rate!(CubicMeterPerMinute, cubic_meter_per_minute);
+    test_uom_volumetric_flow_rate!(CubicMeterPerHour, cubic_meter_per_hour);
+    test_uom_volumetric_flow_rate!(LiterPerMinute, liter_per_minute);
+    test_uom_volumetric_flow_rate!(GallonPerSecond, gallon_per_second);
+    test_uom_volumetric_flow_rate!(GallonPerMinute, gallon_per_minute);
+    test_uom_volumetric_flow_rate!(CubicFootPerSecond, cubic_foot_per_second);
+    test_uom_volumetric_flow_rate!(CubicFootPerMinute, cubic_foot_per_minute);
+
+    #[test]
+    fn test_liter_per_hour() {
+        use crate::si::volumetric_flow_rate::*;
+
+        let flow = VolumetricFlowRate::from::<LiterPerMinute>(1.0);
+        assert!((flow.to::<LiterPerHour>() - 60.0_f64).abs() < 1e-9);
+    }
+}
+
+// Re-export types for convenience
+pub use volumetric_flow_rate::VolumetricFlowRate;
+pub use volumetric_flow_rate::*;
@@ -0,0 +1,134 @@
+/// # Wavenumber Units - SI Reciprocal Length Measurements
+///
+/// This module defines wavenumber units and their conversions. Wavenumber is the spatial
+/// frequency of a wave, the number of wavelengths per unit distance, with reciprocal meter as
+/// the SI base unit. Infrared and Raman spectroscopy report positions in reciprocal
+/// centimeters (kaysers).
+///
+/// ## Base Unit
+///
+/// - **ReciprocalMeter (m⁻¹)**: The SI base unit of wavenumber
+///
+/// ## Other Units
+///
+/// - **ReciprocalKilometer (km⁻¹)**: 10⁻³ reciprocal meters
+/// - **ReciprocalDecimeter (dm⁻¹)**: 10 reciprocal meters
+/// - **ReciprocalCentimeter (cm⁻¹)**: 100 reciprocal meters
+/// - **Kayser (K)**: The CGS unit of wavenumber, equal to one reciprocal centimeter
+/// - **ReciprocalMillimeter (mm⁻¹)**: 10³ reciprocal meters
+/// - **ReciprocalMicrometer (µm⁻¹)**: 10⁶ reciprocal meters
+/// - **ReciprocalNanometer (nm⁻¹)**: 10⁹ reciprocal meters
+/// - **ReciprocalAngstrom (Å⁻¹)**: 10¹⁰ reciprocal meters
+/// - **Diopter (dpt)**: Optical power of a lens, equal to one reciprocal meter
+///
+/// ## Usage
+///
+/// ```rust,ignore
+/// use num_units::si::wavenumber::{Kayser, ReciprocalMicrometer, Wavenumber};
+///
+/// // C=O stretch band in an IR spectrum
+/// let band = Wavenumber::from::<Kayser>(1715.0);
+///
+/// // Convert between units
+/// let band_um = band.to::<ReciprocalMicrometer>(); // 0.1715 µm⁻¹
+/// ```
+use crate::prefix::{CENTI, DECI, KILO, MICRO, MILLI, NANO};
+use typenum::*;
+
+// SI base unit
+units! {
+    ReciprocalMeter: "m⁻¹", "reciprocal meter", "reciprocal meters";
+}
+
+// Other units
+units! {
+    ReciprocalKilometer: "km⁻¹", "reciprocal kilometer", "reciprocal kilometers";
+    ReciprocalDecimeter: "dm⁻¹", "reciprocal decimeter", "reciprocal decimeters";
+    ReciprocalCentimeter: "cm⁻¹", "reciprocal centimeter", "reciprocal centimeters";
+    Kayser: "K", "kayser", "kaysers";
+    ReciprocalMillimeter: "mm⁻¹", "reciprocal millimeter", "reciprocal millimeters";
+    ReciprocalMicrometer: "µm⁻¹", "reciprocal micrometer", "reciprocal micrometers";
+    ReciprocalNanometer: "nm⁻¹", "reciprocal nanometer", "reciprocal nanometers";
+    ReciprocalAngstrom: "Å⁻¹", "reciprocal ångström", "reciprocal ångströms";
+    Diopter: "dpt", "diopter", "diopters";
+}
+
+// Unit conversions using convert_linear! with exact UOM coefficients
+crate::convert_linear! {
+    // Other units
+    ReciprocalKilometer => ReciprocalMeter: 1.0 / KILO;
+    ReciprocalDecimeter => ReciprocalMeter: 1.0 / DECI;
+    ReciprocalCentimeter => ReciprocalMeter: 1.0 / CENTI;
+    Kayser => ReciprocalMeter: 1.0 / CENTI;
+    ReciprocalMillimeter => ReciprocalMeter: 1.0 / MILLI;
+    ReciprocalMicrometer => ReciprocalMeter: 1.0 / MICRO;
+    ReciprocalNanometer => ReciprocalMeter: 1.0 / NANO;
+    ReciprocalAngstrom => ReciprocalMeter: 1.0 / 1.0_E-10;
+    Diopter => ReciprocalMeter: 1.0;
+}
+
+// Engineering-prefixed units considered by `Quantity::format_si`
+crate::si_prefixed! {
+    ReciprocalMeter => ReciprocalKilometer, ReciprocalMillimeter, ReciprocalMicrometer,
+                       ReciprocalNanometer
+}
+
+// Units looked up by symbol or name at runtime
+crate::unit_registry! {
+    ReciprocalMeter => ReciprocalKilometer, ReciprocalDecimeter, ReciprocalCentimeter, Kayser,
+                       ReciprocalMillimeter, ReciprocalMicrometer, ReciprocalNanometer,
+                       ReciprocalAngstrom, Diopter
+}
+
+// Wavenumber quantity definition (1/Length)
+use super::{ISQ, SiScale};
+quantity!(Wavenumber, ISQ<N1, Z0, Z0, Z0, Z0, Z0, Z0>, SiScale, ReciprocalMeter);
+
+// UOM compatibility tests
+#[cfg(test)]
+mod tests {
+
+    macro_rules! test_uom_wavenumber {
+        ($num_units_unit:ty, $uom_unit:ident) => {
+            crate::test_uom_compatibility!(
+                crate::si::wavenumber,
+                uom::si::reciprocal_length,
+                Wavenumber,
+                ReciprocalLength,
+                ReciprocalMeter,
+                $num_units_unit,
+                reciprocal_meter,
+                $uom_unit
+            );
+        };
+    }
+
+    test_uom_wavenumber!(ReciprocalMeter, reciprocal_meter);
+    test_uom_wavenumber!(ReciprocalKilometer, reciprocal_kilometer);
+    test_uom_wavenumber!(ReciprocalDecimeter, reciprocal_decimeter);
+    test_uom_wavenumber!(ReciprocalCentimeter, reciprocal_centimeter);
+    test_uom_wavenumber!(ReciprocalMillimeter, reciprocal_millimeter);
+    test_uom_wavenumber!(ReciprocalMicrometer, reciprocal_micrometer);
+    test_uom_wavenumber!(ReciprocalNanometer, reciprocal_nanometer);
+    test_uom_wavenumber!(ReciprocalAngstrom, reciprocal_angstrom);
+    test_uom_wavenumber!(Diopter, diopter);
+
+    #[test]
+    fn test_kayser() {
+        use crate::si::length::{Centimeter, Length};
+        use crate::si::scalar::{Scalar, Unitless};
+        use crate::si::wavenumber::*;
+
+        let band = Wavenumber::from::<Kayser>(1715.0);
+        assert_eq!(band.to::<ReciprocalCentimeter>(), 1715.0);
+        assert!((band.to::<ReciprocalMeter>() - 171_500.0_f64).abs() < 1e-9);
+
+        let per_length: Wavenumber<f64> =
+            Scalar::from::<Unitless>(1.0) / Length::from::<Centimeter>(4.0);
+        assert!((per_length.to::<Kayser>() - 0.25_f64).abs() < 1e-12);
+    }
+}
+
+// Re-export types for convenience
+pub use wavenumber::Wavenumber;
+pub use wavenumber::*;
@@ -103,8 +103,23 @@ macro_rules! system {
         pub struct $system_name;
 
         // Then create the scale type using the new dimension_scale! macro
-        ::paste::paste! {
+        $crate::paste::paste! {
             $crate::dimension_scale!([<$scale_name>], $($unit),+);
         }
+
+        // Expose the exponents for runtime dimension checks
+        impl<$($dim: $crate::typenum::Integer),+> $crate::system::DimensionVector for $system_name<$($dim),+> {
+            const EXPONENTS: &'static [i8] = &[$(<$dim as $crate::typenum::Integer>::I8),+];
+        }
     };
 }
+
+/// Runtime view of a dimension's exponents
+///
+/// Implemented by the `system!` macro for every dimension of the system. The
+/// exponents follow the order in which the dimensions were declared, e.g.
+/// `[L, M, T, I, TH, N, J]` for `ISQ`, so `ISQ<P1, Z0, N1, ...>` (velocity)
+/// yields `[1, 0, -1, 0, 0, 0, 0]`.
+pub trait DimensionVector {
+    const EXPONENTS: &'static [i8];
+}
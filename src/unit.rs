@@ -98,6 +98,10 @@ pub trait Unit {
 /// }
 /// ```
 ///
+/// The plural defaults to the singular with an "s" appended. Give it explicitly
+/// where that is wrong, e.g. for "foot" or "meter per second"; entries of both
+/// forms can be mixed in one invocation.
+///
 /// # Generated Code
 /// For each unit, this macro generates:
 /// - A unit struct with `Clone`, `Copy`, `Debug`, and `PartialEq` traits
@@ -120,8 +124,12 @@ pub trait Unit {
 /// ```
 #[macro_export]
 macro_rules! units {
-    // New syntax: abbreviation first, then singular, then optional plural
-    ($($(#[$unit_attr:meta])* $unit:ident: $abbrev:expr, $singular:expr, $plural:expr;)+) => {
+    // Plural name: explicit, or the singular with an "s" appended
+    (@plural $singular:expr) => { concat!($singular, "s") };
+    (@plural $singular:expr, $plural:expr) => { $plural };
+
+    // Abbreviation first, then singular, then optional plural
+    ($($(#[$unit_attr:meta])* $unit:ident: $abbrev:expr, $singular:expr $(, $plural:expr)?;)+) => {
         $(
             $(#[$unit_attr])*
             #[allow(non_camel_case_types)]
@@ -132,36 +140,17 @@ macro_rules! units {
             impl $crate::unit::Unit for $unit {
                 const ABBREVIATION: &'static str = $abbrev;
                 const SINGULAR: &'static str = $singular;
-                const PLURAL: &'static str = $plural;
+                const PLURAL: &'static str = $crate::units!(@plural $singular $(, $plural)?);
             }
 
             // Automatic identity conversion - unit to itself (generic over any type)
-            impl<V: num_traits::Num + Copy> $crate::unit::FromUnit<$unit, V> for $unit {
+            impl<V: $crate::num_traits::Num> $crate::unit::FromUnit<$unit, V> for $unit {
                 fn to_base(value: V) -> V { value }
                 fn from_base(base_value: V) -> V { base_value }
             }
-        )+
-    };
-
-    // New syntax: abbreviation first, then singular (plural auto-generated)
-    ($($(#[$unit_attr:meta])* $unit:ident: $abbrev:expr, $singular:expr;)+) => {
-        $(
-            $(#[$unit_attr])*
-            #[allow(non_camel_case_types)]
-            #[derive(Debug, Clone, PartialEq)]
-            #[doc = $abbrev]
-            pub struct $unit;
-
-            impl $crate::unit::Unit for $unit {
-                const ABBREVIATION: &'static str = $abbrev;
-                const SINGULAR: &'static str = $singular;
-                const PLURAL: &'static str = concat!($singular, "s");
-            }
 
-            // Automatic identity conversion - unit to itself (generic over any type)
-            impl<V: num_traits::Num + Copy> $crate::unit::FromUnit<$unit, V> for $unit {
-                fn to_base(value: V) -> V { value }
-                fn from_base(base_value: V) -> V { base_value }
+            impl $crate::unit::UnitFactor<$unit> for $unit {
+                const FACTOR: f64 = 1.0;
             }
         )+
     };
@@ -175,9 +164,38 @@ pub trait FromUnit<From: crate::unit::Unit, V: num_traits::Num = f64> {
     fn from_base(base_value: V) -> V;
 }
 
+/// Constant scale factor between two units related by `base = factor * unit`
+///
+/// Implemented alongside `FromUnit` by `convert_linear!` for pure scale
+/// conversions (no offset). `FACTOR` is the size of one `From` in `Self`, e.g.
+/// 1000.0 for `Meter: UnitFactor<Kilometer>`. Being a constant, it allows
+/// conversions in `const` contexts, see `Quantity::const_from`.
+pub trait UnitFactor<From: crate::unit::Unit> {
+    const FACTOR: f64;
+}
+
+/// Exact rational scale factor between two units, `base = unit * NUM / DEN`
+///
+/// Implemented by `convert_int_linear!`, e.g. `NUM = 1, DEN = 1_000_000` for
+/// `Meter: IntFactor<Micrometer>`. Integer value types convert with it by
+/// multiplying, then dividing in i128, so they never go through f64.
+pub trait IntFactor<From: crate::unit::Unit> {
+    const NUM: u128;
+    const DEN: u128;
+}
+
+/// Absolute (point) conversion between units related by `base = scale * unit + offset`
+///
+/// Implemented by `convert_affine!`. It is a separate path from `FromUnit`, which
+/// converts differences: a temperature of 20 °C is 293.15 K, while a rise of
+/// 20 °C is a rise of 20 K. See `Quantity::from_absolute`.
+pub trait AffineFromUnit<From: crate::unit::Unit, V = f64> {
+    fn to_base_point(value: V) -> V;
+    fn from_base_point(base_value: V) -> V;
+}
+
 /// Trait for converting to a base unit from this unit (generic over any numeric type)
 pub trait IntoUnit<To: crate::unit::Unit, V: num_traits::Num = f64> {
     fn to_base(value: V) -> V;
     fn from_base(base_value: V) -> V;
 }
-